@@ -41,4 +41,24 @@ pub(super) fn bench(c: &mut Criterion) {
             }
         }
     }
+
+    {
+        let mut group = c.benchmark_group("Bitmap::iter::filter_count");
+        let mut rng = SmallRng::seed_from_u64(1234);
+
+        for size in [12345] {
+            for null_fraction in [0., 0.5, 1.] {
+                let input = (0..size)
+                    .map(|_| rng.gen_bool(1. - null_fraction))
+                    .collect::<Vec<_>>();
+                let narrow_bitmap = Bitmap::<BoxBuffer>::from_iter(&input);
+                group.throughput(Throughput::Elements(size as u64));
+                group.bench_with_input(
+                    BenchmarkId::new("narrow", format!("{size}/{null_fraction}")),
+                    &(),
+                    |b, _| b.iter(|| narrow_bitmap.iter().filter(|bit| *bit).count()),
+                );
+            }
+        }
+    }
 }
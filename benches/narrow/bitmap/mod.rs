@@ -1,7 +1,9 @@
 use criterion::Criterion;
 
 mod iter;
+mod words;
 
 pub(super) fn bench(c: &mut Criterion) {
     iter::bench(c);
+    words::bench(c);
 }
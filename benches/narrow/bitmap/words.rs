@@ -0,0 +1,39 @@
+use criterion::{BenchmarkId, Criterion, Throughput};
+use narrow::{bitmap::Bitmap, buffer::VecBuffer};
+use rand::{prelude::SmallRng, Rng, SeedableRng};
+
+pub(super) fn bench(c: &mut Criterion) {
+    let mut rng = SmallRng::seed_from_u64(1234);
+    let size = 12345;
+    let left = (0..size).map(|_| rng.gen_bool(0.5)).collect::<Bitmap>();
+    let right = (0..size).map(|_| rng.gen_bool(0.5)).collect::<Bitmap>();
+    let left_words = Bitmap::<VecBuffer, u64>::from_bytes(&left);
+    let right_words = Bitmap::<VecBuffer, u64>::from_bytes(&right);
+
+    {
+        let mut group = c.benchmark_group("Bitmap::count_ones");
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("u8", size), &(), |b, _| {
+            b.iter(|| left.iter().filter(|bit| *bit).count())
+        });
+        group.bench_with_input(BenchmarkId::new("u64", size), &(), |b, _| {
+            b.iter(|| left_words.count_ones())
+        });
+    }
+
+    {
+        let mut group = c.benchmark_group("Bitmap::and");
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("u8", size), &(), |b, _| {
+            b.iter(|| {
+                left.iter()
+                    .zip(&right)
+                    .map(|(l, r)| l && r)
+                    .collect::<Bitmap>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("u64", size), &(), |b, _| {
+            b.iter(|| left_words.and(&right_words))
+        });
+    }
+}
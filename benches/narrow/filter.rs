@@ -0,0 +1,44 @@
+use criterion::{BenchmarkId, Criterion, Throughput};
+use narrow::{
+    array::Uint32Array,
+    bitmap::Bitmap,
+    compute::{filter_with, FilterStrategy},
+};
+use rand::{prelude::SmallRng, Rng, SeedableRng};
+
+pub(super) fn bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter");
+    let mut rng = SmallRng::seed_from_u64(1234);
+
+    for size in [12345] {
+        let array = (0..size).collect::<Uint32Array>();
+        for run_len in [1, 8, 64] {
+            // Runs of selected and unselected values of random lengths with
+            // the given mean.
+            let mut selected = false;
+            let mut remaining = 0;
+            let mask = (0..size)
+                .map(|_| {
+                    if remaining == 0 {
+                        selected = !selected;
+                        remaining = rng.gen_range(1..=2 * run_len);
+                    }
+                    remaining -= 1;
+                    selected
+                })
+                .collect::<Bitmap>();
+            group.throughput(Throughput::Elements(size as u64));
+            for strategy in [
+                FilterStrategy::Auto,
+                FilterStrategy::Slices,
+                FilterStrategy::Bits,
+            ] {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("{strategy:?}"), format!("{size}/{run_len}")),
+                    &mask,
+                    |b, mask| b.iter(|| filter_with(&array, mask, strategy)),
+                );
+            }
+        }
+    }
+}
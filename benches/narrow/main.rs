@@ -1,6 +1,8 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
 mod bitmap;
+#[cfg(feature = "compute")]
+mod filter;
 
 criterion_group! {
   name = narrow;
@@ -8,4 +10,16 @@ criterion_group! {
   targets =
     bitmap::bench
 }
+
+#[cfg(feature = "compute")]
+criterion_group! {
+  name = compute;
+  config = Criterion::default();
+  targets =
+    filter::bench
+}
+
+#[cfg(feature = "compute")]
+criterion_main!(narrow, compute);
+#[cfg(not(feature = "compute"))]
 criterion_main!(narrow);
@@ -1,9 +1,9 @@
 use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
 use syn::{
-    parse2, parse_quote, punctuated::Punctuated, token, visit_mut::VisitMut, DeriveInput, Field,
-    Fields, Generics, Ident, ItemImpl, ItemStruct, Token, Type, TypeParamBound, Variant,
-    Visibility, WhereClause, WherePredicate,
+    ext::IdentExt, parse2, parse_quote, punctuated::Punctuated, token, visit_mut::VisitMut,
+    DeriveInput, Field, Fields, Generics, Ident, ItemImpl, ItemStruct, Token, Type, TypeParamBound,
+    Variant, Visibility, WhereClause, WherePredicate,
 };
 
 use crate::util::{self, AddTypeParam, AddTypeParamBound, SelfReplace};
@@ -29,6 +29,12 @@ pub(super) fn derive(
     // Generate a default impl for the wrapper struct def.
     let array_struct_default_impl = input.array_struct_default_impl();
 
+    // Generate a ChildrenArrayData impl for the wrapper struct def.
+    let array_struct_children_array_data_impl = input.array_struct_children_array_data_impl();
+
+    // Generate a ChildrenLayout impl for the wrapper struct def.
+    let array_struct_children_layout_impl = input.array_struct_children_layout_impl();
+
     // Generate an extend impl for the wrapper struct def.
     let array_struct_extend_dense_impl = input.array_struct_extend_dense_impl();
 
@@ -52,6 +58,10 @@ pub(super) fn derive(
 
         #array_struct_default_impl
 
+        #array_struct_children_array_data_impl
+
+        #array_struct_children_layout_impl
+
         #array_struct_extend_dense_impl
 
         #array_struct_extend_sparse_impl
@@ -420,6 +430,109 @@ impl<'a> Enum<'a> {
         parse2(tokens).expect("array_struct_default_impl")
     }
 
+    // Adds a ChildrenArrayData impl for the array wrapper struct, to convert
+    // the variant arrays to and from array data.
+    fn array_struct_children_array_data_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let self_generics = self.generics.clone();
+        let (_, self_ty_generics, _) = self_generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(OffsetItem: #narrow::offset::OffsetElement))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(UnionLayout: #narrow::array::UnionType))
+            .visit_generics_mut(&mut generics);
+        let self_ident = self.ident;
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(
+                self.variant_indices()
+                    .map::<WherePredicate, _>(|idx|
+                        parse_quote!(
+                            <<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType>::Array<Buffer, OffsetItem, UnionLayout>
+                        : ::std::convert::Into<#narrow::layout::ArrayData> + ::std::convert::TryFrom<#narrow::layout::ArrayData, Error = #narrow::layout::ArrayDataError>)
+                    )
+            );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let ident = self.array_struct_ident();
+        let variant_name = self.variant_idents().map(|ident| ident.unraw().to_string());
+        let idx = self.variant_indices();
+        let variant = (0..self.variants.len())
+            .map(|idx| format_ident!("_{idx}"))
+            .collect::<Vec<_>>();
+        let count = self.variants.len();
+        let tokens = quote! {
+            impl #impl_generics #narrow::layout::ChildrenArrayData for #ident #ty_generics #where_clause {
+                const NAMES: &'static [&'static str] = &[#(#variant_name),*];
+
+                fn into_children(self) -> ::std::vec::Vec<#narrow::layout::ArrayData> {
+                    ::std::vec![#( ::std::convert::Into::into(self.#idx), )*]
+                }
+
+                fn try_from_children(
+                    _len: usize,
+                    children: ::std::vec::Vec<#narrow::layout::ArrayData>,
+                ) -> ::std::result::Result<Self, #narrow::layout::ArrayDataError> {
+                    let [#( #variant, )*]: [#narrow::layout::ArrayData; #count] = ::std::convert::TryFrom::try_from(children)
+                        .map_err(|children: ::std::vec::Vec<_>| #narrow::layout::ArrayDataError::ChildCount {
+                            count: children.len(),
+                            expected: #count,
+                        })?;
+                    Ok(Self(#( ::std::convert::TryFrom::try_from(#variant)?, )*))
+                }
+            }
+        };
+        parse2(tokens).expect("array_struct_children_array_data_impl")
+    }
+
+    // Adds a ChildrenLayout impl for the array wrapper struct, returning the
+    // variant arrays.
+    fn array_struct_children_layout_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let self_generics = self.generics.clone();
+        let (_, self_ty_generics, _) = self_generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(OffsetItem: #narrow::offset::OffsetElement))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(UnionLayout: #narrow::array::UnionType))
+            .visit_generics_mut(&mut generics);
+        let self_ident = self.ident;
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(
+                self.variant_indices()
+                    .map::<WherePredicate, _>(|idx|
+                        parse_quote!(
+                            <<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType>::Array<Buffer, OffsetItem, UnionLayout>
+                        : #narrow::layout::ArrayLayout)
+                    )
+            );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let ident = self.array_struct_ident();
+        let idx = self.variant_indices();
+        let tokens = quote! {
+            impl #impl_generics #narrow::layout::ChildrenLayout for #ident #ty_generics #where_clause {
+                fn child_layouts(&self) -> ::std::vec::Vec<&dyn #narrow::layout::ArrayLayout> {
+                    ::std::vec![#( &self.#idx as &dyn #narrow::layout::ArrayLayout, )*]
+                }
+            }
+        };
+        parse2(tokens).expect("array_struct_children_layout_impl")
+    }
+
     // Adds an extend impl for the dense array wrapper struct.
     fn array_struct_extend_dense_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
@@ -21,6 +21,8 @@ static NARROW: Lazy<String> = Lazy::new(|| match proc_macro_crate::crate_name(CR
 
 /// Derive macro for the ArrayType trait.
 ///
+/// Structs with named fields can opt in to a column identifiers enum with the
+/// `#[narrow(columns)]` attribute.
 #[proc_macro_derive(ArrayType, attributes(narrow))]
 pub fn derive_array_type(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -1,10 +1,11 @@
 use crate::util::{self, AddTypeParam, AddTypeParamBound, SelfReplace};
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::iter::{Enumerate, Map};
 use syn::{
-    parse2, parse_quote, punctuated, token::Paren, visit_mut::VisitMut, DeriveInput, Field, Fields,
-    Generics, Ident, Index, ItemImpl, ItemStruct, Type, TypeParamBound, Visibility, WherePredicate,
+    ext::IdentExt, parse2, parse_quote, punctuated, token::Paren, visit_mut::VisitMut, Attribute,
+    DeriveInput, Field, Fields, Generics, Ident, Index, ItemImpl, ItemStruct, Type, TypeParamBound,
+    Visibility, WherePredicate,
 };
 
 pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
@@ -22,21 +23,48 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
     // Generate the StructArrayType impl.
     let struct_array_type_impl = input.struct_array_type_impl();
 
+    // Generate the FieldNames impl.
+    let field_names_impl = input.field_names_impl();
+
+    // Generate the column identifiers of structs with the columns attribute.
+    let columns = input.columns();
+
     // Generate the array wrapper struct definition.
     let array_struct_def = input.array_struct_def();
 
     // Generate a `Default` impl for the array wrapper struct.
     let array_default_impl = input.array_default_impl();
 
+    // Generate the WithCapacity implementation.
+    let array_with_capacity_impl = input.array_with_capacity_impl();
+
+    // Generate the ShrinkToFit implementation.
+    let array_shrink_to_fit_impl = input.array_shrink_to_fit_impl();
+
     // Generate the Length implementation.
     let array_len_impl = input.array_len_impl();
 
+    // Generate the ByteLength implementation.
+    let array_byte_len_impl = input.array_byte_len_impl();
+
+    // Generate the StructArrayChildren implementation.
+    let array_children_impl = input.array_children_impl();
+
+    // Generate the ChildrenArrayData implementation.
+    let array_children_array_data_impl = input.array_children_array_data_impl();
+
+    // Generate the ChildrenLayout implementation.
+    let array_children_layout_impl = input.array_children_layout_impl();
+
     // Generate the Extend implementation.
     let array_extend_impl = input.array_extend_impl();
 
     // Generate the FromIterator implementation.
     let array_from_iter_impl = input.array_from_iter_impl();
 
+    // Generate the IntoIterator implementation.
+    let array_into_iter_impl = input.array_into_iter_impl();
+
     let tokens = quote! {
         #unit_impl
 
@@ -44,15 +72,58 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
 
         #struct_array_type_impl
 
+        #field_names_impl
+
+        #columns
+
         #array_struct_def
 
         #array_default_impl
 
+        #array_with_capacity_impl
+
+        #array_shrink_to_fit_impl
+
         #array_len_impl
 
+        #array_byte_len_impl
+
+        #array_children_impl
+
+        #array_children_array_data_impl
+
+        #array_children_layout_impl
+
         #array_extend_impl
 
         #array_from_iter_impl
+
+        #array_into_iter_impl
+    };
+
+    // Optionally generate the StructColumnStats impl.
+    #[cfg(feature = "compute")]
+    let tokens = {
+        let array_column_stats_impl = input.array_column_stats_impl();
+        let array_take_impl = input.array_take_impl();
+        quote! {
+            #tokens
+
+            #array_column_stats_impl
+
+            #array_take_impl
+        }
+    };
+
+    // Optionally generate the TextRow impl.
+    #[cfg(feature = "io-text")]
+    let tokens = {
+        let text_row_impl = input.text_row_impl();
+        quote! {
+            #tokens
+
+            #text_row_impl
+        }
     };
 
     #[cfg(feature = "arrow-rs")]
@@ -83,6 +154,7 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
 type FieldIdents<'a> = Map<Enumerate<punctuated::Iter<'a, Field>>, fn((usize, &Field)) -> Ident>;
 
 struct Struct<'a> {
+    attrs: &'a [Attribute],
     vis: &'a Visibility,
     ident: &'a Ident,
     generics: &'a Generics,
@@ -92,6 +164,7 @@ struct Struct<'a> {
 impl<'a> Struct<'a> {
     pub fn new(input: &'a DeriveInput, fields: &'a Fields) -> Self {
         Self {
+            attrs: &input.attrs,
             vis: &input.vis,
             ident: &input.ident,
             generics: &input.generics,
@@ -132,7 +205,7 @@ impl Struct<'_> {
         self.fields.iter().map(|Field { ty, .. }| ty)
     }
 
-    fn field_idents(&self) -> FieldIdents {
+    fn field_idents(&self) -> FieldIdents<'_> {
         self.fields
             .iter()
             .enumerate()
@@ -211,6 +284,139 @@ impl Struct<'_> {
         parse2(tokens).expect("struct_array_type_impl")
     }
 
+    /// Add a `FieldNames` implementation for the derive input.
+    fn field_names_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let field_name = self.fields.iter().enumerate().map(|(idx, field)| {
+            field
+                .ident
+                .as_ref()
+                .map_or_else(|| idx.to_string(), |ident| ident.unraw().to_string())
+        });
+
+        let ident = self.ident;
+        let tokens = quote! {
+            impl #impl_generics #narrow::projection::FieldNames for #ident #ty_generics #where_clause {
+                const FIELD_NAMES: &'static [&'static str] = &[#(#field_name),*];
+            }
+        };
+        parse2(tokens).expect("field_names_impl")
+    }
+
+    /// Returns the name of the column identifiers enum.
+    fn columns_ident(&self) -> Ident {
+        format_ident!("{}Columns", self.ident)
+    }
+
+    /// Returns whether the derive input has the `#[narrow(columns)]`
+    /// attribute.
+    fn has_columns_attr(&self) -> syn::Result<bool> {
+        let mut columns = false;
+        for attr in self
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("narrow"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("columns") {
+                    columns = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported narrow attribute, expected `columns`"))
+                }
+            })?;
+        }
+        Ok(columns)
+    }
+
+    /// Add a column identifiers enum, with a variant per field, and its
+    /// `ColumnId` implementation, for structs with the `#[narrow(columns)]`
+    /// attribute.
+    ///
+    /// Emits a compile error when the struct does not have named fields, or
+    /// when the name of a field does not map to a unique variant.
+    fn columns(&self) -> TokenStream {
+        let narrow = util::narrow();
+
+        match self.has_columns_attr() {
+            Ok(true) => {}
+            Ok(false) => return TokenStream::new(),
+            Err(error) => return error.to_compile_error(),
+        }
+        if !matches!(self.fields, Fields::Named(_)) {
+            return syn::Error::new_spanned(
+                self.ident,
+                "`#[narrow(columns)]` requires a struct with named fields",
+            )
+            .to_compile_error();
+        }
+
+        let field_ident = self.field_idents().collect::<Vec<_>>();
+        let field_name = field_ident
+            .iter()
+            .map(|ident| ident.unraw().to_string())
+            .collect::<Vec<_>>();
+        let mut variant = Vec::<Ident>::with_capacity(field_ident.len());
+        for (ident, name) in field_ident.iter().zip(&field_name) {
+            let Ok(next) = syn::parse_str::<Ident>(&upper_camel_case(name)) else {
+                return syn::Error::new_spanned(
+                    ident,
+                    format!("field `{name}` does not map to a valid column variant name"),
+                )
+                .to_compile_error();
+            };
+            if let Some(index) = variant.iter().position(|existing| *existing == next) {
+                return syn::Error::new_spanned(
+                    ident,
+                    format!(
+                        "fields `{}` and `{name}` map to the same column variant `{next}`",
+                        field_name[index]
+                    ),
+                )
+                .to_compile_error();
+            }
+            variant.push(next);
+        }
+        let index = (0..variant.len()).map(Index::from);
+        let variant_doc = field_name
+            .iter()
+            .map(|name| format!("The `{name}` column."));
+
+        let vis = self.vis;
+        let ident = self.ident;
+        let doc = format!("The columns of [`{ident}`].");
+        let columns_ident = self.columns_ident();
+        quote! {
+            #[doc = #doc]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #vis enum #columns_ident {
+                #(
+                    #[doc = #variant_doc]
+                    #variant,
+                )*
+            }
+
+            impl #narrow::projection::ColumnId for #columns_ident {
+                const COLUMNS: &'static [Self] = &[#(Self::#variant),*];
+
+                fn column_name(self) -> &'static str {
+                    match self {
+                        #(Self::#variant => #field_name,)*
+                    }
+                }
+
+                fn column_index(self) -> usize {
+                    match self {
+                        #(Self::#variant => #index,)*
+                    }
+                }
+            }
+        }
+    }
+
     /// Add an `StructArrayTypeFields` implementation for the derive input.
     #[cfg(feature = "arrow-rs")]
     fn struct_array_type_fields_impl(&self) -> ItemImpl {
@@ -476,6 +682,131 @@ impl Struct<'_> {
         parse2(tokens).expect("array_default_impl")
     }
 
+    fn array_with_capacity_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::WithCapacity)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let with_capacity_fields = self.surround_with_delimiters(match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                quote!(
+                    #(
+                        #field_ident: #narrow::WithCapacity::with_capacity(capacity),
+                    )*
+                )
+            }
+            Fields::Unnamed(_) => {
+                let with_capacity_field =
+                    std::iter::repeat(quote!(#narrow::WithCapacity::with_capacity(capacity)))
+                        .take(self.fields.len());
+                quote!(
+                    #(
+                        #with_capacity_field,
+                    )*
+                )
+            }
+            Fields::Unit => {
+                quote!(#narrow::WithCapacity::with_capacity(capacity))
+            }
+        });
+        // Forwards a reserve method to all fields.
+        let reserve = |method: Ident| match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                quote!(
+                    #(
+                        #narrow::WithCapacity::#method(&mut self.#field_ident, additional);
+                    )*
+                )
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = self
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, _)| Index::from(idx));
+                quote!(
+                    #(
+                        #narrow::WithCapacity::#method(&mut self.#field_idx, additional);
+                    )*
+                )
+            }
+            Fields::Unit => {
+                quote!(#narrow::WithCapacity::#method(&mut self.0, additional);)
+            }
+        };
+        let reserve_exact = reserve(format_ident!("reserve_exact"));
+        let reserve = reserve(format_ident!("reserve"));
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::WithCapacity for #ident #ty_generics #where_clause {
+                fn with_capacity(capacity: usize) -> Self {
+                    Self #with_capacity_fields
+                }
+
+                fn reserve(&mut self, additional: usize) {
+                    #reserve
+                }
+
+                fn reserve_exact(&mut self, additional: usize) {
+                    #reserve_exact
+                }
+            }
+        );
+        parse2(tokens).expect("array_with_capacity_impl")
+    }
+
+    fn array_shrink_to_fit_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::ShrinkToFit)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let ident = self.array_struct_ident();
+        let shrink_to_fit = match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                quote!(#( #narrow::ShrinkToFit::shrink_to_fit(&mut self.#field_ident); )*)
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = (0..self.fields.len()).map(Index::from);
+                quote!(#( #narrow::ShrinkToFit::shrink_to_fit(&mut self.#field_idx); )*)
+            }
+            Fields::Unit => {
+                quote!(#narrow::ShrinkToFit::shrink_to_fit(&mut self.0);)
+            }
+        };
+        let tokens = quote!(
+            impl #impl_generics #narrow::ShrinkToFit for #ident #ty_generics #where_clause {
+                fn shrink_to_fit(&mut self) {
+                    #shrink_to_fit
+                }
+            }
+        );
+        parse2(tokens).expect("array_shrink_to_fit_impl")
+    }
+
     fn array_len_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
 
@@ -512,6 +843,358 @@ impl Struct<'_> {
         parse2(tokens).expect("array_len_impl")
     }
 
+    fn array_byte_len_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::ByteLength)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let ident = self.array_struct_ident();
+        let byte_len = match self.fields {
+            Fields::Named(_) if self.fields.is_empty() => quote!(0),
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                quote!(#( #narrow::ByteLength::byte_len(&self.#field_ident) )+*)
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = (0..self.fields.len()).map(Index::from);
+                quote!(#( #narrow::ByteLength::byte_len(&self.#field_idx) )+*)
+            }
+            Fields::Unit => {
+                quote!(#narrow::ByteLength::byte_len(&self.0))
+            }
+        };
+        let tokens = quote!(
+            impl #impl_generics #narrow::ByteLength for #ident #ty_generics #where_clause {
+                fn byte_len(&self) -> usize {
+                    #byte_len
+                }
+            }
+        );
+        parse2(tokens).expect("array_byte_len_impl")
+    }
+
+    fn array_children_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::Length)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let child_lengths = match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents().collect::<Vec<_>>();
+                let field_name = field_ident.iter().map(|ident| ident.unraw().to_string());
+                quote!(
+                    #(
+                        (#field_name, #narrow::Length::len(&self.#field_ident)),
+                    )*
+                )
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = (0..self.fields.len()).map(Index::from);
+                let field_name = (0..self.fields.len()).map(|idx| idx.to_string());
+                quote!(
+                    #(
+                        (#field_name, #narrow::Length::len(&self.#field_idx)),
+                    )*
+                )
+            }
+            Fields::Unit => TokenStream::new(),
+        };
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::array::StructArrayChildren for #ident #ty_generics #where_clause {
+                fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+                    ::std::vec![#child_lengths]
+                }
+            }
+        );
+        parse2(tokens).expect("array_children_impl")
+    }
+
+    /// Add a `ChildrenArrayData` implementation for the derive input, to
+    /// convert the field arrays to and from array data.
+    fn array_children_array_data_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        if matches!(self.fields, Fields::Unit) {
+            let ident = self.ident;
+            let (_, ty_generics, _) = self.generics.split_for_impl();
+            generics.make_where_clause().predicates.push(parse_quote!(
+                #narrow::array::NullArray<#ident #ty_generics, false, Buffer>: ::std::iter::FromIterator<#ident #ty_generics>
+            ));
+        } else {
+            let predicates = &mut generics.make_where_clause().predicates;
+            predicates.extend(self.where_predicate_fields(parse_quote!(
+                ::std::convert::Into<#narrow::layout::ArrayData>
+            )));
+            predicates.extend(self.where_predicate_fields(parse_quote!(
+                ::std::convert::TryFrom<#narrow::layout::ArrayData, Error = #narrow::layout::ArrayDataError>
+            )));
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field_name = self.fields.iter().enumerate().map(|(idx, field)| {
+            field
+                .ident
+                .as_ref()
+                .map_or_else(|| idx.to_string(), |ident| ident.unraw().to_string())
+        });
+        let field_access = self.field_access();
+        let field_ident = self.field_idents().collect::<Vec<_>>();
+        let count = self.fields.len();
+        let len = Ident::new("len", Span::mixed_site());
+        let try_from_children = match self.fields {
+            Fields::Named(_) => quote!(
+                Ok(Self {
+                    #( #field_ident: ::std::convert::TryFrom::try_from(#field_ident)?, )*
+                })
+            ),
+            Fields::Unnamed(_) => quote!(
+                Ok(Self(#( ::std::convert::TryFrom::try_from(#field_ident)?, )*))
+            ),
+            Fields::Unit => quote!(
+                Ok(Self(::std::iter::repeat_with(::std::default::Default::default).take(#len).collect()))
+            ),
+        };
+        let len_param = if matches!(self.fields, Fields::Unit) {
+            len
+        } else {
+            Ident::new("_len", Span::mixed_site())
+        };
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::layout::ChildrenArrayData for #ident #ty_generics #where_clause {
+                const NAMES: &'static [&'static str] = &[#(#field_name),*];
+
+                fn into_children(self) -> ::std::vec::Vec<#narrow::layout::ArrayData> {
+                    ::std::vec![#( ::std::convert::Into::into(self.#field_access), )*]
+                }
+
+                fn try_from_children(
+                    #len_param: usize,
+                    children: ::std::vec::Vec<#narrow::layout::ArrayData>,
+                ) -> ::std::result::Result<Self, #narrow::layout::ArrayDataError> {
+                    let [#( #field_ident, )*]: [#narrow::layout::ArrayData; #count] = ::std::convert::TryFrom::try_from(children)
+                        .map_err(|children: ::std::vec::Vec<_>| #narrow::layout::ArrayDataError::ChildCount {
+                            count: children.len(),
+                            expected: #count,
+                        })?;
+                    #try_from_children
+                }
+            }
+        );
+        parse2(tokens).expect("array_children_array_data_impl")
+    }
+
+    /// Add a `ChildrenLayout` implementation for the derive input, returning
+    /// the field arrays.
+    fn array_children_layout_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::layout::ArrayLayout)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field_access = self.field_access();
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::layout::ChildrenLayout for #ident #ty_generics #where_clause {
+                fn child_layouts(&self) -> ::std::vec::Vec<&dyn #narrow::layout::ArrayLayout> {
+                    ::std::vec![#( &self.#field_access as &dyn #narrow::layout::ArrayLayout, )*]
+                }
+            }
+        );
+        parse2(tokens).expect("array_children_layout_impl")
+    }
+
+    /// Add a `StructColumnStats` implementation for the derive input.
+    #[cfg(feature = "compute")]
+    fn array_column_stats_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::compute::AnyColumnStats)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let column_stats = match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents().collect::<Vec<_>>();
+                let field_name = field_ident.iter().map(|ident| ident.unraw().to_string());
+                quote!(
+                    #(
+                        (#field_name, #narrow::compute::AnyColumnStats::any_column_stats(&self.#field_ident)),
+                    )*
+                )
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = (0..self.fields.len()).map(Index::from);
+                let field_name = (0..self.fields.len()).map(|idx| idx.to_string());
+                quote!(
+                    #(
+                        (#field_name, #narrow::compute::AnyColumnStats::any_column_stats(&self.#field_idx)),
+                    )*
+                )
+            }
+            Fields::Unit => TokenStream::new(),
+        };
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::compute::StructColumnStats for #ident #ty_generics #where_clause {
+                fn column_stats(&self) -> ::std::vec::Vec<(&'static str, #narrow::compute::AnyStatistics)> {
+                    ::std::vec![#column_stats]
+                }
+            }
+        );
+        parse2(tokens).expect("array_column_stats_impl")
+    }
+
+    /// Add a `Take` implementation for the derive input, taking the rows of
+    /// every field array.
+    #[cfg(feature = "compute")]
+    fn array_take_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        if matches!(self.fields, Fields::Unit) {
+            let ident = self.ident;
+            let (_, ty_generics, _) = self.generics.split_for_impl();
+            generics.make_where_clause().predicates.push(parse_quote!(
+                #narrow::array::NullArray<#ident #ty_generics, false, Buffer>: #narrow::compute::Take
+            ));
+        } else {
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(self.where_predicate_fields(parse_quote!(#narrow::compute::Take)));
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let take = match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                quote!(
+                    Self {
+                        #( #field_ident: #narrow::compute::Take::take(&self.#field_ident, indices), )*
+                    }
+                )
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = (0..self.fields.len()).map(Index::from);
+                quote!(
+                    Self(#( #narrow::compute::Take::take(&self.#field_idx, indices), )*)
+                )
+            }
+            Fields::Unit => quote!(Self(#narrow::compute::Take::take(&self.0, indices))),
+        };
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::compute::Take for #ident #ty_generics #where_clause {
+                fn take(&self, indices: &[usize]) -> Self {
+                    #take
+                }
+            }
+        );
+        parse2(tokens).expect("array_take_impl")
+    }
+
+    /// Add a `TextRow` implementation for the derive input, writing its fields
+    /// as cells.
+    #[cfg(feature = "io-text")]
+    fn text_row_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        //
+        // The bounds are higher-ranked, so they are only checked where the
+        // impl is used: structs with fields that are not cells still derive
+        // `ArrayType`, they just don't implement `TextRow`.
+        let mut generics = self.generics.clone();
+        generics.make_where_clause().predicates.extend(
+            self.field_types().map::<WherePredicate, _>(
+                |ty| parse_quote!(for<'__cell> #ty: #narrow::text::ToCell),
+            ),
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let len = self.fields.len();
+        let field_access = self.field_access();
+        // Unit structs have no cells.
+        let f = if field_access.is_empty() {
+            format_ident!("_f")
+        } else {
+            format_ident!("f")
+        };
+
+        let ident = self.ident;
+        let tokens = quote!(
+            impl #impl_generics #narrow::text::TextRow for #ident #ty_generics #where_clause {
+                const LEN: usize = #len;
+
+                fn try_for_each_cell(
+                    &self,
+                    #f: &mut dyn ::std::ops::FnMut(#narrow::text::Cell<'_>) -> ::std::io::Result<()>,
+                ) -> ::std::io::Result<()> {
+                    #(
+                        #f(#narrow::text::ToCell::to_cell(&self.#field_access))?;
+                    )*
+                    ::std::result::Result::Ok(())
+                }
+            }
+        );
+        parse2(tokens).expect("text_row_impl")
+    }
+
     fn array_extend_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
         let ident = self.ident;
@@ -531,37 +1214,35 @@ impl Struct<'_> {
             .predicates
             .extend(
                 self.field_types()
-                    .map::<WherePredicate, _>(move |ty| parse_quote!(<#ty as #narrow::array::ArrayType>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::Extend<#ty>))
+                    .map::<WherePredicate, _>(|ty| parse_quote!(<#ty as #narrow::array::ArrayType>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::Extend<#ty>))
             );
+        let array_struct_ident = self.array_struct_ident();
+        if !matches!(self.fields, Fields::Unit) {
+            let (_, ty_generics, _) = generics.split_for_impl();
+            let predicate: WherePredicate =
+                parse_quote!(#array_struct_ident #ty_generics: #narrow::array::StructArrayChildren);
+            generics.make_where_clause().predicates.push(predicate);
+        }
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-        let array_struct_ident = self.array_struct_ident();
+        // Verify the child arrays did not diverge in debug builds.
+        let check_child_lengths = quote!(
+            debug_assert_eq!(
+                #narrow::array::StructArrayChildren::check_child_lengths(self),
+                ::std::result::Result::Ok(())
+            );
+        );
         let extend = match self.fields {
-            Fields::Unnamed(_) => {
-                let field_ident = self.field_idents().collect::<Vec<_>>();
-                let fields = self.surround_with_delimiters(quote!(#( #field_ident, )*));
-                let field_idx = self
-                    .fields
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, _)| Index::from(idx));
-                quote!(
-                    iter.into_iter().for_each(|#ident #fields | {
-                        #(
-                            self.#field_idx.extend(::std::iter::once(#field_ident));
-                        )*
-                    });
-                )
-            }
-            Fields::Named(_) => {
-                let field_ident = self.field_idents().collect::<Vec<_>>();
-                let fields = self.surround_with_delimiters(quote!(#( #field_ident, )*));
+            Fields::Named(_) | Fields::Unnamed(_) => {
+                let children = self
+                    .field_access()
+                    .into_iter()
+                    .map(|field| quote!(self.#field))
+                    .collect::<Vec<_>>();
+                let extend_children = self.extend_children(&children);
                 quote!(
-                    iter.into_iter().for_each(|#ident #fields | {
-                        #(
-                            self.#field_ident.extend(::std::iter::once(#field_ident));
-                        )*
-                    });
+                    #extend_children
+                    #check_child_lengths
                 )
             }
             Fields::Unit => quote!(self.0.extend(iter)),
@@ -601,30 +1282,23 @@ impl Struct<'_> {
 
         let array_struct_ident = self.array_struct_ident();
         let from_iter = match self.fields {
-            Fields::Unnamed(_) => {
-                let field_ident = self.field_idents().collect::<Vec<_>>();
-                let fields = self.surround_with_delimiters(quote!(#( #field_ident, )*));
-                let tuple = self.field_tuple();
-                quote!(
-                    let #tuple = iter.into_iter().map(|#ident #fields| #tuple).unzip();
-                    Self (
-                        #(
-                            #field_ident,
-                        )*
-                    )
-                )
-            }
-            Fields::Named(_) => {
-                let field_ident = self.field_idents().collect::<Vec<_>>();
-                let fields = self.surround_with_delimiters(quote!(#( #field_ident, )*));
-                let tuple = self.field_tuple();
+            Fields::Named(_) | Fields::Unnamed(_) => {
+                let array = Ident::new("array", Span::mixed_site());
+                let field_access = self.field_access();
+                let children = field_access
+                    .iter()
+                    .map(|field| quote!(#array.#field))
+                    .collect::<Vec<_>>();
+                let extend_children = self.extend_children(&children);
+                let default = field_access.iter().map(|field| match self.fields {
+                    Fields::Named(_) => quote!(#field: ::std::default::Default::default()),
+                    _ => quote!(::std::default::Default::default()),
+                });
+                let default = self.surround_with_delimiters(quote!(#( #default, )*));
                 quote!(
-                    let #tuple = iter.into_iter().map(|#ident #fields| #tuple).unzip();
-                    Self {
-                        #(
-                            #field_ident,
-                        )*
-                    }
+                    let mut #array = Self #default;
+                    #extend_children
+                    #array
                 )
             }
             Fields::Unit => quote!(Self(iter.into_iter().collect())),
@@ -639,10 +1313,155 @@ impl Struct<'_> {
         parse2(tokens).expect("array_from_iter_impl")
     }
 
-    fn field_tuple(&self) -> TokenStream {
-        let mut ident = self.field_idents();
-        let initial = ident.next_back().map(|last| quote!((#last, ()))).unwrap();
-        ident.rfold(initial, |acc, x| quote!((#x, #acc)))
+    fn array_into_iter_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+        let ident = self.ident;
+
+        // Generics
+        let mut ident_generics = self.generics.clone();
+        SelfReplace::new(ident, &ident_generics).visit_generics_mut(&mut ident_generics);
+        let (_, ident_ty_generics, _) = ident_generics.split_for_impl();
+
+        // Array generics
+        let mut generics = self.generics.clone();
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        let array_ty = |ty: &Type| -> Type {
+            parse_quote!(<#ty as #narrow::array::ArrayType>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>)
+        };
+        match self.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => {
+                generics.make_where_clause().predicates.extend(
+                    self.field_types().map::<WherePredicate, _>(|ty| {
+                        let array = array_ty(ty);
+                        parse_quote!(#array: ::std::iter::IntoIterator<Item = #ty>)
+                    }),
+                );
+            }
+            Fields::Unit => {
+                generics.make_where_clause().predicates.push(parse_quote!(
+                    #narrow::array::NullArray<#ident #ident_ty_generics, false, Buffer>: ::std::iter::IntoIterator<Item = #ident #ident_ty_generics>
+                ));
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let array_struct_ident = self.array_struct_ident();
+        let (into_iter_ty, into_iter) = match self.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => {
+                // The field iterators are zipped as nested pairs.
+                let field_iter_ty = self
+                    .field_types()
+                    .map(|ty| {
+                        let array = array_ty(ty);
+                        quote!(<#array as ::std::iter::IntoIterator>::IntoIter)
+                    })
+                    .collect::<Vec<_>>();
+                let field_ty = self.field_types().collect::<Vec<_>>();
+                let field_ident = self.field_idents().collect::<Vec<_>>();
+                let field_access = self.field_access();
+
+                let last = field_ident.len() - 1;
+                let zip_ty = field_iter_ty[..last].iter().rfold(
+                    field_iter_ty[last].clone(),
+                    |acc, ty| quote!(::std::iter::Zip<#ty, #acc>),
+                );
+                let item_ty = field_ty[..last].iter().rfold(
+                    field_ty[last].to_token_stream(),
+                    |acc, ty| quote!((#ty, #acc)),
+                );
+                let pattern = field_ident[..last].iter().rfold(
+                    field_ident[last].to_token_stream(),
+                    |acc, ident| quote!((#ident, #acc)),
+                );
+                let last_access = &field_access[last];
+                let zip = field_access[..last].iter().rfold(
+                    quote!(self.#last_access),
+                    |acc, access| quote!(::std::iter::Iterator::zip(::std::iter::IntoIterator::into_iter(self.#access), #acc)),
+                );
+                let fields = self.surround_with_delimiters(quote!(#( #field_ident, )*));
+                (
+                    quote!(::std::iter::Map<#zip_ty, fn(#item_ty) -> #ident #ident_ty_generics>),
+                    quote!(
+                        ::std::iter::Iterator::map(
+                            ::std::iter::IntoIterator::into_iter(#zip),
+                            |#pattern| #ident #fields,
+                        )
+                    ),
+                )
+            }
+            Fields::Unit => (
+                quote!(<#narrow::array::NullArray<#ident #ident_ty_generics, false, Buffer> as ::std::iter::IntoIterator>::IntoIter),
+                quote!(::std::iter::IntoIterator::into_iter(self.0)),
+            ),
+        };
+        let tokens = quote!(
+            impl #impl_generics ::std::iter::IntoIterator for #array_struct_ident #ty_generics #where_clause {
+                type Item = #ident #ident_ty_generics;
+                type IntoIter = #into_iter_ty;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    #into_iter
+                }
+            }
+        );
+        parse2(tokens).expect("array_into_iter_impl")
+    }
+
+    /// Returns the tokens to access the fields of the derive input, i.e. the
+    /// field names or indices.
+    fn field_access(&self) -> Vec<TokenStream> {
+        match self.fields {
+            Fields::Named(_) => self
+                .field_idents()
+                .map(ToTokens::into_token_stream)
+                .collect(),
+            _ => (0..self.fields.len())
+                .map(|idx| Index::from(idx).into_token_stream())
+                .collect(),
+        }
+    }
+
+    /// Returns the statements that extend the `children` arrays with the
+    /// fields of the items of `iter`.
+    ///
+    /// Extending the children one item at a time hides the number of items,
+    /// so they can't reserve capacity. Instead, the first child is extended
+    /// with an iterator that forwards the size hint of `iter`, and the other
+    /// children are extended with their buffered fields.
+    fn extend_children(&self, children: &[TokenStream]) -> TokenStream {
+        let ident = self.ident;
+        let field_ident = self.field_idents().collect::<Vec<_>>();
+        let fields = self.surround_with_delimiters(quote!(#( #field_ident, )*));
+        let (first_child, other_children) = children.split_first().expect("at least one field");
+        let (first_field, other_fields) = field_ident.split_first().expect("at least one field");
+        let items = Ident::new("items", Span::mixed_site());
+        if other_fields.is_empty() {
+            return quote!(
+                #first_child.extend(iter.into_iter().map(|#ident #fields| #first_field));
+            );
+        }
+        let capacity = Ident::new("capacity", Span::mixed_site());
+        let buffers = Ident::new("buffers", Span::mixed_site());
+        let buffer_idx = (0..other_fields.len()).map(Index::from).collect::<Vec<_>>();
+        let with_capacity = other_fields
+            .iter()
+            .map(|_| quote!(::std::vec::Vec::with_capacity(#capacity)));
+        quote!(
+            let #items = iter.into_iter();
+            let #capacity = #items.size_hint().0;
+            let mut #buffers = ( #( #with_capacity, )* );
+            #first_child.extend(#items.map(|#ident #fields| {
+                #(
+                    #buffers.#buffer_idx.push(#other_fields);
+                )*
+                #first_field
+            }));
+            #(
+                #other_children.extend(#buffers.#buffer_idx);
+            )*
+        )
     }
 
     fn where_predicate_fields(
@@ -668,10 +1487,32 @@ impl Struct<'_> {
     }
 }
 
+/// Returns `name`, a field name in snake case, in upper camel case.
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn upper_camel() {
+        assert_eq!(upper_camel_case("a"), "A");
+        assert_eq!(upper_camel_case("tenant_id"), "TenantId");
+        assert_eq!(upper_camel_case("_private"), "Private");
+        assert_eq!(upper_camel_case("ipv4_addr"), "Ipv4Addr");
+    }
+
     #[test]
     fn surround() {
         // unit
@@ -115,6 +115,134 @@ where
         )
     }
 }
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::layout::ChildrenArrayData for FooArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::std::convert::Into<narrow::layout::ArrayData>
+        + ::std::convert::TryFrom<
+            narrow::layout::ArrayData,
+            Error = narrow::layout::ArrayDataError,
+        >,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::std::convert::Into<narrow::layout::ArrayData>
+        + ::std::convert::TryFrom<
+            narrow::layout::ArrayData,
+            Error = narrow::layout::ArrayDataError,
+        >,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::std::convert::Into<narrow::layout::ArrayData>
+        + ::std::convert::TryFrom<
+            narrow::layout::ArrayData,
+            Error = narrow::layout::ArrayDataError,
+        >,
+{
+    const NAMES: &'static [&'static str] = &["Foo", "Bar", "None"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ::std::convert::Into::into(self.0),
+                    ::std::convert::Into::into(self.1),
+                    ::std::convert::Into::into(self.2),
+                ],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0, _1, _2]: [narrow::layout::ArrayData; 3usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 3usize,
+            })?;
+        Ok(
+            Self(
+                ::std::convert::TryFrom::try_from(_0)?,
+                ::std::convert::TryFrom::try_from(_1)?,
+                ::std::convert::TryFrom::try_from(_2)?,
+            ),
+        )
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::layout::ChildrenLayout for FooArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::layout::ArrayLayout,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::layout::ArrayLayout,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    &self.0 as &dyn narrow::layout::ArrayLayout,
+                    &self.1 as &dyn narrow::layout::ArrayLayout,
+                    &self.2 as &dyn narrow::layout::ArrayLayout,
+                ],
+            ),
+        )
+    }
+}
 impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
@@ -141,6 +141,141 @@ where
         )
     }
 }
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::layout::ChildrenArrayData for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::std::convert::Into<narrow::layout::ArrayData>
+        + ::std::convert::TryFrom<
+            narrow::layout::ArrayData,
+            Error = narrow::layout::ArrayDataError,
+        >,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::std::convert::Into<narrow::layout::ArrayData>
+        + ::std::convert::TryFrom<
+            narrow::layout::ArrayData,
+            Error = narrow::layout::ArrayDataError,
+        >,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::std::convert::Into<narrow::layout::ArrayData>
+        + ::std::convert::TryFrom<
+            narrow::layout::ArrayData,
+            Error = narrow::layout::ArrayDataError,
+        >,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::std::convert::Into<narrow::layout::ArrayData>
+        + ::std::convert::TryFrom<
+            narrow::layout::ArrayData,
+            Error = narrow::layout::ArrayDataError,
+        >,
+{
+    const NAMES: &'static [&'static str] = &["Unit", "Foo", "Bar", "FooBar"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ::std::convert::Into::into(self.0),
+                    ::std::convert::Into::into(self.1),
+                    ::std::convert::Into::into(self.2),
+                    ::std::convert::Into::into(self.3),
+                ],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0, _1, _2, _3]: [narrow::layout::ArrayData; 4usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 4usize,
+            })?;
+        Ok(
+            Self(
+                ::std::convert::TryFrom::try_from(_0)?,
+                ::std::convert::TryFrom::try_from(_1)?,
+                ::std::convert::TryFrom::try_from(_2)?,
+                ::std::convert::TryFrom::try_from(_3)?,
+            ),
+        )
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::layout::ChildrenLayout for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::layout::ArrayLayout,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::layout::ArrayLayout,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::layout::ArrayLayout,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    &self.0 as &dyn narrow::layout::ArrayLayout,
+                    &self.1 as &dyn narrow::layout::ArrayLayout,
+                    &self.2 as &dyn narrow::layout::ArrayLayout,
+                    &self.3 as &dyn narrow::layout::ArrayLayout,
+                ],
+            ),
+        )
+    }
+}
 impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
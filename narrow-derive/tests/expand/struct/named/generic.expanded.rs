@@ -31,6 +31,12 @@ where
 {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<'a, T, Buffer>;
 }
+impl<'a, T> narrow::projection::FieldNames for Foo<'a, T>
+where
+    T: Copy,
+{
+    const FIELD_NAMES: &'static [&'static str] = &["a"];
+}
 struct FooArray<'a, T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType>
 where
     T: Copy,
@@ -60,6 +66,48 @@ where
         }
     }
 }
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::WithCapacity for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            a: narrow::WithCapacity::with_capacity(capacity),
+        }
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.a, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.a, additional);
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::ShrinkToFit for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.a);
+    }
+}
 impl<'a, T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::Length
 for FooArray<'a, T, Buffer>
 where
@@ -74,6 +122,113 @@ where
         self.a.len()
     }
 }
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::ByteLength for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.a)
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("a", narrow::Length::len(&self.a))],
+            ),
+        )
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenArrayData for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["a"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.a)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [a]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self {
+            a: ::std::convert::TryFrom::try_from(a)?,
+        })
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenLayout for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.a as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<
     'a,
     T: narrow::array::ArrayType,
@@ -86,12 +241,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<&'a T>,
+    FooArray<'a, T, Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Foo { a }| {
-                self.a.extend(::std::iter::once(a));
-            });
+        self.a.extend(iter.into_iter().map(|Foo { a }| a));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<
@@ -108,7 +279,39 @@ where
     >: ::std::default::Default + ::std::iter::Extend<&'a T>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
-        let (a, ()) = iter.into_iter().map(|Foo { a }| (a, ())).unzip();
-        Self { a }
+        let mut array = Self {
+            a: ::std::default::Default::default(),
+        };
+        array.a.extend(iter.into_iter().map(|Foo { a }| a));
+        array
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> ::std::iter::IntoIterator for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = &'a T>,
+{
+    type Item = Foo<'a, T>;
+    type IntoIter = ::std::iter::Map<
+        <<&'a T as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(&'a T) -> Foo<'a, T>,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.a),
+            |a| Foo { a },
+        )
     }
 }
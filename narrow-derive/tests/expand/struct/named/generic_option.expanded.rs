@@ -1,3 +1,4 @@
+#[narrow(columns)]
 struct Bar<T> {
     a: u32,
     b: Option<bool>,
@@ -21,6 +22,87 @@ for ::std::option::Option<Bar<T>> {
 impl<T: narrow::array::ArrayType> narrow::array::StructArrayType for Bar<T> {
     type Array<Buffer: narrow::buffer::BufferType> = BarArray<T, Buffer>;
 }
+impl<T> narrow::projection::FieldNames for Bar<T> {
+    const FIELD_NAMES: &'static [&'static str] = &["a", "b", "c"];
+}
+///The columns of [`Bar`].
+enum BarColumns {
+    ///The `a` column.
+    A,
+    ///The `b` column.
+    B,
+    ///The `c` column.
+    C,
+}
+#[automatically_derived]
+#[doc(hidden)]
+unsafe impl ::core::clone::TrivialClone for BarColumns {}
+#[automatically_derived]
+impl ::core::clone::Clone for BarColumns {
+    #[inline]
+    fn clone(&self) -> BarColumns {
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for BarColumns {}
+#[automatically_derived]
+impl ::core::fmt::Debug for BarColumns {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                BarColumns::A => "A",
+                BarColumns::B => "B",
+                BarColumns::C => "C",
+            },
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for BarColumns {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for BarColumns {
+    #[inline]
+    fn eq(&self, other: &BarColumns) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for BarColumns {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {}
+}
+#[automatically_derived]
+impl ::core::hash::Hash for BarColumns {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        ::core::hash::Hash::hash(&__self_discr, state)
+    }
+}
+impl narrow::projection::ColumnId for BarColumns {
+    const COLUMNS: &'static [Self] = &[Self::A, Self::B, Self::C];
+    fn column_name(self) -> &'static str {
+        match self {
+            Self::A => "a",
+            Self::B => "b",
+            Self::C => "c",
+        }
+    }
+    fn column_index(self) -> usize {
+        match self {
+            Self::A => 0,
+            Self::B => 1,
+            Self::C => 2,
+        }
+    }
+}
 struct BarArray<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> {
     a: <u32 as narrow::array::ArrayType>::Array<
         Buffer,
@@ -75,6 +157,78 @@ where
         }
     }
 }
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::WithCapacity for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            a: narrow::WithCapacity::with_capacity(capacity),
+            b: narrow::WithCapacity::with_capacity(capacity),
+            c: narrow::WithCapacity::with_capacity(capacity),
+        }
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.a, additional);
+        narrow::WithCapacity::reserve(&mut self.b, additional);
+        narrow::WithCapacity::reserve(&mut self.c, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.a, additional);
+        narrow::WithCapacity::reserve_exact(&mut self.b, additional);
+        narrow::WithCapacity::reserve_exact(&mut self.c, additional);
+    }
+}
+impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit
+for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.a);
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.b);
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.c);
+    }
+}
 impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::Length
 for BarArray<T, Buffer>
 where
@@ -102,6 +256,194 @@ where
         self.a.len()
     }
 }
+impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::ByteLength
+for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.a) + narrow::ByteLength::byte_len(&self.b)
+            + narrow::ByteLength::byte_len(&self.c)
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ("a", narrow::Length::len(&self.a)),
+                    ("b", narrow::Length::len(&self.b)),
+                    ("c", narrow::Length::len(&self.c)),
+                ],
+            ),
+        )
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenArrayData for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["a", "b", "c"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ::std::convert::Into::into(self.a),
+                    ::std::convert::Into::into(self.b),
+                    ::std::convert::Into::into(self.c),
+                ],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [a, b, c]: [narrow::layout::ArrayData; 3usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 3usize,
+            })?;
+        Ok(Self {
+            a: ::std::convert::TryFrom::try_from(a)?,
+            b: ::std::convert::TryFrom::try_from(b)?,
+            c: ::std::convert::TryFrom::try_from(c)?,
+        })
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenLayout for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    &self.a as &dyn narrow::layout::ArrayLayout,
+                    &self.b as &dyn narrow::layout::ArrayLayout,
+                    &self.c as &dyn narrow::layout::ArrayLayout,
+                ],
+            ),
+        )
+    }
+}
 impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
@@ -126,14 +468,44 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<Option<T>>,
+    BarArray<T, Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Bar<T>>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Bar { a, b, c }| {
-                self.a.extend(::std::iter::once(a));
-                self.b.extend(::std::iter::once(b));
-                self.c.extend(::std::iter::once(c));
-            });
+        let items = iter.into_iter();
+        let capacity = items.size_hint().0;
+        let mut buffers = (
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+        );
+        self.a
+            .extend(
+                items
+                    .map(|Bar { a, b, c }| {
+                        buffers.0.push(b);
+                        buffers.1.push(c);
+                        a
+                    }),
+            );
+        self.b.extend(buffers.0);
+        self.c.extend(buffers.1);
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<
@@ -162,10 +534,96 @@ where
     >: ::std::default::Default + ::std::iter::Extend<Option<T>>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar<T>>>(iter: _I) -> Self {
-        let (a, (b, (c, ()))) = iter
-            .into_iter()
-            .map(|Bar { a, b, c }| (a, (b, (c, ()))))
-            .unzip();
-        Self { a, b, c }
+        let mut array = Self {
+            a: ::std::default::Default::default(),
+            b: ::std::default::Default::default(),
+            c: ::std::default::Default::default(),
+        };
+        let items = iter.into_iter();
+        let capacity = items.size_hint().0;
+        let mut buffers = (
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+        );
+        array
+            .a
+            .extend(
+                items
+                    .map(|Bar { a, b, c }| {
+                        buffers.0.push(b);
+                        buffers.1.push(c);
+                        a
+                    }),
+            );
+        array.b.extend(buffers.0);
+        array.c.extend(buffers.1);
+        array
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> ::std::iter::IntoIterator for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = u32>,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = Option<bool>>,
+    <Option<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = Option<T>>,
+{
+    type Item = Bar<T>;
+    type IntoIter = ::std::iter::Map<
+        ::std::iter::Zip<
+            <<u32 as narrow::array::ArrayType>::Array<
+                Buffer,
+                narrow::offset::NA,
+                narrow::array::union::NA,
+            > as ::std::iter::IntoIterator>::IntoIter,
+            ::std::iter::Zip<
+                <<Option<
+                    bool,
+                > as narrow::array::ArrayType>::Array<
+                    Buffer,
+                    narrow::offset::NA,
+                    narrow::array::union::NA,
+                > as ::std::iter::IntoIterator>::IntoIter,
+                <<Option<
+                    T,
+                > as narrow::array::ArrayType>::Array<
+                    Buffer,
+                    narrow::offset::NA,
+                    narrow::array::union::NA,
+                > as ::std::iter::IntoIterator>::IntoIter,
+            >,
+        >,
+        fn((u32, (Option<bool>, Option<T>))) -> Bar<T>,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(
+                ::std::iter::Iterator::zip(
+                    ::std::iter::IntoIterator::into_iter(self.a),
+                    ::std::iter::Iterator::zip(
+                        ::std::iter::IntoIterator::into_iter(self.b),
+                        self.c,
+                    ),
+                ),
+            ),
+            |(a, (b, c))| Bar { a, b, c },
+        )
     }
 }
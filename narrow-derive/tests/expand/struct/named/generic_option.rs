@@ -1,4 +1,5 @@
 #[derive(narrow_derive::ArrayType)]
+#[narrow(columns)]
 struct Bar<T> {
     a: u32,
     b: Option<bool>,
@@ -20,6 +20,9 @@ impl narrow::array::ArrayType<Foo> for ::std::option::Option<Foo> {
 impl narrow::array::StructArrayType for Foo {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<Buffer>;
 }
+impl narrow::projection::FieldNames for Foo {
+    const FIELD_NAMES: &'static [&'static str] = &["a", "b", "c"];
+}
 struct FooArray<Buffer: narrow::buffer::BufferType> {
     a: <u32 as narrow::array::ArrayType>::Array<
         Buffer,
@@ -67,6 +70,70 @@ where
         }
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::WithCapacity for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            a: narrow::WithCapacity::with_capacity(capacity),
+            b: narrow::WithCapacity::with_capacity(capacity),
+            c: narrow::WithCapacity::with_capacity(capacity),
+        }
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.a, additional);
+        narrow::WithCapacity::reserve(&mut self.b, additional);
+        narrow::WithCapacity::reserve(&mut self.c, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.a, additional);
+        narrow::WithCapacity::reserve_exact(&mut self.b, additional);
+        narrow::WithCapacity::reserve_exact(&mut self.c, additional);
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.a);
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.b);
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.c);
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType>::Array<
@@ -91,6 +158,177 @@ where
         self.a.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::ByteLength for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.a) + narrow::ByteLength::byte_len(&self.b)
+            + narrow::ByteLength::byte_len(&self.c)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ("a", narrow::Length::len(&self.a)),
+                    ("b", narrow::Length::len(&self.b)),
+                    ("c", narrow::Length::len(&self.c)),
+                ],
+            ),
+        )
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["a", "b", "c"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ::std::convert::Into::into(self.a),
+                    ::std::convert::Into::into(self.b),
+                    ::std::convert::Into::into(self.c),
+                ],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [a, b, c]: [narrow::layout::ArrayData; 3usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 3usize,
+            })?;
+        Ok(Self {
+            a: ::std::convert::TryFrom::try_from(a)?,
+            b: ::std::convert::TryFrom::try_from(b)?,
+            c: ::std::convert::TryFrom::try_from(c)?,
+        })
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    &self.a as &dyn narrow::layout::ArrayLayout,
+                    &self.b as &dyn narrow::layout::ArrayLayout,
+                    &self.c as &dyn narrow::layout::ArrayLayout,
+                ],
+            ),
+        )
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType>::Array<
@@ -110,14 +348,44 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<Option<Vec<u8>>>,
+    FooArray<Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Foo { a, b, c }| {
-                self.a.extend(::std::iter::once(a));
-                self.b.extend(::std::iter::once(b));
-                self.c.extend(::std::iter::once(c));
-            });
+        let items = iter.into_iter();
+        let capacity = items.size_hint().0;
+        let mut buffers = (
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+        );
+        self.a
+            .extend(
+                items
+                    .map(|Foo { a, b, c }| {
+                        buffers.0.push(b);
+                        buffers.1.push(c);
+                        a
+                    }),
+            );
+        self.b.extend(buffers.0);
+        self.c.extend(buffers.1);
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Foo>
@@ -142,10 +410,89 @@ where
     >: ::std::default::Default + ::std::iter::Extend<Option<Vec<u8>>>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
-        let (a, (b, (c, ()))) = iter
-            .into_iter()
-            .map(|Foo { a, b, c }| (a, (b, (c, ()))))
-            .unzip();
-        Self { a, b, c }
+        let mut array = Self {
+            a: ::std::default::Default::default(),
+            b: ::std::default::Default::default(),
+            c: ::std::default::Default::default(),
+        };
+        let items = iter.into_iter();
+        let capacity = items.size_hint().0;
+        let mut buffers = (
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+        );
+        array
+            .a
+            .extend(
+                items
+                    .map(|Foo { a, b, c }| {
+                        buffers.0.push(b);
+                        buffers.1.push(c);
+                        a
+                    }),
+            );
+        array.b.extend(buffers.0);
+        array.c.extend(buffers.1);
+        array
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = u32>,
+    <bool as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = bool>,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = Option<Vec<u8>>>,
+{
+    type Item = Foo;
+    type IntoIter = ::std::iter::Map<
+        ::std::iter::Zip<
+            <<u32 as narrow::array::ArrayType>::Array<
+                Buffer,
+                narrow::offset::NA,
+                narrow::array::union::NA,
+            > as ::std::iter::IntoIterator>::IntoIter,
+            ::std::iter::Zip<
+                <<bool as narrow::array::ArrayType>::Array<
+                    Buffer,
+                    narrow::offset::NA,
+                    narrow::array::union::NA,
+                > as ::std::iter::IntoIterator>::IntoIter,
+                <<Option<
+                    Vec<u8>,
+                > as narrow::array::ArrayType>::Array<
+                    Buffer,
+                    narrow::offset::NA,
+                    narrow::array::union::NA,
+                > as ::std::iter::IntoIterator>::IntoIter,
+            >,
+        >,
+        fn((u32, (bool, Option<Vec<u8>>))) -> Foo,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(
+                ::std::iter::Iterator::zip(
+                    ::std::iter::IntoIterator::into_iter(self.a),
+                    ::std::iter::Iterator::zip(
+                        ::std::iter::IntoIterator::into_iter(self.b),
+                        self.c,
+                    ),
+                ),
+            ),
+            |(a, (b, c))| Foo { a, b, c },
+        )
     }
 }
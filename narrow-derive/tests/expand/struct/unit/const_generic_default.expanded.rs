@@ -21,6 +21,9 @@ impl<const N: usize> narrow::array::ArrayType<Foo<N>> for ::std::option::Option<
 impl<const N: usize> narrow::array::StructArrayType for Foo<N> {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<N, Buffer>;
 }
+impl<const N: usize> narrow::projection::FieldNames for Foo<N> {
+    const FIELD_NAMES: &'static [&'static str] = &[];
+}
 pub struct FooArray<const N: usize, Buffer: narrow::buffer::BufferType>(
     narrow::array::NullArray<Foo<N>, false, Buffer>,
 );
@@ -30,12 +33,81 @@ for FooArray<N, Buffer> {
         Self(::std::default::Default::default())
     }
 }
+impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::WithCapacity
+for FooArray<N, Buffer> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit
+for FooArray<N, Buffer> {
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::Length
 for FooArray<N, Buffer> {
     fn len(&self) -> usize {
         self.0.len()
     }
 }
+impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::ByteLength
+for FooArray<N, Buffer> {
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<
+    const N: usize,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooArray<N, Buffer> {
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::vec::Vec::new()
+    }
+}
+impl<
+    const N: usize,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenArrayData for FooArray<N, Buffer>
+where
+    narrow::array::NullArray<Foo<N>, false, Buffer>: ::std::iter::FromIterator<Foo<N>>,
+{
+    const NAMES: &'static [&'static str] = &[];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::vec::Vec::new()
+    }
+    fn try_from_children(
+        len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let []: [narrow::layout::ArrayData; 0usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 0usize,
+            })?;
+        Ok(
+            Self(
+                ::std::iter::repeat_with(::std::default::Default::default)
+                    .take(len)
+                    .collect(),
+            ),
+        )
+    }
+}
+impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for FooArray<N, Buffer> {
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::vec::Vec::new()
+    }
+}
 impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo<N>>
 for FooArray<N, Buffer> {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo<N>>>(&mut self, iter: _I) {
@@ -50,3 +122,22 @@ impl<
         Self(iter.into_iter().collect())
     }
 }
+impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator
+for FooArray<N, Buffer>
+where
+    narrow::array::NullArray<
+        Foo<N>,
+        false,
+        Buffer,
+    >: ::std::iter::IntoIterator<Item = Foo<N>>,
+{
+    type Item = Foo<N>;
+    type IntoIter = <narrow::array::NullArray<
+        Foo<N>,
+        false,
+        Buffer,
+    > as ::std::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::IntoIterator::into_iter(self.0)
+    }
+}
@@ -35,6 +35,12 @@ where
 {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<Buffer>;
 }
+impl narrow::projection::FieldNames for Foo
+where
+    Self: Debug,
+{
+    const FIELD_NAMES: &'static [&'static str] = &[];
+}
 struct FooArray<Buffer: narrow::buffer::BufferType>(
     narrow::array::NullArray<Foo, false, Buffer>,
 )
@@ -48,6 +54,28 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::WithCapacity for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer>
 where
     Foo: Debug,
@@ -56,6 +84,62 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::ByteLength for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::vec::Vec::new()
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for FooArray<Buffer>
+where
+    Foo: Debug,
+    narrow::array::NullArray<Foo, false, Buffer>: ::std::iter::FromIterator<Foo>,
+{
+    const NAMES: &'static [&'static str] = &[];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::vec::Vec::new()
+    }
+    fn try_from_children(
+        len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let []: [narrow::layout::ArrayData; 0usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 0usize,
+            })?;
+        Ok(
+            Self(
+                ::std::iter::repeat_with(::std::default::Default::default)
+                    .take(len)
+                    .collect(),
+            ),
+        )
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::vec::Vec::new()
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
 where
     Self: Debug,
@@ -73,3 +157,18 @@ where
         Self(iter.into_iter().collect())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer>
+where
+    Self: Debug,
+    narrow::array::NullArray<Foo, false, Buffer>: ::std::iter::IntoIterator<Item = Foo>,
+{
+    type Item = Foo;
+    type IntoIter = <narrow::array::NullArray<
+        Foo,
+        false,
+        Buffer,
+    > as ::std::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::IntoIterator::into_iter(self.0)
+    }
+}
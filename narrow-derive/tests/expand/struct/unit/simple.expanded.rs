@@ -21,6 +21,9 @@ impl narrow::array::ArrayType<Foo> for ::std::option::Option<Foo> {
 impl narrow::array::StructArrayType for Foo {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<Buffer>;
 }
+impl narrow::projection::FieldNames for Foo {
+    const FIELD_NAMES: &'static [&'static str] = &[];
+}
 struct FooArray<Buffer: narrow::buffer::BufferType>(
     narrow::array::NullArray<Foo, false, Buffer>,
 );
@@ -29,11 +32,73 @@ impl<Buffer: narrow::buffer::BufferType> ::std::default::Default for FooArray<Bu
         Self(::std::default::Default::default())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::WithCapacity for FooArray<Buffer> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit for FooArray<Buffer> {
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer> {
     fn len(&self) -> usize {
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::ByteLength for FooArray<Buffer> {
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooArray<Buffer> {
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::vec::Vec::new()
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for FooArray<Buffer>
+where
+    narrow::array::NullArray<Foo, false, Buffer>: ::std::iter::FromIterator<Foo>,
+{
+    const NAMES: &'static [&'static str] = &[];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::vec::Vec::new()
+    }
+    fn try_from_children(
+        len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let []: [narrow::layout::ArrayData; 0usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 0usize,
+            })?;
+        Ok(
+            Self(
+                ::std::iter::repeat_with(::std::default::Default::default)
+                    .take(len)
+                    .collect(),
+            ),
+        )
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for FooArray<Buffer> {
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::vec::Vec::new()
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer> {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
         self.0.extend(iter)
@@ -45,3 +110,17 @@ for FooArray<Buffer> {
         Self(iter.into_iter().collect())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer>
+where
+    narrow::array::NullArray<Foo, false, Buffer>: ::std::iter::IntoIterator<Item = Foo>,
+{
+    type Item = Foo;
+    type IntoIter = <narrow::array::NullArray<
+        Foo,
+        false,
+        Buffer,
+    > as ::std::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::IntoIterator::into_iter(self.0)
+    }
+}
@@ -40,6 +40,13 @@ where
 {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<N, Buffer>;
 }
+impl<const N: bool> narrow::projection::FieldNames for Foo<N>
+where
+    Self: Sized,
+    (): From<Self>,
+{
+    const FIELD_NAMES: &'static [&'static str] = &[];
+}
 pub(super) struct FooArray<const N: bool, Buffer: narrow::buffer::BufferType>(
     narrow::array::NullArray<Foo<N>, false, Buffer>,
 )
@@ -56,6 +63,32 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::WithCapacity
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::Length
 for FooArray<N, Buffer>
 where
@@ -66,6 +99,69 @@ where
         self.0.len()
     }
 }
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::ByteLength
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<
+    const N: bool,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::vec::Vec::new()
+    }
+}
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+    narrow::array::NullArray<Foo<N>, false, Buffer>: ::std::iter::FromIterator<Foo<N>>,
+{
+    const NAMES: &'static [&'static str] = &[];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::vec::Vec::new()
+    }
+    fn try_from_children(
+        len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let []: [narrow::layout::ArrayData; 0usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 0usize,
+            })?;
+        Ok(
+            Self(
+                ::std::iter::repeat_with(::std::default::Default::default)
+                    .take(len)
+                    .collect(),
+            ),
+        )
+    }
+}
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::vec::Vec::new()
+    }
+}
 impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo<N>>
 for FooArray<N, Buffer>
 where
@@ -86,3 +182,24 @@ where
         Self(iter.into_iter().collect())
     }
 }
+impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator
+for FooArray<N, Buffer>
+where
+    Self: Sized,
+    (): From<Self>,
+    narrow::array::NullArray<
+        Foo<N>,
+        false,
+        Buffer,
+    >: ::std::iter::IntoIterator<Item = Foo<N>>,
+{
+    type Item = Foo<N>;
+    type IntoIter = <narrow::array::NullArray<
+        Foo<N>,
+        false,
+        Buffer,
+    > as ::std::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::IntoIterator::into_iter(self.0)
+    }
+}
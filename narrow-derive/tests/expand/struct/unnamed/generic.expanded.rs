@@ -35,6 +35,13 @@ where
 {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<'a, T, Buffer>;
 }
+impl<'a, T: Add<Self>> narrow::projection::FieldNames for Foo<'a, T>
+where
+    Self: Sized,
+    <T as Add<Self>>::Output: Debug,
+{
+    const FIELD_NAMES: &'static [&'static str] = &["0"];
+}
 struct FooArray<
     'a,
     T: Add<Foo<'a, T>> + narrow::array::ArrayType,
@@ -67,6 +74,48 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<
+    'a,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::WithCapacity for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<
+    'a,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::ShrinkToFit for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<
     'a,
     T: Add<Foo<'a, T>> + narrow::array::ArrayType,
@@ -85,6 +134,115 @@ where
         self.0.len()
     }
 }
+impl<
+    'a,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::ByteLength for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<
+    'a,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("0", narrow::Length::len(&self.0))],
+            ),
+        )
+    }
+}
+impl<
+    'a,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenArrayData for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.0)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self(::std::convert::TryFrom::try_from(_0)?))
+    }
+}
+impl<
+    'a,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenLayout for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.0 as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<
     'a,
     T: Add<Self> + narrow::array::ArrayType,
@@ -98,12 +256,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<&'a T>,
+    FooArray<'a, T, Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Foo(_0)| {
-                self.0.extend(::std::iter::once(_0));
-            });
+        self.0.extend(iter.into_iter().map(|Foo(_0)| _0));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<
@@ -121,8 +295,39 @@ where
     >: ::std::default::Default + ::std::iter::Extend<&'a T>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
-        let (_0, ()) = iter.into_iter().map(|Foo(_0)| (_0, ())).unzip();
-        Self(_0)
+        let mut array = Self(::std::default::Default::default());
+        array.0.extend(iter.into_iter().map(|Foo(_0)| _0));
+        array
+    }
+}
+impl<
+    'a,
+    T: Add<Self> + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> ::std::iter::IntoIterator for FooArray<'a, T, Buffer>
+where
+    Self: Sized,
+    <T as Add<Self>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = &'a T>,
+{
+    type Item = Foo<'a, T>;
+    type IntoIter = ::std::iter::Map<
+        <<&'a T as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(&'a T) -> Foo<'a, T>,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.0),
+            |_0| Foo(_0),
+        )
     }
 }
 struct FooBar<T>(T);
@@ -144,6 +349,9 @@ for ::std::option::Option<FooBar<T>> {
 impl<T: narrow::array::ArrayType> narrow::array::StructArrayType for FooBar<T> {
     type Array<Buffer: narrow::buffer::BufferType> = FooBarArray<T, Buffer>;
 }
+impl<T> narrow::projection::FieldNames for FooBar<T> {
+    const FIELD_NAMES: &'static [&'static str] = &["0"];
+}
 struct FooBarArray<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType>(
     <T as narrow::array::ArrayType>::Array<
         Buffer,
@@ -166,6 +374,40 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::WithCapacity for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit
+for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::Length
 for FooBarArray<T, Buffer>
 where
@@ -179,6 +421,101 @@ where
         self.0.len()
     }
 }
+impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::ByteLength
+for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("0", narrow::Length::len(&self.0))],
+            ),
+        )
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenArrayData for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.0)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self(::std::convert::TryFrom::try_from(_0)?))
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenLayout for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.0 as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
@@ -189,12 +526,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<T>,
+    FooBarArray<T, Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = FooBar<T>>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|FooBar(_0)| {
-                self.0.extend(::std::iter::once(_0));
-            });
+        self.0.extend(iter.into_iter().map(|FooBar(_0)| _0));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<
@@ -209,7 +562,35 @@ where
     >: ::std::default::Default + ::std::iter::Extend<T>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = FooBar<T>>>(iter: _I) -> Self {
-        let (_0, ()) = iter.into_iter().map(|FooBar(_0)| (_0, ())).unzip();
-        Self(_0)
+        let mut array = Self(::std::default::Default::default());
+        array.0.extend(iter.into_iter().map(|FooBar(_0)| _0));
+        array
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> ::std::iter::IntoIterator for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = T>,
+{
+    type Item = FooBar<T>;
+    type IntoIter = ::std::iter::Map<
+        <<T as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(T) -> FooBar<T>,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.0),
+            |_0| FooBar(_0),
+        )
     }
 }
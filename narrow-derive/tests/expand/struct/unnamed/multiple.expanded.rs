@@ -16,6 +16,9 @@ impl narrow::array::ArrayType<Bar> for ::std::option::Option<Bar> {
 impl narrow::array::StructArrayType for Bar {
     type Array<Buffer: narrow::buffer::BufferType> = BarArray<Buffer>;
 }
+impl narrow::projection::FieldNames for Bar {
+    const FIELD_NAMES: &'static [&'static str] = &["0", "1", "2", "3"];
+}
 struct BarArray<Buffer: narrow::buffer::BufferType>(
     <u8 as narrow::array::ArrayType>::Array<
         Buffer,
@@ -70,6 +73,80 @@ where
         )
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::WithCapacity for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(
+            narrow::WithCapacity::with_capacity(capacity),
+            narrow::WithCapacity::with_capacity(capacity),
+            narrow::WithCapacity::with_capacity(capacity),
+            narrow::WithCapacity::with_capacity(capacity),
+        )
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+        narrow::WithCapacity::reserve(&mut self.1, additional);
+        narrow::WithCapacity::reserve(&mut self.2, additional);
+        narrow::WithCapacity::reserve(&mut self.3, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+        narrow::WithCapacity::reserve_exact(&mut self.1, additional);
+        narrow::WithCapacity::reserve_exact(&mut self.2, additional);
+        narrow::WithCapacity::reserve_exact(&mut self.3, additional);
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.1);
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.2);
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.3);
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType>::Array<
@@ -97,6 +174,202 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::ByteLength for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0) + narrow::ByteLength::byte_len(&self.1)
+            + narrow::ByteLength::byte_len(&self.2)
+            + narrow::ByteLength::byte_len(&self.3)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ("0", narrow::Length::len(&self.0)),
+                    ("1", narrow::Length::len(&self.1)),
+                    ("2", narrow::Length::len(&self.2)),
+                    ("3", narrow::Length::len(&self.3)),
+                ],
+            ),
+        )
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0", "1", "2", "3"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ::std::convert::Into::into(self.0),
+                    ::std::convert::Into::into(self.1),
+                    ::std::convert::Into::into(self.2),
+                    ::std::convert::Into::into(self.3),
+                ],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0, _1, _2, _3]: [narrow::layout::ArrayData; 4usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 4usize,
+            })?;
+        Ok(
+            Self(
+                ::std::convert::TryFrom::try_from(_0)?,
+                ::std::convert::TryFrom::try_from(_1)?,
+                ::std::convert::TryFrom::try_from(_2)?,
+                ::std::convert::TryFrom::try_from(_3)?,
+            ),
+        )
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    &self.0 as &dyn narrow::layout::ArrayLayout,
+                    &self.1 as &dyn narrow::layout::ArrayLayout,
+                    &self.2 as &dyn narrow::layout::ArrayLayout,
+                    &self.3 as &dyn narrow::layout::ArrayLayout,
+                ],
+            ),
+        )
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Bar> for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType>::Array<
@@ -119,15 +392,47 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<u64>,
+    BarArray<Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Bar>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Bar(_0, _1, _2, _3)| {
-                self.0.extend(::std::iter::once(_0));
-                self.1.extend(::std::iter::once(_1));
-                self.2.extend(::std::iter::once(_2));
-                self.3.extend(::std::iter::once(_3));
-            });
+        let items = iter.into_iter();
+        let capacity = items.size_hint().0;
+        let mut buffers = (
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+        );
+        self.0
+            .extend(
+                items
+                    .map(|Bar(_0, _1, _2, _3)| {
+                        buffers.0.push(_1);
+                        buffers.1.push(_2);
+                        buffers.2.push(_3);
+                        _0
+                    }),
+            );
+        self.1.extend(buffers.0);
+        self.2.extend(buffers.1);
+        self.3.extend(buffers.2);
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Bar>
@@ -155,10 +460,104 @@ where
     >: ::std::default::Default + ::std::iter::Extend<u64>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar>>(iter: _I) -> Self {
-        let (_0, (_1, (_2, (_3, ())))) = iter
-            .into_iter()
-            .map(|Bar(_0, _1, _2, _3)| (_0, (_1, (_2, (_3, ())))))
-            .unzip();
-        Self(_0, _1, _2, _3)
+        let mut array = Self(
+            ::std::default::Default::default(),
+            ::std::default::Default::default(),
+            ::std::default::Default::default(),
+            ::std::default::Default::default(),
+        );
+        let items = iter.into_iter();
+        let capacity = items.size_hint().0;
+        let mut buffers = (
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+            ::std::vec::Vec::with_capacity(capacity),
+        );
+        array
+            .0
+            .extend(
+                items
+                    .map(|Bar(_0, _1, _2, _3)| {
+                        buffers.0.push(_1);
+                        buffers.1.push(_2);
+                        buffers.2.push(_3);
+                        _0
+                    }),
+            );
+        array.1.extend(buffers.0);
+        array.2.extend(buffers.1);
+        array.3.extend(buffers.2);
+        array
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = u8>,
+    <u16 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = u16>,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = u32>,
+    <u64 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = u64>,
+{
+    type Item = Bar;
+    type IntoIter = ::std::iter::Map<
+        ::std::iter::Zip<
+            <<u8 as narrow::array::ArrayType>::Array<
+                Buffer,
+                narrow::offset::NA,
+                narrow::array::union::NA,
+            > as ::std::iter::IntoIterator>::IntoIter,
+            ::std::iter::Zip<
+                <<u16 as narrow::array::ArrayType>::Array<
+                    Buffer,
+                    narrow::offset::NA,
+                    narrow::array::union::NA,
+                > as ::std::iter::IntoIterator>::IntoIter,
+                ::std::iter::Zip<
+                    <<u32 as narrow::array::ArrayType>::Array<
+                        Buffer,
+                        narrow::offset::NA,
+                        narrow::array::union::NA,
+                    > as ::std::iter::IntoIterator>::IntoIter,
+                    <<u64 as narrow::array::ArrayType>::Array<
+                        Buffer,
+                        narrow::offset::NA,
+                        narrow::array::union::NA,
+                    > as ::std::iter::IntoIterator>::IntoIter,
+                >,
+            >,
+        >,
+        fn((u8, (u16, (u32, u64)))) -> Bar,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(
+                ::std::iter::Iterator::zip(
+                    ::std::iter::IntoIterator::into_iter(self.0),
+                    ::std::iter::Iterator::zip(
+                        ::std::iter::IntoIterator::into_iter(self.1),
+                        ::std::iter::Iterator::zip(
+                            ::std::iter::IntoIterator::into_iter(self.2),
+                            self.3,
+                        ),
+                    ),
+                ),
+            ),
+            |(_0, (_1, (_2, _3)))| Bar(_0, _1, _2, _3),
+        )
     }
 }
@@ -16,6 +16,9 @@ impl narrow::array::ArrayType<Foo> for ::std::option::Option<Foo> {
 impl narrow::array::StructArrayType for Foo {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<Buffer>;
 }
+impl narrow::projection::FieldNames for Foo {
+    const FIELD_NAMES: &'static [&'static str] = &["0"];
+}
 struct FooArray<Buffer: narrow::buffer::BufferType>(
     <u32 as narrow::array::ArrayType>::Array<
         Buffer,
@@ -35,6 +38,36 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::WithCapacity for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType>::Array<
@@ -47,6 +80,94 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::ByteLength for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("0", narrow::Length::len(&self.0))],
+            ),
+        )
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.0)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self(::std::convert::TryFrom::try_from(_0)?))
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.0 as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType>::Array<
@@ -54,12 +175,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<u32>,
+    FooArray<Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Foo(_0)| {
-                self.0.extend(::std::iter::once(_0));
-            });
+        self.0.extend(iter.into_iter().map(|Foo(_0)| _0));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Foo>
@@ -72,8 +209,33 @@ where
     >: ::std::default::Default + ::std::iter::Extend<u32>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
-        let (_0, ()) = iter.into_iter().map(|Foo(_0)| (_0, ())).unzip();
-        Self(_0)
+        let mut array = Self(::std::default::Default::default());
+        array.0.extend(iter.into_iter().map(|Foo(_0)| _0));
+        array
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = u32>,
+{
+    type Item = Foo;
+    type IntoIter = ::std::iter::Map<
+        <<u32 as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(u32) -> Foo,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.0),
+            |_0| Foo(_0),
+        )
     }
 }
 struct Bar(Foo);
@@ -94,6 +256,9 @@ impl narrow::array::ArrayType<Bar> for ::std::option::Option<Bar> {
 impl narrow::array::StructArrayType for Bar {
     type Array<Buffer: narrow::buffer::BufferType> = BarArray<Buffer>;
 }
+impl narrow::projection::FieldNames for Bar {
+    const FIELD_NAMES: &'static [&'static str] = &["0"];
+}
 struct BarArray<Buffer: narrow::buffer::BufferType>(
     <Foo as narrow::array::ArrayType>::Array<
         Buffer,
@@ -113,6 +278,36 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::WithCapacity for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType>::Array<
@@ -125,6 +320,94 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::ByteLength for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("0", narrow::Length::len(&self.0))],
+            ),
+        )
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.0)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self(::std::convert::TryFrom::try_from(_0)?))
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.0 as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Bar> for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType>::Array<
@@ -132,12 +415,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<Foo>,
+    BarArray<Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Bar>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Bar(_0)| {
-                self.0.extend(::std::iter::once(_0));
-            });
+        self.0.extend(iter.into_iter().map(|Bar(_0)| _0));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Bar>
@@ -150,7 +449,32 @@ where
     >: ::std::default::Default + ::std::iter::Extend<Foo>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar>>(iter: _I) -> Self {
-        let (_0, ()) = iter.into_iter().map(|Bar(_0)| (_0, ())).unzip();
-        Self(_0)
+        let mut array = Self(::std::default::Default::default());
+        array.0.extend(iter.into_iter().map(|Bar(_0)| _0));
+        array
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = Foo>,
+{
+    type Item = Bar;
+    type IntoIter = ::std::iter::Map<
+        <<Foo as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(Foo) -> Bar,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.0),
+            |_0| Bar(_0),
+        )
     }
 }
@@ -30,6 +30,12 @@ where
 {
     type Array<Buffer: narrow::buffer::BufferType> = FooArray<T, Buffer>;
 }
+impl<T> narrow::projection::FieldNames for Foo<T>
+where
+    T: Copy,
+{
+    const FIELD_NAMES: &'static [&'static str] = &["0"];
+}
 struct FooArray<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType>(
     <T as narrow::array::ArrayType>::Array<
         Buffer,
@@ -55,6 +61,42 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::WithCapacity for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit
+for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::Length
 for FooArray<T, Buffer>
 where
@@ -69,6 +111,105 @@ where
         self.0.len()
     }
 }
+impl<T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::ByteLength
+for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("0", narrow::Length::len(&self.0))],
+            ),
+        )
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenArrayData for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.0)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self(::std::convert::TryFrom::try_from(_0)?))
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenLayout for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.0 as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
@@ -80,12 +221,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<T>,
+    FooArray<T, Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo<T>>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Foo(_0)| {
-                self.0.extend(::std::iter::once(_0));
-            });
+        self.0.extend(iter.into_iter().map(|Foo(_0)| _0));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<
@@ -101,8 +258,37 @@ where
     >: ::std::default::Default + ::std::iter::Extend<T>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<T>>>(iter: _I) -> Self {
-        let (_0, ()) = iter.into_iter().map(|Foo(_0)| (_0, ())).unzip();
-        Self(_0)
+        let mut array = Self(::std::default::Default::default());
+        array.0.extend(iter.into_iter().map(|Foo(_0)| _0));
+        array
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> ::std::iter::IntoIterator for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = T>,
+{
+    type Item = Foo<T>;
+    type IntoIter = ::std::iter::Map<
+        <<T as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(T) -> Foo<T>,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.0),
+            |_0| Foo(_0),
+        )
     }
 }
 struct Bar<'a, T>(&'a Foo<T>);
@@ -124,6 +310,9 @@ for ::std::option::Option<Bar<'a, T>> {
 impl<'a, T: narrow::array::ArrayType> narrow::array::StructArrayType for Bar<'a, T> {
     type Array<Buffer: narrow::buffer::BufferType> = BarArray<'a, T, Buffer>;
 }
+impl<'a, T> narrow::projection::FieldNames for Bar<'a, T> {
+    const FIELD_NAMES: &'static [&'static str] = &["0"];
+}
 struct BarArray<'a, T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType>(
     <&'a Foo<
         T,
@@ -151,6 +340,48 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::WithCapacity for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::ShrinkToFit for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<'a, T: narrow::array::ArrayType, Buffer: narrow::buffer::BufferType> narrow::Length
 for BarArray<'a, T, Buffer>
 where
@@ -166,6 +397,117 @@ where
         self.0.len()
     }
 }
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::ByteLength for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("0", narrow::Length::len(&self.0))],
+            ),
+        )
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenArrayData for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.0)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self(::std::convert::TryFrom::try_from(_0)?))
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> narrow::layout::ChildrenLayout for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.0 as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<
     'a,
     T: narrow::array::ArrayType,
@@ -179,12 +521,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<&'a Foo<T>>,
+    BarArray<'a, T, Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = Bar<'a, T>>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|Bar(_0)| {
-                self.0.extend(::std::iter::once(_0));
-            });
+        self.0.extend(iter.into_iter().map(|Bar(_0)| _0));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<
@@ -202,8 +560,41 @@ where
     >: ::std::default::Default + ::std::iter::Extend<&'a Foo<T>>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar<'a, T>>>(iter: _I) -> Self {
-        let (_0, ()) = iter.into_iter().map(|Bar(_0)| (_0, ())).unzip();
-        Self(_0)
+        let mut array = Self(::std::default::Default::default());
+        array.0.extend(iter.into_iter().map(|Bar(_0)| _0));
+        array
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+> ::std::iter::IntoIterator for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = &'a Foo<T>>,
+{
+    type Item = Bar<'a, T>;
+    type IntoIter = ::std::iter::Map<
+        <<&'a Foo<
+            T,
+        > as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(&'a Foo<T>) -> Bar<'a, T>,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.0),
+            |_0| Bar(_0),
+        )
     }
 }
 struct FooBar<'a>(Bar<'a, u32>);
@@ -224,6 +615,9 @@ impl<'a> narrow::array::ArrayType<FooBar<'a>> for ::std::option::Option<FooBar<'
 impl<'a> narrow::array::StructArrayType for FooBar<'a> {
     type Array<Buffer: narrow::buffer::BufferType> = FooBarArray<'a, Buffer>;
 }
+impl<'a> narrow::projection::FieldNames for FooBar<'a> {
+    const FIELD_NAMES: &'static [&'static str] = &["0"];
+}
 struct FooBarArray<'a, Buffer: narrow::buffer::BufferType>(
     <Bar<
         'a,
@@ -250,6 +644,44 @@ where
         Self(::std::default::Default::default())
     }
 }
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::WithCapacity
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(narrow::WithCapacity::with_capacity(capacity))
+    }
+    fn reserve(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve(&mut self.0, additional);
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        narrow::WithCapacity::reserve_exact(&mut self.0, additional);
+    }
+}
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::ShrinkToFit
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        narrow::ShrinkToFit::shrink_to_fit(&mut self.0);
+    }
+}
 impl<'a, Buffer: narrow::buffer::BufferType> narrow::Length for FooBarArray<'a, Buffer>
 where
     <Bar<
@@ -265,6 +697,110 @@ where
         self.0.len()
     }
 }
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::ByteLength
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        narrow::ByteLength::byte_len(&self.0)
+    }
+}
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::Length,
+{
+    fn child_lengths(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [("0", narrow::Length::len(&self.0))],
+            ),
+        )
+    }
+}
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenArrayData
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::Into<narrow::layout::ArrayData>,
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::convert::TryFrom<
+        narrow::layout::ArrayData,
+        Error = narrow::layout::ArrayDataError,
+    >,
+{
+    const NAMES: &'static [&'static str] = &["0"];
+    fn into_children(self) -> ::std::vec::Vec<narrow::layout::ArrayData> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [::std::convert::Into::into(self.0)],
+            ),
+        )
+    }
+    fn try_from_children(
+        _len: usize,
+        children: ::std::vec::Vec<narrow::layout::ArrayData>,
+    ) -> ::std::result::Result<Self, narrow::layout::ArrayDataError> {
+        let [_0]: [narrow::layout::ArrayData; 1usize] = ::std::convert::TryFrom::try_from(
+                children,
+            )
+            .map_err(|children: ::std::vec::Vec<_>| narrow::layout::ArrayDataError::ChildCount {
+                count: children.len(),
+                expected: 1usize,
+            })?;
+        Ok(Self(::std::convert::TryFrom::try_from(_0)?))
+    }
+}
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::layout::ChildrenLayout
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: narrow::layout::ArrayLayout,
+{
+    fn child_layouts(&self) -> ::std::vec::Vec<&dyn narrow::layout::ArrayLayout> {
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [&self.0 as &dyn narrow::layout::ArrayLayout],
+            ),
+        )
+    }
+}
 impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<FooBar<'a>>
 for FooBarArray<'a, Buffer>
 where
@@ -276,12 +812,28 @@ where
         narrow::offset::NA,
         narrow::array::union::NA,
     >: ::std::iter::Extend<Bar<'a, u32>>,
+    FooBarArray<'a, Buffer>: narrow::array::StructArrayChildren,
 {
     fn extend<_I: ::std::iter::IntoIterator<Item = FooBar<'a>>>(&mut self, iter: _I) {
-        iter.into_iter()
-            .for_each(|FooBar(_0)| {
-                self.0.extend(::std::iter::once(_0));
-            });
+        self.0.extend(iter.into_iter().map(|FooBar(_0)| _0));
+        if true {
+            match (
+                &narrow::array::StructArrayChildren::check_child_lengths(self),
+                &::std::result::Result::Ok(()),
+            ) {
+                (left_val, right_val) => {
+                    if !(*left_val == *right_val) {
+                        let kind = ::core::panicking::AssertKind::Eq;
+                        ::core::panicking::assert_failed(
+                            kind,
+                            &*left_val,
+                            &*right_val,
+                            ::core::option::Option::None,
+                        );
+                    }
+                }
+            };
+        }
     }
 }
 impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<FooBar<'a>>
@@ -297,7 +849,39 @@ where
     >: ::std::default::Default + ::std::iter::Extend<Bar<'a, u32>>,
 {
     fn from_iter<_I: ::std::iter::IntoIterator<Item = FooBar<'a>>>(iter: _I) -> Self {
-        let (_0, ()) = iter.into_iter().map(|FooBar(_0)| (_0, ())).unzip();
-        Self(_0)
+        let mut array = Self(::std::default::Default::default());
+        array.0.extend(iter.into_iter().map(|FooBar(_0)| _0));
+        array
+    }
+}
+impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::std::iter::IntoIterator<Item = Bar<'a, u32>>,
+{
+    type Item = FooBar<'a>;
+    type IntoIter = ::std::iter::Map<
+        <<Bar<
+            'a,
+            u32,
+        > as narrow::array::ArrayType>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as ::std::iter::IntoIterator>::IntoIter,
+        fn(Bar<'a, u32>) -> FooBar<'a>,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(self.0),
+            |_0| FooBar(_0),
+        )
     }
 }
@@ -6,7 +6,7 @@ use crate::{
     buffer::{BufferRef, BufferRefMut, BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    ByteLength, Index, Length, ShrinkToFit, WithCapacity,
 };
 
 /// Array with boolean values.
@@ -32,6 +32,20 @@ where
     }
 }
 
+impl BooleanArray {
+    /// Returns an array with `value` repeated `n` times.
+    #[must_use]
+    pub fn repeat(value: bool, n: usize) -> Self {
+        Self(Bitmap::repeat(value, n))
+    }
+
+    /// Returns an array with the values of this array repeated `n` times.
+    #[must_use]
+    pub fn tile(&self, n: usize) -> Self {
+        Self(self.0.tile(n))
+    }
+}
+
 impl<const NULLABLE: bool, Buffer: BufferType> Array for BooleanArray<NULLABLE, Buffer>
 where
     Bitmap<Buffer>: Validity<NULLABLE>,
@@ -76,6 +90,24 @@ where
     }
 }
 
+impl<const NULLABLE: bool, Buffer: BufferType> WithCapacity for BooleanArray<NULLABLE, Buffer>
+where
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(WithCapacity::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
 impl<U, const NULLABLE: bool, Buffer: BufferType> Extend<U> for BooleanArray<NULLABLE, Buffer>
 where
     Bitmap<Buffer>: Validity<NULLABLE>,
@@ -110,7 +142,8 @@ where
     Bitmap<Buffer>: Validity<NULLABLE>,
     <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Index,
 {
-    type Item<'a> = <<Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
+    type Item<'a>
+        = <<Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
     where
         Self: 'a;
 
@@ -159,6 +192,26 @@ where
     }
 }
 
+impl<const NULLABLE: bool, Buffer: BufferType> ByteLength for BooleanArray<NULLABLE, Buffer>
+where
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> ShrinkToFit for BooleanArray<NULLABLE, Buffer>
+where
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<Buffer: BufferType> BitmapRef for BooleanArray<true, Buffer> {
     type Buffer = Buffer;
 
@@ -181,6 +234,16 @@ mod tests {
     use crate::buffer::BoxBuffer;
     use std::mem;
 
+    #[test]
+    fn repeat_tile() {
+        assert!(BooleanArray::repeat(true, 11).into_iter().all(|bit| bit));
+        let array = [true, false, false].into_iter().collect::<BooleanArray>();
+        assert_eq!(
+            array.tile(5).into_iter().collect::<Vec<_>>(),
+            [true, false, false].repeat(5)
+        );
+    }
+
     #[test]
     fn from_iter() {
         let mut array = [true, false, true, true]
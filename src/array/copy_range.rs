@@ -0,0 +1,243 @@
+//! Copying ranges of values between arrays.
+
+#[cfg(feature = "strings")]
+use super::StringArray;
+use super::{BooleanArray, FixedSizePrimitiveArray, VariableSizeBinaryArray};
+use crate::{bitmap::Bitmap, nullable::Nullable, offset::OffsetElement, FixedSize};
+use std::ops::Range;
+
+/// Arrays that can copy a range of values from another array in place.
+pub trait CopyRange {
+    /// Copies the values of `src` in `src_range` to this array, starting at
+    /// index `dst_offset`.
+    ///
+    /// Values of this array in the destination range are overwritten, and
+    /// the array is extended when the copied values don't fit. Data is copied
+    /// in blocks, offsets of variable-size arrays are rebased and validity
+    /// bits are copied in blocks of bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src_range` is out of bounds of `src`, or if `dst_offset` is
+    /// greater than the length of this array.
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize);
+}
+
+/// Copies the values of `src` in `src_range` to `dst`, starting at index
+/// `dst_offset`, extending `dst` when needed.
+fn copy_values<T: Copy>(dst: &mut Vec<T>, src: &[T], src_range: Range<usize>, dst_offset: usize) {
+    assert!(
+        dst_offset <= dst.len(),
+        "destination offset (is {dst_offset}) should be <= len (is {})",
+        dst.len()
+    );
+    let values = &src[src_range];
+    let overlap = values.len().min(dst.len() - dst_offset);
+    let (overwritten, appended) = values.split_at(overlap);
+    dst[dst_offset..dst_offset + overlap].copy_from_slice(overwritten);
+    dst.extend_from_slice(appended);
+}
+
+impl<T: FixedSize> CopyRange for FixedSizePrimitiveArray<T> {
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize) {
+        copy_values(&mut self.0, &src.0, src_range, dst_offset);
+    }
+}
+
+impl<T: FixedSize> CopyRange for FixedSizePrimitiveArray<T, true> {
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize) {
+        copy_nullable(
+            &mut self.0,
+            &src.0,
+            src_range,
+            dst_offset,
+            |data, values, range, offset| copy_values(data, values, range, offset),
+        );
+    }
+}
+
+/// Copies the validity bits and the values of `src` in `src_range` to `dst`,
+/// using `copy` to copy the values.
+fn copy_nullable<T>(
+    dst: &mut Nullable<T>,
+    src: &Nullable<T>,
+    src_range: Range<usize>,
+    dst_offset: usize,
+    copy: impl FnOnce(&mut T, &T, Range<usize>, usize),
+) {
+    dst.validity
+        .copy_bits(&src.validity, src_range.start, dst_offset, src_range.len());
+    copy(&mut dst.data, &src.data, src_range, dst_offset);
+}
+
+/// Copies the bits of `src` in `src_range` to `dst`, starting at index
+/// `dst_offset`.
+fn copy_bitmap(dst: &mut Bitmap, src: &Bitmap, src_range: Range<usize>, dst_offset: usize) {
+    dst.copy_bits(src, src_range.start, dst_offset, src_range.len());
+}
+
+impl CopyRange for BooleanArray {
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize) {
+        copy_bitmap(&mut self.0, &src.0, src_range, dst_offset);
+    }
+}
+
+impl CopyRange for BooleanArray<true> {
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize) {
+        copy_nullable(&mut self.0, &src.0, src_range, dst_offset, copy_bitmap);
+    }
+}
+
+/// Copies the variable-size values of `src` in `src_range` to `dst`,
+/// starting at index `dst_offset`, where the values are described by the
+/// offsets and the data.
+///
+/// The data of the overwritten values is replaced, and the offsets of the
+/// copied values and the values after them are rebased.
+fn copy_variable_size<OffsetItem: OffsetElement>(
+    (dst_offsets, dst_data): (&mut Vec<OffsetItem>, &mut Vec<u8>),
+    (src_offsets, src_data): (&[OffsetItem], &[u8]),
+    src_range: Range<usize>,
+    dst_offset: usize,
+) {
+    /// Returns the offset as a `usize`.
+    fn position<OffsetItem: OffsetElement>(offset: OffsetItem) -> usize {
+        offset.try_into().expect("offset should fit in usize")
+    }
+    /// Returns the `usize` as an offset.
+    fn offset<OffsetItem: OffsetElement>(position: usize) -> OffsetItem {
+        OffsetItem::try_from(position).expect("position should fit in offset type")
+    }
+
+    let len = dst_offsets.len() - 1;
+    assert!(
+        dst_offset <= len,
+        "destination offset (is {dst_offset}) should be <= len (is {len})"
+    );
+    assert!(
+        src_range.end < src_offsets.len(),
+        "source range end (is {}) should be <= source len (is {})",
+        src_range.end,
+        src_offsets.len() - 1
+    );
+    let replaced_end = (dst_offset + src_range.len()).min(len);
+    let src_start = position(src_offsets[src_range.start]);
+    let src_end = position(src_offsets[src_range.end]);
+    let dst_start = position(dst_offsets[dst_offset]);
+    let dst_end = position(dst_offsets[replaced_end]);
+
+    dst_data.splice(
+        dst_start..dst_end,
+        src_data[src_start..src_end].iter().copied(),
+    );
+
+    let copied = src_offsets[src_range.start + 1..=src_range.end]
+        .iter()
+        .map(|&item| offset(dst_start + position(item) - src_start));
+    let tail_start = dst_offset + src_range.len() + 1;
+    dst_offsets.splice(dst_offset + 1..=replaced_end, copied);
+    let new_end = dst_start + src_end - src_start;
+    for item in &mut dst_offsets[tail_start..] {
+        *item = offset(position(*item) - dst_end + new_end);
+    }
+}
+
+impl<OffsetItem: OffsetElement> CopyRange for VariableSizeBinaryArray<false, OffsetItem> {
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize) {
+        copy_variable_size(
+            (&mut self.0.offsets, &mut self.0.data.0),
+            (&src.0.offsets, &src.0.data.0),
+            src_range,
+            dst_offset,
+        );
+    }
+}
+
+impl<OffsetItem: OffsetElement> CopyRange for VariableSizeBinaryArray<true, OffsetItem> {
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize) {
+        self.0.offsets.validity.copy_bits(
+            &src.0.offsets.validity,
+            src_range.start,
+            dst_offset,
+            src_range.len(),
+        );
+        copy_variable_size(
+            (&mut self.0.offsets.data, &mut self.0.data.0),
+            (&src.0.offsets.data, &src.0.data.0),
+            src_range,
+            dst_offset,
+        );
+    }
+}
+
+#[cfg(feature = "strings")]
+impl<const NULLABLE: bool, OffsetItem: OffsetElement> CopyRange
+    for StringArray<NULLABLE, OffsetItem>
+where
+    Vec<OffsetItem>: crate::validity::Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem>: CopyRange,
+{
+    fn copy_range(&mut self, src: &Self, src_range: Range<usize>, dst_offset: usize) {
+        self.0.copy_range(&src.0, src_range, dst_offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint16Array;
+
+    #[test]
+    fn fixed_size() {
+        let mut dst = [Some(1_u16), None, Some(3)]
+            .into_iter()
+            .collect::<Uint16Array<true>>();
+        let src = (10..20)
+            .map(|value| (value % 2 == 0).then_some(value))
+            .collect::<Uint16Array<true>>();
+        dst.copy_range(&src, 3..7, 1);
+        assert_eq!(
+            dst.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(14), None, Some(16)]
+        );
+
+        let mut values = [1_u16, 2, 3].into_iter().collect::<Uint16Array>();
+        values.copy_range(&[7, 8].into_iter().collect(), 0..1, 1);
+        assert_eq!(values.as_ref(), [1, 7, 3]);
+    }
+
+    #[test]
+    fn boolean() {
+        let input = (0..20).map(|index| index % 3 == 0).collect::<Vec<_>>();
+        let src = input.iter().copied().collect::<BooleanArray>();
+        let mut dst = [false; 12].into_iter().collect::<BooleanArray>();
+        dst.copy_range(&src, 5..18, 3);
+        assert!(dst
+            .into_iter()
+            .eq([false; 3].into_iter().chain(input[5..18].iter().copied())));
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn string() {
+        let mut dst = [Some("a"), None, Some("ccc"), Some("dd")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let src = [Some("xx"), Some("yyy"), None]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        dst.copy_range(&src, 1..3, 1);
+        assert_eq!(
+            dst.into_iter().collect::<Vec<_>>(),
+            [Some("a"), Some("yyy"), None, Some("dd")]
+        );
+
+        let mut values = ["a", "b"].into_iter().collect::<StringArray>();
+        values.copy_range(&["xyz", "uv", "w"].into_iter().collect(), 0..3, 1);
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            ["a", "xyz", "uv", "w"]
+        );
+        assert_eq!(values.0 .0.offsets, [0, 1, 4, 6, 7]);
+    }
+}
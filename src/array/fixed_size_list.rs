@@ -10,7 +10,7 @@ use crate::{
     buffer::{BufferMut, BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    ByteLength, Index, Length, ShrinkToFit, WithCapacity,
 };
 
 use super::Array;
@@ -75,6 +75,45 @@ where
     }
 }
 
+impl<const N: usize, T: Array + WithCapacity, Buffer: BufferType> WithCapacity
+    for FixedSizeListArray<N, T, false, Buffer>
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(T::with_capacity(capacity * N))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional * N);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional * N);
+    }
+}
+
+impl<const N: usize, T: Array + WithCapacity, Buffer: BufferType> WithCapacity
+    for FixedSizeListArray<N, T, true, Buffer>
+where
+    Bitmap<Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(Nullable {
+            data: T::with_capacity(capacity * N),
+            validity: Bitmap::with_capacity(capacity),
+        })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.data.reserve(additional * N);
+        self.0.validity.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.data.reserve_exact(additional * N);
+        self.0.validity.reserve_exact(additional);
+    }
+}
+
 impl<U, const N: usize, T: Array, Buffer: BufferType> Extend<[U; N]>
     for FixedSizeListArray<N, T, false, Buffer>
 where
@@ -148,7 +187,8 @@ impl<const N: usize, T: Array, Buffer: BufferType> Index for FixedSizeListArray<
 where
     T: Index,
 {
-    type Item<'a> = [<T as Index>::Item<'a>; N]
+    type Item<'a>
+        = [<T as Index>::Item<'a>; N]
     where
         Self: 'a;
 
@@ -174,7 +214,8 @@ impl<const N: usize, T: Array, Buffer: BufferType> Index for FixedSizeListArray<
 where
     T: Index,
 {
-    type Item<'a> = Option<[<T as Index>::Item<'a>; N]>
+    type Item<'a>
+        = Option<[<T as Index>::Item<'a>; N]>
     where
         Self: 'a;
 
@@ -262,12 +303,35 @@ where
     }
 }
 
+impl<const N: usize, T: Array, const NULLABLE: bool, Buffer: BufferType> ByteLength
+    for FixedSizeListArray<N, T, NULLABLE, Buffer>
+where
+    T: Validity<NULLABLE>,
+    <T as Validity<NULLABLE>>::Storage<Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<const N: usize, T: Array, const NULLABLE: bool, Buffer: BufferType> ShrinkToFit
+    for FixedSizeListArray<N, T, NULLABLE, Buffer>
+where
+    T: Validity<NULLABLE>,
+    <T as Validity<NULLABLE>>::Storage<Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<const N: usize, T: Array, Buffer: BufferType> ValidityBitmap
     for FixedSizeListArray<N, T, true, Buffer>
 {
 }
 
 #[cfg(test)]
+#[cfg(feature = "strings")]
 mod tests {
     use crate::array::{FixedSizePrimitiveArray, StringArray};
 
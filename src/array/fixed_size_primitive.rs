@@ -3,12 +3,12 @@
 use super::Array;
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{Buffer, BufferType, VecBuffer},
+    buffer::{ArcBuffer, Buffer, BufferMut, BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    FixedSize, Index, Length,
+    ByteLength, FixedSize, Index, Length, PlainOldData, ShrinkToFit, WithCapacity,
 };
-use std::{ops, slice::SliceIndex};
+use std::{marker::PhantomData, mem, ops, slice::SliceIndex, sync::Arc};
 
 /// Array with primitive values.
 pub struct FixedSizePrimitiveArray<
@@ -78,6 +78,381 @@ impl<T: FixedSize, Buffer: BufferType> AsRef<[T]> for FixedSizePrimitiveArray<T,
     }
 }
 
+impl<T: FixedSize, Buffer: BufferType> AsMut<[T]> for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: BufferMut<T>,
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        self.0.as_mut_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> ops::Deref for FixedSizePrimitiveArray<T, false, Buffer> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> ops::DerefMut for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: BufferMut<T>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Clone for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: FixedSize> FixedSizePrimitiveArray<T, false, ArcBuffer> {
+    /// Returns `true` if the buffer of this array is not shared with other
+    /// arrays, so [`Self::make_mut`] doesn't copy the values.
+    #[must_use]
+    pub fn is_unique(&self) -> bool {
+        Arc::strong_count(&self.0) == 1 && Arc::weak_count(&self.0) == 0
+    }
+
+    /// Returns a mutable slice of the values in this array.
+    ///
+    /// When the buffer is shared with other arrays the values are copied to a
+    /// new buffer first, so the other arrays are not modified.
+    pub fn make_mut(&mut self) -> &mut [T] {
+        if Arc::get_mut(&mut self.0).is_none() {
+            self.0 = self.0.iter().copied().collect();
+        }
+        self.0.as_mut_slice()
+    }
+
+    /// Returns a copy of this array with the value at `index` replaced by the
+    /// result of `f`.
+    ///
+    /// Only the buffer of this array is copied. Cloning the returned array
+    /// again is cheap.
+    ///
+    /// Struct batches don't have a row-level `with_updated` yet, because the
+    /// derive macro doesn't generate borrowed row types to pass to the update
+    /// function. Until it does, update the Arc-buffered child arrays of a
+    /// shared batch with this method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[must_use]
+    pub fn with_updated(&self, index: usize, f: impl FnOnce(T) -> T) -> Self {
+        let mut array = self.clone();
+        let value = &mut array.make_mut()[index];
+        *value = f(*value);
+        array
+    }
+}
+
+/// Checks that two types have the same size and alignment, at compile time.
+struct SameLayout<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> SameLayout<T, U> {
+    /// Fails compilation if the size or the alignment of `T` and `U` differ.
+    const ASSERT: () = assert!(
+        mem::size_of::<T>() == mem::size_of::<U>() && mem::align_of::<T>() == mem::align_of::<U>(),
+        "size and alignment of target type should be equal to size and alignment of source type"
+    );
+}
+
+/// Returns `values` as a vector of `U`, without copying.
+///
+/// Compilation fails if the size or the alignment of `T` and `U` differ.
+fn reinterpret_vec<T: PlainOldData, U: PlainOldData>(values: Vec<T>) -> Vec<U> {
+    let () = SameLayout::<T, U>::ASSERT;
+    let mut source = mem::ManuallyDrop::new(values);
+    // Safety:
+    // - `U` is plain old data, so it is valid for any bit pattern, and `T` is
+    //   plain old data, so all bytes are initialized.
+    // - `T` and `U` have the same size and alignment, so the allocation has
+    //   the layout of a vector of `U` with the same length and capacity.
+    unsafe {
+        Vec::from_raw_parts(
+            source.as_mut_ptr().cast::<U>(),
+            source.len(),
+            source.capacity(),
+        )
+    }
+}
+
+impl<T: PlainOldData> FixedSizePrimitiveArray<T> {
+    /// Returns this array with its values reinterpreted as values of type
+    /// `U`, without copying.
+    ///
+    /// This is a bit-level cast, e.g. from `f64` to `u64` to hash or order
+    /// floating point numbers by their bits. Compilation fails if the size or
+    /// the alignment of `T` and `U` differ:
+    ///
+    /// ```compile_fail
+    /// use narrow::array::Uint64Array;
+    ///
+    /// let _ = [1_u64].into_iter().collect::<Uint64Array>().reinterpret::<u32>();
+    /// ```
+    #[must_use]
+    pub fn reinterpret<U: PlainOldData>(self) -> FixedSizePrimitiveArray<U> {
+        FixedSizePrimitiveArray(reinterpret_vec(self.0))
+    }
+}
+
+impl<T: PlainOldData> FixedSizePrimitiveArray<T, true> {
+    /// Returns this array with its values reinterpreted as values of type
+    /// `U`, without copying. The validity bitmap is kept as is.
+    ///
+    /// Compilation fails if the size or the alignment of `T` and `U` differ.
+    #[must_use]
+    pub fn reinterpret<U: PlainOldData>(self) -> FixedSizePrimitiveArray<U, true> {
+        FixedSizePrimitiveArray(Nullable {
+            data: reinterpret_vec(self.0.data),
+            validity: self.0.validity,
+        })
+    }
+}
+
+/// Returns the values of `values` repeated `n` times.
+fn tile_vec<T: Copy>(values: &[T], n: usize) -> Vec<T> {
+    let mut tiled = Vec::with_capacity(values.len() * n);
+    if n > 0 {
+        tiled.extend_from_slice(values);
+        for _ in 1..n {
+            tiled.extend_from_within(..values.len());
+        }
+    }
+    tiled
+}
+
+impl<T: FixedSize> FixedSizePrimitiveArray<T> {
+    /// Returns an array with `value` repeated `n` times, e.g. to broadcast a
+    /// scalar onto a batch.
+    #[must_use]
+    pub fn repeat(value: T, n: usize) -> Self {
+        Self(vec![value; n])
+    }
+
+    /// Returns an array with the values of this array repeated `n` times.
+    #[must_use]
+    pub fn tile(&self, n: usize) -> Self {
+        Self(tile_vec(&self.0, n))
+    }
+}
+
+impl<T: FixedSize + Default> FixedSizePrimitiveArray<T, true> {
+    /// Returns an array with `value` repeated `n` times, e.g. to broadcast a
+    /// scalar onto a batch.
+    #[must_use]
+    pub fn repeat(value: Option<T>, n: usize) -> Self {
+        Self(Nullable {
+            data: vec![value.unwrap_or_default(); n],
+            validity: Bitmap::repeat(value.is_some(), n),
+        })
+    }
+
+    /// Returns an array with the values of this array repeated `n` times.
+    #[must_use]
+    pub fn tile(&self, n: usize) -> Self {
+        Self(Nullable {
+            data: tile_vec(&self.0.data, n),
+            validity: self.0.validity.tile(n),
+        })
+    }
+}
+
+/// Fixed-size types that form arithmetic sequences.
+pub trait Sequence: FixedSize {
+    /// Returns the value after this value in a sequence with the given step.
+    ///
+    /// This wraps around for integer types.
+    #[must_use]
+    fn advance(self, step: Self) -> Self;
+}
+
+/// Implements [`Sequence`] for integer types.
+macro_rules! impl_sequence_int {
+    ($($ty:ty),+) => {
+        $(
+            impl Sequence for $ty {
+                fn advance(self, step: Self) -> Self {
+                    self.wrapping_add(step)
+                }
+            }
+        )+
+    };
+}
+
+impl_sequence_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl Sequence for f32 {
+    fn advance(self, step: Self) -> Self {
+        self + step
+    }
+}
+
+impl Sequence for f64 {
+    fn advance(self, step: Self) -> Self {
+        self + step
+    }
+}
+
+impl<T: FixedSize> FixedSizePrimitiveArray<T> {
+    /// Returns an array with the values in `range`, e.g. to generate a column
+    /// with row ids.
+    #[must_use]
+    pub fn from_range(range: ops::Range<T>) -> Self
+    where
+        ops::Range<T>: Iterator<Item = T>,
+    {
+        Self(range.collect())
+    }
+}
+
+impl<T: Sequence> FixedSizePrimitiveArray<T> {
+    /// Returns an array with `len` values, starting at `start` and
+    /// incrementing by `step`.
+    ///
+    /// Integer values wrap around.
+    #[must_use]
+    pub fn from_step(start: T, step: T, len: usize) -> Self {
+        let mut values = Vec::with_capacity(len);
+        let mut value = start;
+        for _ in 0..len {
+            values.push(value);
+            value = value.advance(step);
+        }
+        Self(values)
+    }
+}
+
+/// Fixed-size types with a byte order.
+pub trait SwapBytes: PlainOldData {
+    /// Returns this value with the order of its bytes reversed.
+    #[must_use]
+    fn swap_bytes(self) -> Self;
+}
+
+/// Implements [`SwapBytes`] for integer types.
+macro_rules! impl_swap_bytes {
+    ($($ty:ty),+) => {
+        $(
+            impl SwapBytes for $ty {
+                fn swap_bytes(self) -> Self {
+                    <$ty>::swap_bytes(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_swap_bytes!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl SwapBytes for f32 {
+    fn swap_bytes(self) -> Self {
+        f32::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl SwapBytes for f64 {
+    fn swap_bytes(self) -> Self {
+        f64::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+/// Returns the values encoded in the chunks of bytes, in big-endian order if
+/// `big_endian` is set and in little-endian order otherwise.
+///
+/// Whole values in a chunk are copied at once, values that span chunks are
+/// assembled from their parts. The byte order is fixed afterwards, in a
+/// single pass over the values.
+///
+/// # Panics
+///
+/// Panics if the total number of bytes is not a multiple of the size of `T`.
+fn decode_bytes<T: SwapBytes>(
+    chunks: impl IntoIterator<Item = impl AsRef<[u8]>>,
+    big_endian: bool,
+) -> Vec<T> {
+    let mut values = Vec::<T>::new();
+    let mut pending = Vec::with_capacity(T::SIZE);
+    let mut total = 0;
+    for chunk in chunks {
+        let mut bytes = chunk.as_ref();
+        total += bytes.len();
+        if !pending.is_empty() {
+            let (head, tail) = bytes.split_at((T::SIZE - pending.len()).min(bytes.len()));
+            pending.extend_from_slice(head);
+            bytes = tail;
+            if pending.len() == T::SIZE {
+                // Safety:
+                // - `pending` has `T::SIZE` bytes, and plain old data is
+                //   valid for any bit pattern.
+                values.push(unsafe { pending.as_ptr().cast::<T>().read_unaligned() });
+                pending.clear();
+            }
+        }
+        let (whole, rest) = bytes.split_at(bytes.len() - bytes.len() % T::SIZE);
+        let count = whole.len() / T::SIZE;
+        values.reserve(count);
+        let spare = values.spare_capacity_mut().as_mut_ptr().cast::<u8>();
+        // Safety:
+        // - The vector has capacity for `count` more values, which are
+        //   `whole.len()` bytes.
+        unsafe { spare.copy_from_nonoverlapping(whole.as_ptr(), whole.len()) };
+        // Safety:
+        // - The values up to the new length are initialized above, and
+        //   plain old data is valid for any bit pattern.
+        unsafe { values.set_len(values.len() + count) };
+        pending.extend_from_slice(rest);
+    }
+    assert!(
+        pending.is_empty(),
+        "number of bytes (is {total}) should be a multiple of the size of the values (is {})",
+        T::SIZE
+    );
+    if big_endian != cfg!(target_endian = "big") {
+        for value in &mut values {
+            *value = value.swap_bytes();
+        }
+    }
+    values
+}
+
+impl<T: SwapBytes> FixedSizePrimitiveArray<T> {
+    /// Returns an array with the values decoded from the big-endian bytes in
+    /// `chunks`, e.g. the packets of a network protocol.
+    ///
+    /// Values may span chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of bytes is not a multiple of the size of
+    /// `T`.
+    pub fn from_be_bytes_iter(chunks: impl IntoIterator<Item = impl AsRef<[u8]>>) -> Self {
+        Self(decode_bytes(chunks, true))
+    }
+
+    /// Returns an array with the values decoded from the little-endian bytes
+    /// in `chunks`.
+    ///
+    /// Values may span chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of bytes is not a multiple of the size of
+    /// `T`.
+    pub fn from_le_bytes_iter(chunks: impl IntoIterator<Item = impl AsRef<[u8]>>) -> Self {
+        Self(decode_bytes(chunks, false))
+    }
+}
+
 impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> Default
     for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
@@ -89,6 +464,25 @@ where
     }
 }
 
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> WithCapacity
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(WithCapacity::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
 impl<T: FixedSize, U, const NULLABLE: bool, Buffer: BufferType> Extend<U>
     for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
@@ -140,7 +534,11 @@ where
     <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
     <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: Index,
 {
-    type Item<'a> = <<<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
+    type Item<'a>
+        =
+        <<<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<
+            'a,
+        >
     where
         Self: 'a;
 
@@ -189,6 +587,28 @@ where
     }
 }
 
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> ByteLength
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> ShrinkToFit
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<T: FixedSize, Buffer: BufferType> BitmapRef for FixedSizePrimitiveArray<T, true, Buffer> {
     type Buffer = Buffer;
 
@@ -218,7 +638,7 @@ mod tests {
         assert_eq!(array.0.as_slice(), &[1, 2, 3, 4]);
         assert_eq!(array.0.as_slice(), array.0.as_bytes());
 
-        #[cfg(not(feature = "arrow-rs"))]
+        #[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
         {
             let input_array = [[1_u8, 2], [3, 4]];
             let array_array = input_array
@@ -247,7 +667,7 @@ mod tests {
         let array = input.into_iter().collect::<FixedSizePrimitiveArray<_>>();
         assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
 
-        #[cfg(not(feature = "arrow-rs"))]
+        #[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
         {
             let input_array = [[1_u8, 2], [3, 4]];
             let array_array = input_array
@@ -270,7 +690,7 @@ mod tests {
         let array = input.into_iter().collect::<FixedSizePrimitiveArray<_>>();
         assert_eq!(array.len(), input.as_slice().len());
 
-        #[cfg(not(feature = "arrow-rs"))]
+        #[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
         {
             let input_array = [[1_u8, 2], [3, 4]];
             let array_array = input_array
@@ -314,6 +734,119 @@ mod tests {
         assert_eq!(nullable.index_checked(3), Some(&4));
     }
 
+    #[test]
+    fn deref() {
+        let mut array = [4, 1, 3, 2]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u32>>();
+        array.sort_unstable();
+        assert_eq!(array.as_ref(), &[1, 2, 3, 4]);
+        assert_eq!(array.binary_search(&3), Ok(2));
+        assert_eq!(array.windows(2).count(), 3);
+        assert_eq!(
+            array.chunks(3).map(<[u32]>::len).collect::<Vec<_>>(),
+            [3, 1]
+        );
+        array.as_mut()[0] = 5;
+        assert_eq!(array.first(), Some(&5));
+    }
+
+    #[test]
+    fn with_updated() {
+        let shared = [1, 2, 3]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u32, false, ArcBuffer>>();
+        let updated = shared.with_updated(1, |value| value * 10);
+        assert_eq!(shared.as_ref(), &[1, 2, 3]);
+        assert_eq!(updated.as_ref(), &[1, 20, 3]);
+
+        let mut unique = updated.clone();
+        assert!(!unique.is_unique());
+        drop(updated);
+        assert!(unique.is_unique());
+        let ptr = unique.as_ptr();
+        unique.make_mut()[0] = 0;
+        assert_eq!(unique.as_ptr(), ptr);
+        assert_eq!(unique.as_ref(), &[0, 20, 3]);
+    }
+
+    #[test]
+    fn reinterpret() {
+        let floats = [1.0_f64, -0.0].into_iter().collect::<Float64Array>();
+        let ptr = floats.as_ptr();
+        let bits = floats.reinterpret::<u64>();
+        assert_eq!(bits.as_ptr().cast::<f64>(), ptr);
+        assert_eq!(bits.as_ref(), &[1.0_f64.to_bits(), (-0.0_f64).to_bits()]);
+
+        let nullable = [Some(-1_i32), None]
+            .into_iter()
+            .collect::<Int32Array<true>>()
+            .reinterpret::<u32>();
+        assert_eq!(
+            nullable.into_iter().collect::<Vec<_>>(),
+            [Some(u32::MAX), None]
+        );
+    }
+
+    #[test]
+    fn from_bytes_iter() {
+        let chunks: [&[u8]; 3] = [&[0, 0, 1], &[2, 0, 0], &[3, 4]];
+        assert_eq!(
+            Uint32Array::from_be_bytes_iter(chunks).as_ref(),
+            &[0x0000_0102, 0x0000_0304]
+        );
+        assert_eq!(
+            Uint16Array::from_le_bytes_iter(chunks).as_ref(),
+            &[0, 0x0201, 0, 0x0403]
+        );
+        assert_eq!(
+            Float64Array::from_be_bytes_iter([1.5_f64.to_be_bytes()]).as_ref(),
+            &[1.5]
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "number of bytes (is 3) should be a multiple of the size of the values (is 2)"
+    )]
+    fn from_bytes_iter_trailing() {
+        let _ = Uint16Array::from_le_bytes_iter([[1_u8, 2, 3]]);
+    }
+
+    #[test]
+    fn repeat_tile() {
+        assert_eq!(Uint8Array::<false>::repeat(3, 4).as_ref(), [3, 3, 3, 3]);
+        let values = [1_u8, 2].into_iter().collect::<Uint8Array>();
+        assert_eq!(values.tile(3).as_ref(), [1, 2, 1, 2, 1, 2]);
+        assert!(values.tile(0).is_empty());
+
+        assert_eq!(
+            Uint8Array::<true>::repeat(None, 2)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [None, None]
+        );
+        let nullable = [Some(1_u8), None, Some(3)]
+            .into_iter()
+            .collect::<Uint8Array<true>>();
+        assert_eq!(
+            nullable.tile(3).into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)].repeat(3)
+        );
+    }
+
+    #[test]
+    fn sequence() {
+        assert_eq!(Uint32Array::from_range(3..7).as_ref(), [3, 4, 5, 6]);
+        assert!(Int64Array::from_range(5..5).is_empty());
+        assert_eq!(Int8Array::from_step(-2, 3, 4).as_ref(), [-2, 1, 4, 7]);
+        assert_eq!(Uint8Array::from_step(254, 1, 3).as_ref(), [254, 255, 0]);
+        assert_eq!(
+            Float32Array::from_step(0.5, 0.25, 3).as_ref(),
+            [0.5, 0.75, 1.0]
+        );
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(mem::size_of::<Int8Array>(), mem::size_of::<Vec<i8>>());
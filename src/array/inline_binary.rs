@@ -0,0 +1,283 @@
+//! Array with short binary values stored inline.
+
+use super::{Array, VariableSizeBinaryArray};
+use crate::{
+    buffer::{Buffer, BufferType, VecBuffer},
+    offset::OffsetElement,
+    ByteLength, Index, Length, ShrinkToFit,
+};
+use std::iter::FusedIterator;
+
+/// Array with binary values stored inline in slots of `STRIDE` bytes.
+///
+/// The first byte of a slot is the length of the value, followed by the bytes
+/// of the value. Values longer than [`Self::INLINE_LEN`] are stored in an
+/// overflow array instead, and their slot stores the index in the overflow
+/// array as a little-endian `u32`.
+///
+/// This avoids the offset per value of a [`VariableSizeBinaryArray`], which
+/// dominates the size of arrays with values of a few bytes. `STRIDE` must be
+/// in `5..=255`.
+pub struct InlineBinaryArray<const STRIDE: usize = 9, Buffer: BufferType = VecBuffer> {
+    /// The slots with the inline values.
+    pub(crate) slots: <Buffer as BufferType>::Buffer<u8>,
+    /// The values that don't fit in a slot.
+    pub(crate) overflow: VariableSizeBinaryArray<false, i32, Buffer>,
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> InlineBinaryArray<STRIDE, Buffer> {
+    /// The maximum length of a value stored inline.
+    pub const INLINE_LEN: usize = Self::STRIDE - 1;
+
+    /// The number of bytes per slot, checked at compile time.
+    const STRIDE: usize = {
+        assert!(STRIDE >= 5 && STRIDE <= 255, "STRIDE must be in 5..=255");
+        STRIDE
+    };
+
+    /// The length byte of a slot with a value in the overflow array.
+    const OVERFLOW: u8 = u8::MAX;
+
+    /// Returns the slots of this array.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.slots.as_slice()
+    }
+
+    /// Returns the array with the values that don't fit in a slot.
+    pub fn overflow(&self) -> &VariableSizeBinaryArray<false, i32, Buffer> {
+        &self.overflow
+    }
+
+    /// Returns the value at `index`, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        (index < self.len()).then(|| self.value(index))
+    }
+
+    /// Returns an iterator over the values in this array.
+    pub fn iter(&self) -> InlineBinaryIter<'_, STRIDE, Buffer> {
+        InlineBinaryIter {
+            array: self,
+            position: 0,
+        }
+    }
+
+    /// Returns a [`VariableSizeBinaryArray`] with the values of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offsets don't fit in `OffsetItem`.
+    pub fn to_binary<OffsetItem: OffsetElement>(
+        &self,
+    ) -> VariableSizeBinaryArray<false, OffsetItem, Buffer>
+    where
+        for<'a> VariableSizeBinaryArray<false, OffsetItem, Buffer>: FromIterator<&'a [u8]>,
+    {
+        self.iter().collect()
+    }
+
+    /// Returns the value at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn value(&self, index: usize) -> &[u8] {
+        let slot = &self.slots.as_slice()[index * STRIDE..(index + 1) * STRIDE];
+        match slot[0] {
+            Self::OVERFLOW => {
+                let position = u32::from_le_bytes([slot[1], slot[2], slot[3], slot[4]]);
+                &self.overflow[usize::try_from(position).expect("position should fit in usize")]
+            }
+            len => &slot[1..=usize::from(len)],
+        }
+    }
+}
+
+impl<const STRIDE: usize, OffsetItem: OffsetElement, Buffer: BufferType>
+    From<&VariableSizeBinaryArray<false, OffsetItem, Buffer>> for InlineBinaryArray<STRIDE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    for<'a> VariableSizeBinaryArray<false, i32, Buffer>: FromIterator<&'a [u8]>,
+{
+    fn from(binary: &VariableSizeBinaryArray<false, OffsetItem, Buffer>) -> Self {
+        (0..binary.len()).map(|index| &binary[index]).collect()
+    }
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> Array for InlineBinaryArray<STRIDE, Buffer> {
+    type Item = Vec<u8>;
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> ByteLength for InlineBinaryArray<STRIDE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: ByteLength,
+    VariableSizeBinaryArray<false, i32, Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.slots.byte_len() + self.overflow.byte_len()
+    }
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> ShrinkToFit for InlineBinaryArray<STRIDE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: ShrinkToFit,
+    VariableSizeBinaryArray<false, i32, Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+        self.overflow.shrink_to_fit();
+    }
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> Default for InlineBinaryArray<STRIDE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: Default,
+    VariableSizeBinaryArray<false, i32, Buffer>: Default,
+{
+    fn default() -> Self {
+        Self {
+            slots: Default::default(),
+            overflow: VariableSizeBinaryArray::default(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized, const STRIDE: usize, Buffer: BufferType> FromIterator<&'a T>
+    for InlineBinaryArray<STRIDE, Buffer>
+where
+    T: AsRef<[u8]>,
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    VariableSizeBinaryArray<false, i32, Buffer>: FromIterator<&'a [u8]>,
+{
+    /// # Panics
+    ///
+    /// Panics if there are more than [`u32::MAX`] overflow values.
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+        let mut slots = Vec::new();
+        let mut overflow = Vec::new();
+        for value in iter.into_iter().map(AsRef::as_ref) {
+            let start = slots.len();
+            slots.resize(start + Self::STRIDE, 0);
+            let slot = &mut slots[start..];
+            if value.len() <= Self::INLINE_LEN {
+                slot[0] = u8::try_from(value.len()).expect("inline length fits in u8");
+                slot[1..=value.len()].copy_from_slice(value);
+            } else {
+                let position =
+                    u32::try_from(overflow.len()).expect("overflow position should fit in u32");
+                slot[0] = Self::OVERFLOW;
+                slot[1..5].copy_from_slice(&position.to_le_bytes());
+                overflow.push(value);
+            }
+        }
+        Self {
+            slots: slots.into_iter().collect(),
+            overflow: overflow.into_iter().collect(),
+        }
+    }
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> Index for InlineBinaryArray<STRIDE, Buffer> {
+    type Item<'a>
+        = &'a [u8]
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.value(index)
+    }
+}
+
+impl<'a, const STRIDE: usize, Buffer: BufferType> IntoIterator
+    for &'a InlineBinaryArray<STRIDE, Buffer>
+{
+    type Item = &'a [u8];
+    type IntoIter = InlineBinaryIter<'a, STRIDE, Buffer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> Length for InlineBinaryArray<STRIDE, Buffer> {
+    fn len(&self) -> usize {
+        self.slots.as_slice().len() / STRIDE
+    }
+}
+
+/// Iterator over the values of an [`InlineBinaryArray`].
+pub struct InlineBinaryIter<'a, const STRIDE: usize, Buffer: BufferType> {
+    /// The array.
+    array: &'a InlineBinaryArray<STRIDE, Buffer>,
+    /// The position of the next value.
+    position: usize,
+}
+
+impl<'a, const STRIDE: usize, Buffer: BufferType> Iterator
+    for InlineBinaryIter<'a, STRIDE, Buffer>
+{
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.array
+            .get(self.position)
+            .into_iter()
+            .inspect(|_| {
+                self.position += 1;
+            })
+            .next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.array.len() - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> ExactSizeIterator
+    for InlineBinaryIter<'_, STRIDE, Buffer>
+{
+}
+
+impl<const STRIDE: usize, Buffer: BufferType> FusedIterator
+    for InlineBinaryIter<'_, STRIDE, Buffer>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::BinaryArray;
+
+    #[test]
+    fn from_iter() {
+        let input = [&b"ab"[..], b"", b"12345678", b"123456789", b"xyz"];
+        let array = input.into_iter().collect::<InlineBinaryArray>();
+        assert_eq!(array.len(), 5);
+        assert_eq!(&array.as_bytes()[..9], [2, b'a', b'b', 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&array.as_bytes()[27..32], [u8::MAX, 0, 0, 0, 0]);
+        assert_eq!(array.overflow().len(), 1);
+        assert_eq!(array.iter().collect::<Vec<_>>(), input);
+        assert_eq!(array.index_checked(3), b"123456789");
+        assert_eq!(array.get(5), None);
+    }
+
+    #[test]
+    fn binary() {
+        let binary = [&b"a"[..], b"bc", b"a long value"]
+            .into_iter()
+            .collect::<BinaryArray>();
+        let array = InlineBinaryArray::<5>::from(&binary);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.overflow().len(), 1);
+        let round_trip = array.to_binary::<i32>();
+        assert_eq!(round_trip.0.offsets, binary.0.offsets);
+        assert_eq!(round_trip.0.data.0, binary.0.data.0);
+    }
+
+    #[test]
+    fn byte_len() {
+        let array = [[1_u8, 2, 3]; 4].iter().collect::<InlineBinaryArray<5>>();
+        // 4 slots of 5 bytes, and an empty overflow array with one offset.
+        assert_eq!(array.byte_len(), 4 * 5 + 4);
+    }
+}
@@ -1,27 +1,45 @@
 //! Sequences of values with known length all having the same type.
 
-use crate::{
-    buffer::BufferType,
-    offset::{self, OffsetElement},
-};
+#[cfg(feature = "lists")]
+use crate::offset;
+use crate::{buffer::BufferType, offset::OffsetElement};
+#[cfg(feature = "lists")]
 use std::collections::VecDeque;
 
 mod boolean;
 pub use boolean::*;
 
+mod copy_range;
+pub use copy_range::*;
+
+#[cfg(feature = "lists")]
 mod fixed_size_list;
+#[cfg(feature = "lists")]
 pub use fixed_size_list::*;
 
 mod fixed_size_primitive;
 pub use fixed_size_primitive::*;
 
+mod inline_binary;
+pub use inline_binary::*;
+
 mod null;
 pub use null::*;
 
+mod packed_int;
+pub use packed_int::*;
+
+mod sparse;
+pub use sparse::*;
+
+#[cfg(feature = "strings")]
 mod string;
+#[cfg(feature = "strings")]
 pub use string::*;
 
+#[cfg(feature = "structs")]
 mod r#struct;
+#[cfg(feature = "structs")]
 pub use r#struct::*;
 
 pub mod union;
@@ -30,7 +48,9 @@ pub use union::*;
 mod variable_size_binary;
 pub use variable_size_binary::*;
 
+#[cfg(feature = "lists")]
 mod variable_size_list;
+#[cfg(feature = "lists")]
 pub use variable_size_list::*;
 
 /// Types that store their data in Arrow arrays.
@@ -52,7 +72,7 @@ pub trait ArrayType<T: ?Sized = Self> {
     /// - `UnionLayout`: a [`UnionType`] that is used for union arrays.
     ///
     /// When using this type constructor for arrays that have no offset buffer
-    /// [`offset::NA`] should be used to indicate that this does not apply.
+    /// [`offset::NA`](crate::offset::NA) should be used to indicate that this does not apply.
     ///
     /// When using this type constructor to construct arrays that are not union
     /// arrays [`union::NA`] should be used to indicate that does not apply.
@@ -120,6 +140,7 @@ impl_array_type!(Option<f64>, FixedSizePrimitiveArray<f64, true, Buffer>);
 impl_array_type!((), NullArray<(), false, Buffer>);
 impl_array_type!(Option<()>, NullArray<(), true, Buffer>);
 
+#[cfg(feature = "lists")]
 impl<T: ArrayType, const N: usize> ArrayType for [T; N] {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         FixedSizeListArray<
@@ -129,6 +150,7 @@ impl<T: ArrayType, const N: usize> ArrayType for [T; N] {
             Buffer,
         >;
 }
+#[cfg(feature = "lists")]
 impl<T: ArrayType, const N: usize> ArrayType for Option<[T; N]> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         FixedSizeListArray<
@@ -139,24 +161,29 @@ impl<T: ArrayType, const N: usize> ArrayType for Option<[T; N]> {
         >;
 }
 
+#[cfg(feature = "strings")]
 impl ArrayType for str {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         StringArray<false, OffsetItem, Buffer>;
 }
-impl<'a> ArrayType for Option<&'a str> {
+#[cfg(feature = "strings")]
+impl ArrayType for Option<&str> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         StringArray<true, OffsetItem, Buffer>;
 }
+#[cfg(feature = "strings")]
 impl ArrayType for String {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         StringArray<false, OffsetItem, Buffer>;
 }
+#[cfg(feature = "strings")]
 impl ArrayType for Option<String> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         StringArray<true, OffsetItem, Buffer>;
 }
 
-impl<'a, T: ArrayType> ArrayType for &'a [T] {
+#[cfg(feature = "lists")]
+impl<T: ArrayType> ArrayType for &[T] {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         VariableSizeListArray<
             <T as ArrayType>::Array<Buffer, offset::NA, union::NA>,
@@ -165,7 +192,8 @@ impl<'a, T: ArrayType> ArrayType for &'a [T] {
             Buffer,
         >;
 }
-impl<'a, T: ArrayType> ArrayType for Option<&'a [T]> {
+#[cfg(feature = "lists")]
+impl<T: ArrayType> ArrayType for Option<&[T]> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         VariableSizeListArray<
             <T as ArrayType>::Array<Buffer, offset::NA, union::NA>,
@@ -174,6 +202,7 @@ impl<'a, T: ArrayType> ArrayType for Option<&'a [T]> {
             Buffer,
         >;
 }
+#[cfg(feature = "lists")]
 impl<T: ArrayType> ArrayType for Vec<T> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         VariableSizeListArray<
@@ -183,6 +212,7 @@ impl<T: ArrayType> ArrayType for Vec<T> {
             Buffer,
         >;
 }
+#[cfg(feature = "lists")]
 impl<T: ArrayType> ArrayType for Option<Vec<T>> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         VariableSizeListArray<
@@ -192,6 +222,7 @@ impl<T: ArrayType> ArrayType for Option<Vec<T>> {
             Buffer,
         >;
 }
+#[cfg(feature = "lists")]
 impl<T: ArrayType> ArrayType for VecDeque<T> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         VariableSizeListArray<
@@ -201,6 +232,7 @@ impl<T: ArrayType> ArrayType for VecDeque<T> {
             Buffer,
         >;
 }
+#[cfg(feature = "lists")]
 impl<T: ArrayType> ArrayType for Option<VecDeque<T>> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         VariableSizeListArray<
@@ -210,3 +242,60 @@ impl<T: ArrayType> ArrayType for Option<VecDeque<T>> {
             Buffer,
         >;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bitmap::Bitmap,
+        buffer::{ArcBuffer, BoxBuffer, VecBuffer},
+    };
+
+    /// Compile-time assertion that `T` can be sent and shared across threads.
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    /// Asserts that all array types are `Send` and `Sync` with the given buffer
+    /// type.
+    macro_rules! assert_arrays_send_sync {
+        ($buffer:ty) => {
+            const _: () = {
+                assert_send_sync::<Bitmap<$buffer>>();
+                assert_send_sync::<BooleanArray<false, $buffer>>();
+                assert_send_sync::<BooleanArray<true, $buffer>>();
+                #[cfg(feature = "lists")]
+                assert_send_sync::<FixedSizeListArray<2, Int32Array<false, $buffer>, false, $buffer>>();
+                #[cfg(feature = "lists")]
+                assert_send_sync::<FixedSizeListArray<2, Int32Array<false, $buffer>, true, $buffer>>();
+                assert_send_sync::<FixedSizePrimitiveArray<u32, false, $buffer>>();
+                assert_send_sync::<FixedSizePrimitiveArray<u32, true, $buffer>>();
+                assert_send_sync::<NullArray<(), false, $buffer>>();
+                assert_send_sync::<NullArray<(), true, $buffer>>();
+                assert_send_sync::<PackedIntArray<3, $buffer>>();
+                assert_send_sync::<SparseArray<u32, $buffer>>();
+                #[cfg(feature = "strings")]
+                assert_send_sync::<StringArray<false, i32, $buffer>>();
+                #[cfg(feature = "strings")]
+                assert_send_sync::<StringArray<true, i64, $buffer>>();
+                assert_send_sync::<VariableSizeBinaryArray<false, i32, $buffer>>();
+                assert_send_sync::<VariableSizeBinaryArray<true, i64, $buffer>>();
+                #[cfg(feature = "lists")]
+                assert_send_sync::<VariableSizeListArray<Int32Array<false, $buffer>, false, i32, $buffer>>();
+                #[cfg(feature = "lists")]
+                assert_send_sync::<VariableSizeListArray<Int32Array<false, $buffer>, true, i64, $buffer>>();
+            };
+        };
+    }
+
+    assert_arrays_send_sync!(VecBuffer);
+    assert_arrays_send_sync!(BoxBuffer);
+    assert_arrays_send_sync!(ArcBuffer);
+
+    #[test]
+    fn send_sync() {
+        let array = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u32, true>>();
+        let handle = std::thread::spawn(move || array.into_iter().flatten().sum::<u32>());
+        assert_eq!(handle.join().expect("thread panicked"), 4);
+    }
+}
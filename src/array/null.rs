@@ -6,7 +6,7 @@ use crate::{
     buffer::{BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    ByteLength, Index, Length, ShrinkToFit, WithCapacity,
 };
 use std::{
     iter::{self, Repeat, Take},
@@ -62,6 +62,25 @@ where
     }
 }
 
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> WithCapacity
+    for NullArray<T, NULLABLE, Buffer>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(WithCapacity::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
 impl<T: Unit, U, const NULLABLE: bool, Buffer: BufferType> Extend<U>
     for NullArray<T, NULLABLE, Buffer>
 where
@@ -101,7 +120,8 @@ where
     Nulls<T>: Validity<NULLABLE>,
     <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: Index,
 {
-    type Item<'a> = <<Nulls<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
+    type Item<'a>
+        = <<Nulls<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
     where
         Self: 'a;
 
@@ -120,6 +140,28 @@ where
     }
 }
 
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> ByteLength
+    for NullArray<T, NULLABLE, Buffer>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> ShrinkToFit
+    for NullArray<T, NULLABLE, Buffer>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> IntoIterator
     for NullArray<T, NULLABLE, Buffer>
 where
@@ -203,6 +245,20 @@ impl<T: Unit> FromIterator<T> for Nulls<T> {
     }
 }
 
+/// Nulls don't allocate, so this only returns an empty instance.
+impl<T: Unit> WithCapacity for Nulls<T> {
+    fn with_capacity(_capacity: usize) -> Self {
+        Self {
+            len: 0,
+            _ty: PhantomData,
+        }
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn reserve_exact(&mut self, _additional: usize) {}
+}
+
 impl<T: Unit> Extend<T> for Nulls<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         self.len = self
@@ -213,7 +269,8 @@ impl<T: Unit> Extend<T> for Nulls<T> {
 }
 
 impl<T: Unit> Index for Nulls<T> {
-    type Item<'a> = T
+    type Item<'a>
+        = T
     where
         Self: 'a;
 
@@ -238,6 +295,18 @@ impl<T: Unit> Length for Nulls<T> {
     }
 }
 
+impl<T: Unit> ByteLength for Nulls<T> {
+    /// Returns zero, null values are not stored.
+    fn byte_len(&self) -> usize {
+        0
+    }
+}
+
+impl<T: Unit> ShrinkToFit for Nulls<T> {
+    /// Does nothing, null values are not stored.
+    fn shrink_to_fit(&mut self) {}
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,252 @@
+//! Array with bit-packed unsigned integer values.
+
+use super::{Array, FixedSizePrimitiveArray};
+use crate::{
+    buffer::{Buffer, BufferType, VecBuffer},
+    FixedSize, Index, Length,
+};
+use std::iter::FusedIterator;
+
+/// Array with unsigned integer values packed in `BITS` bits per value.
+///
+/// Values are packed least significant bit first, without padding between
+/// values. `BITS` must be in `1..=64`.
+pub struct PackedIntArray<const BITS: usize, Buffer: BufferType = VecBuffer> {
+    /// The buffer with the packed values.
+    pub(crate) buffer: <Buffer as BufferType>::Buffer<u8>,
+    /// The number of values in this array.
+    pub(crate) len: usize,
+}
+
+impl<const BITS: usize, Buffer: BufferType> PackedIntArray<BITS, Buffer> {
+    /// The maximum value that can be stored in this array.
+    pub const MAX: u64 = u64::MAX >> (64 - Self::BITS);
+
+    /// The number of bits per value, checked at compile time.
+    const BITS: usize = {
+        assert!(BITS > 0 && BITS <= 64, "BITS must be in 1..=64");
+        BITS
+    };
+
+    /// Returns the packed bytes of this array.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Returns the value at `index`, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<u64> {
+        (index < self.len).then(|| unpack::<BITS>(self.buffer.as_slice(), index))
+    }
+
+    /// Returns an iterator over the values in this array.
+    pub fn iter(&self) -> PackedIntIter<'_, BITS> {
+        PackedIntIter {
+            bytes: self.buffer.as_slice(),
+            position: 0,
+            len: self.len,
+        }
+    }
+
+    /// Returns a [`FixedSizePrimitiveArray`] with the values of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value does not fit in `T`.
+    pub fn to_primitive<T: FixedSize + TryFrom<u64>>(
+        &self,
+    ) -> FixedSizePrimitiveArray<T, false, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+    {
+        self.iter()
+            .map(|value| T::try_from(value).ok().expect("value out of range"))
+            .collect()
+    }
+}
+
+/// Returns the value at `index` in the packed `bytes`.
+fn unpack<const BITS: usize>(bytes: &[u8], index: usize) -> u64 {
+    let start = index * BITS;
+    let end = start + BITS;
+    let word = bytes[start / 8..(end + 7) / 8]
+        .iter()
+        .rev()
+        .fold(0_u128, |word, &byte| word << 8 | u128::from(byte));
+    let value = (word >> (start % 8)) & ((1_u128 << BITS) - 1);
+    u64::try_from(value).expect("value fits in 64 bits")
+}
+
+impl<const BITS: usize, Buffer: BufferType> Array for PackedIntArray<BITS, Buffer> {
+    type Item = u64;
+}
+
+impl<const BITS: usize, Buffer: BufferType> Default for PackedIntArray<BITS, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: Default,
+{
+    fn default() -> Self {
+        Self {
+            buffer: Default::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: Into<u64>, const BITS: usize, Buffer: BufferType> FromIterator<T>
+    for PackedIntArray<BITS, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    /// # Panics
+    ///
+    /// Panics if a value does not fit in `BITS` bits.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut len = 0;
+        let buffer = Packer::<_, BITS> {
+            iter: iter.into_iter().map(Into::into).inspect(|_| {
+                len += 1;
+            }),
+            word: 0,
+            bits: 0,
+            done: false,
+        }
+        .collect();
+        Self { buffer, len }
+    }
+}
+
+impl<const BITS: usize, Buffer: BufferType> Index for PackedIntArray<BITS, Buffer> {
+    type Item<'a>
+        = u64
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        unpack::<BITS>(self.buffer.as_slice(), index)
+    }
+}
+
+impl<'a, const BITS: usize, Buffer: BufferType> IntoIterator for &'a PackedIntArray<BITS, Buffer> {
+    type Item = u64;
+    type IntoIter = PackedIntIter<'a, BITS>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const BITS: usize, Buffer: BufferType> Length for PackedIntArray<BITS, Buffer> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Iterator adapter that packs values in `BITS` bits, yielding bytes.
+struct Packer<I, const BITS: usize> {
+    /// The values to pack.
+    iter: I,
+    /// The pending bits, least significant bit first.
+    word: u128,
+    /// The number of pending bits.
+    bits: usize,
+    /// Whether the values are exhausted.
+    done: bool,
+}
+
+impl<I: Iterator<Item = u64>, const BITS: usize> Iterator for Packer<I, BITS> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.bits < 8 && !self.done {
+            match self.iter.next() {
+                Some(value) => {
+                    assert!(
+                        value <= PackedIntArray::<BITS>::MAX,
+                        "value (is {value}) does not fit in {BITS} bits"
+                    );
+                    self.word |= u128::from(value) << self.bits;
+                    self.bits += BITS;
+                }
+                None => self.done = true,
+            }
+        }
+        (self.bits != 0).then(|| {
+            let byte = self.word.to_le_bytes()[0];
+            self.word >>= 8;
+            self.bits = self.bits.saturating_sub(8);
+            byte
+        })
+    }
+}
+
+/// Iterator over the values of a [`PackedIntArray`].
+#[derive(Clone, Debug)]
+pub struct PackedIntIter<'a, const BITS: usize> {
+    /// The packed bytes.
+    bytes: &'a [u8],
+    /// The position of the next value.
+    position: usize,
+    /// The number of values.
+    len: usize,
+}
+
+impl<const BITS: usize> Iterator for PackedIntIter<'_, BITS> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.position < self.len).then(|| {
+            let value = unpack::<BITS>(self.bytes, self.position);
+            self.position += 1;
+            value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const BITS: usize> ExactSizeIterator for PackedIntIter<'_, BITS> {}
+
+impl<const BITS: usize> FusedIterator for PackedIntIter<'_, BITS> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_iter() {
+        let input = [1_u8, 0, 3, 2, 1];
+        let array = input.into_iter().collect::<PackedIntArray<2>>();
+        assert_eq!(array.len(), input.len());
+        assert_eq!(array.as_bytes(), [0b1011_0001, 0b0000_0001]);
+        assert_eq!(
+            array.iter().collect::<Vec<_>>(),
+            input.map(u64::from).to_vec()
+        );
+    }
+
+    #[test]
+    fn wide() {
+        let input = [u64::MAX >> 1, 0, 12345, u64::MAX >> 1];
+        let array = input.into_iter().collect::<PackedIntArray<63>>();
+        assert_eq!(array.as_bytes().len(), 32);
+        assert_eq!(array.iter().collect::<Vec<_>>(), input);
+        assert_eq!(array.index_checked(2), 12345);
+        assert_eq!(array.get(4), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in 3 bits")]
+    fn overflow() {
+        let _ = [8_u8].into_iter().collect::<PackedIntArray<3>>();
+    }
+
+    #[test]
+    fn to_primitive() {
+        let array = [5_u8, 7, 1].into_iter().collect::<PackedIntArray<3>>();
+        assert_eq!(array.to_primitive::<u8>().as_ref(), [5, 7, 1]);
+        assert_eq!(PackedIntArray::<3>::MAX, 7);
+    }
+}
@@ -0,0 +1,299 @@
+//! Array with mostly default values.
+
+use super::{Array, FixedSizePrimitiveArray};
+use crate::{
+    buffer::{Buffer, BufferType, VecBuffer},
+    FixedSize, Index, Length, PlainOldData,
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+};
+
+/// Array that only stores the values that differ from a default value.
+///
+/// The positions of the stored values are kept in an increasing index buffer.
+/// This is useful for columns that are overwhelmingly a single value.
+pub struct SparseArray<T: FixedSize, Buffer: BufferType = VecBuffer> {
+    /// The positions of the values that differ from the default value, in
+    /// increasing order.
+    pub(crate) indices: <Buffer as BufferType>::Buffer<u64>,
+    /// The values that differ from the default value.
+    pub(crate) values: <Buffer as BufferType>::Buffer<T>,
+    /// The default value.
+    pub(crate) default: T,
+    /// The number of values in this array.
+    pub(crate) len: usize,
+}
+
+impl<T: FixedSize, Buffer: BufferType> SparseArray<T, Buffer> {
+    /// Returns a new [`SparseArray`] with `len` default values.
+    pub fn new_default(default: T, len: usize) -> Self
+    where
+        <Buffer as BufferType>::Buffer<u64>: Default,
+        <Buffer as BufferType>::Buffer<T>: Default,
+    {
+        Self {
+            indices: Default::default(),
+            values: Default::default(),
+            default,
+            len,
+        }
+    }
+
+    /// Returns a new [`SparseArray`] with the items of the dense iterator,
+    /// only storing the items that differ from `default`.
+    ///
+    /// Items are compared by their bits, so that a `NaN` default matches
+    /// `NaN` items with the same bits, and the dense values are restored
+    /// exactly, e.g. `-0.0` is stored when the default is `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of items does not fit in an [`u64`].
+    pub fn from_dense<I: IntoIterator<Item = T>>(iter: I, default: T) -> Self
+    where
+        T: PlainOldData,
+        <Buffer as BufferType>::Buffer<u64>: Default + Extend<u64>,
+        <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+    {
+        let default_bits = [default];
+        let mut len = 0;
+        let (indices, values) = iter
+            .into_iter()
+            .filter_map(|value| {
+                let index = len;
+                len += 1;
+                ([value].as_bytes() != default_bits.as_bytes())
+                    .then(|| (u64::try_from(index).expect("index overflow"), value))
+            })
+            .unzip();
+        Self {
+            indices,
+            values,
+            default,
+            len,
+        }
+    }
+
+    /// Returns the default value of this array.
+    pub fn default_value(&self) -> T {
+        self.default
+    }
+
+    /// Returns the positions of the values that differ from the default value.
+    pub fn indices(&self) -> &[u64] {
+        self.indices.as_slice()
+    }
+
+    /// Returns the values that differ from the default value.
+    pub fn values(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    /// Returns the number of values that differ from the default value.
+    pub fn non_default_count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns an iterator over the (dense) values in this array.
+    pub fn iter(&self) -> SparseIter<'_, T> {
+        SparseIter {
+            indices: self.indices.as_slice(),
+            values: self.values.as_slice(),
+            default: self.default,
+            position: 0,
+            len: self.len,
+        }
+    }
+
+    /// Returns a dense [`FixedSizePrimitiveArray`] with the values of this array.
+    pub fn to_dense(&self) -> FixedSizePrimitiveArray<T, false, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+    {
+        self.iter().collect()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Clone for SparseArray<T, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u64>: Clone,
+    <Buffer as BufferType>::Buffer<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indices: self.indices.clone(),
+            values: self.values.clone(),
+            default: self.default,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: FixedSize + Debug, Buffer: BufferType> Debug for SparseArray<T, Buffer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SparseArray")
+            .field("indices", &self.indices.as_slice())
+            .field("values", &self.values.as_slice())
+            .field("default", &self.default)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T: FixedSize + PartialEq, Buffer: BufferType> PartialEq for SparseArray<T, Buffer> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self.default == other.default
+            && self.indices.as_slice() == other.indices.as_slice()
+            && self.values.as_slice() == other.values.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Array for SparseArray<T, Buffer> {
+    type Item = T;
+}
+
+impl<T: FixedSize, Buffer: BufferType> From<SparseArray<T, Buffer>>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+{
+    fn from(value: SparseArray<T, Buffer>) -> Self {
+        value.to_dense()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Index for SparseArray<T, Buffer> {
+    type Item<'a>
+        = T
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        let position = u64::try_from(index).expect("index overflow");
+        match self.indices.as_slice().binary_search(&position) {
+            Ok(idx) => self.values.as_slice()[idx],
+            Err(_) => self.default,
+        }
+    }
+}
+
+impl<'a, T: FixedSize, Buffer: BufferType> IntoIterator for &'a SparseArray<T, Buffer> {
+    type Item = T;
+    type IntoIter = SparseIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Length for SparseArray<T, Buffer> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Iterator over the (dense) values of a [`SparseArray`].
+#[derive(Clone, Debug)]
+pub struct SparseIter<'a, T> {
+    /// The remaining positions of the non-default values.
+    indices: &'a [u64],
+    /// The remaining non-default values.
+    values: &'a [T],
+    /// The default value.
+    default: T,
+    /// The position of the next value.
+    position: usize,
+    /// The number of values.
+    len: usize,
+}
+
+impl<T: Copy> Iterator for SparseIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.position < self.len).then(|| {
+            let position = u64::try_from(self.position).expect("index overflow");
+            self.position += 1;
+            match (self.indices.split_first(), self.values.split_first()) {
+                (Some((&index, indices)), Some((&value, values))) if index == position => {
+                    self.indices = indices;
+                    self.values = values;
+                    value
+                }
+                _ => self.default,
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for SparseIter<'_, T> {}
+
+impl<T: Copy> FusedIterator for SparseIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_dense() {
+        let input = [0_u32, 0, 7, 0, 0, 9, 0];
+        let array = SparseArray::<u32>::from_dense(input, 0);
+        assert_eq!(array.len(), input.len());
+        assert_eq!(array.indices(), [2, 5]);
+        assert_eq!(array.values(), [7, 9]);
+        assert_eq!(array.non_default_count(), 2);
+        assert_eq!(array.iter().collect::<Vec<_>>(), input);
+        assert_eq!(array.to_dense().as_ref(), input);
+    }
+
+    #[test]
+    fn from_dense_nan() {
+        let input = [f64::NAN, 1.0, f64::NAN, -0.0];
+        let array = SparseArray::<f64>::from_dense(input, f64::NAN);
+        assert_eq!(array.indices(), [1, 3]);
+        assert_eq!(array.values()[0].to_bits(), 1.0_f64.to_bits());
+        assert_eq!(array.values()[1].to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(
+            array.iter().map(f64::to_bits).collect::<Vec<_>>(),
+            input.map(f64::to_bits)
+        );
+
+        let zeros = SparseArray::<f32>::from_dense([0.0, -0.0], 0.0);
+        assert_eq!(zeros.indices(), [1]);
+    }
+
+    #[test]
+    fn new_default() {
+        let array = SparseArray::<i8>::new_default(-1, 3);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.iter().collect::<Vec<_>>(), [-1, -1, -1]);
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let array = SparseArray::<u8>::from_dense([0, 4, 0], 0);
+        assert_eq!(array.clone(), array);
+        assert_ne!(array, SparseArray::<u8>::from_dense([0, 4, 0], 4));
+        assert_eq!(
+            format!("{array:?}"),
+            "SparseArray { indices: [1], values: [4], default: 0, len: 3 }"
+        );
+    }
+
+    #[test]
+    fn index() {
+        let array = SparseArray::<u16>::from_dense([1, 2, 1, 3], 1);
+        assert_eq!(array.index_checked(0), 1);
+        assert_eq!(array.index_checked(1), 2);
+        assert_eq!(array.index_checked(3), 3);
+        assert_eq!(array.index(4), None);
+    }
+}
@@ -1,14 +1,19 @@
 //! Array with string values.
 
-use std::str;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{self, Range},
+    str,
+};
 
 use super::{Array, VariableSizeBinaryArray};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{BufferType, VecBuffer},
+    buffer::{Buffer, BufferType, VecBuffer},
     offset::OffsetElement,
     validity::{Nullability, Validity},
-    Index, Length,
+    ByteLength, Index, Length, ShrinkToFit, WithCapacity,
 };
 
 /// Array with string values.
@@ -28,6 +33,85 @@ pub type Utf8Array<const NULLABLE: bool = false, Buffer = VecBuffer> =
 pub type LargeUtf8Array<const NULLABLE: bool = false, Buffer = VecBuffer> =
     StringArray<NULLABLE, i64, Buffer>;
 
+/// Error returned when the values of a binary array are not valid UTF-8.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StringArrayError {
+    /// A value is not valid UTF-8.
+    InvalidUtf8 {
+        /// The index of the first invalid value.
+        index: usize,
+    },
+}
+
+impl Display for StringArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidUtf8 { index } => write!(f, "value {index} should be valid UTF-8"),
+        }
+    }
+}
+
+impl Error for StringArrayError {}
+
+/// Checks that the values referenced by `offsets` in `data` are valid UTF-8.
+///
+/// Instead of validating every value separately, the data spanned by the
+/// offsets is validated in a single pass, after which it remains to check
+/// that no offset splits a character.
+///
+/// # Panics
+///
+/// Panics if the offsets are negative, decreasing or out of bounds.
+pub(crate) fn validate_utf8<OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+    data: &[u8],
+) -> Result<(), StringArrayError> {
+    let positions = offsets
+        .iter()
+        .map(|&offset| offset.try_into().expect("offset should fit in usize"))
+        .collect::<Vec<usize>>();
+    let (Some(&start), Some(&end)) = (positions.first(), positions.last()) else {
+        return Ok(());
+    };
+    match str::from_utf8(&data[start..end]) {
+        Ok(values) => positions
+            .iter()
+            .position(|&position| !values.is_char_boundary(position - start))
+            .map_or(Ok(()), |offset| {
+                Err(StringArrayError::InvalidUtf8 { index: offset - 1 })
+            }),
+        // The first invalid value is the last value starting at or before
+        // the first invalid byte.
+        Err(error) => Err(StringArrayError::InvalidUtf8 {
+            index: positions
+                .partition_point(|&position| position <= start + error.valid_up_to())
+                .saturating_sub(1),
+        }),
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+{
+    /// Returns a [`StringArray`] with the values of `binary`, without
+    /// checking that they are valid UTF-8.
+    ///
+    /// Use [`TryFrom`] to validate the values instead.
+    ///
+    /// # Safety
+    ///
+    /// The values of `binary` must be valid UTF-8.
+    #[must_use]
+    pub unsafe fn from_binary_unchecked(
+        binary: VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>,
+    ) -> Self {
+        Self(binary)
+    }
+}
+
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
     StringArray<NULLABLE, OffsetItem, Buffer>
 where
@@ -40,6 +124,28 @@ where
     }
 }
 
+impl<OffsetItem: OffsetElement> StringArray<false, OffsetItem> {
+    /// Returns an array with `value` repeated `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offsets don't fit in `OffsetItem`.
+    #[must_use]
+    pub fn repeat(value: &str, n: usize) -> Self {
+        Self(VariableSizeBinaryArray::repeat(value.as_bytes(), n))
+    }
+
+    /// Returns an array with the values of this array repeated `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offsets don't fit in `OffsetItem`.
+    #[must_use]
+    pub fn tile(&self, n: usize) -> Self {
+        Self(self.0.tile(n))
+    }
+}
+
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Array
     for StringArray<NULLABLE, OffsetItem, Buffer>
 where
@@ -60,6 +166,25 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> WithCapacity
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(VariableSizeBinaryArray::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
 impl<'a, T: ?Sized, OffsetItem: OffsetElement, Buffer: BufferType> Extend<&'a T>
     for StringArray<false, OffsetItem, Buffer>
 where
@@ -118,6 +243,34 @@ where
     }
 }
 
+impl<OffsetItem: OffsetElement, Buffer: BufferType>
+    TryFrom<VariableSizeBinaryArray<false, OffsetItem, Buffer>>
+    for StringArray<false, OffsetItem, Buffer>
+{
+    type Error = StringArrayError;
+
+    fn try_from(
+        binary: VariableSizeBinaryArray<false, OffsetItem, Buffer>,
+    ) -> Result<Self, Self::Error> {
+        validate_utf8(binary.0.offsets.as_slice(), binary.0.data.0.as_slice())?;
+        Ok(Self(binary))
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType>
+    TryFrom<VariableSizeBinaryArray<true, OffsetItem, Buffer>>
+    for StringArray<true, OffsetItem, Buffer>
+{
+    type Error = StringArrayError;
+
+    fn try_from(
+        binary: VariableSizeBinaryArray<true, OffsetItem, Buffer>,
+    ) -> Result<Self, Self::Error> {
+        validate_utf8(binary.0.offsets.data.as_slice(), binary.0.data.0.as_slice())?;
+        Ok(Self(binary))
+    }
+}
+
 impl<'a, T: ?Sized, OffsetItem: OffsetElement, Buffer: BufferType> FromIterator<&'a T>
     for StringArray<false, OffsetItem, Buffer>
 where
@@ -175,7 +328,8 @@ where
 impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
     for StringArray<false, OffsetItem, Buffer>
 {
-    type Item<'a> = &'a str
+    type Item<'a>
+        = &'a str
     where
         Self: 'a;
 
@@ -187,7 +341,8 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
 impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
     for StringArray<true, OffsetItem, Buffer>
 {
-    type Item<'a> = Option<&'a str>
+    type Item<'a>
+        = Option<&'a str>
     where
         Self: 'a;
 
@@ -198,6 +353,34 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
     }
 }
 
+impl<OffsetItem: OffsetElement, Buffer: BufferType> ops::Index<usize>
+    for StringArray<false, OffsetItem, Buffer>
+{
+    type Output = str;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        // Safety:
+        // - The data buffer of a string array contains valid UTF-8
+        unsafe { str::from_utf8_unchecked(&self.0[index]) }
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<false, OffsetItem, Buffer> {
+    /// Returns an iterator over the strings in `range`, borrowed from this
+    /// array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn rows(&self, range: Range<usize>) -> impl Iterator<Item = &str> + '_ {
+        self.0.rows(range).map(|bytes| {
+            // Safety:
+            // - The data buffer of a string array contains valid UTF-8
+            unsafe { str::from_utf8_unchecked(bytes) }
+        })
+    }
+}
+
 /// An iterator over strings in a [`StringArray`].
 pub struct StringIter<'a, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
 where
@@ -256,6 +439,28 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ByteLength
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ShrinkToFit
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for StringArray<true, OffsetItem, Buffer>
 {
@@ -287,6 +492,21 @@ mod tests {
         buffer::BufferRef,
     };
 
+    #[test]
+    fn repeat_tile() {
+        assert_eq!(
+            StringArray::<false>::repeat("ab", 2)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            ["ab", "ab"]
+        );
+        let array = ["a", "bc"].into_iter().collect::<StringArray>();
+        assert_eq!(
+            array.tile(2).into_iter().collect::<Vec<_>>(),
+            ["a", "bc", "a", "bc"]
+        );
+    }
+
     #[test]
     fn from_iter() {
         let input = ["1", "23", "456", "7890"];
@@ -339,14 +559,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ops_index() {
+        let input = ["1", "23", "", "456"];
+        let array = input.into_iter().collect::<StringArray>();
+        assert_eq!(&array[0], "1");
+        assert_eq!(&array[1], "23");
+        assert_eq!(&array[2], "");
+        assert_eq!(&array[3], "456");
+        assert_eq!(array.rows(1..4).collect::<Vec<_>>(), ["23", "", "456"]);
+    }
+
     #[test]
     fn convert_nullable() {
         let input = ["hello", " ", "world"];
         let array = input
             .into_iter()
-            .map(ToString::to_string)
+            .map(str::to_owned)
             .collect::<StringArray>();
         let nullable: StringArray<true> = array.into();
         assert_eq!(nullable.bitmap_ref().buffer_ref(), &[0b0000_0111]);
     }
+
+    #[test]
+    fn try_from_binary() {
+        let binary = [&b"ab"[..], "\u{e9}".as_bytes(), b""]
+            .into_iter()
+            .collect::<VariableSizeBinaryArray>();
+        let array = StringArray::try_from(binary).expect("valid UTF-8");
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), ["ab", "\u{e9}", ""]);
+
+        let invalid = [&b"a"[..], b"", b"b\xff"]
+            .into_iter()
+            .collect::<VariableSizeBinaryArray>();
+        assert_eq!(
+            StringArray::try_from(invalid).err(),
+            Some(StringArrayError::InvalidUtf8 { index: 2 })
+        );
+
+        // Valid data, but the offsets split a character.
+        let split = [Some(&b"a\xc3"[..]), None, Some(b"\xa9")]
+            .into_iter()
+            .collect::<VariableSizeBinaryArray<true>>();
+        assert_eq!(
+            StringArray::try_from(split).err(),
+            Some(StringArrayError::InvalidUtf8 { index: 0 })
+        );
+
+        let unchecked = [&b"a"[..], b"bc"]
+            .into_iter()
+            .collect::<VariableSizeBinaryArray>();
+        // Safety:
+        // - The values are valid UTF-8
+        let array_unchecked = unsafe { StringArray::from_binary_unchecked(unchecked) };
+        assert_eq!(&array_unchecked[1], "bc");
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut array = StringArray::<true>::with_capacity(64);
+        array.extend([Some("ab"), None]);
+        array.shrink_to_fit();
+        assert_eq!(array.0 .0.data.0.capacity(), 2);
+        assert_eq!(array.0 .0.offsets.data.capacity(), 3);
+        assert_eq!(array.0 .0.offsets.validity.buffer_ref().capacity(), 1);
+    }
+
+    #[test]
+    fn byte_len() {
+        let input = [Some("a"), None, Some("bcd")];
+        let array = input.into_iter().collect::<StringArray<true>>();
+        // 4 bytes of data, 4 i32 offsets and 1 byte of validity.
+        assert_eq!(array.byte_len(), 4 + 16 + 1);
+    }
 }
@@ -6,7 +6,12 @@ use crate::{
     buffer::{BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Length,
+    ByteLength, CollectWithCapacity, Length, ShrinkToFit, WithCapacity,
+};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    iter,
 };
 
 /// Struct array types.
@@ -18,6 +23,85 @@ pub trait StructArrayType: ArrayType {
     type Array<Buffer: BufferType>; // into<fields> this then requires all arraytype impls to provide a field
 }
 
+/// Error returned when the child arrays of a struct array are inconsistent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StructArrayError {
+    /// The length of a child array differs from the length of the first child
+    /// array.
+    LengthMismatch {
+        /// The name of the child array.
+        field: &'static str,
+        /// The length of the child array.
+        len: usize,
+        /// The length of the first child array.
+        expected: usize,
+    },
+    /// The length of the validity bitmap differs from the length of the child
+    /// arrays.
+    ValidityLengthMismatch {
+        /// The length of the validity bitmap.
+        len: usize,
+        /// The length of the child arrays.
+        expected: usize,
+    },
+}
+
+impl Display for StructArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::LengthMismatch {
+                field,
+                len,
+                expected,
+            } => write!(
+                f,
+                "length of field `{field}` (is {len}) should be equal to {expected}"
+            ),
+            Self::ValidityLengthMismatch { len, expected } => write!(
+                f,
+                "length of validity bitmap (is {len}) should be equal to {expected}"
+            ),
+        }
+    }
+}
+
+impl Error for StructArrayError {}
+
+/// The child arrays of a struct array.
+///
+/// This is implemented by the derive macro for the [`StructArrayType::Array`]
+/// of structs.
+pub trait StructArrayChildren {
+    /// Returns the name and length of all child arrays, in field order.
+    fn child_lengths(&self) -> Vec<(&'static str, usize)>;
+
+    /// Checks that all child arrays have the same length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the first child array with a length that differs
+    /// from the length of the first child array.
+    fn check_child_lengths(&self) -> Result<(), StructArrayError> {
+        let child_lengths = self.child_lengths();
+        let mut iter = child_lengths.iter().copied();
+        match iter.next() {
+            Some((_, expected)) => iter.try_for_each(|(field, len)| {
+                if len == expected {
+                    Ok(())
+                } else {
+                    Err(StructArrayError::LengthMismatch {
+                        field,
+                        len,
+                        expected,
+                    })
+                }
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
 /// Array for product types.
 pub struct StructArray<
     T: StructArrayType,
@@ -27,6 +111,90 @@ pub struct StructArray<
 where
     <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>;
 
+impl<T: StructArrayType, Buffer: BufferType> StructArray<T, false, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: StructArrayChildren,
+{
+    /// Returns a new [`StructArray`] with the given child arrays.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the child arrays differ in length.
+    pub fn try_new(
+        fields: <T as StructArrayType>::Array<Buffer>,
+    ) -> Result<Self, StructArrayError> {
+        fields.check_child_lengths()?;
+        Ok(Self(fields))
+    }
+}
+
+impl<T: StructArrayType, Buffer: BufferType> StructArray<T, true, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: StructArrayChildren + Length,
+{
+    /// Returns a new nullable [`StructArray`] with the given child arrays and
+    /// validity bitmap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the child arrays differ in length, or when the
+    /// length of the validity bitmap differs from the length of the child
+    /// arrays.
+    pub fn try_new(
+        fields: <T as StructArrayType>::Array<Buffer>,
+        validity: Bitmap<Buffer>,
+    ) -> Result<Self, StructArrayError> {
+        fields.check_child_lengths()?;
+        let expected = fields.len();
+        let len = validity.len();
+        if len == expected {
+            Ok(Self(Nullable {
+                data: fields,
+                validity,
+            }))
+        } else {
+            Err(StructArrayError::ValidityLengthMismatch { len, expected })
+        }
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    Self: IntoIterator + Length,
+{
+    /// Converts this array into a vector of rows.
+    ///
+    /// The columns are consumed front to back in lockstep, so every column is
+    /// read sequentially instead of being randomly accessed per row.
+    pub fn to_rows(self) -> Vec<<Self as IntoIterator>::Item> {
+        let len = self.len();
+        self.into_iter().collect_with_capacity(len)
+    }
+
+    /// Converts this array into chunks of at most `chunk_len` rows.
+    ///
+    /// Like [`StructArray::to_rows`], but only the rows of one chunk are
+    /// materialized at a time, for sinks that take rows in bounded batches.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is zero.
+    pub fn to_row_chunks(
+        self,
+        chunk_len: usize,
+    ) -> impl Iterator<Item = Vec<<Self as IntoIterator>::Item>> {
+        assert!(chunk_len > 0, "chunk len (is 0) should be positive");
+        let mut remaining = self.len();
+        let mut rows = self.into_iter();
+        iter::from_fn(move || {
+            let len = chunk_len.min(remaining);
+            remaining -= len;
+            (len != 0).then(|| rows.by_ref().take(len).collect_with_capacity(len))
+        })
+    }
+}
+
 impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Array
     for StructArray<T, NULLABLE, Buffer>
 where
@@ -47,6 +215,25 @@ where
     }
 }
 
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> WithCapacity
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(WithCapacity::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
 impl<T: StructArrayType, Buffer: BufferType> From<StructArray<T, false, Buffer>>
     for StructArray<T, true, Buffer>
 where
@@ -80,6 +267,22 @@ where
     }
 }
 
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> IntoIterator
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: IntoIterator,
+{
+    type Item =
+        <<<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer> as IntoIterator>::Item;
+    type IntoIter =
+        <<<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Length
     for StructArray<T, NULLABLE, Buffer>
 where
@@ -91,6 +294,28 @@ where
     }
 }
 
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> ByteLength
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> ShrinkToFit
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<T: StructArrayType, Buffer: BufferType> BitmapRef for StructArray<T, true, Buffer> {
     type Buffer = Buffer;
 
@@ -108,6 +333,7 @@ impl<T: StructArrayType, Buffer: BufferType> BitmapRefMut for StructArray<T, tru
 impl<T: StructArrayType, Buffer: BufferType> ValidityBitmap for StructArray<T, true, Buffer> {}
 
 #[cfg(test)]
+#[cfg(all(feature = "lists", feature = "strings"))]
 mod tests {
     use crate::{
         array::{union, UnionType},
@@ -248,7 +474,7 @@ mod tests {
         type Array<Buffer: BufferType> = FooArray<'a, Buffer>;
     }
 
-    impl<'a, Buffer: BufferType> Length for FooArray<'a, Buffer>
+    impl<Buffer: BufferType> Length for FooArray<'_, Buffer>
     where
         <u32 as ArrayType>::Array<Buffer, offset::NA, union::NA>: Length,
     {
@@ -1,18 +1,22 @@
 //! Array for sum types.
 
+#[cfg(feature = "unions")]
 use std::iter;
 
+#[cfg(feature = "unions")]
 use crate::{
     buffer::{BufferType, VecBuffer},
     offset::{self, OffsetElement},
     Length,
 };
 
+#[cfg(feature = "unions")]
 use super::{Array, ArrayType, Int32Array, Int8Array};
 
 /// Different types of union layouts.
 pub trait UnionType {
     /// The array for this union type.
+    #[cfg(feature = "unions")]
     type Array<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -28,7 +32,16 @@ pub trait UnionType {
 pub struct DenseLayout;
 
 impl UnionType for DenseLayout {
-    type Array<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, Buffer: BufferType, OffsetItem: OffsetElement> = DenseUnionArray<T, VARIANTS, Buffer, OffsetItem> where for<'a> i8: From<&'a T>;
+    #[cfg(feature = "unions")]
+    type Array<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    >
+        = DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+    where
+        for<'a> i8: From<&'a T>;
 }
 
 /// The sparse union layout.
@@ -36,7 +49,16 @@ impl UnionType for DenseLayout {
 pub struct SparseLayout;
 
 impl UnionType for SparseLayout {
-    type Array<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, Buffer: BufferType, OffsetItem: OffsetElement> = SparseUnionArray<T, VARIANTS, Buffer, OffsetItem> where for<'a> i8: From<&'a T>;
+    #[cfg(feature = "unions")]
+    type Array<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    >
+        = SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+    where
+        for<'a> i8: From<&'a T>;
 }
 
 /// Indicates that a [`UnionType`] generic is not applicable.
@@ -49,6 +71,7 @@ impl UnionType for SparseLayout {
 pub type NA = SparseLayout;
 
 /// Union array types.
+#[cfg(feature = "unions")]
 pub trait UnionArrayType<const VARIANTS: usize>
 where
     for<'a> i8: From<&'a Self>,
@@ -64,6 +87,7 @@ where
 /// The array data for enum variants stored in union array wrappers.
 ///
 /// Implementations provide the method to convert back to the original enum.
+#[cfg(feature = "unions")]
 pub trait EnumVariant<const INDEX: usize>: Sized {
     /// The data for this variant. It must be an `ArrayType` because it is stored in an array.
     /// And it must implement `Into<Self>` (this is taking the data and wrapping it in the original enum variant).
@@ -74,6 +98,7 @@ pub trait EnumVariant<const INDEX: usize>: Sized {
 }
 
 /// Array for sum types.
+#[cfg(feature = "unions")]
 pub struct UnionArray<
     T: UnionArrayType<VARIANTS>,
     // we need this const here because:
@@ -87,6 +112,7 @@ pub struct UnionArray<
 where
     for<'a> i8: From<&'a T>;
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -100,6 +126,7 @@ where
     type Item = T;
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -116,6 +143,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -132,6 +160,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -148,6 +177,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -166,6 +196,7 @@ where
 }
 
 /// A dense union array.
+#[cfg(feature = "unions")]
 pub struct DenseUnionArray<
     T: UnionArrayType<VARIANTS>,
     const VARIANTS: usize,
@@ -182,6 +213,7 @@ pub struct DenseUnionArray<
     pub offsets: Int32Array<false, Buffer>,
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -203,6 +235,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -217,6 +250,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -250,6 +284,7 @@ where
 }
 
 /// A sparse union array.
+#[cfg(feature = "unions")]
 pub struct SparseUnionArray<
     T: UnionArrayType<VARIANTS>,
     const VARIANTS: usize,
@@ -264,6 +299,7 @@ pub struct SparseUnionArray<
     pub types: Int8Array<false, Buffer>,
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -283,6 +319,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -302,6 +339,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -316,6 +354,7 @@ where
     }
 }
 
+#[cfg(feature = "unions")]
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -334,6 +373,7 @@ where
 }
 
 #[cfg(test)]
+#[cfg(feature = "unions")]
 mod tests {
     use super::*;
     use crate::array::Uint32Array;
@@ -1,13 +1,18 @@
 //! Variable-size binary elements.
 
-use super::{Array, FixedSizePrimitiveArray, StringArray, VariableSizeListArray};
+#[cfg(feature = "strings")]
+use super::StringArray;
+#[cfg(feature = "lists")]
+use super::VariableSizeListArray;
+use super::{Array, FixedSizePrimitiveArray};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
     buffer::{Buffer, BufferType, VecBuffer},
     offset::{Offset, OffsetElement},
     validity::{Nullability, Validity},
-    Index, Length,
+    ByteLength, Index, Length, ShrinkToFit, WithCapacity,
 };
+use std::ops::{self, Range};
 
 /// Variable-size binary elements.
 pub struct VariableSizeBinaryArray<
@@ -26,6 +31,59 @@ pub type BinaryArray<const NULLABLE: bool = false, Buffer = VecBuffer> =
 pub type LargeBinaryArray<const NULLABLE: bool = false, Buffer = VecBuffer> =
     VariableSizeBinaryArray<NULLABLE, i64, Buffer>;
 
+impl<OffsetItem: OffsetElement> VariableSizeBinaryArray<false, OffsetItem> {
+    /// Returns an array with `value` repeated `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offsets don't fit in `OffsetItem`.
+    #[must_use]
+    pub fn repeat(value: &[u8], n: usize) -> Self {
+        Self(Offset {
+            data: FixedSizePrimitiveArray(value.repeat(n)),
+            offsets: (0..=n)
+                .map(|index| {
+                    OffsetItem::try_from(index * value.len())
+                        .expect("offset should fit in offset type")
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns an array with the values of this array repeated `n` times.
+    ///
+    /// The data is repeated in blocks, and the offsets are rebased for every
+    /// repetition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offsets don't fit in `OffsetItem`.
+    #[must_use]
+    pub fn tile(&self, n: usize) -> Self {
+        let offsets = self
+            .0
+            .offsets
+            .iter()
+            .map(|&offset| offset.try_into().expect("offset should fit in usize"))
+            .collect::<Vec<usize>>();
+        let (start, end) = (offsets[0], offsets[offsets.len() - 1]);
+        let values = &self.0.data.0[start..end];
+        Self(Offset {
+            data: FixedSizePrimitiveArray(values.repeat(n)),
+            offsets: std::iter::once(0)
+                .chain((0..n).flat_map(|repetition| {
+                    offsets[1..]
+                        .iter()
+                        .map(move |offset| repetition * values.len() + offset - start)
+                }))
+                .map(|offset| {
+                    OffsetItem::try_from(offset).expect("offset should fit in offset type")
+                })
+                .collect(),
+        })
+    }
+}
+
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Array
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
@@ -46,6 +104,25 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> WithCapacity
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(Offset::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
 impl<T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Extend<T>
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
@@ -57,6 +134,7 @@ where
     }
 }
 
+#[cfg(feature = "lists")]
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
     From<
         VariableSizeListArray<
@@ -93,6 +171,7 @@ where
     }
 }
 
+#[cfg(feature = "strings")]
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
     From<StringArray<NULLABLE, OffsetItem, Buffer>>
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
@@ -121,7 +200,8 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Index,
 {
-    type Item<'a> = &'a [u8]
+    type Item<'a>
+        = &'a [u8]
     where
         Self: 'a;
 
@@ -133,7 +213,7 @@ where
             .index_unchecked(index)
             .to_owned()
             .try_into()
-            .expect("convert fail");
+            .expect("offset should fit in usize");
         let end: usize = self
             .0
             .offsets
@@ -141,7 +221,7 @@ where
             .index_unchecked(index + 1)
             .to_owned()
             .try_into()
-            .expect("convert fail");
+            .expect("offset should fit in usize");
         &self.0.data.0.as_slice()[start..end]
     }
 }
@@ -151,7 +231,8 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Index,
 {
-    type Item<'a> = Option<&'a [u8]>
+    type Item<'a>
+        = Option<&'a [u8]>
     where
         Self: 'a;
 
@@ -165,7 +246,7 @@ where
                 .index_unchecked(index)
                 .to_owned()
                 .try_into()
-                .expect("convert fail");
+                .expect("offset should fit in usize");
             let end: usize = self
                 .0
                 .offsets
@@ -174,12 +255,74 @@ where
                 .index_unchecked(index + 1)
                 .to_owned()
                 .try_into()
-                .expect("convert fail");
+                .expect("offset should fit in usize");
             &self.0.data.0.as_slice()[start..end]
         })
     }
 }
 
+impl<OffsetItem: OffsetElement, Buffer: BufferType>
+    VariableSizeBinaryArray<false, OffsetItem, Buffer>
+{
+    /// Returns the range of bytes in the data buffer spanned by the items in
+    /// `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    fn byte_range(&self, range: Range<usize>) -> Range<usize> {
+        let offsets = self.0.offsets.as_slice();
+        let len = offsets.len() - 1;
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range (is {range:?}) should be within len (is {len})"
+        );
+        let start: usize = offsets[range.start]
+            .try_into()
+            .expect("offset should fit in usize");
+        let end: usize = offsets[range.end]
+            .try_into()
+            .expect("offset should fit in usize");
+        start..end
+    }
+
+    /// Returns an iterator over the items in `range`, borrowing their bytes
+    /// from this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn rows(&self, range: Range<usize>) -> impl Iterator<Item = &[u8]> + '_ {
+        let len = self.0.offsets.as_slice().len() - 1;
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range (is {range:?}) should be within len (is {len})"
+        );
+        range.map(move |index| &self[index])
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> ops::Index<usize>
+    for VariableSizeBinaryArray<false, OffsetItem, Buffer>
+{
+    type Output = [u8];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        /// Panic when out of bounds.
+        #[cold]
+        #[inline(never)]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("index (is {index}) should be < len (is {len})");
+        }
+
+        let len = self.0.offsets.as_slice().len() - 1;
+        if index >= len {
+            assert_failed(index, len);
+        }
+        &self.0.data.0.as_slice()[self.byte_range(index..index + 1)]
+    }
+}
+
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
@@ -191,6 +334,28 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ByteLength
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ShrinkToFit
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for VariableSizeBinaryArray<true, OffsetItem, Buffer>
 {
@@ -220,6 +385,21 @@ mod tests {
     use crate::buffer::BufferRef;
     use std::mem;
 
+    #[test]
+    fn repeat_tile() {
+        let repeated = BinaryArray::<false>::repeat(&[1, 2], 3);
+        assert_eq!(repeated.0.offsets, [0, 2, 4, 6]);
+        assert_eq!(repeated.0.data.0, [1, 2, 1, 2, 1, 2]);
+
+        let array = [vec![1_u8], vec![], vec![2, 3]]
+            .into_iter()
+            .collect::<LargeBinaryArray>();
+        let tiled = array.tile(2);
+        assert_eq!(tiled.0.offsets, [0, 1, 1, 3, 4, 4, 6]);
+        assert_eq!(tiled.0.data.0, [1, 2, 3, 1, 2, 3]);
+        assert_eq!(array.tile(0).len(), 0);
+    }
+
     #[test]
     fn from_iter() {
         let input: [&[u8]; 4] = [&[1], &[2, 3], &[4, 5, 6], &[7, 8, 9, 0]];
@@ -283,6 +463,45 @@ mod tests {
     }
 
     #[test]
+    fn ops_index() {
+        let input: [&[u8]; 4] = [&[1], &[2, 3], &[], &[4, 5, 6]];
+        let array = input
+            .into_iter()
+            .map(<[u8]>::to_vec)
+            .collect::<VariableSizeBinaryArray>();
+        assert_eq!(&array[0], &[1]);
+        assert_eq!(&array[1], &[2, 3]);
+        assert!(array[2].is_empty());
+        assert_eq!(&array[3], &[4, 5, 6]);
+        assert_eq!(
+            array.rows(1..4).collect::<Vec<_>>(),
+            [&[2, 3][..], &[], &[4, 5, 6]]
+        );
+        assert_eq!(array.rows(2..2).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (is 4) should be < len (is 4)")]
+    fn ops_index_out_of_bounds() {
+        let array = [[1_u8]; 4]
+            .into_iter()
+            .map(Vec::from)
+            .collect::<VariableSizeBinaryArray>();
+        let _ = &array[4];
+    }
+
+    #[test]
+    #[should_panic(expected = "range (is 2..5) should be within len (is 4)")]
+    fn rows_out_of_bounds() {
+        let array = [[1_u8]; 4]
+            .into_iter()
+            .map(Vec::from)
+            .collect::<VariableSizeBinaryArray>();
+        let _ = array.rows(2..5);
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
     fn convert() {
         let input = vec![Some("a".to_owned()), None, Some("b".to_owned())];
         let array = input.into_iter().collect::<StringArray<true>>();
@@ -6,7 +6,7 @@ use crate::{
     buffer::{BufferType, VecBuffer},
     offset::{Offset, OffsetElement},
     validity::{Nullability, Validity},
-    Index, Length,
+    ByteLength, Index, Length, ShrinkToFit, WithCapacity,
 };
 use std::fmt::{Debug, Formatter, Result};
 
@@ -29,6 +29,54 @@ where
     type Item = <Vec<T> as Nullability<NULLABLE>>::Item;
 }
 
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType>
+    VariableSizeListArray<T, false, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+{
+    /// Appends `n` empty lists, writing their offsets in bulk.
+    pub fn append_empty_n(&mut self, n: usize) {
+        self.0.append_empty_n(n);
+    }
+
+    /// Extends with `items`, appending runs of consecutive empty lists in
+    /// bulk. See [`Offset::extend_dedup`].
+    pub fn extend_dedup<U: IntoIterator + Length, I: IntoIterator<Item = U>>(&mut self, items: I)
+    where
+        Offset<T, false, OffsetItem, Buffer>: Extend<U>,
+    {
+        self.0.extend_dedup(items);
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType>
+    VariableSizeListArray<T, true, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+    Bitmap<Buffer>: Extend<bool>,
+{
+    /// Appends `n` empty valid lists, writing their offsets in bulk.
+    pub fn append_empty_n(&mut self, n: usize) {
+        self.0.append_empty_n(n);
+    }
+
+    /// Appends `n` null lists, writing their offsets in bulk.
+    pub fn append_null_n(&mut self, n: usize) {
+        self.0.append_null_n(n);
+    }
+
+    /// Extends with `items`, appending runs of consecutive null lists and
+    /// consecutive empty valid lists in bulk. See [`Offset::extend_dedup`].
+    pub fn extend_dedup<U: IntoIterator + Length, I: IntoIterator<Item = Option<U>>>(
+        &mut self,
+        items: I,
+    ) where
+        Offset<T, true, OffsetItem, Buffer>: Extend<Option<U>>,
+    {
+        self.0.extend_dedup(items);
+    }
+}
+
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Debug
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
@@ -53,6 +101,25 @@ where
     }
 }
 
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> WithCapacity
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem, Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self(Offset::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
 impl<T: Array, U, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Extend<U>
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
@@ -92,7 +159,8 @@ where
     <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Index,
 {
-    type Item<'a> = <Offset<T, NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>
+    type Item<'a>
+        = <Offset<T, NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>
     where
         Self: 'a;
 
@@ -112,6 +180,28 @@ where
     }
 }
 
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ByteLength
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem, Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.0.byte_len()
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ShrinkToFit
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem, Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for VariableSizeListArray<T, true, OffsetItem, Buffer>
 {
@@ -291,4 +381,13 @@ mod tests {
         );
         assert!(array.index(2).is_none());
     }
+
+    #[test]
+    fn append_empty_n() {
+        let mut array = VariableSizeListArray::<FixedSizePrimitiveArray<u8>>::default();
+        array.append_empty_n(3);
+        array.extend_dedup([vec![], vec![1, 2], vec![]]);
+        assert_eq!(array.len(), 6);
+        assert_eq!(array.0.offsets, &[0, 0, 0, 0, 0, 2, 2]);
+    }
 }
@@ -10,6 +10,7 @@ use crate::{
     nullable::Nullable,
     validity::{Nullability, Validity},
 };
+use arrow_array::Array as _;
 use arrow_buffer::{BooleanBuffer, NullBuffer};
 use arrow_schema::{DataType, Field};
 
@@ -86,9 +87,40 @@ where
     }
 }
 
+/// Copies the bits, panics when there are nulls
+impl<Buffer: BufferType> From<&arrow_array::BooleanArray> for BooleanArray<false, Buffer>
+where
+    Bitmap<Buffer>: FromIterator<bool>,
+{
+    fn from(value: &arrow_array::BooleanArray) -> Self {
+        match value.nulls() {
+            Some(_) => panic!("expected array without a null buffer"),
+            None => BooleanArray(value.values().iter().collect()),
+        }
+    }
+}
+
+/// Copies the value bits and the null bits, panics when there are no nulls
+impl<Buffer: BufferType> From<&arrow_array::BooleanArray> for BooleanArray<true, Buffer>
+where
+    Bitmap<Buffer>: FromIterator<bool>,
+{
+    fn from(value: &arrow_array::BooleanArray) -> Self {
+        match value.nulls() {
+            Some(null_buffer) => BooleanArray(Nullable {
+                data: value.values().iter().collect(),
+                validity: null_buffer.iter().collect(),
+            }),
+            None => panic!("expected array with a null buffer"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{array::BooleanArray, buffer::ArcBuffer};
+    use crate::{
+        array::BooleanArray, arrow::buffer::scalar_buffer::ArrowScalarBuffer, buffer::ArcBuffer,
+    };
 
     const INPUT: [bool; 4] = [true, true, false, true];
     const INPUT_NULLABLE: [Option<bool>; 4] = [Some(true), None, Some(false), Some(true)];
@@ -168,4 +200,40 @@ mod tests {
             INPUT_NULLABLE
         );
     }
+
+    #[test]
+    fn copied() {
+        let boolean_array_nullable = INPUT_NULLABLE
+            .into_iter()
+            .collect::<arrow_array::BooleanArray>()
+            .slice(1, 3);
+        let narrow = BooleanArray::<true>::from(&boolean_array_nullable);
+        assert_eq!(narrow.0.data.offset, 0);
+        assert_eq!(
+            narrow.into_iter().collect::<Vec<_>>(),
+            [None, Some(false), Some(true)]
+        );
+
+        let boolean_array = arrow_array::BooleanArray::from(INPUT.to_vec()).slice(2, 2);
+        assert_eq!(
+            BooleanArray::<false>::from(&boolean_array)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [false, true]
+        );
+    }
+
+    #[test]
+    fn sliced() {
+        let boolean_array_nullable = INPUT_NULLABLE
+            .into_iter()
+            .collect::<arrow_array::BooleanArray>()
+            .slice(1, 3);
+        let narrow: BooleanArray<true, ArrowScalarBuffer> = boolean_array_nullable.into();
+        assert_eq!(narrow.0.data.offset, 1);
+        assert_eq!(
+            narrow.into_iter().collect::<Vec<_>>(),
+            [None, Some(false), Some(true)]
+        );
+    }
 }
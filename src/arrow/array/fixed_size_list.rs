@@ -133,6 +133,7 @@ where
 }
 
 #[cfg(test)]
+#[cfg(feature = "strings")]
 mod tests {
     use arrow_array::types::UInt32Type;
 
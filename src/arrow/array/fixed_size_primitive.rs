@@ -131,7 +131,7 @@ where
 mod tests {
     use arrow_array::types::{UInt16Type, UInt32Type};
 
-    use crate::array::FixedSizePrimitiveArray;
+    use crate::{array::FixedSizePrimitiveArray, arrow::buffer::scalar_buffer::ArrowScalarBuffer};
 
     const INPUT: [u32; 4] = [1, 2, 3, 4];
     const INPUT_NULLABLE: [Option<u16>; 4] = [Some(1), None, Some(3), Some(4)];
@@ -217,4 +217,24 @@ mod tests {
             INPUT_NULLABLE
         );
     }
+
+    #[test]
+    fn sliced() {
+        let primitive_array_nullable = INPUT_NULLABLE
+            .into_iter()
+            .collect::<arrow_array::PrimitiveArray<UInt16Type>>()
+            .slice(1, 3);
+        let values = primitive_array_nullable.values().as_ptr();
+        let narrow: FixedSizePrimitiveArray<u16, true, ArrowScalarBuffer> =
+            primitive_array_nullable.into();
+        assert_eq!(narrow.0.data.as_ptr(), values);
+        assert_eq!(narrow.0.validity.offset, 1);
+        assert_eq!(
+            narrow
+                .into_iter()
+                .map(Option::<&u16>::copied)
+                .collect::<Vec<_>>(),
+            [None, Some(3), Some(4)]
+        );
+    }
 }
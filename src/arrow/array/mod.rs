@@ -1,13 +1,20 @@
 //! Interop with [`arrow-array`].
 
 mod boolean;
+#[cfg(feature = "lists")]
 mod fixed_size_list;
 mod fixed_size_primitive;
+#[cfg(feature = "strings")]
 mod string;
+#[cfg(feature = "structs")]
 mod r#struct;
+#[cfg(feature = "structs")]
 pub use r#struct::StructArrayTypeFields;
 mod logical;
 mod null;
+#[cfg(feature = "unions")]
 mod union;
+#[cfg(feature = "unions")]
 pub use union::UnionArrayTypeFields;
+#[cfg(feature = "lists")]
 mod variable_size_list;
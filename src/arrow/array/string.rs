@@ -25,7 +25,12 @@ where
     type Array = arrow_array::GenericStringArray<OffsetItem>;
 
     fn as_field(name: &str) -> arrow_schema::Field {
-        Field::new(name, DataType::Utf8, NULLABLE)
+        let data_type = if OffsetItem::IS_LARGE {
+            DataType::LargeUtf8
+        } else {
+            DataType::Utf8
+        };
+        Field::new(name, data_type, NULLABLE)
     }
 }
 
@@ -121,8 +126,6 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::i64;
-
     use crate::{array::StringArray, arrow::scalar_buffer::ArrowScalarBuffer};
 
     const INPUT: [&str; 3] = ["hello", "world", "!"];
@@ -186,4 +189,22 @@ mod tests {
         let _: StringArray<true, i32, ArrowScalarBuffer> = string_array_nullable.into();
         // todo(mbrobbel): intoiterator for stringarray
     }
+
+    #[test]
+    fn sliced() {
+        let string_array_nullable = INPUT_NULLABLE
+            .into_iter()
+            .collect::<arrow_array::StringArray>()
+            .slice(1, 2);
+        let values = string_array_nullable.values().as_ptr();
+        let narrow: StringArray<true, i32, ArrowScalarBuffer> = string_array_nullable.into();
+        assert_eq!(narrow.0 .0.data.0.as_ptr(), values);
+        assert_eq!((&narrow).into_iter().collect::<Vec<_>>(), [None, Some("!")]);
+        assert_eq!(
+            arrow_array::StringArray::from(narrow)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [None, Some("!")]
+        );
+    }
 }
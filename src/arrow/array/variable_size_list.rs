@@ -29,11 +29,13 @@ where
     type Array = arrow_array::GenericListArray<OffsetItem>;
 
     fn as_field(name: &str) -> arrow_schema::Field {
-        Field::new(
-            name,
-            DataType::List(Arc::new(T::as_field("item"))),
-            NULLABLE,
-        )
+        let item = Arc::new(T::as_field("item"));
+        let data_type = if OffsetItem::IS_LARGE {
+            DataType::LargeList(item)
+        } else {
+            DataType::List(item)
+        };
+        Field::new(name, data_type, NULLABLE)
     }
 }
 
@@ -138,6 +140,7 @@ where
 }
 
 #[cfg(test)]
+#[cfg(feature = "strings")]
 mod tests {
     use arrow_array::{
         builder::{ListBuilder, StringBuilder},
@@ -192,17 +195,19 @@ mod tests {
     fn into_non_nullable() {
         let mut list_builder =
             ListBuilder::with_capacity(StringBuilder::new(), INPUT_NULLABLE.len());
-        INPUT_NULLABLE.into_iter().for_each(|opt| match opt {
-            Some(items) => {
-                for item in items {
-                    list_builder.values().append_value(item);
+        for opt in INPUT_NULLABLE {
+            match opt {
+                Some(items) => {
+                    for item in items {
+                        list_builder.values().append_value(item);
+                    }
+                    list_builder.append(true);
+                }
+                None => {
+                    list_builder.append(false);
                 }
-                list_builder.append(true);
-            }
-            None => {
-                list_builder.append(false);
             }
-        });
+        }
         let list_array_nullable = list_builder.finish();
         let _: VariableSizeListArray<
             StringArray<false, i32, ArrowScalarBuffer>,
@@ -229,17 +234,19 @@ mod tests {
 
         let mut list_builder =
             ListBuilder::with_capacity(StringBuilder::new(), INPUT_NULLABLE.len());
-        INPUT_NULLABLE.into_iter().for_each(|opt| match opt {
-            Some(items) => {
-                for item in items {
-                    list_builder.values().append_value(item);
+        for opt in INPUT_NULLABLE {
+            match opt {
+                Some(items) => {
+                    for item in items {
+                        list_builder.values().append_value(item);
+                    }
+                    list_builder.append(true);
+                }
+                None => {
+                    list_builder.append(false);
                 }
-                list_builder.append(true);
-            }
-            None => {
-                list_builder.append(false);
             }
-        });
+        }
         let list_array_nullable = list_builder.finish();
         let _: VariableSizeListArray<
             StringArray<false, i32, ArrowScalarBuffer>,
@@ -248,4 +255,26 @@ mod tests {
             ArrowScalarBuffer,
         > = list_array_nullable.into();
     }
+
+    #[test]
+    fn sliced() {
+        let list_array = arrow_array::ListArray::from_iter_primitive::<UInt16Type, _, _>(
+            INPUT
+                .into_iter()
+                .map(|opt| opt.iter().copied().map(Option::Some))
+                .map(Option::Some),
+        )
+        .slice(1, 2);
+        let narrow: VariableSizeListArray<
+            Uint16Array<false, ArrowScalarBuffer>,
+            false,
+            i32,
+            ArrowScalarBuffer,
+        > = list_array.into();
+        assert_eq!(narrow.len(), 2);
+        assert_eq!(narrow.0.offsets.as_ref(), [2, 3, 4]);
+        let round_trip = arrow_array::ListArray::from(narrow);
+        assert_eq!(round_trip.value(0).len(), 1);
+        assert_eq!(round_trip.value(1).len(), 1);
+    }
 }
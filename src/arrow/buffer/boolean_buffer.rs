@@ -2,6 +2,7 @@
 
 use arrow_buffer::BooleanBuffer;
 
+use super::IntoArrowBuffer;
 use crate::{bitmap::Bitmap, buffer::BufferType, Length};
 
 impl Length for BooleanBuffer {
@@ -12,10 +13,10 @@ impl Length for BooleanBuffer {
 
 impl<Buffer: BufferType> From<Bitmap<Buffer>> for BooleanBuffer
 where
-    <Buffer as BufferType>::Buffer<u8>: Into<arrow_buffer::Buffer>,
+    <Buffer as BufferType>::Buffer<u8>: IntoArrowBuffer,
 {
     fn from(value: Bitmap<Buffer>) -> Self {
-        Self::new(value.buffer.into(), value.offset, value.bits)
+        Self::new(value.buffer.into_arrow_buffer(), value.offset, value.bits)
     }
 }
 
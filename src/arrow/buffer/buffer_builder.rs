@@ -5,7 +5,7 @@ use arrow_buffer::BufferBuilder;
 use crate::{
     array::FixedSizePrimitiveArray,
     buffer::{Buffer, BufferMut, BufferType},
-    FixedSize, Index, Length,
+    FixedSize, Index, Length, WithCapacity,
 };
 
 /// A [`BufferType`] implementation for [`BufferBuilder`].
@@ -29,7 +29,8 @@ impl<T: FixedSize> BufferMut<T> for BufferBuilder<T> {
 }
 
 impl<T: FixedSize> Index for BufferBuilder<T> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -44,6 +45,16 @@ impl<T: FixedSize> Length for BufferBuilder<T> {
     }
 }
 
+impl<T: FixedSize> WithCapacity for BufferBuilder<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        BufferBuilder::new(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        BufferBuilder::reserve(self, additional);
+    }
+}
+
 impl<T: FixedSize, Buffer: BufferType> From<FixedSizePrimitiveArray<T, false, Buffer>>
     for BufferBuilder<T>
 {
@@ -1,7 +1,96 @@
 //! Interop with [`arrow-rs`] buffer types.
 
+use arrow_buffer::{BufferBuilder, ScalarBuffer};
+use std::{ptr::NonNull, rc::Rc, sync::Arc};
+
 pub mod boolean_buffer;
 pub mod buffer_builder;
 pub mod null_buffer;
 pub mod offset_buffer;
 pub mod scalar_buffer;
+
+/// Buffers of bytes that can be converted to an [`arrow_buffer::Buffer`].
+///
+/// Owned and reference-counted buffers are converted without copying their
+/// bytes.
+pub trait IntoArrowBuffer {
+    /// Returns an [`arrow_buffer::Buffer`] with the bytes of this buffer.
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer;
+}
+
+impl IntoArrowBuffer for Vec<u8> {
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer {
+        arrow_buffer::Buffer::from_vec(self)
+    }
+}
+
+impl IntoArrowBuffer for Box<[u8]> {
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer {
+        arrow_buffer::Buffer::from_vec(self.into_vec())
+    }
+}
+
+impl IntoArrowBuffer for Arc<[u8]> {
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer {
+        let len = self.len();
+        let ptr = NonNull::from(&*self).cast::<u8>();
+        // Safety:
+        // - The pointer and length are those of the slice in the `Arc`, which
+        //   is kept alive by the owner of the returned buffer.
+        unsafe { arrow_buffer::Buffer::from_custom_allocation(ptr, len, Arc::new(self)) }
+    }
+}
+
+impl IntoArrowBuffer for Rc<[u8]> {
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer {
+        // Note: this makes a copy, because `Rc` is not `Send`.
+        arrow_buffer::Buffer::from_slice_ref(self)
+    }
+}
+
+impl IntoArrowBuffer for &[u8] {
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer {
+        // Note: this makes a copy
+        arrow_buffer::Buffer::from_slice_ref(self)
+    }
+}
+
+impl<const N: usize> IntoArrowBuffer for [u8; N] {
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer {
+        // Note: this makes a copy
+        arrow_buffer::Buffer::from_slice_ref(self)
+    }
+}
+
+impl IntoArrowBuffer for ScalarBuffer<u8> {
+    fn into_arrow_buffer(self) -> arrow_buffer::Buffer {
+        self.into_inner()
+    }
+}
+
+impl IntoArrowBuffer for BufferBuilder<u8> {
+    fn into_arrow_buffer(mut self) -> arrow_buffer::Buffer {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_copy() {
+        let vec = vec![1_u8, 2, 3];
+        let ptr = vec.as_ptr();
+        assert_eq!(vec.into_arrow_buffer().as_ptr(), ptr);
+
+        let arc: Arc<[u8]> = Arc::from([1_u8, 2, 3]);
+        let shared = Arc::clone(&arc);
+        let buffer = arc.into_arrow_buffer();
+        assert_eq!(buffer.as_ptr(), shared.as_ptr());
+        assert_eq!(buffer.as_slice(), [1, 2, 3]);
+        assert_eq!(Arc::strong_count(&shared), 2);
+        drop(buffer);
+        assert_eq!(Arc::strong_count(&shared), 1);
+    }
+}
@@ -31,6 +31,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{arrow::buffer::scalar_buffer::ArrowScalarBuffer, buffer::ArcBuffer};
+    use std::sync::Arc;
 
     use super::*;
 
@@ -57,6 +58,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shared() {
+        let bytes: Arc<[u8]> = Arc::from([0b1011_0100_u8]);
+        let bitmap = Bitmap::<ArcBuffer> {
+            buffer: Arc::clone(&bytes),
+            bits: 4,
+            offset: 2,
+        };
+        let null_buffer = NullBuffer::from(bitmap);
+        assert_eq!(null_buffer.buffer().as_ptr(), bytes.as_ptr());
+        assert_eq!(null_buffer.offset(), 2);
+        assert_eq!(
+            null_buffer.into_iter().collect::<Vec<_>>(),
+            [true, false, true, true]
+        );
+    }
+
     #[test]
     fn into() {
         let null_buffer = INPUT.into_iter().collect::<NullBuffer>();
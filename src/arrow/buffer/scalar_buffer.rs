@@ -23,7 +23,8 @@ impl<T: FixedSize> Buffer<T> for ScalarBuffer<T> {
 }
 
 impl<T: FixedSize> Index for ScalarBuffer<T> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -61,6 +62,27 @@ where
     }
 }
 
+impl<T: FixedSize> FixedSizePrimitiveArray<T, false, ArrowScalarBuffer> {
+    /// Returns an array with the values of this array in a [`Vec`], without
+    /// copying, when the buffer of this array is not shared.
+    ///
+    /// This can be used to mutate the values of an array received from
+    /// `arrow-rs` in place, and to copy them only when that is not possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns this array when its buffer is shared, e.g. with other arrays
+    /// or slices, or when the allocation can't be reused by a [`Vec`].
+    pub fn try_unwrap(self) -> Result<FixedSizePrimitiveArray<T>, Self> {
+        let len = self.0.len();
+        self.0
+            .into_inner()
+            .into_vec()
+            .map(FixedSizePrimitiveArray)
+            .map_err(|buffer| FixedSizePrimitiveArray(ScalarBuffer::new(buffer, 0, len)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::buffer::ArcBuffer;
@@ -110,4 +132,19 @@ mod tests {
             INPUT
         );
     }
+
+    #[test]
+    fn try_unwrap() {
+        let array = FixedSizePrimitiveArray::<u32, false, ArrowScalarBuffer>::from(
+            ScalarBuffer::from(INPUT.to_vec()),
+        );
+        let shared = array.clone();
+        let array_shared = array.try_unwrap().expect_err("shared buffer");
+        drop(shared);
+        let ptr = array_shared.as_ptr();
+        let mut unique = array_shared.try_unwrap().expect("unique buffer");
+        assert_eq!(unique.as_ptr(), ptr);
+        unique.as_mut()[0] = 0;
+        assert_eq!(unique.as_ref(), [0, 2, 3, 4]);
+    }
 }
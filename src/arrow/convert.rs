@@ -0,0 +1,321 @@
+//! Fallible conversion of [`arrow-rs`] arrays.
+//!
+//! [`arrow-rs`]: https://crates.io/crates/arrow
+
+use crate::arrow::ArrowArray;
+use arrow_array::{cast::AsArray, Array};
+use arrow_schema::{DataType, Field};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+/// Error returned when an [`arrow-rs`] array can't be converted to an array.
+///
+/// Every variant has the path of the mismatching array, e.g.
+/// `children[2].offsets`, which is empty for the converted array itself.
+///
+/// [`arrow-rs`]: https://crates.io/crates/arrow
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArrowConversionError {
+    /// The data type differs from the data type of the array.
+    DataType {
+        /// The path of the array.
+        path: String,
+        /// The data type of the arrow array.
+        found: DataType,
+        /// The data type of the array.
+        expected: DataType,
+    },
+    /// The type of the offsets differs from the type of the offsets of the
+    /// array.
+    Offsets {
+        /// The path of the offsets.
+        path: String,
+        /// The offset type of the arrow array.
+        found: &'static str,
+        /// The offset type of the array.
+        expected: &'static str,
+    },
+    /// The number of children differs from the number of children of the
+    /// array.
+    ChildCount {
+        /// The path of the array.
+        path: String,
+        /// The number of children of the arrow array.
+        count: usize,
+        /// The number of children of the array.
+        expected: usize,
+    },
+    /// The arrow array has a null buffer and the array is not nullable, or
+    /// the other way around.
+    Nullability {
+        /// The path of the array.
+        path: String,
+        /// Whether the array is nullable.
+        nullable: bool,
+    },
+}
+
+impl Display for ArrowConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = match *self {
+            Self::DataType { ref path, .. }
+            | Self::Offsets { ref path, .. }
+            | Self::ChildCount { ref path, .. }
+            | Self::Nullability { ref path, .. } => path,
+        };
+        if !path.is_empty() {
+            write!(f, "{path}: ")?;
+        }
+        match *self {
+            Self::DataType {
+                ref found,
+                ref expected,
+                ..
+            } => write!(f, "expected {expected} got {found}"),
+            Self::Offsets {
+                found, expected, ..
+            } => write!(f, "expected {expected} got {found}"),
+            Self::ChildCount {
+                count, expected, ..
+            } => write!(f, "expected {expected} children got {count}"),
+            Self::Nullability { nullable: true, .. } => {
+                write!(f, "expected array with a null buffer")
+            }
+            Self::Nullability {
+                nullable: false, ..
+            } => write!(f, "expected array without a null buffer"),
+        }
+    }
+}
+
+impl Error for ArrowConversionError {}
+
+/// Returns the path of a child of the array at `path`.
+fn child_path(path: &str, child: impl Display) -> String {
+    if path.is_empty() {
+        child.to_string()
+    } else {
+        format!("{path}.{child}")
+    }
+}
+
+/// Returns the name of the offset type of a data type with offsets.
+fn offset_type(data_type: &DataType) -> Option<&'static str> {
+    match *data_type {
+        DataType::Utf8 | DataType::Binary | DataType::List(_) => Some("i32"),
+        DataType::LargeUtf8 | DataType::LargeBinary | DataType::LargeList(_) => Some("i64"),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the data types only differ in their offset type.
+fn offsets_differ(expected: &DataType, found: &DataType) -> bool {
+    matches!(
+        (expected, found),
+        (DataType::Utf8, DataType::LargeUtf8)
+            | (DataType::LargeUtf8, DataType::Utf8)
+            | (DataType::Binary, DataType::LargeBinary)
+            | (DataType::LargeBinary, DataType::Binary)
+            | (DataType::List(_), DataType::LargeList(_))
+            | (DataType::LargeList(_), DataType::List(_))
+    )
+}
+
+/// Checks that `array`, at `path`, matches `field`.
+fn check(field: &Field, array: &dyn Array, path: &str) -> Result<(), ArrowConversionError> {
+    let expected = field.data_type();
+    let found = array.data_type();
+
+    if offsets_differ(expected, found) {
+        return Err(ArrowConversionError::Offsets {
+            path: child_path(path, "offsets"),
+            found: offset_type(found).expect("data type with offsets"),
+            expected: offset_type(expected).expect("data type with offsets"),
+        });
+    }
+
+    if *expected != DataType::Null && field.is_nullable() != array.nulls().is_some() {
+        return Err(ArrowConversionError::Nullability {
+            path: path.to_owned(),
+            nullable: field.is_nullable(),
+        });
+    }
+
+    match *expected {
+        DataType::List(ref item) if matches!(*found, DataType::List(_)) => check(
+            item,
+            array.as_list::<i32>().values(),
+            &child_path(path, "children[0]"),
+        ),
+        DataType::LargeList(ref item) if matches!(*found, DataType::LargeList(_)) => check(
+            item,
+            array.as_list::<i64>().values(),
+            &child_path(path, "children[0]"),
+        ),
+        DataType::FixedSizeList(ref item, size) if matches!(*found, DataType::FixedSizeList(_, found_size) if found_size == size) => {
+            check(
+                item,
+                array.as_fixed_size_list().values(),
+                &child_path(path, "children[0]"),
+            )
+        }
+        DataType::Struct(ref fields) if matches!(*found, DataType::Struct(_)) => {
+            let columns = array.as_struct().columns();
+            if fields.len() != columns.len() {
+                return Err(ArrowConversionError::ChildCount {
+                    path: path.to_owned(),
+                    count: columns.len(),
+                    expected: fields.len(),
+                });
+            }
+            fields
+                .iter()
+                .zip(columns)
+                .enumerate()
+                .try_for_each(|(index, (child, column))| {
+                    check(
+                        child,
+                        column,
+                        &child_path(path, format_args!("children[{index}]")),
+                    )
+                })
+        }
+        _ if *expected == *found => Ok(()),
+        _ => Err(ArrowConversionError::DataType {
+            path: path.to_owned(),
+            found: found.clone(),
+            expected: expected.clone(),
+        }),
+    }
+}
+
+/// Returns an error if `array` can't be converted to an array of type `T`.
+///
+/// The data types, offset types and null buffers of `array` and its children
+/// are compared with those of `T`. Field names are not compared.
+///
+/// # Errors
+///
+/// Returns an error describing the first mismatch.
+pub fn validate<T: ArrowArray>(array: &dyn Array) -> Result<(), ArrowConversionError> {
+    check(&T::as_field(""), array, "")
+}
+
+/// Fallible conversion of [`arrow-rs`] arrays.
+///
+/// The conversions with [`From`] panic when the arrow array doesn't match
+/// the array type. This trait validates the arrow array first, and returns
+/// an error describing the mismatch instead.
+///
+/// ```
+/// # #[cfg(feature = "strings")]
+/// # {
+/// use narrow::{
+///     array::StringArray,
+///     arrow::{scalar_buffer::ArrowScalarBuffer, TryFromArrow},
+///     Length,
+/// };
+/// use std::sync::Arc;
+///
+/// let array = Arc::new(arrow_array::StringArray::from(vec!["a", "b"]));
+/// let strings = StringArray::<false, i32, ArrowScalarBuffer>::try_from_arrow(array)?;
+/// assert_eq!(strings.len(), 2);
+///
+/// let large = Arc::new(arrow_array::LargeStringArray::from(vec!["a", "b"]));
+/// assert!(StringArray::<false, i32, ArrowScalarBuffer>::try_from_arrow(large).is_err());
+/// # }
+/// # Ok::<(), narrow::arrow::ArrowConversionError>(())
+/// ```
+///
+/// Implementing [`TryFrom`] directly is not possible, because it conflicts
+/// with the blanket implementation for types that implement [`From`].
+///
+/// [`arrow-rs`]: https://crates.io/crates/arrow
+pub trait TryFromArrow: Sized {
+    /// Converts `array` to this array type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `array` doesn't match this array type.
+    fn try_from_arrow(array: Arc<dyn Array>) -> Result<Self, ArrowConversionError>;
+}
+
+impl<T: ArrowArray + From<Arc<dyn Array>>> TryFromArrow for T {
+    fn try_from_arrow(array: Arc<dyn Array>) -> Result<Self, ArrowConversionError> {
+        validate::<T>(array.as_ref())?;
+        Ok(Self::from(array))
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "lists", feature = "strings"))]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{StringArray, Uint16Array, VariableSizeListArray},
+        arrow::scalar_buffer::ArrowScalarBuffer,
+        Length,
+    };
+
+    #[test]
+    fn valid() {
+        let array: Arc<dyn Array> = Arc::new(arrow_array::UInt16Array::from(vec![1, 2, 3]));
+        let converted =
+            Uint16Array::<false, ArrowScalarBuffer>::try_from_arrow(array).expect("valid");
+        assert_eq!(converted.len(), 3);
+
+        let list: Arc<dyn Array> = Arc::new(arrow_array::LargeListArray::from_iter_primitive::<
+            arrow_array::types::UInt16Type,
+            _,
+            _,
+        >([Some(vec![Some(1), None]), None]));
+        assert_eq!(
+            validate::<
+                VariableSizeListArray<
+                    Uint16Array<true, ArrowScalarBuffer>,
+                    true,
+                    i64,
+                    ArrowScalarBuffer,
+                >,
+            >(list.as_ref()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn mismatch() {
+        let array = arrow_array::UInt32Array::from(vec![1, 2, 3]);
+        assert_eq!(
+            validate::<Uint16Array>(&array),
+            Err(ArrowConversionError::DataType {
+                path: String::new(),
+                found: DataType::UInt32,
+                expected: DataType::UInt16,
+            })
+        );
+
+        let nullable = arrow_array::UInt16Array::from(vec![Some(1), None]);
+        assert_eq!(
+            validate::<Uint16Array>(&nullable)
+                .expect_err("nullable")
+                .to_string(),
+            "expected array without a null buffer"
+        );
+
+        let list = arrow_array::ListArray::new(
+            Arc::new(Field::new("item", DataType::LargeUtf8, false)),
+            arrow_buffer::OffsetBuffer::from_lengths([1]),
+            Arc::new(arrow_array::LargeStringArray::from(vec!["a"])),
+            None,
+        );
+        assert_eq!(
+            validate::<VariableSizeListArray<StringArray>>(&list)
+                .expect_err("large offsets")
+                .to_string(),
+            "children[0].offsets: expected i32 got i64"
+        );
+    }
+}
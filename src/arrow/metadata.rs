@@ -0,0 +1,114 @@
+//! Key-value metadata of batches.
+
+use arrow_array::RecordBatch;
+use std::{collections::HashMap, sync::Arc};
+
+/// An array with key-value metadata, e.g. an ingest timestamp or a source
+/// id of a batch.
+///
+/// The metadata is stored as the custom metadata of the schema of an
+/// [`arrow-rs`] record batch, so it is kept by the IPC and FFI
+/// implementations of `arrow-rs`.
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use narrow::{array::StructArray, arrow::Annotated, ArrayType};
+///
+/// #[derive(ArrayType)]
+/// struct Event {
+///     value: u32,
+/// }
+///
+/// let batch = [Event { value: 1 }]
+///     .into_iter()
+///     .collect::<StructArray<Event>>();
+/// let record_batch = arrow_array::RecordBatch::from(
+///     Annotated::new(batch).with_metadata("source", "sensor-1"),
+/// );
+/// assert_eq!(record_batch.schema().metadata()["source"], "sensor-1");
+/// # }
+/// ```
+///
+/// [`arrow-rs`]: https://crates.io/crates/arrow
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Annotated<A> {
+    /// The array.
+    pub array: A,
+    /// The key-value metadata of the array.
+    pub metadata: HashMap<String, String>,
+}
+
+impl<A> Annotated<A> {
+    /// Returns `array` without metadata.
+    pub fn new(array: A) -> Self {
+        Self {
+            array,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Adds the metadata entry `key` with `value`, replacing the previous
+    /// value of `key`.
+    #[must_use]
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
+impl<A> From<Annotated<A>> for RecordBatch
+where
+    RecordBatch: From<A>,
+{
+    fn from(value: Annotated<A>) -> Self {
+        let batch = RecordBatch::from(value.array);
+        let schema = batch
+            .schema()
+            .as_ref()
+            .clone()
+            .with_metadata(value.metadata);
+        batch
+            .with_schema(Arc::new(schema))
+            .expect("schema should only differ in metadata")
+    }
+}
+
+impl<A: From<RecordBatch>> From<RecordBatch> for Annotated<A> {
+    fn from(value: RecordBatch) -> Self {
+        let metadata = value.schema().metadata().clone();
+        Self {
+            array: A::from(value),
+            metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "derive")]
+mod tests {
+    use super::*;
+    use crate::{array::StructArray, arrow::scalar_buffer::ArrowScalarBuffer, Length};
+
+    #[derive(crate::ArrayType)]
+    struct Foo {
+        a: u32,
+        b: bool,
+    }
+
+    #[test]
+    fn round_trip() {
+        let batch = [Foo { a: 1, b: true }, Foo { a: 2, b: false }]
+            .into_iter()
+            .collect::<StructArray<Foo>>();
+        let record_batch = RecordBatch::from(
+            Annotated::new(batch)
+                .with_metadata("source", "a")
+                .with_metadata("source", "b"),
+        );
+        let annotated: Annotated<StructArray<Foo, false, ArrowScalarBuffer>> = record_batch.into();
+        assert_eq!(annotated.array.len(), 2);
+        assert_eq!(annotated.metadata.len(), 1);
+        assert_eq!(annotated.metadata["source"], "b");
+    }
+}
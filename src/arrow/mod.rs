@@ -1,13 +1,27 @@
 //! Interop with the [`arrow-rs`] crate.
 //!
+//! Arrays with the [`ArrowScalarBuffer`](scalar_buffer::ArrowScalarBuffer)
+//! buffer type are converted from `arrow-rs` arrays without copying,
+//! including arrays with a non-zero offset returned by `slice`: value and
+//! offset buffers keep their slice and validity bitmaps keep their bit offset.
+//!
 //! [`arrow-rs`]: https://crates.io/crates/arrow
 
 mod array;
-pub use array::{StructArrayTypeFields, UnionArrayTypeFields};
+#[cfg(feature = "structs")]
+pub use array::StructArrayTypeFields;
+#[cfg(feature = "unions")]
+pub use array::UnionArrayTypeFields;
 
 mod buffer;
 pub use buffer::*;
 
+mod convert;
+pub use convert::{validate, ArrowConversionError, TryFromArrow};
+
+mod metadata;
+pub use metadata::Annotated;
+
 use crate::array::Array;
 use arrow_schema::Field;
 
@@ -0,0 +1,158 @@
+//! Kernels on bitmap words with runtime CPU feature dispatch.
+//!
+//! Every kernel is compiled once for the baseline target and once for every
+//! supported set of target features, e.g. AVX2 on `x86_64`. The fastest
+//! version supported by the CPU is selected at runtime, so a single binary
+//! uses the fast paths without `target-cpu` flags.
+//!
+//! The kernels take 64-bit words. Bitmaps with byte words, like the validity
+//! bitmaps of arrays, pass their aligned full words to [`count_ones`].
+
+/// Returns the number of set bits in `words`.
+// Inlined into the versions with target features, so it is vectorized with
+// these features.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+fn count_ones_generic(words: &[u64]) -> u64 {
+    words.iter().map(|word| u64::from(word.count_ones())).sum()
+}
+
+/// Returns the bitwise AND of the words of `left` and `right`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+fn and_generic(left: &[u64], right: &[u64]) -> Vec<u64> {
+    left.iter().zip(right).map(|(&l, &r)| l & r).collect()
+}
+
+/// Kernels with `x86_64` target features.
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    /// [`super::count_ones_generic`] with AVX2.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support the `avx2` and `popcnt` features.
+    #[target_feature(enable = "avx2,popcnt")]
+    pub(super) unsafe fn count_ones_avx2(words: &[u64]) -> u64 {
+        super::count_ones_generic(words)
+    }
+
+    /// [`super::count_ones_generic`] with the `popcnt` instruction.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support the `popcnt` feature.
+    #[target_feature(enable = "popcnt")]
+    pub(super) unsafe fn count_ones_popcnt(words: &[u64]) -> u64 {
+        super::count_ones_generic(words)
+    }
+
+    /// [`super::and_generic`] with AVX2.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support the `avx2` feature.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn and_avx2(left: &[u64], right: &[u64]) -> Vec<u64> {
+        super::and_generic(left, right)
+    }
+}
+
+/// Kernels with `aarch64` target features.
+#[cfg(target_arch = "aarch64")]
+mod arm {
+    /// [`super::count_ones_generic`] with NEON.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support the `neon` feature.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn count_ones_neon(words: &[u64]) -> u64 {
+        super::count_ones_generic(words)
+    }
+
+    /// [`super::and_generic`] with NEON.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support the `neon` feature.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn and_neon(left: &[u64], right: &[u64]) -> Vec<u64> {
+        super::and_generic(left, right)
+    }
+}
+
+/// Returns the number of set bits in `words`.
+pub(super) fn count_ones(words: &[u64]) -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("popcnt") {
+            // Safety:
+            // - The CPU supports the enabled features.
+            return unsafe { x86::count_ones_avx2(words) };
+        }
+        if is_x86_feature_detected!("popcnt") {
+            // Safety:
+            // - The CPU supports the enabled feature.
+            return unsafe { x86::count_ones_popcnt(words) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            // Safety:
+            // - The CPU supports the enabled feature.
+            return unsafe { arm::count_ones_neon(words) };
+        }
+    }
+    count_ones_generic(words)
+}
+
+/// Returns the bitwise AND of the words of `left` and `right`.
+pub(super) fn and(left: &[u64], right: &[u64]) -> Vec<u64> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // Safety:
+            // - The CPU supports the enabled feature.
+            return unsafe { x86::and_avx2(left, right) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            // Safety:
+            // - The CPU supports the enabled feature.
+            return unsafe { arm::and_neon(left, right) };
+        }
+    }
+    and_generic(left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns `len` pseudo-random words.
+    fn words(len: usize, seed: u64) -> Vec<u64> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                state
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dispatch() {
+        for len in [0, 1, 3, 4, 7, 33, 1000] {
+            let left = words(len, 1);
+            let right = words(len, 2);
+            assert_eq!(count_ones(&left), count_ones_generic(&left));
+            assert_eq!(and(&left, &right), and_generic(&left, &right));
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! An iterator over the bits in a bitmap.
+
+use std::{iter::FusedIterator, slice};
+
+/// An iterator over the bits in a Bitmap.
+///
+/// This iterator returns boolean values that represent the bits stored in a
+/// Bitmap. Bytes are loaded once and their bits are yielded from a cached
+/// copy, and skipping over bits (via [`Iterator::nth`]) skips whole bytes.
+#[derive(Clone, Debug)]
+pub struct BitmapIter<'a> {
+    /// The remaining bytes.
+    bytes: slice::Iter<'a, u8>,
+    /// The current byte, shifted so the next bit is the least significant bit.
+    current: u8,
+    /// The number of bits left in the current byte.
+    current_bits: usize,
+    /// The number of bits left to yield.
+    remaining: usize,
+}
+
+impl<'a> BitmapIter<'a> {
+    /// Returns a new iterator over `len` bits of `bytes`, starting at bit
+    /// `offset`.
+    pub(crate) fn new(bytes: &'a [u8], offset: usize, len: usize) -> Self {
+        let mut iter = Self {
+            bytes: bytes.get(offset / 8..).unwrap_or_default().iter(),
+            current: 0,
+            current_bits: 0,
+            remaining: len,
+        };
+        let shift = offset % 8;
+        if shift != 0 && len != 0 {
+            if let Some(&byte) = iter.bytes.next() {
+                iter.current = byte >> shift;
+                iter.current_bits = 8 - shift;
+            }
+        }
+        iter
+    }
+}
+
+impl Iterator for BitmapIter<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.current_bits == 0 {
+            self.current = *self.bytes.next()?;
+            self.current_bits = 8;
+        }
+        let bit = self.current & 1 == 1;
+        self.current >>= 1;
+        self.current_bits -= 1;
+        self.remaining -= 1;
+        Some(bit)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        if n < self.current_bits {
+            self.current >>= n;
+            self.current_bits -= n;
+        } else {
+            let skip = n - self.current_bits;
+            self.current = *self.bytes.nth(skip / 8)? >> (skip % 8);
+            self.current_bits = 8 - skip % 8;
+        }
+        self.remaining -= n;
+        self.next()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+
+        // Drain the bits of the current byte.
+        let current_bits = self.current_bits.min(self.remaining);
+        for _ in 0..current_bits {
+            acc = f(acc, self.current & 1 == 1);
+            self.current >>= 1;
+        }
+        self.remaining -= current_bits;
+
+        // Yield whole bytes.
+        while self.remaining >= 8 {
+            let Some(&byte) = self.bytes.next() else {
+                return acc;
+            };
+            for bit in 0..8 {
+                acc = f(acc, byte & (1 << bit) != 0);
+            }
+            self.remaining -= 8;
+        }
+
+        // Yield the bits of the trailing partial byte.
+        if self.remaining != 0 {
+            if let Some(&byte) = self.bytes.next() {
+                for bit in 0..self.remaining {
+                    acc = f(acc, byte & (1 << bit) != 0);
+                }
+            }
+        }
+        acc
+    }
+}
+
+impl ExactSizeIterator for BitmapIter<'_> {}
+
+impl FusedIterator for BitmapIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BYTES: [u8; 3] = [0b1010_1100, 0b0000_1111, 0b1000_0001];
+
+    fn expected(offset: usize, len: usize) -> Vec<bool> {
+        BYTES
+            .iter()
+            .flat_map(|byte| (0..8).map(move |bit| byte & (1 << bit) != 0))
+            .skip(offset)
+            .take(len)
+            .collect()
+    }
+
+    #[test]
+    fn next() {
+        for offset in 0..8 {
+            for len in 0..(24 - offset) {
+                assert_eq!(
+                    BitmapIter::new(&BYTES, offset, len).collect::<Vec<_>>(),
+                    expected(offset, len)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fold() {
+        for offset in 0..8 {
+            for len in 0..(24 - offset) {
+                let mut bits = Vec::new();
+                BitmapIter::new(&BYTES, offset, len).for_each(|bit| bits.push(bit));
+                assert_eq!(bits, expected(offset, len));
+                assert_eq!(BitmapIter::new(&BYTES, offset, len).count(), len);
+            }
+        }
+    }
+
+    #[test]
+    fn nth() {
+        for offset in 0..8 {
+            let len = 24 - offset;
+            for n in 0..=len {
+                let mut iter = BitmapIter::new(&BYTES, offset, len);
+                assert_eq!(iter.nth(n), expected(offset, len).get(n).copied());
+                assert_eq!(
+                    iter.collect::<Vec<_>>(),
+                    expected(offset, len).get(n + 1..).unwrap_or_default()
+                );
+            }
+        }
+    }
+}
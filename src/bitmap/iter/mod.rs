@@ -1,9 +1,9 @@
 //! Iterators for bitmaps.
 
-use std::{
-    iter::{Skip, Take},
-    slice,
-};
+use std::iter::{Skip, Take};
+
+mod bitmap_iter;
+pub use self::bitmap_iter::*;
 
 mod bit_packed;
 pub use self::bit_packed::*;
@@ -11,11 +11,8 @@ pub use self::bit_packed::*;
 mod bit_unpacked;
 pub use self::bit_unpacked::*;
 
-/// An iterator over the bits in a Bitmap.
-///
-/// This iterator returns boolean values that represent the bits stored in a
-/// Bitmap.
-pub type BitmapIter<'a> = Take<Skip<BitUnpacked<slice::Iter<'a, u8>, &'a u8>>>;
+mod valid_slices;
+pub use self::valid_slices::*;
 
 /// An iterator over the bits in a Bitmap. Consumes the Bitmap.
 pub type BitmapIntoIter<I> = Take<Skip<BitUnpacked<I, u8>>>;
@@ -0,0 +1,85 @@
+//! An iterator over the runs of set bits in a bitmap.
+
+use std::{iter::FusedIterator, ops::Range};
+
+/// An iterator over the runs of set bits in a Bitmap.
+///
+/// Every item is the range of indices of a maximal run of consecutive set
+/// bits. Bytes with all bits set or unset are skipped at once.
+#[derive(Clone, Debug)]
+pub struct ValidSlices<'a> {
+    /// The bytes storing the bits.
+    bytes: &'a [u8],
+    /// The offset of the first bit in `bytes`, in bits.
+    offset: usize,
+    /// The number of bits.
+    len: usize,
+    /// The index of the next bit to inspect.
+    position: usize,
+}
+
+impl<'a> ValidSlices<'a> {
+    /// Returns a new iterator over the runs of set bits in `len` bits of
+    /// `bytes`, starting at bit `offset`.
+    pub(crate) fn new(bytes: &'a [u8], offset: usize, len: usize) -> Self {
+        Self {
+            bytes,
+            offset,
+            len,
+            position: 0,
+        }
+    }
+
+    /// Returns the index of the first bit equal to `bit` at or after
+    /// `self.position`, or the number of bits if there is none.
+    fn next_bit(&self, bit: bool) -> usize {
+        let skipped = if bit { 0 } else { u8::MAX };
+        let mut index = self.position;
+        while index < self.len {
+            let position = self.offset + index;
+            let byte = self.bytes[position / 8];
+            if position % 8 == 0 && index + 8 <= self.len && byte == skipped {
+                index += 8;
+            } else if (byte & 1 << (position % 8) != 0) == bit {
+                return index;
+            } else {
+                index += 1;
+            }
+        }
+        self.len
+    }
+}
+
+impl Iterator for ValidSlices<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.position = self.next_bit(true);
+        (self.position < self.len).then(|| {
+            let start = self.position;
+            self.position = self.next_bit(false);
+            start..self.position
+        })
+    }
+}
+
+impl FusedIterator for ValidSlices<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs() {
+        let bytes = [0b1110_0110, u8::MAX, 0, 0b0000_0001];
+        assert_eq!(
+            ValidSlices::new(&bytes, 0, 32).collect::<Vec<_>>(),
+            [1..3, 5..16, 24..25]
+        );
+        assert_eq!(
+            ValidSlices::new(&bytes, 2, 22).collect::<Vec<_>>(),
+            [0..1, 3..14]
+        );
+        assert_eq!(ValidSlices::new(&bytes, 16, 8).next(), None);
+    }
+}
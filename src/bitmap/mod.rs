@@ -2,18 +2,23 @@
 
 use crate::{
     buffer::{Buffer, BufferMut, BufferRef, BufferRefMut, BufferType, VecBuffer},
-    Index, Length,
+    ByteLength, Index, Length, ShrinkToFit, WithCapacity,
 };
 use std::{
     any,
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
     fmt::{Debug, Formatter, Result},
     ops,
 };
 
+mod dispatch;
+
 mod iter;
 use self::iter::{BitPackedExt, BitUnpackedExt};
-pub use self::iter::{BitmapIntoIter, BitmapIter};
+pub use self::iter::{BitmapIntoIter, BitmapIter, ValidSlices};
+
+mod mutable;
+pub use self::mutable::BitmapMut;
 
 mod fmt;
 use self::fmt::BitsDisplayExt;
@@ -21,6 +26,9 @@ use self::fmt::BitsDisplayExt;
 mod validity;
 pub use self::validity::ValidityBitmap;
 
+mod word;
+pub use self::word::BitmapWord;
+
 /// An immutable reference to a bitmap.
 pub trait BitmapRef {
     /// The buffer type of the bitmap.
@@ -38,11 +46,14 @@ pub trait BitmapRefMut: BitmapRef {
 
 /// A collection of bits.
 ///
-/// The validity bits are stored LSB-first in the bytes of the `Buffer`.
+/// The validity bits are stored LSB-first in the words of the `Buffer`. The
+/// default words are bytes, which matches the Arrow layout. Bitmaps with
+/// `u64` words are faster to count and combine, and are converted to bytes
+/// with [`Bitmap::to_bytes`] for Arrow export.
 // todo(mb): implement ops
-pub struct Bitmap<Buffer: BufferType = VecBuffer> {
-    /// The bits are stored in this buffer of bytes.
-    pub(crate) buffer: <Buffer as BufferType>::Buffer<u8>,
+pub struct Bitmap<Buffer: BufferType = VecBuffer, Word: BitmapWord = u8> {
+    /// The bits are stored in this buffer of words.
+    pub(crate) buffer: <Buffer as BufferType>::Buffer<Word>,
 
     /// The number of bits stored in the bitmap.
     pub(crate) bits: usize,
@@ -66,23 +77,17 @@ impl<Buffer: BufferType> Bitmap<Buffer> {
         <&Self as IntoIterator>::into_iter(self)
     }
 
-    /// Forms a Bitmap from a buffer, a number of bits and an offset (in
-    /// bits).
+    /// Returns an iterator over the ranges of indices of the runs of set bits
+    /// in this [`Bitmap`].
     ///
-    /// # Safety
+    /// ```
+    /// use narrow::bitmap::Bitmap;
     ///
-    /// Caller must ensure that the buffer contains enough bytes for the
-    /// specified number of bits including the offset.
-    pub unsafe fn from_raw_parts(
-        buffer: <Buffer as BufferType>::Buffer<u8>,
-        bits: usize,
-        offset: usize,
-    ) -> Self {
-        Bitmap {
-            buffer,
-            bits,
-            offset,
-        }
+    /// let bitmap = [true, true, false, true].into_iter().collect::<Bitmap>();
+    /// assert_eq!(bitmap.valid_slices().collect::<Vec<_>>(), [0..2, 3..4]);
+    /// ```
+    pub fn valid_slices(&self) -> ValidSlices<'_> {
+        ValidSlices::new(self.buffer.as_slice(), self.offset, self.bits)
     }
 
     /// Returns the bit at given bit index. Returns `None` when the index is out
@@ -141,6 +146,62 @@ impl<Buffer: BufferType> Bitmap<Buffer> {
         (self.offset + index) / 8
     }
 
+    /// Returns a bitmap without the first `n` bits of this bitmap, with a
+    /// zero offset.
+    ///
+    /// The bits are shifted across bytes in word-sized chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length of this bitmap.
+    #[must_use]
+    pub fn shift_left(&self, n: usize) -> Bitmap {
+        assert!(
+            n <= self.bits,
+            "shift (is {n}) should be <= len (is {})",
+            self.bits
+        );
+        Bitmap {
+            buffer: read_bits(self.buffer.as_slice(), self.offset + n, self.bits - n),
+            bits: self.bits - n,
+            offset: 0,
+        }
+    }
+
+    /// Returns a bitmap with the bits of this bitmap rotated left by `n`
+    /// bits, i.e. with the first `n` bits moved to the end, with a zero
+    /// offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length of this bitmap.
+    #[must_use]
+    pub fn rotate_left(&self, n: usize) -> Bitmap {
+        let mut bitmap = self.shift_left(n);
+        let head = read_bits(self.buffer.as_slice(), self.offset, n);
+        let shift = bitmap.bits % 8;
+        if shift == 0 {
+            bitmap.buffer.extend(head);
+        } else {
+            for byte in head {
+                if let Some(last) = bitmap.buffer.last_mut() {
+                    *last |= byte << shift;
+                }
+                bitmap.buffer.push(byte >> (8 - shift));
+            }
+        }
+        bitmap.bits = self.bits;
+        bitmap.buffer.truncate(bytes_for_bits(self.bits));
+        bitmap
+    }
+
+    /// Returns a copy of this bitmap with a zero offset, for consumers that
+    /// can't express bit offsets.
+    #[must_use]
+    pub fn align_to_byte(&self) -> Bitmap {
+        self.shift_left(0)
+    }
+
     /// Returns a [`Bitmap`] with `len` bits set.
     #[must_use]
     pub fn new_valid(len: usize) -> Self
@@ -152,6 +213,356 @@ impl<Buffer: BufferType> Bitmap<Buffer> {
     }
 }
 
+impl Bitmap {
+    /// Copies `len` bits of `src`, starting at bit `src_start`, to this
+    /// bitmap, starting at bit `dst_start`. The bitmap is extended with unset
+    /// bits when the copied bits don't fit.
+    ///
+    /// The bits are copied in blocks of bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bits to copy are out of bounds of `src`, or if
+    /// `dst_start` is greater than the length of this bitmap.
+    pub(crate) fn copy_bits<B: BufferType>(
+        &mut self,
+        src: &Bitmap<B>,
+        src_start: usize,
+        dst_start: usize,
+        len: usize,
+    ) {
+        assert!(
+            src_start + len <= src.bits,
+            "end of copied bits (is {}) should be <= source len (is {})",
+            src_start + len,
+            src.bits
+        );
+        assert!(
+            dst_start <= self.bits,
+            "destination start (is {dst_start}) should be <= len (is {})",
+            self.bits
+        );
+        let missing = (dst_start + len).saturating_sub(self.bits);
+        self.extend(std::iter::repeat(false).take(missing));
+        let bits = read_bits(src.buffer.as_slice(), src.offset + src_start, len);
+        let position = self.offset + dst_start;
+        let (first, shift) = (position / 8, position % 8);
+        for (index, byte) in bits.into_iter().enumerate() {
+            let count = (len - index * 8).min(8);
+            let mask = u8::MAX >> (8 - count);
+            let target = &mut self.buffer[first + index];
+            *target = (*target & !(mask << shift)) | (byte << shift);
+            let carry = if shift == 0 { 0 } else { mask >> (8 - shift) };
+            if carry != 0 {
+                let next = &mut self.buffer[first + index + 1];
+                *next = (*next & !carry) | (byte >> (8 - shift));
+            }
+        }
+    }
+}
+
+impl Bitmap {
+    /// Returns a bitmap with `n` bits equal to `bit`.
+    ///
+    /// The bytes are filled at once.
+    #[must_use]
+    pub fn repeat(bit: bool, n: usize) -> Self {
+        let mut buffer = vec![if bit { u8::MAX } else { 0 }; bytes_for_bits(n)];
+        if let Some(last) = buffer.last_mut() {
+            if n % 8 != 0 {
+                *last &= (1 << (n % 8)) - 1;
+            }
+        }
+        Self {
+            buffer,
+            bits: n,
+            offset: 0,
+        }
+    }
+}
+
+impl<Buffer: BufferType> Bitmap<Buffer> {
+    /// Returns a bitmap with the bits of this bitmap repeated `n` times.
+    #[must_use]
+    pub fn tile(&self, n: usize) -> Bitmap {
+        let mut bitmap = Bitmap::with_capacity(self.bits * n);
+        for index in 0..n {
+            bitmap.copy_bits(self, 0, index * self.bits, self.bits);
+        }
+        bitmap
+    }
+}
+
+impl<Buffer: BufferType> Bitmap<Buffer> {
+    /// Returns the number of bits before the first byte boundary, and the
+    /// number of full bytes after them.
+    fn byte_aligned_parts(&self) -> (usize, usize) {
+        let head = ((8 - self.offset % 8) % 8).min(self.bits);
+        (head, (self.bits - head) / 8)
+    }
+
+    /// Returns the number of set bits in this bitmap.
+    ///
+    /// The full bytes are counted per aligned 64-bit word, with the fastest
+    /// instructions supported by the CPU, which are detected at runtime. The
+    /// bits in the partial bytes at the start and the end are counted one by
+    /// one. This is how the null counts of arrays are computed.
+    // The number of bits in a bitmap fits in usize.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn count_ones(&self) -> usize {
+        let (head, full) = self.byte_aligned_parts();
+        let start = self.byte_index(head);
+        // Safety:
+        // - Every bit pattern is a valid u64.
+        let (prefix, words, suffix) =
+            unsafe { self.buffer.as_slice()[start..start + full].align_to::<u64>() };
+        let bytes = prefix
+            .iter()
+            .chain(suffix)
+            .map(|byte| u64::from(byte.count_ones()))
+            .sum::<u64>()
+            + dispatch::count_ones(words);
+        let partial = (0..head)
+            .chain(head + full * 8..self.bits)
+            .filter(|&index| {
+                // Safety:
+                // - The indices are in bounds.
+                unsafe { self.get_unchecked(index) }
+            })
+            .count();
+        usize::try_from(bytes).expect("bit count should fit in usize") + partial
+    }
+
+    /// Returns the indices of the bits that are equal to `bit`, in ascending
+    /// order.
+    ///
+    /// Bytes without matching bits are skipped at once.
+    pub fn indices_of(&self, bit: bool) -> Vec<usize> {
+        let (head, full) = self.byte_aligned_parts();
+        let start = self.byte_index(head);
+        let matches = |index: &usize| {
+            // Safety:
+            // - The indices are in bounds.
+            unsafe { self.get_unchecked(*index) == bit }
+        };
+        let mut indices = (0..head).filter(matches).collect::<Vec<_>>();
+        for (position, &byte) in self.buffer.as_slice()[start..start + full]
+            .iter()
+            .enumerate()
+        {
+            let word = if bit { byte } else { !byte };
+            if word != 0 {
+                let base = head + position * 8;
+                indices.extend(
+                    (0..8)
+                        .filter(|shift| word & 1 << shift != 0)
+                        .map(|shift| base + shift),
+                );
+            }
+        }
+        indices.extend((head + full * 8..self.bits).filter(matches));
+        indices
+    }
+}
+
+impl<Buffer: BufferType, Word: BitmapWord> Bitmap<Buffer, Word> {
+    /// Forms a Bitmap from a buffer, a number of bits and an offset (in
+    /// bits).
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that the buffer contains enough words for the
+    /// specified number of bits including the offset.
+    pub unsafe fn from_raw_parts(
+        buffer: <Buffer as BufferType>::Buffer<Word>,
+        bits: usize,
+        offset: usize,
+    ) -> Self {
+        Bitmap {
+            buffer,
+            bits,
+            offset,
+        }
+    }
+}
+
+impl Bitmap<VecBuffer, u64> {
+    /// Returns a bitmap with the bits of `bitmap` stored in `u64` words, with
+    /// a zero offset.
+    #[must_use]
+    pub fn from_bytes<Buffer: BufferType>(bitmap: &Bitmap<Buffer>) -> Self {
+        Self {
+            buffer: read_bits(bitmap.buffer.as_slice(), bitmap.offset, bitmap.bits)
+                .chunks(8)
+                .map(|chunk| {
+                    let mut word = [0; 8];
+                    word[..chunk.len()].copy_from_slice(chunk);
+                    u64::from_le_bytes(word)
+                })
+                .collect(),
+            bits: bitmap.bits,
+            offset: 0,
+        }
+    }
+}
+
+impl<Buffer: BufferType> Bitmap<Buffer, u64> {
+    /// Returns a bitmap with the bits of this bitmap stored in bytes, with
+    /// the same offset, e.g. to export it to Arrow.
+    #[must_use]
+    pub fn to_bytes(&self) -> Bitmap {
+        let mut buffer = self
+            .buffer
+            .as_slice()
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        buffer.truncate(bytes_for_bits(self.offset + self.bits));
+        Bitmap {
+            buffer,
+            bits: self.bits,
+            offset: self.offset,
+        }
+    }
+
+    /// Returns the bit at given bit index. Returns `None` when the index is
+    /// out of bounds.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.bits).then(|| {
+            let position = self.offset + index;
+            self.buffer.as_slice()[position / 64] & 1 << (position % 64) != 0
+        })
+    }
+
+    /// Returns an iterator over the bits in this bitmap.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (self.offset..self.offset + self.bits)
+            .map(|position| self.buffer.as_slice()[position / 64] & 1 << (position % 64) != 0)
+    }
+
+    /// Returns the words of this bitmap shifted to a zero offset, with the
+    /// padding bits of the last word unset.
+    fn aligned_words(&self) -> impl Iterator<Item = u64> + '_ {
+        let words = self.buffer.as_slice();
+        let shift = self.offset % 64;
+        let start = self.offset / 64;
+        let len = self.bits / 64 + usize::from(self.bits % 64 != 0);
+        (start..start + len).map(move |index| {
+            let low = words[index] >> shift;
+            let high = match (shift, words.get(index + 1)) {
+                (0, _) | (_, None) => 0,
+                (_, Some(next)) => next << (64 - shift),
+            };
+            let remaining = self.bits - (index - start) * 64;
+            if remaining < 64 {
+                (low | high) & ((1 << remaining) - 1)
+            } else {
+                low | high
+            }
+        })
+    }
+
+    /// Returns the full words of this bitmap shifted to a zero offset, and
+    /// the last partial word, with its padding bits unset.
+    ///
+    /// The full words are borrowed when the offset is a multiple of 64.
+    fn split_words(&self) -> (Cow<'_, [u64]>, Option<u64>) {
+        let full = self.bits / 64;
+        let remaining = self.bits % 64;
+        if self.offset % 64 == 0 {
+            let words = &self.buffer.as_slice()[self.offset / 64..];
+            (
+                Cow::Borrowed(&words[..full]),
+                (remaining != 0).then(|| words[full] & ((1 << remaining) - 1)),
+            )
+        } else {
+            let mut words = self.aligned_words().collect::<Vec<_>>();
+            let partial = if remaining == 0 { None } else { words.pop() };
+            (Cow::Owned(words), partial)
+        }
+    }
+
+    /// Returns the number of set bits in this bitmap.
+    ///
+    /// The bits are counted with the fastest instructions supported by the
+    /// CPU, which are detected at runtime.
+    // The number of bits in a bitmap fits in usize.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn count_ones(&self) -> usize {
+        let (words, partial) = self.split_words();
+        let count =
+            dispatch::count_ones(&words) + partial.map_or(0, |word| u64::from(word.count_ones()));
+        usize::try_from(count).expect("bit count should fit in usize")
+    }
+
+    /// Returns a bitmap with the bits that are set in both this bitmap and
+    /// `other`, with a zero offset.
+    ///
+    /// The words are combined with the fastest instructions supported by the
+    /// CPU, which are detected at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of the bitmaps differ.
+    #[must_use]
+    pub fn and<B: BufferType>(&self, other: &Bitmap<B, u64>) -> Bitmap<VecBuffer, u64> {
+        assert_eq!(
+            self.bits, other.bits,
+            "len of other bitmap (is {}) should be equal to len (is {})",
+            other.bits, self.bits
+        );
+        let (left, left_partial) = self.split_words();
+        let (right, right_partial) = other.split_words();
+        let mut buffer = dispatch::and(&left, &right);
+        buffer.extend(left_partial.zip(right_partial).map(|(l, r)| l & r));
+        Bitmap {
+            buffer,
+            bits: self.bits,
+            offset: 0,
+        }
+    }
+}
+
+impl<Buffer: BufferType> Length for Bitmap<Buffer, u64> {
+    fn len(&self) -> usize {
+        self.bits
+    }
+}
+
+/// Returns the number of bytes required to store `bits` bits.
+fn bytes_for_bits(bits: usize) -> usize {
+    bits / 8 + usize::from(bits % 8 != 0)
+}
+
+/// Returns `len` bits of `bytes` starting at bit `offset`, packed starting at
+/// the first bit of the first byte. The padding bits of the last byte are
+/// unset.
+pub(crate) fn read_bits(bytes: &[u8], offset: usize, len: usize) -> Vec<u8> {
+    /// The number of bytes produced per step.
+    const WORD: usize = 8;
+
+    let byte_len = bytes_for_bits(len);
+    let shift = offset % 8;
+    let mut output = Vec::with_capacity(byte_len + WORD);
+    let mut index = offset / 8;
+    while output.len() < byte_len {
+        // Read one byte more than a word, for the bits shifted in.
+        let end = (index + WORD + 1).min(bytes.len());
+        let mut word = [0; 16];
+        word[..end - index].copy_from_slice(&bytes[index..end]);
+        let shifted = u128::from_le_bytes(word) >> shift;
+        output.extend_from_slice(&shifted.to_le_bytes()[..WORD]);
+        index += WORD;
+    }
+    output.truncate(byte_len);
+    if let Some(last) = output.last_mut() {
+        if len % 8 != 0 {
+            *last &= (1 << (len % 8)) - 1;
+        }
+    }
+    output
+}
+
 impl<Buffer: BufferType> BufferRef<u8> for Bitmap<Buffer> {
     type Buffer = <Buffer as BufferType>::Buffer<u8>;
 
@@ -194,6 +605,37 @@ where
     }
 }
 
+impl<Buffer: BufferType> Bitmap<Buffer> {
+    /// Returns the number of bytes to add to the buffer to store `additional`
+    /// more bits.
+    fn additional_bytes(&self, additional: usize) -> usize {
+        let bits = self.offset + self.bits + additional;
+        let bytes = bits / 8 + usize::from(bits % 8 != 0);
+        bytes.saturating_sub(self.buffer.as_slice().len())
+    }
+}
+
+impl<Buffer: BufferType> WithCapacity for Bitmap<Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: WithCapacity::with_capacity(capacity / 8 + usize::from(capacity % 8 != 0)),
+            bits: 0,
+            offset: 0,
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(self.additional_bytes(additional));
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.buffer.reserve_exact(self.additional_bytes(additional));
+    }
+}
+
 impl<T, Buffer: BufferType> Extend<T> for Bitmap<Buffer>
 where
     T: Borrow<bool>,
@@ -226,6 +668,29 @@ where
     }
 }
 
+impl<Buffer: BufferType> Bitmap<Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Extend<u8>,
+{
+    /// Appends `n` bits equal to `bit`.
+    ///
+    /// Only the bits in the partial last byte are appended one at a time, the
+    /// other bits are appended in whole bytes.
+    pub fn extend_constant(&mut self, bit: bool, n: usize) {
+        let head = self.trailing_bits().min(n);
+        self.extend(std::iter::repeat(bit).take(head));
+        let rest = n - head;
+        self.buffer
+            .extend(std::iter::repeat(if bit { u8::MAX } else { 0 }).take(bytes_for_bits(rest)));
+        if bit && rest % 8 != 0 {
+            if let Some(last) = self.buffer.as_mut_slice().last_mut() {
+                *last &= u8::MAX >> (8 - rest % 8);
+            }
+        }
+        self.bits += rest;
+    }
+}
+
 impl<Buffer: BufferType, T> FromIterator<T> for Bitmap<Buffer>
 where
     T: Borrow<bool>,
@@ -252,7 +717,8 @@ where
 }
 
 impl<Buffer: BufferType> Index for Bitmap<Buffer> {
-    type Item<'a> = bool
+    type Item<'a>
+        = bool
     where
         Self: 'a;
 
@@ -292,12 +758,7 @@ impl<'a, Buffer: BufferType> IntoIterator for &'a Bitmap<Buffer> {
     type IntoIter = BitmapIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.buffer
-            .as_slice()
-            .iter()
-            .bit_unpacked()
-            .skip(self.offset)
-            .take(self.bits)
+        BitmapIter::new(self.buffer.as_slice(), self.offset, self.bits)
     }
 }
 
@@ -323,6 +784,21 @@ impl<Buffer: BufferType> Length for Bitmap<Buffer> {
     }
 }
 
+impl<Buffer: BufferType> ByteLength for Bitmap<Buffer> {
+    fn byte_len(&self) -> usize {
+        self.buffer.as_slice().len()
+    }
+}
+
+impl<Buffer: BufferType> ShrinkToFit for Bitmap<Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+}
+
 impl<Buffer: BufferType> ValidityBitmap for Bitmap<Buffer> {}
 
 #[cfg(test)]
@@ -332,6 +808,91 @@ mod tests {
     use super::*;
     use std::mem;
 
+    #[test]
+    fn with_capacity() {
+        let mut bitmap = Bitmap::<VecBuffer>::with_capacity(9);
+        assert!(bitmap.buffer.capacity() >= 2);
+        bitmap.extend([true; 3]);
+        bitmap.reserve(13);
+        assert!(bitmap.buffer.capacity() >= 2);
+        bitmap.reserve(14);
+        assert!(bitmap.buffer.capacity() >= 3);
+        assert_eq!(bitmap.len(), 3);
+    }
+
+    #[test]
+    fn shift_and_rotate() {
+        let input = (0..75).map(|index| index % 3 == 0).collect::<Vec<_>>();
+        let bitmap = input.iter().collect::<Bitmap>();
+        // Safety:
+        // - The buffer has bytes for 75 bits, so 70 bits with an offset of 5.
+        let sliced =
+            unsafe { Bitmap::<SliceBuffer>::from_raw_parts(bitmap.buffer.as_slice(), 70, 5) };
+
+        let aligned = sliced.align_to_byte();
+        assert_eq!(aligned.offset, 0);
+        assert_eq!(aligned.buffer.len(), 9);
+        assert!(aligned.iter().eq(input[5..].iter().copied()));
+
+        let shifted = sliced.shift_left(3);
+        assert_eq!(shifted.len(), 67);
+        assert!(shifted.iter().eq(input[8..].iter().copied()));
+        // Padding bits are unset.
+        assert_eq!(shifted.buffer[8] >> 3, 0);
+        assert_eq!(sliced.shift_left(70).len(), 0);
+
+        for n in [0, 3, 8, 70] {
+            let rotated = sliced.rotate_left(n);
+            assert_eq!(rotated.buffer.len(), 9);
+            assert!(rotated
+                .iter()
+                .eq(input[5 + n..].iter().chain(&input[5..5 + n]).copied()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "shift (is 4) should be <= len (is 3)")]
+    fn shift_out_of_bounds() {
+        let _ = [true; 3].iter().collect::<Bitmap>().shift_left(4);
+    }
+
+    #[test]
+    fn words() {
+        let input = (0..150).map(|index| index % 3 == 0).collect::<Vec<_>>();
+        let bytes = input.iter().collect::<Bitmap>();
+        // Safety:
+        // - The buffer has bytes for 150 bits, so 140 bits with an offset of 10.
+        let sliced =
+            unsafe { Bitmap::<SliceBuffer>::from_raw_parts(bytes.buffer.as_slice(), 140, 10) };
+        let words = Bitmap::<VecBuffer, u64>::from_bytes(&sliced);
+        assert_eq!(words.len(), 140);
+        assert_eq!(words.buffer.len(), 3);
+        assert!(words.iter().eq(input[10..].iter().copied()));
+        assert_eq!(words.get(2), Some(true));
+        assert_eq!(words.get(140), None);
+        assert_eq!(
+            words.count_ones(),
+            input[10..].iter().filter(|bit| **bit).count()
+        );
+        assert!(words.to_bytes().iter().eq(input[10..].iter().copied()));
+
+        let other = (0..140).map(|index| index % 2 == 0).collect::<Bitmap>();
+        let both = words.and(&Bitmap::<VecBuffer, u64>::from_bytes(&other));
+        assert!(both
+            .iter()
+            .eq((10..150).map(|index| index % 3 == 0 && (index - 10) % 2 == 0)));
+
+        // Safety:
+        // - The buffer has words for 192 bits, so 100 bits with an offset of 30.
+        let offset = unsafe { Bitmap::<SliceBuffer, u64>::from_raw_parts(&words.buffer, 100, 30) };
+        assert!(offset.iter().eq(input[40..140].iter().copied()));
+        assert_eq!(
+            offset.count_ones(),
+            input[40..140].iter().filter(|bit| **bit).count()
+        );
+        assert!(offset.to_bytes().iter().eq(input[40..140].iter().copied()));
+    }
+
     #[test]
     fn offset_byte_slice() {
         let mut bitmap = [true; 32].iter().collect::<Bitmap>();
@@ -478,6 +1039,16 @@ mod tests {
         assert_eq!(array.to_vec(), bitmap.into_iter().collect::<Vec<_>>());
     }
 
+    #[test]
+    fn extend_constant() {
+        let mut bitmap = [true, false, true].iter().collect::<Bitmap>();
+        bitmap.extend_constant(true, 14);
+        bitmap.extend_constant(false, 2);
+        bitmap.extend(std::iter::once(true));
+        assert_eq!(bitmap.len(), 20);
+        assert_eq!(bitmap.buffer, [0b1111_1101, u8::MAX, 0b0000_1001]);
+    }
+
     #[test]
     fn into_iter() {
         let vec = vec![true, false, true, false];
@@ -497,4 +1068,46 @@ mod tests {
             mem::size_of::<Box<[u8]>>() + 2 * mem::size_of::<usize>()
         );
     }
+
+    #[test]
+    fn count_ones_indices() {
+        let bits = (0..29).map(|index| index % 3 == 0 || index == 20);
+        let bitmap = bits.clone().collect::<Bitmap>();
+        let expected = bits
+            .enumerate()
+            .filter_map(|(index, bit)| bit.then_some(index))
+            .collect::<Vec<_>>();
+        assert_eq!(bitmap.count_ones(), expected.len());
+        assert_eq!(bitmap.indices_of(true), expected);
+
+        // Offsets that don't start at a byte boundary.
+        for skip in [1, 5, 9, 26] {
+            let sliced = bitmap.shift_left(skip);
+            let offset = Bitmap::<SliceBuffer> {
+                buffer: bitmap.buffer.as_slice(),
+                bits: bitmap.bits - skip,
+                offset: skip,
+            };
+            assert_eq!(offset.count_ones(), sliced.count_ones());
+            assert_eq!(offset.indices_of(false), sliced.indices_of(false));
+            assert_eq!(
+                offset.indices_of(true).len() + offset.indices_of(false).len(),
+                offset.len()
+            );
+        }
+
+        // Bitmaps with full words, counted by the dispatched kernels.
+        let long = (0..300).map(|index| index % 7 != 0).collect::<Bitmap>();
+        for skip in [0, 3, 8, 70] {
+            let offset = Bitmap::<SliceBuffer> {
+                buffer: long.buffer.as_slice(),
+                bits: long.bits - skip,
+                offset: skip,
+            };
+            assert_eq!(
+                offset.count_ones(),
+                offset.iter().filter(|&bit| bit).count()
+            );
+        }
+    }
 }
@@ -0,0 +1,275 @@
+//! A bitmap for random writes.
+
+use super::Bitmap;
+use crate::{
+    buffer::{BufferType, VecBuffer},
+    ByteLength, Length, ShrinkToFit, WithCapacity,
+};
+use std::borrow::Borrow;
+
+/// Returns the number of words needed to store `bits` bits.
+fn words_for_bits(bits: usize) -> usize {
+    bits / 64 + usize::from(bits % 64 != 0)
+}
+
+/// A bitmap that supports writing bits at any position.
+///
+/// [`Bitmap`] can only be extended at the end, e.g. when building validity
+/// bitmaps with [`Extend<bool>`]. This bitmap stores its bits LSB-first in
+/// `u64` words without an offset, so setting a bit at any position is a
+/// single word update. [`BitmapMut::freeze`] converts it into a [`Bitmap`].
+///
+/// ```
+/// use narrow::bitmap::BitmapMut;
+///
+/// let mut bitmap = BitmapMut::repeat(false, 100);
+/// bitmap.set(97, true);
+/// bitmap.set(3, true);
+/// let frozen = bitmap.freeze();
+/// assert_eq!(frozen.indices_of(true), [3, 97]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitmapMut {
+    /// The words storing the bits. The padding bits of the last word are
+    /// unset.
+    words: Vec<u64>,
+    /// The number of bits stored in the bitmap.
+    bits: usize,
+}
+
+impl BitmapMut {
+    /// Returns an empty bitmap.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a bitmap with `n` bits set to `bit`.
+    #[must_use]
+    pub fn repeat(bit: bool, n: usize) -> Self {
+        let mut bitmap = Self::new();
+        bitmap.resize(n, bit);
+        bitmap
+    }
+
+    /// Returns the number of bits this bitmap can store without allocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.words.capacity() * 64
+    }
+
+    /// Returns the bit at `index`, or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.bits).then(|| self.words[index / 64] & 1 << (index % 64) != 0)
+    }
+
+    /// Sets the bit at `index` to `bit`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, bit: bool) {
+        assert!(
+            index < self.bits,
+            "index (is {index}) should be < len (is {})",
+            self.bits
+        );
+        // Safety:
+        // - Bound checked
+        unsafe { self.set_unchecked(index, bit) };
+    }
+
+    /// Sets the bit at `index` to `bit`. Skips bound checking.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure index is within bounds.
+    pub unsafe fn set_unchecked(&mut self, index: usize, bit: bool) {
+        let word = self.words.get_unchecked_mut(index / 64);
+        let mask = 1 << (index % 64);
+        if bit {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// Appends `bit` to the end of this bitmap.
+    pub fn push(&mut self, bit: bool) {
+        if self.bits % 64 == 0 {
+            self.words.push(0);
+        }
+        self.bits += 1;
+        // Safety:
+        // - The bit was just added
+        unsafe { self.set_unchecked(self.bits - 1, bit) };
+    }
+
+    /// Resizes this bitmap to `len` bits, setting new bits to `bit`.
+    pub fn resize(&mut self, len: usize, bit: bool) {
+        if bit && len > self.bits && self.bits % 64 != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last |= u64::MAX << (self.bits % 64);
+            }
+        }
+        self.words
+            .resize(words_for_bits(len), if bit { u64::MAX } else { 0 });
+        self.bits = len;
+        self.unset_padding();
+    }
+
+    /// Converts this bitmap into an immutable [`Bitmap`], with the bits
+    /// stored in bytes.
+    #[must_use]
+    pub fn freeze(self) -> Bitmap {
+        Bitmap::<VecBuffer, u64> {
+            buffer: self.words,
+            bits: self.bits,
+            offset: 0,
+        }
+        .to_bytes()
+    }
+
+    /// Unsets the padding bits of the last word.
+    fn unset_padding(&mut self) {
+        if self.bits % 64 != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= u64::MAX >> (64 - self.bits % 64);
+            }
+        }
+    }
+}
+
+impl<Buffer: BufferType> From<&Bitmap<Buffer>> for BitmapMut {
+    fn from(bitmap: &Bitmap<Buffer>) -> Self {
+        let words = Bitmap::<VecBuffer, u64>::from_bytes(bitmap);
+        Self {
+            words: words.buffer,
+            bits: words.bits,
+        }
+    }
+}
+
+impl From<BitmapMut> for Bitmap {
+    fn from(bitmap: BitmapMut) -> Self {
+        bitmap.freeze()
+    }
+}
+
+impl ByteLength for BitmapMut {
+    fn byte_len(&self) -> usize {
+        self.words.len() * 8
+    }
+}
+
+impl<T: Borrow<bool>> Extend<T> for BitmapMut {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let bits = iter.into_iter();
+        self.reserve(bits.size_hint().0);
+        bits.for_each(|bit| self.push(*bit.borrow()));
+    }
+}
+
+impl<T: Borrow<bool>> FromIterator<T> for BitmapMut {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bitmap = Self::new();
+        bitmap.extend(iter);
+        bitmap
+    }
+}
+
+impl Length for BitmapMut {
+    fn len(&self) -> usize {
+        self.bits
+    }
+}
+
+impl ShrinkToFit for BitmapMut {
+    fn shrink_to_fit(&mut self) {
+        self.words.shrink_to_fit();
+    }
+}
+
+impl WithCapacity for BitmapMut {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(words_for_bits(capacity)),
+            bits: 0,
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.words
+            .reserve(words_for_bits(self.bits + additional) - self.words.len());
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.words
+            .reserve_exact(words_for_bits(self.bits + additional) - self.words.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set() {
+        let mut bitmap = BitmapMut::repeat(true, 70);
+        bitmap.set(0, false);
+        bitmap.set(69, false);
+        assert_eq!(bitmap.get(0), Some(false));
+        assert_eq!(bitmap.get(1), Some(true));
+        assert_eq!(bitmap.get(69), Some(false));
+        assert_eq!(bitmap.get(70), None);
+        let frozen = bitmap.freeze();
+        assert_eq!(frozen.len(), 70);
+        assert_eq!(frozen.count_ones(), 68);
+        assert_eq!(frozen.buffer.len(), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (is 3) should be < len (is 3)")]
+    fn set_out_of_bounds() {
+        BitmapMut::repeat(false, 3).set(3, true);
+    }
+
+    #[test]
+    fn push_resize() {
+        let mut bitmap = [true, false, true].into_iter().collect::<BitmapMut>();
+        bitmap.push(true);
+        bitmap.resize(66, true);
+        assert_eq!(bitmap.len(), 66);
+        assert_eq!(bitmap.byte_len(), 16);
+        bitmap.resize(2, false);
+        bitmap.resize(5, false);
+        assert_eq!(
+            bitmap.freeze().into_iter().collect::<Vec<_>>(),
+            [true, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn thaw() {
+        let bitmap = [false, true, true, false, true]
+            .into_iter()
+            .collect::<Bitmap>()
+            .shift_left(1);
+        let mut thawed = BitmapMut::from(&bitmap);
+        thawed.set(2, false);
+        thawed.push(true);
+        assert_eq!(
+            Bitmap::from(thawed).into_iter().collect::<Vec<_>>(),
+            [true, true, false, true, true]
+        );
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut bitmap = BitmapMut::with_capacity(100);
+        assert!(bitmap.capacity() >= 128);
+        bitmap.extend([true; 100]);
+        assert_eq!(bitmap.capacity(), 128);
+    }
+}
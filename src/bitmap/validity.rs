@@ -58,13 +58,19 @@ pub trait ValidityBitmap: BitmapRef {
     /// Returns the number of valid elements.
     #[inline]
     fn valid_count(&self) -> usize {
-        (0..self.bitmap_ref().len())
-            .filter(|&index|
-                // Safety:
-                // - The index is always in range by iterating over the range
-                //   with length upper bound.
-                unsafe { self.is_valid_unchecked(index) })
-            .count()
+        self.bitmap_ref().count_ones()
+    }
+
+    /// Returns the indices of the valid elements, in ascending order.
+    #[inline]
+    fn valid_indices(&self) -> Vec<usize> {
+        self.bitmap_ref().indices_of(true)
+    }
+
+    /// Returns the indices of the null elements, in ascending order.
+    #[inline]
+    fn null_indices(&self) -> Vec<usize> {
+        self.bitmap_ref().indices_of(false)
     }
 
     /// Returns `true` if the array contains at least one null element.
@@ -0,0 +1,30 @@
+//! Words that store the bits of a bitmap.
+
+use crate::FixedSize;
+
+/// Words that store the bits of a [`Bitmap`](super::Bitmap), LSB-first.
+///
+/// This is implemented for `u8`, the default with a byte layout that matches
+/// Arrow, and for `u64`, which is faster for operations that process many bits
+/// at once, e.g. counting set bits and combining bitmaps.
+pub trait BitmapWord: FixedSize + sealed::Sealed {
+    /// The number of bits in a word.
+    const BITS: usize;
+}
+
+impl BitmapWord for u8 {
+    const BITS: usize = 8;
+}
+
+impl BitmapWord for u64 {
+    const BITS: usize = 64;
+}
+
+/// Private module for [`sealed::Sealed`] trait.
+mod sealed {
+    /// Used to seal [`super::BitmapWord`].
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u64 {}
+}
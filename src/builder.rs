@@ -0,0 +1,513 @@
+//! Utilities for building arrays incrementally.
+
+use crate::{
+    layout::{ArrayData, ArrayDataError, ArrayLayout},
+    GrowthStrategy, Length, WithCapacity,
+};
+use std::{
+    convert::Infallible,
+    fmt::{self, Debug},
+    io::{self, Read, Write},
+    marker::PhantomData,
+    mem,
+    sync::mpsc::{SendError, Sender, SyncSender},
+    time::{Duration, Instant},
+};
+
+/// Destination of batches flushed by a [`BatchFlusher`].
+pub trait BatchOutput<A> {
+    /// The error returned when a batch can't be handed over.
+    type Error;
+
+    /// Hands over a flushed batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the batch can't be handed over.
+    fn output(&mut self, batch: A) -> Result<(), Self::Error>;
+}
+
+impl<A, F: FnMut(A)> BatchOutput<A> for F {
+    type Error = Infallible;
+
+    fn output(&mut self, batch: A) -> Result<(), Self::Error> {
+        self(batch);
+        Ok(())
+    }
+}
+
+impl<A> BatchOutput<A> for Sender<A> {
+    type Error = SendError<A>;
+
+    fn output(&mut self, batch: A) -> Result<(), Self::Error> {
+        self.send(batch)
+    }
+}
+
+impl<A> BatchOutput<A> for SyncSender<A> {
+    type Error = SendError<A>;
+
+    fn output(&mut self, batch: A) -> Result<(), Self::Error> {
+        self.send(batch)
+    }
+}
+
+/// The thresholds that trigger a flush of a [`BatchFlusher`].
+///
+/// A threshold that is `None` never triggers a flush.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FlushThresholds {
+    /// Flush when a batch contains this number of rows.
+    pub max_rows: Option<usize>,
+    /// Flush when the estimated size of a batch reaches this number of bytes.
+    pub max_bytes: Option<usize>,
+    /// Flush when the first row of a batch was added this long ago.
+    pub max_age: Option<Duration>,
+}
+
+/// Collects rows of type `T` in batches of type `A`, e.g. a
+/// [`StructArray`](crate::array::StructArray), and hands finished batches
+/// to a [`BatchOutput`] when one of the [`FlushThresholds`] is hit.
+///
+/// The size of a batch is estimated by summing the size of its rows, which
+/// defaults to [`mem::size_of`] and can be changed with
+/// [`BatchFlusher::with_row_size`]. Time-based flushes only happen when
+/// calling [`BatchFlusher::push`] or [`BatchFlusher::poll`].
+pub struct BatchFlusher<T, A, O> {
+    /// The batch being collected.
+    batch: A,
+    /// The destination of flushed batches.
+    output: O,
+    /// The thresholds that trigger a flush.
+    thresholds: FlushThresholds,
+    /// Returns the estimated size of a row in bytes.
+    row_size: fn(&T) -> usize,
+    /// The estimated size of the current batch in bytes.
+    bytes: usize,
+    /// The number of rows reserved in the current batch.
+    capacity: usize,
+    /// The strategy to reserve rows in a full batch, if any.
+    growth: Option<GrowthStrategy>,
+    /// Reserves exactly the given number of additional rows in a batch.
+    reserve_exact: fn(&mut A, usize),
+    /// The time the first row of the current batch was added.
+    opened: Option<Instant>,
+    /// The type of the rows.
+    _row: PhantomData<fn(T)>,
+}
+
+impl<T, A: Default, O> BatchFlusher<T, A, O> {
+    /// Returns a new [`BatchFlusher`] that flushes batches to `output`.
+    pub fn new(thresholds: FlushThresholds, output: O) -> Self {
+        Self {
+            batch: A::default(),
+            output,
+            thresholds,
+            row_size: |_| mem::size_of::<T>(),
+            bytes: 0,
+            capacity: 0,
+            growth: None,
+            reserve_exact: |_, _| {},
+            opened: None,
+            _row: PhantomData,
+        }
+    }
+
+    /// Sets the function used to estimate the size of a row in bytes.
+    #[must_use]
+    pub fn with_row_size(mut self, row_size: fn(&T) -> usize) -> Self {
+        self.row_size = row_size;
+        self
+    }
+}
+
+impl<T, A: Default + Extend<T> + Length, O: BatchOutput<A>> BatchFlusher<T, A, O> {
+    /// Adds a row to the current batch, flushing the batch when a threshold is
+    /// hit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the output fails to accept a flushed batch.
+    pub fn push(&mut self, row: T) -> Result<(), O::Error> {
+        if let Some(strategy) = self.growth {
+            let len = self.batch.len();
+            if len >= self.capacity {
+                let capacity = strategy.next_capacity(self.capacity, len + 1);
+                (self.reserve_exact)(&mut self.batch, capacity - len);
+                self.capacity = capacity;
+            }
+        }
+        self.bytes += (self.row_size)(&row);
+        self.batch.extend(std::iter::once(row));
+        self.opened.get_or_insert_with(Instant::now);
+        self.poll()
+    }
+
+    /// Flushes the current batch when a threshold is hit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the output fails to accept a flushed batch.
+    pub fn poll(&mut self) -> Result<(), O::Error> {
+        let FlushThresholds {
+            max_rows,
+            max_bytes,
+            max_age,
+        } = self.thresholds;
+        let rows = max_rows.is_some_and(|limit| self.batch.len() >= limit);
+        let bytes = max_bytes.is_some_and(|limit| self.bytes >= limit);
+        let age = max_age
+            .zip(self.opened)
+            .is_some_and(|(limit, opened)| opened.elapsed() >= limit);
+        if rows || bytes || age {
+            self.flush()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Flushes the current batch, if it is not empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the output fails to accept the batch.
+    pub fn flush(&mut self) -> Result<(), O::Error> {
+        if self.batch.is_empty() {
+            Ok(())
+        } else {
+            self.bytes = 0;
+            self.capacity = 0;
+            self.opened = None;
+            self.output.output(mem::take(&mut self.batch))
+        }
+    }
+
+    /// Flushes the current batch and returns the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the output fails to accept the batch.
+    pub fn finish(mut self) -> Result<O, O::Error> {
+        self.flush()?;
+        Ok(self.output)
+    }
+
+    /// Returns the number of rows in the current batch.
+    pub fn len(&self) -> usize {
+        self.batch.len()
+    }
+
+    /// Returns `true` if the current batch has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.batch.is_empty()
+    }
+
+    /// Returns the estimated size of the current batch in bytes.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the number of rows reserved in the current batch, which is at
+    /// least the number of rows in the batch.
+    pub fn capacity(&self) -> usize {
+        self.capacity.max(self.batch.len())
+    }
+}
+
+impl<T, A: WithCapacity + Length, O> BatchFlusher<T, A, O> {
+    /// Reserves capacity for `capacity` rows in the current batch.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.batch
+            .reserve(capacity.saturating_sub(self.batch.len()));
+        self.capacity = self.capacity.max(capacity);
+        self
+    }
+
+    /// Sets the [`GrowthStrategy`] used to reserve rows in a batch when it
+    /// is full.
+    ///
+    /// By default the batch grows as its buffers see fit, which doubles the
+    /// capacity of [`Vec`]-backed buffers. With a growth strategy, rows are
+    /// reserved with [`WithCapacity::reserve_exact`] instead, e.g. in fixed
+    /// chunks to bound the peak memory use of a batch.
+    #[must_use]
+    pub fn with_growth(mut self, strategy: GrowthStrategy) -> Self {
+        self.growth = Some(strategy);
+        self.reserve_exact = A::reserve_exact;
+        self
+    }
+
+    /// Returns the current batch without handing it to the output, and starts
+    /// a new batch with the same capacity.
+    ///
+    /// The capacity of the new batch is the capacity of the returned batch, so
+    /// producing batches of a steady size doesn't reallocate while rows are
+    /// added.
+    pub fn finish_and_reuse(&mut self) -> A {
+        let capacity = self.capacity.max(self.batch.len());
+        self.bytes = 0;
+        self.opened = None;
+        self.capacity = capacity;
+        mem::replace(&mut self.batch, A::with_capacity(capacity))
+    }
+}
+
+impl<T, A, O> BatchFlusher<T, A, O>
+where
+    A: Default
+        + Length
+        + ArrayLayout
+        + Into<ArrayData>
+        + TryFrom<ArrayData, Error = ArrayDataError>,
+{
+    /// Writes the current batch and its estimated size to `writer`, so
+    /// collecting can continue with [`BatchFlusher::resume`], e.g. after a
+    /// crash.
+    ///
+    /// The batch is written with [`ArrayData::write_layout`], so the buffers,
+    /// offsets and validity of partially filled batches are kept. The batch
+    /// is written from its borrowed layout, so it is not copied and keeps
+    /// its capacity. Its data type is the data type of an empty batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when writing to `writer` fails.
+    pub fn checkpoint(&self, mut writer: impl Write) -> io::Result<()> {
+        let bytes = u64::try_from(self.bytes).unwrap_or(u64::MAX);
+        writer.write_all(&bytes.to_le_bytes())?;
+        let data_type = A::default().into().data_type;
+        ArrayData::write_layout(&data_type, &self.batch, &mut writer)
+    }
+
+    /// Returns a [`BatchFlusher`] that continues collecting the batch of a
+    /// [checkpoint](BatchFlusher::checkpoint) read from `reader`.
+    ///
+    /// The age of a restored batch that is not empty starts at the time of
+    /// the call. The row size function is reset to the default, and can be
+    /// set again with [`BatchFlusher::with_row_size`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when reading from `reader` fails, or when the
+    /// checkpoint is not a valid batch of type `A`.
+    pub fn resume(
+        thresholds: FlushThresholds,
+        output: O,
+        mut reader: impl Read,
+    ) -> io::Result<Self> {
+        let mut bytes = [0; 8];
+        reader.read_exact(&mut bytes)?;
+        let data = ArrayData::read_from(&mut reader)?;
+        let batch =
+            A::try_from(data).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let mut flusher = Self::new(thresholds, output);
+        flusher.bytes = usize::try_from(u64::from_le_bytes(bytes)).unwrap_or(usize::MAX);
+        flusher.opened = (!batch.is_empty()).then(Instant::now);
+        flusher.batch = batch;
+        Ok(flusher)
+    }
+}
+
+impl<T, A: Debug, O: Debug> Debug for BatchFlusher<T, A, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchFlusher")
+            .field("batch", &self.batch)
+            .field("output", &self.output)
+            .field("thresholds", &self.thresholds)
+            .field("bytes", &self.bytes)
+            .field("capacity", &self.capacity)
+            .field("growth", &self.growth)
+            .field("opened", &self.opened)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint32Array;
+    use std::sync::mpsc;
+
+    #[test]
+    fn max_rows() {
+        let mut batches = Vec::new();
+        let thresholds = FlushThresholds {
+            max_rows: Some(2),
+            ..FlushThresholds::default()
+        };
+        let remaining = {
+            let mut flusher = BatchFlusher::<_, Uint32Array, _>::new(thresholds, |batch| {
+                batches.push(batch);
+            });
+            for value in 1..=5 {
+                flusher.push(value).expect("infallible");
+            }
+            let len = flusher.len();
+            flusher.flush().expect("infallible");
+            len
+        };
+        assert_eq!(remaining, 1);
+        assert_eq!(
+            batches
+                .into_iter()
+                .map(|batch| batch.into_iter().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn max_bytes() {
+        let (sender, receiver) = mpsc::channel();
+        let thresholds = FlushThresholds {
+            max_bytes: Some(10),
+            ..FlushThresholds::default()
+        };
+        let mut flusher = BatchFlusher::<_, Uint32Array, _>::new(thresholds, sender);
+        for value in 1..=3 {
+            flusher.push(value).expect("send");
+        }
+        assert_eq!(flusher.bytes(), 0);
+        assert_eq!(receiver.try_recv().map(|batch| batch.len()), Ok(3));
+
+        let mut flusher_row_size =
+            BatchFlusher::<u32, Uint32Array, _>::new(thresholds, |_| {}).with_row_size(|_| 5);
+        flusher_row_size.push(1).expect("infallible");
+        assert_eq!(flusher_row_size.bytes(), 5);
+        flusher_row_size.push(2).expect("infallible");
+        assert!(flusher_row_size.is_empty());
+    }
+
+    #[test]
+    fn finish_and_reuse() {
+        let mut flusher =
+            BatchFlusher::<_, Uint32Array, _>::new(FlushThresholds::default(), |_| {
+                unreachable!("no threshold is set")
+            })
+            .with_capacity(2);
+        assert_eq!(flusher.capacity(), 2);
+        for value in 1..=3 {
+            flusher.push(value).expect("infallible");
+        }
+        assert_eq!(flusher.capacity(), 3);
+        let batch = flusher.finish_and_reuse();
+        assert_eq!(batch.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(flusher.is_empty());
+        assert_eq!(flusher.bytes(), 0);
+        assert_eq!(flusher.capacity(), 3);
+    }
+
+    #[test]
+    fn with_growth() {
+        let (sender, receiver) = mpsc::channel();
+        let thresholds = FlushThresholds {
+            max_rows: Some(5),
+            ..FlushThresholds::default()
+        };
+        let mut flusher = BatchFlusher::<_, Uint32Array, _>::new(thresholds, sender)
+            .with_growth(GrowthStrategy::Chunked(2));
+        for value in 1..=3 {
+            flusher.push(value).expect("receiver alive");
+        }
+        assert_eq!(flusher.capacity(), 4);
+        flusher.push(4).expect("receiver alive");
+        flusher.push(5).expect("receiver alive");
+        assert!(flusher.is_empty());
+        assert_eq!(flusher.capacity(), 0);
+        let batch = receiver.try_recv().expect("flushed batch");
+        assert_eq!(batch.0.capacity(), 6);
+    }
+
+    #[test]
+    fn max_age() {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        let thresholds = FlushThresholds {
+            max_age: Some(Duration::ZERO),
+            ..FlushThresholds::default()
+        };
+        let mut flusher = BatchFlusher::<_, Uint32Array, _>::new(thresholds, sender);
+        flusher.push(1).expect("send");
+        assert!(flusher.is_empty());
+        assert_eq!(receiver.try_recv().map(|batch| batch.len()), Ok(1));
+
+        drop(receiver);
+        assert!(flusher.push(2).is_err());
+    }
+
+    #[test]
+    fn checkpoint() {
+        let thresholds = FlushThresholds {
+            max_rows: Some(3),
+            ..FlushThresholds::default()
+        };
+        let mut flusher = BatchFlusher::<_, Uint32Array<true>, _>::new(thresholds, |_| {})
+            .with_row_size(|_| 4)
+            .with_capacity(8);
+        flusher.push(Some(1)).expect("infallible");
+        flusher.push(None).expect("infallible");
+        let capacity = flusher.batch.0.data.capacity();
+        let mut checkpoint = Vec::new();
+        flusher.checkpoint(&mut checkpoint).expect("write to vec");
+        assert_eq!(flusher.batch.0.data.capacity(), capacity);
+        drop(flusher);
+
+        let mut batches = Vec::new();
+        let mut resumed = BatchFlusher::<_, Uint32Array<true>, _>::resume(
+            thresholds,
+            |batch: Uint32Array<true>| {
+                batches.push(batch.into_iter().collect::<Vec<_>>());
+            },
+            checkpoint.as_slice(),
+        )
+        .expect("valid checkpoint");
+        assert_eq!(resumed.len(), 2);
+        assert_eq!(resumed.bytes(), 8);
+        resumed.push(Some(3)).expect("infallible");
+        drop(resumed);
+        assert_eq!(batches, [vec![Some(1), None, Some(3)]]);
+
+        assert!(BatchFlusher::<u32, Uint32Array, _>::resume(
+            thresholds,
+            |_: Uint32Array| {},
+            checkpoint.as_slice()
+        )
+        .is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "derive", feature = "structs"))]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn checkpoint_struct() {
+        use crate::{array::StructArray, ArrayType};
+
+        #[derive(ArrayType, Clone, Copy, Debug, Default, PartialEq)]
+        struct Row {
+            id: u32,
+            score: Option<u16>,
+        }
+
+        let row = |id, score| Row { id, score };
+        let mut flusher =
+            BatchFlusher::<_, StructArray<Row>, _>::new(FlushThresholds::default(), |_| {});
+        flusher.push(row(1, Some(10))).expect("infallible");
+        flusher.push(row(2, None)).expect("infallible");
+        let mut checkpoint = Vec::new();
+        flusher.checkpoint(&mut checkpoint).expect("write to vec");
+
+        let mut batches = Vec::new();
+        let mut resumed = BatchFlusher::<_, StructArray<Row>, _>::resume(
+            FlushThresholds::default(),
+            |batch: StructArray<Row>| batches.push(batch.into_iter().collect::<Vec<_>>()),
+            checkpoint.as_slice(),
+        )
+        .expect("valid checkpoint");
+        resumed.push(row(3, Some(30))).expect("infallible");
+        resumed.flush().expect("infallible");
+        drop(resumed);
+        assert_eq!(
+            batches,
+            [vec![row(1, Some(10)), row(2, None), row(3, Some(30))]]
+        );
+    }
+}
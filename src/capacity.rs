@@ -0,0 +1,188 @@
+//! Pre-allocation of capacity.
+
+use std::collections::VecDeque;
+
+/// Types that can allocate capacity for a number of items up front.
+///
+/// Arrays implement this by allocating capacity in all their (child)
+/// buffers, which avoids reallocations when the number of items is known.
+pub trait WithCapacity {
+    /// Returns a new empty instance with capacity for at least `capacity`
+    /// items.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Reserves capacity for at least `additional` more items.
+    fn reserve(&mut self, additional: usize);
+
+    /// Reserves capacity for `additional` more items, without deliberately
+    /// over-allocating to amortize future growth.
+    ///
+    /// Defaults to [`WithCapacity::reserve`] for types that can't control
+    /// their over-allocation.
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+/// Types that can release capacity they don't use.
+///
+/// Arrays implement this by shrinking all their (child) buffers, including
+/// offsets and validity bitmaps. This is useful for arrays that are kept
+/// around after they are built, which may hold on to twice the capacity
+/// they need.
+pub trait ShrinkToFit {
+    /// Shrinks the capacity as much as possible.
+    fn shrink_to_fit(&mut self);
+}
+
+impl<T> ShrinkToFit for Vec<T> {
+    fn shrink_to_fit(&mut self) {
+        Vec::shrink_to_fit(self);
+    }
+}
+
+impl<T> ShrinkToFit for VecDeque<T> {
+    fn shrink_to_fit(&mut self) {
+        VecDeque::shrink_to_fit(self);
+    }
+}
+
+/// How the capacity of a collection grows when it is full.
+///
+/// Buffers backed by a [`Vec`] double their capacity when they are full,
+/// which can temporarily require three times the memory of the items while
+/// they are copied. Growing by a smaller factor or by fixed chunks bounds the
+/// peak memory use at the cost of more reallocations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GrowthStrategy {
+    /// Double the capacity.
+    #[default]
+    Double,
+    /// Grow the capacity by this percentage, e.g. `50` for a growth factor
+    /// of 1.5.
+    Percent(usize),
+    /// Grow the capacity by this number of items.
+    Chunked(usize),
+}
+
+impl GrowthStrategy {
+    /// Returns the capacity to grow to from `capacity` to make room for at
+    /// least `required` items.
+    ///
+    /// The capacity grows by at least one item.
+    #[must_use]
+    pub fn next_capacity(self, capacity: usize, required: usize) -> usize {
+        let grown = match self {
+            Self::Double => capacity.saturating_mul(2),
+            Self::Percent(percent) => {
+                capacity.saturating_add(capacity.saturating_mul(percent) / 100)
+            }
+            Self::Chunked(chunk) => capacity.saturating_add(chunk),
+        };
+        grown.max(capacity.saturating_add(1)).max(required)
+    }
+}
+
+impl<T> WithCapacity for Vec<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        Vec::reserve_exact(self, additional);
+    }
+}
+
+impl<T> WithCapacity for VecDeque<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        VecDeque::with_capacity(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        VecDeque::reserve(self, additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        VecDeque::reserve_exact(self, additional);
+    }
+}
+
+/// Extends `left` and `right` with the items of the pairs in `iter`.
+///
+/// Extending collections one item at a time hides the number of items, so
+/// they can't reserve capacity up front. Instead, `left` is extended once
+/// with an iterator that forwards the size hint of `iter`, and `right` is
+/// extended once with the buffered items. Unlike [`WithCapacity::reserve`],
+/// this works for any collection that implements [`Extend`].
+pub(crate) fn extend_unzip<A, B>(
+    left: &mut impl Extend<A>,
+    right: &mut impl Extend<B>,
+    iter: impl IntoIterator<Item = (A, B)>,
+) {
+    let items = iter.into_iter();
+    let mut buffer = Vec::with_capacity(items.size_hint().0);
+    left.extend(items.map(|(first, second)| {
+        buffer.push(second);
+        first
+    }));
+    right.extend(buffer);
+}
+
+/// Extension trait for collecting iterators into pre-allocated collections.
+///
+/// This is useful when the size hint of an iterator is not accurate, for
+/// example after filtering, but the number of items is known.
+pub trait CollectWithCapacity: Iterator + Sized {
+    /// Collects the items of this iterator into a new collection with
+    /// capacity for at least `capacity` items.
+    fn collect_with_capacity<C: WithCapacity + Extend<Self::Item>>(self, capacity: usize) -> C {
+        let mut collection = C::with_capacity(capacity);
+        collection.extend(self);
+        collection
+    }
+}
+
+impl<I: Iterator> CollectWithCapacity for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_with_capacity() {
+        let vec: Vec<u8> = [1, 2, 3]
+            .into_iter()
+            .filter(|x| x % 2 == 1)
+            .collect_with_capacity(10);
+        assert_eq!(vec, [1, 3]);
+        assert!(vec.capacity() >= 10);
+    }
+
+    #[test]
+    fn extend_unzip() {
+        let mut left = vec![0];
+        let mut right = Vec::<char>::new();
+        super::extend_unzip(&mut left, &mut right, [(1, 'a'), (2, 'b')]);
+        assert_eq!(left, [0, 1, 2]);
+        assert_eq!(right, ['a', 'b']);
+        assert!(right.capacity() >= 2);
+    }
+
+    #[test]
+    fn growth_strategy() {
+        assert_eq!(GrowthStrategy::Double.next_capacity(0, 0), 1);
+        assert_eq!(GrowthStrategy::Double.next_capacity(8, 9), 16);
+        assert_eq!(GrowthStrategy::Percent(50).next_capacity(8, 9), 12);
+        assert_eq!(GrowthStrategy::Percent(50).next_capacity(1, 2), 2);
+        assert_eq!(GrowthStrategy::Chunked(4).next_capacity(8, 9), 12);
+        assert_eq!(GrowthStrategy::Chunked(4).next_capacity(8, 20), 20);
+
+        let mut vec = Vec::<u8>::with_capacity(8);
+        vec.reserve_exact(12);
+        assert_eq!(vec.capacity(), 12);
+    }
+}
@@ -0,0 +1,378 @@
+//! Bounded channels of batches.
+//!
+//! A channel created with [`bounded`] limits the number of rows or bytes of
+//! the batches that were sent but not yet received, instead of the number of
+//! batches. This bounds the memory used between pipeline stages that
+//! exchange batches of different sizes, e.g. [`StructArray`] batches.
+//!
+//! [`StructArray`]: crate::array::StructArray
+
+use crate::{builder::BatchOutput, ByteLength, Length};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+    sync::{
+        mpsc::{RecvError, SendError, TryRecvError, TrySendError},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+};
+
+/// The capacity of a [`bounded`] channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Capacity {
+    /// The maximum number of rows in the channel.
+    Rows(usize),
+    /// The maximum number of bytes, as returned by [`ByteLength`], in the
+    /// channel.
+    Bytes(usize),
+}
+
+impl Capacity {
+    /// Returns the size of `batch` in the unit of this capacity.
+    fn size_of<A: Length + ByteLength>(self, batch: &A) -> usize {
+        match self {
+            Self::Rows(_) => batch.len(),
+            Self::Bytes(_) => batch.byte_len(),
+        }
+    }
+
+    /// Returns the limit of this capacity.
+    const fn limit(self) -> usize {
+        match self {
+            Self::Rows(limit) | Self::Bytes(limit) => limit,
+        }
+    }
+}
+
+/// The state of a channel, shared by the senders and the receiver.
+struct State<A> {
+    /// The batches in the channel, with their size.
+    queue: VecDeque<(A, usize)>,
+    /// The sum of the sizes of the batches in the channel.
+    used: usize,
+    /// The number of senders.
+    senders: usize,
+    /// Whether the receiver is alive.
+    receiver: bool,
+}
+
+/// The shared part of a channel.
+struct Shared<A> {
+    /// The capacity of the channel.
+    capacity: Capacity,
+    /// The state of the channel.
+    state: Mutex<State<A>>,
+    /// Notified when batches are received or the receiver is dropped.
+    not_full: Condvar,
+    /// Notified when batches are sent or the last sender is dropped.
+    not_empty: Condvar,
+}
+
+impl<A> Shared<A> {
+    /// Locks the state of the channel.
+    ///
+    /// The state is consistent after every operation, so a panic of another
+    /// thread holding the lock doesn't affect it.
+    fn lock(&self) -> MutexGuard<'_, State<A>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns `true` if a batch of `size` fits in the channel.
+    ///
+    /// A batch always fits in an empty channel, so batches larger than the
+    /// capacity can still be sent.
+    fn fits(&self, state: &State<A>, size: usize) -> bool {
+        state.queue.is_empty() || state.used.saturating_add(size) <= self.capacity.limit()
+    }
+
+    /// Adds a batch to the channel.
+    fn push(&self, state: &mut State<A>, batch: A, size: usize) {
+        state.used += size;
+        state.queue.push_back((batch, size));
+        self.not_empty.notify_one();
+    }
+
+    /// Removes the oldest batch from the channel.
+    fn pop(&self, state: &mut State<A>) -> Option<A> {
+        state.queue.pop_front().map(|(batch, size)| {
+            state.used -= size;
+            self.not_full.notify_all();
+            batch
+        })
+    }
+}
+
+/// Returns a sender and receiver of batches of type `A`, where the senders
+/// block while the batches in the channel exceed the `capacity`.
+///
+/// A batch that is larger than the capacity is sent when the channel is
+/// empty, so it doesn't block forever.
+///
+/// ```
+/// use narrow::{
+///     array::Uint32Array,
+///     channel::{bounded, Capacity},
+/// };
+/// use std::thread;
+///
+/// let (sender, receiver) = bounded::<Uint32Array>(Capacity::Rows(4));
+/// let producer = thread::spawn(move || {
+///     for start in (0..12).step_by(3) {
+///         sender.send((start..start + 3).collect()).expect("receiver alive");
+///     }
+/// });
+/// let rows = receiver.into_iter().flatten().collect::<Vec<_>>();
+/// producer.join().expect("producer");
+/// assert_eq!(rows, (0..12).collect::<Vec<_>>());
+/// ```
+#[must_use]
+pub fn bounded<A: Length + ByteLength>(capacity: Capacity) -> (BatchSender<A>, BatchReceiver<A>) {
+    let shared = Arc::new(Shared {
+        capacity,
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            used: 0,
+            senders: 1,
+            receiver: true,
+        }),
+        not_full: Condvar::new(),
+        not_empty: Condvar::new(),
+    });
+    (
+        BatchSender {
+            shared: Arc::clone(&shared),
+        },
+        BatchReceiver { shared },
+    )
+}
+
+/// The sending half of a [`bounded`] channel.
+///
+/// Senders can be cloned to send batches from multiple threads.
+pub struct BatchSender<A> {
+    /// The shared part of the channel.
+    shared: Arc<Shared<A>>,
+}
+
+impl<A: Length + ByteLength> BatchSender<A> {
+    /// Sends a batch, blocking while it doesn't fit in the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns the batch when the receiver is dropped.
+    pub fn send(&self, batch: A) -> Result<(), SendError<A>> {
+        let size = self.shared.capacity.size_of(&batch);
+        let mut state = self.shared.lock();
+        while state.receiver && !self.shared.fits(&state, size) {
+            state = self
+                .shared
+                .not_full
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        if state.receiver {
+            self.shared.push(&mut state, batch, size);
+            Ok(())
+        } else {
+            Err(SendError(batch))
+        }
+    }
+
+    /// Sends a batch if it fits in the channel, without blocking.
+    ///
+    /// # Errors
+    ///
+    /// Returns the batch when it doesn't fit in the channel, or when the
+    /// receiver is dropped.
+    pub fn try_send(&self, batch: A) -> Result<(), TrySendError<A>> {
+        let size = self.shared.capacity.size_of(&batch);
+        let mut state = self.shared.lock();
+        if !state.receiver {
+            Err(TrySendError::Disconnected(batch))
+        } else if self.shared.fits(&state, size) {
+            self.shared.push(&mut state, batch, size);
+            Ok(())
+        } else {
+            Err(TrySendError::Full(batch))
+        }
+    }
+}
+
+impl<A> Clone for BatchSender<A> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<A> Drop for BatchSender<A> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<A> Debug for BatchSender<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchSender")
+            .field("capacity", &self.shared.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Length + ByteLength> BatchOutput<A> for BatchSender<A> {
+    type Error = SendError<A>;
+
+    fn output(&mut self, batch: A) -> Result<(), Self::Error> {
+        self.send(batch)
+    }
+}
+
+/// The receiving half of a [`bounded`] channel.
+///
+/// Iterating over the receiver blocks until a batch is available, and ends
+/// when all senders are dropped and the channel is empty.
+pub struct BatchReceiver<A> {
+    /// The shared part of the channel.
+    shared: Arc<Shared<A>>,
+}
+
+impl<A> BatchReceiver<A> {
+    /// Receives a batch, blocking until a batch is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the channel is empty and all senders are
+    /// dropped.
+    pub fn recv(&self) -> Result<A, RecvError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(batch) = self.shared.pop(&mut state) {
+                return Ok(batch);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = self
+                .shared
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Receives a batch if one is available, without blocking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the channel is empty.
+    pub fn try_recv(&self) -> Result<A, TryRecvError> {
+        let mut state = self.shared.lock();
+        match self.shared.pop(&mut state) {
+            Some(batch) => Ok(batch),
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Returns the sum of the sizes of the batches in the channel, in the
+    /// unit of its [`Capacity`].
+    #[must_use]
+    pub fn used(&self) -> usize {
+        self.shared.lock().used
+    }
+}
+
+impl<A> Iterator for BatchReceiver<A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv().ok()
+    }
+}
+
+impl<A> Drop for BatchReceiver<A> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver = false;
+        self.shared.not_full.notify_all();
+    }
+}
+
+impl<A> Debug for BatchReceiver<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchReceiver")
+            .field("capacity", &self.shared.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint32Array;
+    use std::thread;
+
+    /// Returns a batch with `rows` rows.
+    fn batch(rows: u32) -> Uint32Array {
+        (0..rows).collect()
+    }
+
+    #[test]
+    fn capacity() {
+        let (sender, receiver) = bounded::<Uint32Array>(Capacity::Bytes(16));
+        sender.try_send(batch(3)).expect("fits");
+        assert_eq!(receiver.used(), 12);
+        assert!(matches!(
+            sender.try_send(batch(2)),
+            Err(TrySendError::Full(_))
+        ));
+        sender.try_send(batch(1)).expect("fits");
+        assert_eq!(receiver.try_recv().map(|array| array.len()), Ok(3));
+        assert_eq!(receiver.used(), 4);
+
+        // A batch larger than the capacity is accepted by an empty channel.
+        receiver.try_recv().expect("a batch");
+        sender.try_send(batch(10)).expect("empty channel");
+        drop(sender);
+        assert_eq!(receiver.recv().map(|array| array.len()), Ok(10));
+        assert!(receiver.recv().is_err());
+    }
+
+    #[test]
+    fn backpressure() {
+        let (sender, receiver) = bounded::<Uint32Array>(Capacity::Rows(2));
+        let producers = (0..2)
+            .map(|_| {
+                let producer = sender.clone();
+                thread::spawn(move || {
+                    for _ in 0..50 {
+                        producer.send(batch(2)).expect("receiver alive");
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(sender);
+        let mut rows = 0;
+        for array in receiver {
+            rows += array.len();
+        }
+        for producer in producers {
+            producer.join().expect("producer");
+        }
+        assert_eq!(rows, 200);
+    }
+
+    #[test]
+    fn disconnected() {
+        let (sender, receiver) = bounded::<Uint32Array>(Capacity::Rows(1));
+        sender.send(batch(1)).expect("receiver alive");
+        let blocked = thread::spawn(move || sender.send(batch(1)).is_err());
+        drop(receiver);
+        assert!(blocked.join().expect("sender"));
+    }
+}
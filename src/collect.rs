@@ -0,0 +1,62 @@
+//! Fallible collection of iterators.
+
+/// Extension trait for collecting iterators of results.
+///
+/// Collecting stops at the first error, and the items are collected directly
+/// into the target collection, without collecting into a `Vec` first. This
+/// is useful when constructing arrays from parsed rows:
+///
+/// ```
+/// use narrow::{array::Uint16Array, TryCollect};
+///
+/// let array: Uint16Array = ["1", "2", "3"]
+///     .into_iter()
+///     .map(str::parse::<u16>)
+///     .try_collect()?;
+/// assert_eq!(array.0, [1, 2, 3]);
+///
+/// let error = ["1", "x", "3"]
+///     .into_iter()
+///     .map(str::parse::<u16>)
+///     .try_collect::<Uint16Array>();
+/// assert!(error.is_err());
+/// # Ok::<(), std::num::ParseIntError>(())
+/// ```
+pub trait TryCollect<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects the values of this iterator into a new collection, or
+    /// returns the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error yielded by this iterator.
+    fn try_collect<C: FromIterator<T>>(self) -> Result<C, E> {
+        self.collect()
+    }
+}
+
+impl<T, E, I: Iterator<Item = Result<T, E>>> TryCollect<T, E> for I {}
+
+#[cfg(test)]
+#[cfg(feature = "strings")]
+mod tests {
+    use super::*;
+    use crate::array::StringArray;
+
+    #[test]
+    fn short_circuit() {
+        let mut consumed = 0;
+        let result = ["a", "b", "", "c"]
+            .into_iter()
+            .inspect(|_| consumed += 1)
+            .map(|value| {
+                if value.is_empty() {
+                    Err("empty")
+                } else {
+                    Ok(value)
+                }
+            })
+            .try_collect::<StringArray>();
+        assert_eq!(result.err(), Some("empty"));
+        assert_eq!(consumed, 3);
+    }
+}
@@ -0,0 +1,92 @@
+//! As-of joins of time series.
+
+use crate::{
+    array::FixedSizePrimitiveArray,
+    buffer::{Buffer, BufferType},
+};
+
+/// Returns, for every timestamp in `left_times`, the index of the latest
+/// timestamp in `right_times` that is not after it, or null if there is no
+/// such timestamp.
+///
+/// When `tolerance` is set, matches that are more than `tolerance` before the
+/// left timestamp are null as well. The returned indices can be used to take
+/// the matching rows of the right side. Of equal right timestamps, the last
+/// one is matched.
+///
+/// The right timestamps are searched with a binary search, so they must be
+/// sorted in ascending order. The left timestamps don't have to be sorted.
+///
+/// # Panics
+///
+/// Panics if `tolerance` is negative.
+///
+/// ```
+/// use narrow::{array::Int64Array, compute::asof_join};
+///
+/// let trades = [3, 10, 21].into_iter().collect::<Int64Array>();
+/// let quotes = [0, 2, 10, 15].into_iter().collect::<Int64Array>();
+/// assert_eq!(
+///     asof_join(&trades, &quotes, Some(5)),
+///     [Some(1), Some(2), None]
+/// );
+/// ```
+pub fn asof_join<LeftBuffer: BufferType, RightBuffer: BufferType>(
+    left_times: &FixedSizePrimitiveArray<i64, false, LeftBuffer>,
+    right_times: &FixedSizePrimitiveArray<i64, false, RightBuffer>,
+    tolerance: Option<i64>,
+) -> Vec<Option<usize>> {
+    if let Some(max_distance) = tolerance {
+        assert!(
+            max_distance >= 0,
+            "tolerance (is {max_distance}) should not be negative"
+        );
+    }
+    let right = right_times.0.as_slice();
+    debug_assert!(
+        right.windows(2).all(|pair| pair[0] <= pair[1]),
+        "right timestamps should be sorted"
+    );
+    left_times
+        .0
+        .as_slice()
+        .iter()
+        .map(|&time| {
+            right
+                .partition_point(|&right_time| right_time <= time)
+                .checked_sub(1)
+                .filter(|&index| {
+                    tolerance.map_or(true, |max_distance| {
+                        time.abs_diff(right[index]) <= max_distance.unsigned_abs()
+                    })
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int64Array;
+
+    #[test]
+    fn asof() {
+        let left = [5, -1, 7, 30].into_iter().collect::<Int64Array>();
+        let right = [0, 5, 5, 20].into_iter().collect::<Int64Array>();
+        assert_eq!(
+            asof_join(&left, &right, None),
+            [Some(2), None, Some(2), Some(3)]
+        );
+        assert_eq!(
+            asof_join(&left, &right, Some(0)),
+            [Some(2), None, None, None]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "tolerance (is -1) should not be negative")]
+    fn negative_tolerance() {
+        let times = [1].into_iter().collect::<Int64Array>();
+        let _ = asof_join(&times, &times, Some(-1));
+    }
+}
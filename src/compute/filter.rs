@@ -0,0 +1,184 @@
+//! Selection of the values of fixed-size primitive arrays with a mask.
+
+use super::Elementwise;
+use crate::{
+    bitmap::Bitmap,
+    buffer::{BufferType, VecBuffer},
+    Length,
+};
+
+/// How [`filter_with`] copies the selected values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FilterStrategy {
+    /// [`FilterStrategy::Slices`] when the selected values form long runs,
+    /// [`FilterStrategy::Bits`] otherwise.
+    #[default]
+    Auto,
+    /// The runs of selected values, returned by [`Bitmap::valid_slices`], are
+    /// copied as slices.
+    Slices,
+    /// The mask is inspected bit by bit and selected values are copied one at
+    /// a time.
+    Bits,
+}
+
+impl FilterStrategy {
+    /// The minimum average length of the runs of selected values for which
+    /// [`FilterStrategy::Auto`] copies slices.
+    pub const MIN_RUN_LEN: usize = 8;
+
+    /// Returns the strategy that is used to filter with `mask`.
+    ///
+    /// This resolves [`FilterStrategy::Auto`] to [`FilterStrategy::Slices`] or
+    /// [`FilterStrategy::Bits`], depending on the density of the runs of set
+    /// bits in `mask`. Other strategies are returned unchanged.
+    #[must_use]
+    pub fn resolve<Buffer: BufferType>(self, mask: &Bitmap<Buffer>) -> Self {
+        match self {
+            Self::Auto => {
+                let runs = mask.valid_slices().count();
+                if mask.count_ones() >= runs * Self::MIN_RUN_LEN {
+                    Self::Slices
+                } else {
+                    Self::Bits
+                }
+            }
+            strategy => strategy,
+        }
+    }
+}
+
+/// Returns an array with the values of `array` for which the bit in `mask`
+/// is set.
+///
+/// The validity of the selected values is kept.
+///
+/// # Panics
+///
+/// Panics if the length of the mask differs from the length of the array.
+///
+/// ```
+/// use narrow::{array::Uint8Array, bitmap::Bitmap, compute::filter};
+///
+/// let array = [Some(1), None, Some(3), Some(4)]
+///     .into_iter()
+///     .collect::<Uint8Array<true>>();
+/// let mask = [true, true, false, true].into_iter().collect::<Bitmap>();
+/// assert_eq!(
+///     filter(&array, &mask).into_iter().collect::<Vec<_>>(),
+///     [Some(1), None, Some(4)]
+/// );
+/// ```
+pub fn filter<A: Elementwise, Buffer: BufferType>(
+    array: &A,
+    mask: &Bitmap<Buffer>,
+) -> A::Output<A::Item> {
+    filter_with(array, mask, FilterStrategy::Auto)
+}
+
+/// Returns an array with the values of `array` for which the bit in `mask`
+/// is set, copied with the given strategy.
+///
+/// The output does not depend on the strategy.
+///
+/// # Panics
+///
+/// Panics if the length of the mask differs from the length of the array.
+pub fn filter_with<A: Elementwise, Buffer: BufferType>(
+    array: &A,
+    mask: &Bitmap<Buffer>,
+    strategy: FilterStrategy,
+) -> A::Output<A::Item> {
+    assert_eq!(
+        mask.len(),
+        array.len(),
+        "len of mask (is {}) should be equal to len of array (is {})",
+        mask.len(),
+        array.len()
+    );
+    let input = array.values();
+    let input_validity = array.validity();
+    let (values, validity) = if strategy.resolve(mask) == FilterStrategy::Slices {
+        let mut output = Vec::with_capacity(mask.count_ones());
+        for range in mask.valid_slices() {
+            output.extend_from_slice(&input[range]);
+        }
+        let output_validity = input_validity.map(|bits| {
+            mask.valid_slices()
+                .flatten()
+                .filter_map(|index| bits.get(index))
+                .collect::<Bitmap>()
+        });
+        (output, output_validity)
+    } else {
+        let output = input
+            .iter()
+            .zip(mask)
+            .filter_map(|(&value, selected)| selected.then_some(value))
+            .collect();
+        let output_validity = input_validity.map(|bits| {
+            bits.iter()
+                .zip(mask)
+                .filter_map(|(valid, selected)| selected.then_some(valid))
+                .collect::<Bitmap>()
+        });
+        (output, output_validity)
+    };
+    A::output(
+        values,
+        validity.as_ref().map(Bitmap::<VecBuffer, u64>::from_bytes),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, Uint16Array};
+
+    #[test]
+    fn strategies() {
+        let array = (0..100).collect::<Uint16Array>();
+        let runs = (0..100).map(|index| index < 40).collect::<Bitmap>();
+        let sparse = (0..100).map(|index| index % 3 == 0).collect::<Bitmap>();
+        assert_eq!(FilterStrategy::Auto.resolve(&runs), FilterStrategy::Slices);
+        assert_eq!(FilterStrategy::Auto.resolve(&sparse), FilterStrategy::Bits);
+        assert_eq!(FilterStrategy::Bits.resolve(&runs), FilterStrategy::Bits);
+        for mask in [runs, sparse] {
+            let slices = filter_with(&array, &mask, FilterStrategy::Slices);
+            let bits = filter_with(&array, &mask, FilterStrategy::Bits);
+            assert_eq!(slices.0, bits.0);
+            assert_eq!(
+                slices.0,
+                mask.indices_of(true)
+                    .iter()
+                    .map(|&index| u16::try_from(index).expect("fits"))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn nullable() {
+        let array = [Some(1), None, Some(3), None, Some(5)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let mask = [false, true, true, true, false]
+            .into_iter()
+            .collect::<Bitmap>();
+        for strategy in [FilterStrategy::Slices, FilterStrategy::Bits] {
+            assert_eq!(
+                filter_with(&array, &mask, strategy)
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                [None, Some(3), None]
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "len of mask (is 1) should be equal to len of array (is 2)")]
+    fn len_mismatch() {
+        let array = [1, 2].into_iter().collect::<Int32Array>();
+        let _ = filter(&array, &[true].into_iter().collect::<Bitmap>());
+    }
+}
@@ -0,0 +1,133 @@
+//! Boolean kernels with three-valued logic.
+//!
+//! These kernels follow the Kleene logic used by SQL, where null means
+//! "unknown": `false AND null` is `false` and `true OR null` is `true`,
+//! because the result does not depend on the unknown value.
+
+use crate::{array::BooleanArray, buffer::BufferType, Length};
+
+/// Returns the logical AND of two nullable boolean arrays using three-valued
+/// logic.
+///
+/// | `left`  | `right` | result  |
+/// |---------|---------|---------|
+/// | `true`  | `true`  | `true`  |
+/// | `true`  | `false` | `false` |
+/// | `true`  | null    | null    |
+/// | `false` | any     | `false` |
+/// | null    | `true`  | null    |
+/// | null    | `false` | `false` |
+/// | null    | null    | null    |
+///
+/// # Panics
+///
+/// Panics if the arrays differ in length.
+pub fn and_kleene<LeftBuffer: BufferType, RightBuffer: BufferType>(
+    left: &BooleanArray<true, LeftBuffer>,
+    right: &BooleanArray<true, RightBuffer>,
+) -> BooleanArray<true> {
+    kleene(left, right, |lhs, rhs| match (lhs, rhs) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    })
+}
+
+/// Returns the logical OR of two nullable boolean arrays using three-valued
+/// logic.
+///
+/// | `left`  | `right` | result  |
+/// |---------|---------|---------|
+/// | `true`  | any     | `true`  |
+/// | `false` | `true`  | `true`  |
+/// | `false` | `false` | `false` |
+/// | `false` | null    | null    |
+/// | null    | `true`  | `true`  |
+/// | null    | `false` | null    |
+/// | null    | null    | null    |
+///
+/// # Panics
+///
+/// Panics if the arrays differ in length.
+pub fn or_kleene<LeftBuffer: BufferType, RightBuffer: BufferType>(
+    left: &BooleanArray<true, LeftBuffer>,
+    right: &BooleanArray<true, RightBuffer>,
+) -> BooleanArray<true> {
+    kleene(left, right, |lhs, rhs| match (lhs, rhs) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    })
+}
+
+/// Applies `op` to the values of `left` and `right` pairwise.
+fn kleene<LeftBuffer: BufferType, RightBuffer: BufferType>(
+    left: &BooleanArray<true, LeftBuffer>,
+    right: &BooleanArray<true, RightBuffer>,
+    op: fn(Option<bool>, Option<bool>) -> Option<bool>,
+) -> BooleanArray<true> {
+    assert_eq!(
+        left.len(),
+        right.len(),
+        "left len (is {}) should be equal to right len (is {})",
+        left.len(),
+        right.len()
+    );
+    left.into_iter()
+        .zip(right)
+        .map(|(lhs, rhs)| op(lhs, rhs))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the left and right operands for all nine combinations of
+    /// `true`, `false` and null.
+    fn operands() -> (BooleanArray<true>, BooleanArray<true>) {
+        let values = [Some(true), Some(false), None];
+        values
+            .iter()
+            .flat_map(|&lhs| values.iter().map(move |&rhs| (lhs, rhs)))
+            .unzip()
+    }
+
+    #[test]
+    fn and() {
+        let (left, right) = operands();
+        assert_eq!(
+            and_kleene(&left, &right).into_iter().collect::<Vec<_>>(),
+            [
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+                Some(false),
+                Some(false),
+                None,
+                Some(false),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn or() {
+        let (left, right) = operands();
+        assert_eq!(
+            or_kleene(&left, &right).into_iter().collect::<Vec<_>>(),
+            [
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                None,
+                None
+            ]
+        );
+    }
+}
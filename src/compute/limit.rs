@@ -0,0 +1,167 @@
+//! Selection of subsets of the rows of arrays, for debugging and
+//! downsampling.
+//!
+//! These functions work with any array that can be iterated and collected,
+//! including [`StructArray`](crate::array::StructArray) batches.
+
+use crate::{bitmap::Bitmap, Length};
+use std::ops::Range;
+
+/// Returns the first `n` values of `array`, or all values if it has fewer
+/// than `n` values.
+///
+/// ```
+/// use narrow::{array::Uint8Array, compute::head};
+///
+/// let array = [1, 2, 3, 4].into_iter().collect::<Uint8Array>();
+/// assert_eq!(head(array, 2).into_iter().collect::<Vec<_>>(), [1, 2]);
+/// ```
+pub fn head<A: IntoIterator + FromIterator<A::Item>>(array: A, n: usize) -> A {
+    array.into_iter().take(n).collect()
+}
+
+/// Returns the last `n` values of `array`, or all values if it has fewer
+/// than `n` values.
+pub fn tail<A: IntoIterator + FromIterator<A::Item> + Length>(array: A, n: usize) -> A {
+    let skip = array.len().saturating_sub(n);
+    array.into_iter().skip(skip).collect()
+}
+
+/// Returns the values of `array` in `range`.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds.
+pub fn slice_rows<A: IntoIterator + FromIterator<A::Item> + Length>(
+    array: A,
+    range: Range<usize>,
+) -> A {
+    let len = array.len();
+    assert!(
+        range.start <= range.end && range.end <= len,
+        "range (is {range:?}) should be within len (is {len})"
+    );
+    array
+        .into_iter()
+        .skip(range.start)
+        .take(range.len())
+        .collect()
+}
+
+/// Returns a random sample of the values of `array`, where every value is
+/// selected with probability `fraction`.
+///
+/// The values keep their order. The sample is the same for the same seed.
+///
+/// # Panics
+///
+/// Panics if the fraction is not between 0 and 1.
+///
+/// ```
+/// use narrow::{array::Uint32Array, compute::sample, Length};
+///
+/// let array = (0..1000).collect::<Uint32Array>();
+/// let sampled = sample(array, 0.1, 42);
+/// assert!(sampled.len() < 200);
+/// ```
+pub fn sample<A: IntoIterator + FromIterator<A::Item> + Length>(
+    array: A,
+    fraction: f64,
+    seed: u64,
+) -> A {
+    let selection = sample_bitmap(array.len(), fraction, seed);
+    array
+        .into_iter()
+        .zip(&selection)
+        .filter_map(|(value, selected)| selected.then_some(value))
+        .collect()
+}
+
+/// Returns a bitmap with `len` bits, where every bit is set with probability
+/// `fraction`.
+///
+/// The bits are generated with the `SplitMix64` generator, seeded with
+/// `seed`.
+///
+/// # Panics
+///
+/// Panics if the fraction is not between 0 and 1.
+#[must_use]
+pub fn sample_bitmap(len: usize, fraction: f64, seed: u64) -> Bitmap {
+    assert!(
+        (0.0..=1.0).contains(&fraction),
+        "fraction (is {fraction}) should be between 0 and 1"
+    );
+    // Compare the upper 53 bits of every random value with the fraction
+    // scaled to the same range.
+    let threshold = fraction * 9_007_199_254_740_992.0;
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut value = state;
+            value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            value ^= value >> 31;
+            // The shifted value has at most 53 bits, which fit in the mantissa.
+            #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+            let scaled = (value >> 11) as f64;
+            scaled < threshold
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint8Array;
+
+    #[test]
+    fn head_tail() {
+        let array = [1, 2, 3, 4].into_iter().collect::<Uint8Array>();
+        assert_eq!(
+            head(array.clone(), 8).into_iter().collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(
+            tail(array.clone(), 3).into_iter().collect::<Vec<_>>(),
+            [2, 3, 4]
+        );
+        assert!(tail(array, 0).is_empty());
+    }
+
+    #[test]
+    fn slice() {
+        let array = [Some(1), None, Some(3), Some(4)]
+            .into_iter()
+            .collect::<Uint8Array<true>>();
+        assert_eq!(
+            slice_rows(array, 1..3).into_iter().collect::<Vec<_>>(),
+            [None, Some(3)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range (is 2..5) should be within len (is 4)")]
+    fn slice_out_of_bounds() {
+        let array = [1, 2, 3, 4].into_iter().collect::<Uint8Array>();
+        let _ = slice_rows(array, 2..5);
+    }
+
+    #[test]
+    fn sample_fraction() {
+        let selection = sample_bitmap(10_000, 0.25, 7);
+        let count = selection.iter().filter(|&bit| bit).count();
+        assert!((2_250..2_750).contains(&count));
+        assert_eq!(
+            sample_bitmap(64, 0.5, 1).into_iter().collect::<Vec<_>>(),
+            sample_bitmap(64, 0.5, 1).into_iter().collect::<Vec<_>>()
+        );
+        assert!(sample_bitmap(64, 0.0, 1).iter().all(|bit| !bit));
+        assert!(sample_bitmap(64, 1.0, 1).iter().all(|bit| bit));
+
+        let array = (0..100).collect::<Uint8Array>();
+        let sampled = sample(array, 0.5, 3).into_iter().collect::<Vec<_>>();
+        assert!(sampled.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,159 @@
+//! Evaluation of row-wise expressions over multiple columns.
+
+/// A set of columns that can be iterated row by row.
+///
+/// This is implemented for tuples of up to six columns, where every column is
+/// a type that implements [`IntoIterator`], e.g. a reference to an array. A
+/// row is a tuple with the item of every column.
+pub trait Rows {
+    /// The type of the rows.
+    type Row;
+
+    /// The iterator over the rows.
+    type Iter: Iterator<Item = Self::Row>;
+
+    /// Returns an iterator over the rows.
+    ///
+    /// The iterator stops at the end of the shortest column.
+    fn rows(self) -> Self::Iter;
+}
+
+/// Iterator over the rows of a tuple of columns.
+#[derive(Clone, Debug)]
+pub struct RowIter<I>(I);
+
+/// Implement [`Rows`] for tuples of columns.
+macro_rules! impl_rows {
+    ($($column:ident: $index:tt),+) => {
+        impl<$($column: IntoIterator),+> Rows for ($($column,)+) {
+            type Row = ($(<$column as IntoIterator>::Item,)+);
+            type Iter = RowIter<($(<$column as IntoIterator>::IntoIter,)+)>;
+
+            fn rows(self) -> Self::Iter {
+                RowIter(($(self.$index.into_iter(),)+))
+            }
+        }
+
+        impl<$($column: Iterator),+> Iterator for RowIter<($($column,)+)> {
+            type Item = ($(<$column as Iterator>::Item,)+);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(($(self.0.$index.next()?,)+))
+            }
+        }
+    };
+}
+
+impl_rows!(A: 0);
+impl_rows!(A: 0, B: 1);
+impl_rows!(A: 0, B: 1, C: 2);
+impl_rows!(A: 0, B: 1, C: 2, D: 3);
+impl_rows!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_rows!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+/// Returns a new column with the result of `f` for every row of `columns`.
+///
+/// ```
+/// use narrow::{array::Int32Array, compute::map_rows};
+///
+/// let a = [1, 2, 3].into_iter().collect::<Int32Array>();
+/// let b = [Some(4), None, Some(6)].into_iter().collect::<Int32Array<true>>();
+/// let sum: Int32Array<true> = map_rows((&a, &b), |(a, b)| b.map(|b| a + b));
+/// assert_eq!(sum.into_iter().collect::<Vec<_>>(), [Some(5), None, Some(9)]);
+/// ```
+pub fn map_rows<R: Rows, U, Output: FromIterator<U>>(
+    columns: R,
+    f: impl FnMut(R::Row) -> U,
+) -> Output {
+    columns.rows().map(f).collect()
+}
+
+/// Returns a new column with the results of `f` for chunks of at most
+/// `chunk_len` rows of `columns`.
+///
+/// The rows of a chunk are collected in a buffer that is reused between
+/// chunks, so that `f` can process multiple rows at once.
+///
+/// # Panics
+///
+/// Panics if `chunk_len` is zero, or if `f` returns a different number of
+/// values than the number of rows in a chunk.
+pub fn compute_column<R: Rows, I: IntoIterator, Output: Default + Extend<I::Item>>(
+    columns: R,
+    chunk_len: usize,
+    mut f: impl FnMut(&[R::Row]) -> I,
+) -> Output {
+    assert!(chunk_len > 0, "chunk len should be non-zero");
+    let mut output = Output::default();
+    let mut rows = columns.rows();
+    let mut chunk = Vec::with_capacity(chunk_len);
+    loop {
+        chunk.clear();
+        chunk.extend(rows.by_ref().take(chunk_len));
+        if chunk.is_empty() {
+            break output;
+        }
+        let mut produced = 0;
+        output.extend(f(&chunk).into_iter().inspect(|_| produced += 1));
+        assert_eq!(
+            produced,
+            chunk.len(),
+            "number of values (is {produced}) should be equal to chunk len (is {})",
+            chunk.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "strings")]
+    use crate::array::StringArray;
+    use crate::{
+        array::{Float64Array, Uint32Array},
+        Length,
+    };
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn map() {
+        let ints = [1, 2, 3].into_iter().collect::<Uint32Array>();
+        let strings = ["a", "bb", "ccc"].into_iter().collect::<StringArray>();
+        let product: Uint32Array = map_rows((&ints, &strings), |(int, string)| {
+            int * u32::try_from(string.len()).expect("fits")
+        });
+        assert_eq!(product.into_iter().collect::<Vec<_>>(), [1, 4, 9]);
+        let single: Uint32Array = map_rows((&ints,), |(int,)| int + 1);
+        assert_eq!(single.into_iter().collect::<Vec<_>>(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn chunks() {
+        let a = (0..10).map(f64::from).collect::<Float64Array>();
+        let mut chunk_lens = Vec::new();
+        let offsets: Float64Array = compute_column((&a,), 4, |chunk: &[(&f64,)]| {
+            chunk_lens.push(chunk.len());
+            let min = chunk
+                .iter()
+                .map(|&(value,)| *value)
+                .fold(f64::INFINITY, f64::min);
+            chunk
+                .iter()
+                .map(move |&(value,)| *value - min)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(chunk_lens, [4, 4, 2]);
+        assert_eq!(offsets.len(), 10);
+        assert_eq!(
+            offsets.into_iter().collect::<Vec<_>>(),
+            [0., 1., 2., 3., 0., 1., 2., 3., 0., 1.]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values (is 1) should be equal to chunk len (is 2)")]
+    fn chunk_len_mismatch() {
+        let a = [1, 2].into_iter().collect::<Uint32Array>();
+        let _: Uint32Array = compute_column((&a,), 2, |_| [1]);
+    }
+}
@@ -0,0 +1,222 @@
+//! Minimum and maximum values of arrays.
+
+use super::Ordered;
+use crate::{
+    array::{FixedSizePrimitiveArray, SparseArray},
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferType},
+};
+use std::cmp::Ordering;
+
+/// Policy for handling NaN values when computing the minimum and maximum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// NaN values are skipped.
+    #[default]
+    Skip,
+    /// The result is NaN when the array contains a NaN value.
+    Propagate,
+    /// NaN values are ordered using the IEEE 754 total order.
+    TotalOrder,
+}
+
+/// Policy for handling null values when computing the minimum and maximum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NullPolicy {
+    /// Null values are skipped.
+    #[default]
+    Skip,
+    /// The result is `None` when the array contains a null value.
+    Propagate,
+}
+
+/// Options for [`min_max`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct MinMaxOptions {
+    /// The policy for NaN values.
+    pub nans: NanPolicy,
+    /// The policy for null values.
+    pub nulls: NullPolicy,
+}
+
+impl MinMaxOptions {
+    /// Sets the policy for NaN values.
+    #[must_use]
+    pub fn with_nans(mut self, nans: NanPolicy) -> Self {
+        self.nans = nans;
+        self
+    }
+
+    /// Sets the policy for null values.
+    #[must_use]
+    pub fn with_nulls(mut self, nulls: NullPolicy) -> Self {
+        self.nulls = nulls;
+        self
+    }
+}
+
+/// Arrays with a minimum and maximum value.
+pub trait MinMax {
+    /// The type of the values in this array.
+    type Value;
+
+    /// Returns the minimum and maximum value in this array, using the given
+    /// options to handle NaN and null values.
+    ///
+    /// Returns `None` when there are no values left to compare.
+    fn min_max_with(&self, options: MinMaxOptions) -> Option<(Self::Value, Self::Value)>;
+}
+
+/// Returns the minimum and maximum value in the array, using the given options
+/// to handle NaN and null values.
+///
+/// Returns `None` when there are no values left to compare.
+pub fn min_max<T: MinMax>(array: &T, options: MinMaxOptions) -> Option<(T::Value, T::Value)> {
+    array.min_max_with(options)
+}
+
+/// Returns the minimum and maximum of the given values, using the NaN policy
+/// of the options. Nulls must be handled by the caller.
+fn fold_min_max<'a, T: Ordered + 'a>(
+    values: impl IntoIterator<Item = Option<&'a T>>,
+    nans: NanPolicy,
+) -> Option<(T, T)> {
+    let mut result = None;
+    for &value in values.into_iter().flatten() {
+        if value.is_nan() {
+            match nans {
+                NanPolicy::Skip => continue,
+                NanPolicy::Propagate => return Some((value, value)),
+                NanPolicy::TotalOrder => {}
+            }
+        }
+        result = Some(match result {
+            None => (value, value),
+            Some((min, max)) => (
+                if value.total_cmp(&min) == Ordering::Less {
+                    value
+                } else {
+                    min
+                },
+                if value.total_cmp(&max) == Ordering::Greater {
+                    value
+                } else {
+                    max
+                },
+            ),
+        });
+    }
+    result
+}
+
+impl<T: Ordered, Buffer: BufferType> MinMax for FixedSizePrimitiveArray<T, false, Buffer> {
+    type Value = T;
+
+    fn min_max_with(&self, options: MinMaxOptions) -> Option<(T, T)> {
+        fold_min_max(self.0.as_slice().iter().map(Some), options.nans)
+    }
+}
+
+impl<T: Ordered, Buffer: BufferType> MinMax for FixedSizePrimitiveArray<T, true, Buffer> {
+    type Value = T;
+
+    fn min_max_with(&self, options: MinMaxOptions) -> Option<(T, T)> {
+        if options.nulls == NullPolicy::Propagate && self.any_null() {
+            return None;
+        }
+        fold_min_max(
+            self.0
+                .data
+                .as_slice()
+                .iter()
+                .zip(self.0.validity.iter())
+                .map(|(value, valid)| valid.then_some(value)),
+            options.nans,
+        )
+    }
+}
+
+impl<T: Ordered, Buffer: BufferType> MinMax for SparseArray<T, Buffer> {
+    type Value = T;
+
+    fn min_max_with(&self, options: MinMaxOptions) -> Option<(T, T)> {
+        // The default value only has to be compared once.
+        let default = (self.non_default_count() < self.len).then_some(&self.default);
+        fold_min_max(
+            self.values().iter().map(Some).chain(Some(default)),
+            options.nans,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Int32Array};
+
+    #[test]
+    fn non_nullable() {
+        let array = [3, 1, 4, 1, 5].into_iter().collect::<Int32Array>();
+        assert_eq!(min_max(&array, MinMaxOptions::default()), Some((1, 5)));
+
+        let empty = Int32Array::<false>::default();
+        assert_eq!(min_max(&empty, MinMaxOptions::default()), None);
+    }
+
+    #[test]
+    fn nulls() {
+        let array = [Some(3), None, Some(-2)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(min_max(&array, MinMaxOptions::default()), Some((-2, 3)));
+        assert_eq!(
+            min_max(
+                &array,
+                MinMaxOptions {
+                    nulls: NullPolicy::Propagate,
+                    ..Default::default()
+                }
+            ),
+            None
+        );
+
+        let all_null = [None::<i32>, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(min_max(&all_null, MinMaxOptions::default()), None);
+    }
+
+    #[test]
+    fn nans() {
+        let array = [1.0, f64::NAN, -1.0].into_iter().collect::<Float64Array>();
+        assert_eq!(min_max(&array, MinMaxOptions::default()), Some((-1.0, 1.0)));
+
+        let propagated = min_max(
+            &array,
+            MinMaxOptions {
+                nans: NanPolicy::Propagate,
+                ..Default::default()
+            },
+        );
+        assert!(propagated.is_some_and(|(min, max)| min.is_nan() && max.is_nan()));
+
+        let total = min_max(
+            &array,
+            MinMaxOptions::default().with_nans(NanPolicy::TotalOrder),
+        );
+        assert!(total.is_some_and(|(min, max)| min.total_cmp(&-1.0).is_eq() && max.is_nan()));
+
+        let only_nan = [f64::NAN].into_iter().collect::<Float64Array>();
+        assert_eq!(min_max(&only_nan, MinMaxOptions::default()), None);
+    }
+
+    #[test]
+    fn sparse() {
+        let array = SparseArray::<i32>::from_dense([0, 0, 4, 0, -3], 0);
+        assert_eq!(min_max(&array, MinMaxOptions::default()), Some((-3, 4)));
+
+        let dense = SparseArray::<i32>::from_dense([1, 4, 3], 0);
+        assert_eq!(min_max(&dense, MinMaxOptions::default()), Some((1, 4)));
+    }
+}
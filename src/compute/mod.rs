@@ -0,0 +1,166 @@
+//! Compute kernels for arrays.
+//!
+//! The kernels require the `compute` feature. The [`ColumnValues`] and
+//! [`Ordered`] traits are always available.
+
+use crate::FixedSize;
+use std::cmp::Ordering;
+
+#[cfg(feature = "compute")]
+mod asof;
+#[cfg(feature = "compute")]
+pub use self::asof::*;
+
+#[cfg(feature = "compute")]
+mod filter;
+#[cfg(feature = "compute")]
+pub use self::filter::*;
+
+#[cfg(feature = "compute")]
+mod kleene;
+#[cfg(feature = "compute")]
+pub use self::kleene::*;
+
+#[cfg(feature = "compute")]
+mod limit;
+#[cfg(feature = "compute")]
+pub use self::limit::*;
+
+#[cfg(feature = "compute")]
+mod map_rows;
+#[cfg(feature = "compute")]
+pub use self::map_rows::*;
+
+#[cfg(feature = "compute")]
+mod min_max;
+#[cfg(feature = "compute")]
+pub use self::min_max::*;
+
+#[cfg(all(feature = "compute", feature = "structs"))]
+mod partition;
+#[cfg(all(feature = "compute", feature = "structs"))]
+pub use self::partition::*;
+
+#[cfg(feature = "compute")]
+mod predicate;
+#[cfg(feature = "compute")]
+pub use self::predicate::*;
+
+#[cfg(feature = "compute")]
+mod resample;
+#[cfg(feature = "compute")]
+pub use self::resample::*;
+
+#[cfg(feature = "compute")]
+mod rolling;
+#[cfg(feature = "compute")]
+pub use self::rolling::*;
+
+#[cfg(feature = "compute")]
+mod sort;
+#[cfg(feature = "compute")]
+pub use self::sort::*;
+
+#[cfg(feature = "compute")]
+mod statistics;
+#[cfg(feature = "compute")]
+pub use self::statistics::*;
+
+#[cfg(feature = "compute")]
+mod take;
+#[cfg(feature = "compute")]
+pub use self::take::*;
+
+#[cfg(feature = "compute")]
+mod unary;
+#[cfg(feature = "compute")]
+pub use self::unary::*;
+
+#[cfg(all(feature = "compute", feature = "lists"))]
+mod unnest;
+#[cfg(all(feature = "compute", feature = "lists"))]
+pub use self::unnest::*;
+
+#[cfg(feature = "compute")]
+mod validity;
+#[cfg(feature = "compute")]
+pub use self::validity::*;
+
+mod values;
+pub use self::values::*;
+
+/// Values with a total order, used by kernels that compare values.
+///
+/// For floating point types the order is the IEEE 754 total order, which
+/// places positive NaN values after positive infinity and negative NaN values
+/// before negative infinity.
+pub trait Ordered: FixedSize + PartialOrd {
+    /// Returns `true` if this value is NaN.
+    fn is_nan(&self) -> bool;
+
+    /// Returns the ordering between `self` and `other` using a total order.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Implement [`Ordered`] for integer types.
+macro_rules! impl_ordered_int {
+    ($($ty:ty),+) => {
+        $(
+            impl Ordered for $ty {
+                #[inline]
+                fn is_nan(&self) -> bool {
+                    false
+                }
+
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+            }
+        )+
+    };
+}
+
+/// Implement [`Ordered`] for floating point types.
+macro_rules! impl_ordered_float {
+    ($($ty:ty),+) => {
+        $(
+            impl Ordered for $ty {
+                #[inline]
+                fn is_nan(&self) -> bool {
+                    <$ty>::is_nan(*self)
+                }
+
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$ty>::total_cmp(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_ordered_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+#[cfg(not(feature = "arrow-rs"))]
+impl_ordered_int!(u128, isize, usize);
+impl_ordered_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_cmp() {
+        assert_eq!(Ordered::total_cmp(&1_u8, &2), Ordering::Less);
+        assert_eq!(
+            Ordered::total_cmp(&f64::NAN, &f64::INFINITY),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Ordered::total_cmp(&-f64::NAN, &f64::NEG_INFINITY),
+            Ordering::Less
+        );
+        assert!(Ordered::is_nan(&f32::NAN));
+        assert!(!Ordered::is_nan(&1_i32));
+    }
+}
@@ -0,0 +1,90 @@
+//! Partitioning of struct arrays by the values of a column.
+
+use super::{predicate::Children, Column, ColumnValues, Take};
+use crate::array::{StructArray, StructArrayType};
+use std::{collections::HashMap, hash::Hash};
+
+/// The key of a partition returned by [`partition_map`], which is `None` for
+/// rows with a null value in the key column.
+pub type PartitionKey<A> = Option<<<A as ColumnValues>::Scalar as ToOwned>::Owned>;
+
+/// Returns the rows of `batch` grouped by the values of the `key` column.
+///
+/// The rows of every key are gathered with [`Take`], in order. Rows with a
+/// null key are grouped under `None`. This is used to route the rows of a
+/// batch to per-key sinks, e.g. a sink per tenant.
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use narrow::{array::StructArray, compute::{col, partition_map}, ArrayType, Length};
+///
+/// #[derive(ArrayType)]
+/// struct Event {
+///     tenant: u16,
+///     value: u32,
+/// }
+///
+/// let batch = [(1, 10), (2, 20), (1, 30)]
+///     .into_iter()
+///     .map(|(tenant, value)| Event { tenant, value })
+///     .collect::<StructArray<Event>>();
+/// let partitions = partition_map(&batch, &col::<Event, _>(|event| &event.tenant));
+/// assert_eq!(partitions[&Some(1)].len(), 2);
+/// assert_eq!(partitions[&Some(2)].len(), 1);
+/// # }
+/// ```
+pub fn partition_map<T: StructArrayType, A: ColumnValues + ?Sized>(
+    batch: &StructArray<T>,
+    key: &Column<Children<T>, A, impl Fn(&Children<T>) -> &A>,
+) -> HashMap<PartitionKey<A>, StructArray<T>>
+where
+    A::Scalar: ToOwned,
+    <A::Scalar as ToOwned>::Owned: Eq + Hash,
+    StructArray<T>: Take,
+{
+    let mut indices = HashMap::<_, Vec<usize>>::new();
+    let mut index = 0;
+    key.values(&batch.0).for_each_value(|value| {
+        indices
+            .entry(value.map(ToOwned::to_owned))
+            .or_default()
+            .push(index);
+        index += 1;
+    });
+    indices
+        .into_iter()
+        .map(|(partition_key, rows)| (partition_key, batch.take(&rows)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "derive")]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn partition_map() {
+        use crate::{
+            array::StructArray,
+            compute::{col, partition_map},
+            ArrayType, Length,
+        };
+
+        #[derive(ArrayType, Debug, PartialEq)]
+        struct Event {
+            tenant: Option<u16>,
+            value: u32,
+        }
+
+        let batch = [(Some(1), 10), (None, 20), (Some(2), 30), (Some(1), 40)]
+            .into_iter()
+            .map(|(tenant, value)| Event { tenant, value })
+            .collect::<StructArray<Event>>();
+        let partitions = partition_map(&batch, &col::<Event, _>(|event| &event.tenant));
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(partitions[&Some(1)].0.value.0, [10, 40]);
+        assert_eq!(partitions[&Some(2)].0.value.0, [30]);
+        assert_eq!(partitions[&None].0.value.0, [20]);
+        assert_eq!(batch.len(), 4);
+    }
+}
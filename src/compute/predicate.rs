@@ -0,0 +1,333 @@
+//! Typed predicates over the columns of struct arrays.
+//!
+//! Predicates use the same three-valued logic as [`and_kleene`] and
+//! [`or_kleene`]: comparisons with null values are null, and only rows for
+//! which a predicate is `true` are selected. This means `col.eq(2).not()`
+//! selects the same rows as `col.ne(2)`, without the null values.
+
+use super::{and_kleene, or_kleene, ColumnValues};
+use crate::{array::BooleanArray, bitmap::Bitmap};
+#[cfg(feature = "structs")]
+use crate::{
+    array::{StructArray, StructArrayType},
+    buffer::{BufferType, VecBuffer},
+};
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// Predicates that select rows of a set of columns `X`.
+pub trait Predicate<X: ?Sized> {
+    /// Returns the result of this predicate for every row, which is null when
+    /// the result is unknown because of null values.
+    fn evaluate_kleene(&self, columns: &X) -> BooleanArray<true>;
+
+    /// Returns a bitmap with a set bit for every selected row, i.e. every row
+    /// for which this predicate is `true`.
+    fn evaluate(&self, columns: &X) -> Bitmap {
+        self.evaluate_kleene(columns)
+            .into_iter()
+            .map(|result| result == Some(true))
+            .collect()
+    }
+
+    /// Returns a predicate that selects rows selected by both predicates.
+    fn and<P: Predicate<X>>(self, other: P) -> And<Self, P>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Returns a predicate that selects rows selected by either predicate.
+    fn or<P: Predicate<X>>(self, other: P) -> Or<Self, P>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+
+    /// Returns a predicate that selects rows for which this predicate is
+    /// `false`. Rows for which this predicate is null remain null.
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+/// Predicate that selects rows selected by both predicates.
+#[derive(Clone, Copy, Debug)]
+pub struct And<L, R>(L, R);
+
+impl<X: ?Sized, L: Predicate<X>, R: Predicate<X>> Predicate<X> for And<L, R> {
+    fn evaluate_kleene(&self, columns: &X) -> BooleanArray<true> {
+        and_kleene(
+            &self.0.evaluate_kleene(columns),
+            &self.1.evaluate_kleene(columns),
+        )
+    }
+}
+
+/// Predicate that selects rows selected by either predicate.
+#[derive(Clone, Copy, Debug)]
+pub struct Or<L, R>(L, R);
+
+impl<X: ?Sized, L: Predicate<X>, R: Predicate<X>> Predicate<X> for Or<L, R> {
+    fn evaluate_kleene(&self, columns: &X) -> BooleanArray<true> {
+        or_kleene(
+            &self.0.evaluate_kleene(columns),
+            &self.1.evaluate_kleene(columns),
+        )
+    }
+}
+
+/// Predicate that selects rows for which the inner predicate is `false`.
+#[derive(Clone, Copy, Debug)]
+pub struct Not<P>(P);
+
+impl<X: ?Sized, P: Predicate<X>> Predicate<X> for Not<P> {
+    fn evaluate_kleene(&self, columns: &X) -> BooleanArray<true> {
+        self.0
+            .evaluate_kleene(columns)
+            .into_iter()
+            .map(|result| result.map(|value| !value))
+            .collect()
+    }
+}
+
+/// Comparison operators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    /// Equal to.
+    Eq,
+    /// Not equal to.
+    Ne,
+    /// Less than.
+    Lt,
+    /// Less than or equal to.
+    Le,
+    /// Greater than.
+    Gt,
+    /// Greater than or equal to.
+    Ge,
+}
+
+impl Operator {
+    /// Applies this operator to `left` and `right`.
+    fn apply<V: PartialOrd + ?Sized>(self, left: &V, right: &V) -> bool {
+        match self {
+            Self::Eq => left == right,
+            Self::Ne => left != right,
+            Self::Lt => left < right,
+            Self::Le => left <= right,
+            Self::Gt => left > right,
+            Self::Ge => left >= right,
+        }
+    }
+}
+
+/// A column `A` of a set of columns `X`, selected with an accessor function.
+///
+/// Use [`col`] to select a column of a [`StructArray`].
+pub struct Column<X: ?Sized, A: ?Sized, F> {
+    /// Returns a reference to the column.
+    accessor: F,
+    /// The set of columns and the column.
+    _columns: PhantomData<fn(&X) -> &A>,
+}
+
+/// The child arrays of a [`StructArray`] of `T`.
+#[cfg(feature = "structs")]
+pub(super) type Children<T> = <T as StructArrayType>::Array<VecBuffer>;
+
+/// Returns the column of the [`StructArray`] of `T` selected by `accessor`.
+///
+/// The accessor receives the [`StructArrayType::Array`] of `T`, i.e. the
+/// struct with all the child arrays.
+#[cfg(feature = "structs")]
+pub fn col<T: StructArrayType, A: ColumnValues + ?Sized>(
+    accessor: impl Fn(&Children<T>) -> &A,
+) -> Column<Children<T>, A, impl Fn(&Children<T>) -> &A> {
+    Column::new(accessor)
+}
+
+impl<X: ?Sized, A: ColumnValues + ?Sized, F: Fn(&X) -> &A> Column<X, A, F> {
+    /// Returns a column selected with `accessor`.
+    pub fn new(accessor: F) -> Self {
+        Self {
+            accessor,
+            _columns: PhantomData,
+        }
+    }
+
+    /// Returns the column of `columns`.
+    pub(crate) fn values<'a>(&self, columns: &'a X) -> &'a A {
+        (self.accessor)(columns)
+    }
+
+    /// Returns a comparison of this column with `value`.
+    fn compare<V: Borrow<A::Scalar>>(self, operator: Operator, value: V) -> Comparison<X, A, F, V> {
+        Comparison {
+            column: self,
+            operator,
+            value,
+        }
+    }
+
+    /// Selects rows with values equal to `value`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn eq<V: Borrow<A::Scalar>>(self, value: V) -> Comparison<X, A, F, V> {
+        self.compare(Operator::Eq, value)
+    }
+
+    /// Selects rows with values not equal to `value`.
+    pub fn ne<V: Borrow<A::Scalar>>(self, value: V) -> Comparison<X, A, F, V> {
+        self.compare(Operator::Ne, value)
+    }
+
+    /// Selects rows with values less than `value`.
+    pub fn lt<V: Borrow<A::Scalar>>(self, value: V) -> Comparison<X, A, F, V> {
+        self.compare(Operator::Lt, value)
+    }
+
+    /// Selects rows with values less than or equal to `value`.
+    pub fn le<V: Borrow<A::Scalar>>(self, value: V) -> Comparison<X, A, F, V> {
+        self.compare(Operator::Le, value)
+    }
+
+    /// Selects rows with values greater than `value`.
+    pub fn gt<V: Borrow<A::Scalar>>(self, value: V) -> Comparison<X, A, F, V> {
+        self.compare(Operator::Gt, value)
+    }
+
+    /// Selects rows with values greater than or equal to `value`.
+    pub fn ge<V: Borrow<A::Scalar>>(self, value: V) -> Comparison<X, A, F, V> {
+        self.compare(Operator::Ge, value)
+    }
+}
+
+/// Predicate that compares the values of a column with a value.
+///
+/// The result is null for null values, so they are never selected.
+pub struct Comparison<X: ?Sized, A: ?Sized, F, V> {
+    /// The column to compare.
+    column: Column<X, A, F>,
+    /// The comparison operator.
+    operator: Operator,
+    /// The value to compare with.
+    value: V,
+}
+
+impl<X: ?Sized, A: ColumnValues + ?Sized, F: Fn(&X) -> &A, V: Borrow<A::Scalar>> Predicate<X>
+    for Comparison<X, A, F, V>
+{
+    fn evaluate_kleene(&self, columns: &X) -> BooleanArray<true> {
+        let value = self.value.borrow();
+        let mut results = Vec::new();
+        (self.column.accessor)(columns).for_each_value(|item| {
+            results.push(item.map(|inner| self.operator.apply(inner, value)));
+        });
+        results.into_iter().collect()
+    }
+
+    fn evaluate(&self, columns: &X) -> Bitmap {
+        let value = self.value.borrow();
+        (self.column.accessor)(columns).select(|item| self.operator.apply(item, value))
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType, Buffer: BufferType> StructArray<T, false, Buffer> {
+    /// Returns a bitmap with a set bit for every row selected by `predicate`.
+    pub fn selection<P: Predicate<<T as StructArrayType>::Array<Buffer>>>(
+        &self,
+        predicate: &P,
+    ) -> Bitmap {
+        predicate.evaluate(&self.0)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "strings")]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, StringArray};
+
+    /// Columns used in tests.
+    struct Columns {
+        /// Non-nullable integers.
+        a: Int32Array,
+        /// Nullable integers.
+        b: Int32Array<true>,
+        /// Strings.
+        c: StringArray,
+    }
+
+    /// Returns the columns used in tests.
+    fn columns() -> Columns {
+        Columns {
+            a: [1, 2, 3, 4].into_iter().collect(),
+            b: [Some(4), None, Some(2), Some(1)].into_iter().collect(),
+            c: ["a", "b", "c", "d"].into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let columns = columns();
+        let a = || Column::new(|set: &Columns| &set.a);
+        assert_eq!(
+            a().gt(2).evaluate(&columns).into_iter().collect::<Vec<_>>(),
+            [false, false, true, true]
+        );
+        assert_eq!(
+            a().le(2).evaluate(&columns).into_iter().collect::<Vec<_>>(),
+            [true, true, false, false]
+        );
+        assert_eq!(
+            a().ne(2).evaluate(&columns).into_iter().collect::<Vec<_>>(),
+            [true, false, true, true]
+        );
+        let b = Column::new(|set: &Columns| &set.b);
+        assert_eq!(
+            b.lt(3).evaluate(&columns).into_iter().collect::<Vec<_>>(),
+            [false, false, true, true]
+        );
+    }
+
+    #[test]
+    fn combine() {
+        let columns = columns();
+        let predicate = Column::new(|set: &Columns| &set.a)
+            .ge(2)
+            .and(Column::new(|set: &Columns| &set.b).eq(2).not())
+            .or(Column::new(|set: &Columns| &set.c).eq("a"));
+        assert_eq!(
+            predicate.evaluate(&columns).into_iter().collect::<Vec<_>>(),
+            [true, false, false, true]
+        );
+        assert_eq!(
+            predicate
+                .evaluate_kleene(&columns)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(true), None, Some(false), Some(true)]
+        );
+    }
+
+    #[test]
+    fn not_null() {
+        let columns = columns();
+        let b = || Column::new(|set: &Columns| &set.b);
+        let not_eq = b().eq(2).not().evaluate(&columns);
+        let ne = b().ne(2).evaluate(&columns);
+        assert_eq!(
+            not_eq.into_iter().collect::<Vec<_>>(),
+            ne.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            b().ne(2).evaluate(&columns).into_iter().collect::<Vec<_>>(),
+            [true, false, false, true]
+        );
+    }
+}
@@ -0,0 +1,343 @@
+//! Resampling of time series into fixed intervals.
+
+use super::ColumnValues;
+use crate::{
+    array::{FixedSizePrimitiveArray, Float64Array, Int64Array},
+    buffer::{Buffer, BufferType},
+    Length,
+};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// The aggregation of the values in a bucket of [`resample`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    /// The number of non-null values.
+    Count,
+    /// The sum of the values.
+    Sum,
+    /// The mean of the values.
+    #[default]
+    Mean,
+    /// The minimum value.
+    Min,
+    /// The maximum value.
+    Max,
+    /// The value with the earliest timestamp.
+    First,
+    /// The value with the latest timestamp.
+    Last,
+}
+
+/// A time series resampled by [`resample`].
+#[derive(Debug)]
+pub struct Resampled {
+    /// The start timestamps of the buckets, in ascending order.
+    pub timestamps: Int64Array,
+    /// The aggregated values of the buckets, or null for empty buckets.
+    pub values: Float64Array<true>,
+}
+
+/// Error returned by [`resample`] when the buckets of the timestamps can't be
+/// represented.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResampleError {
+    /// The start timestamp of the bucket of a timestamp doesn't fit in an
+    /// `i64`.
+    BucketOverflow {
+        /// The timestamp.
+        timestamp: i64,
+    },
+    /// The buckets from the earliest to the latest timestamp don't fit in
+    /// memory.
+    TooManyBuckets {
+        /// The earliest timestamp.
+        first: i64,
+        /// The latest timestamp.
+        last: i64,
+    },
+}
+
+impl Display for ResampleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::BucketOverflow { timestamp } => write!(
+                f,
+                "start of the bucket of timestamp (is {timestamp}) should fit in i64"
+            ),
+            Self::TooManyBuckets { first, last } => write!(
+                f,
+                "buckets from timestamp {first} to {last} should fit in memory"
+            ),
+        }
+    }
+}
+
+impl Error for ResampleError {}
+
+/// The running aggregates of the values in a bucket.
+#[derive(Clone, Copy)]
+struct Accumulator {
+    /// The number of values.
+    count: usize,
+    /// The sum of the values.
+    sum: f64,
+    /// The minimum value.
+    min: f64,
+    /// The maximum value.
+    max: f64,
+    /// The timestamp and value of the first value.
+    first: (i64, f64),
+    /// The timestamp and value of the last value.
+    last: (i64, f64),
+}
+
+impl Accumulator {
+    /// Returns an accumulator with a single value.
+    fn new(timestamp: i64, value: f64) -> Self {
+        Self {
+            count: 1,
+            sum: value,
+            min: value,
+            max: value,
+            first: (timestamp, value),
+            last: (timestamp, value),
+        }
+    }
+
+    /// Adds a value to this accumulator.
+    fn push(&mut self, timestamp: i64, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        if timestamp < self.first.0 {
+            self.first = (timestamp, value);
+        }
+        if timestamp >= self.last.0 {
+            self.last = (timestamp, value);
+        }
+    }
+
+    /// Returns the aggregated value.
+    fn finish(self, aggregation: Aggregation) -> f64 {
+        match aggregation {
+            Aggregation::Count => {
+                // Counts up to 2^53 are exact.
+                #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+                let count = self.count as f64;
+                count
+            }
+            Aggregation::Sum => self.sum,
+            Aggregation::Mean => {
+                #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+                let count = self.count as f64;
+                self.sum / count
+            }
+            Aggregation::Min => self.min,
+            Aggregation::Max => self.max,
+            Aggregation::First => self.first.1,
+            Aggregation::Last => self.last.1,
+        }
+    }
+}
+
+/// Returns the aggregates of `values` in buckets of `interval`, based on
+/// their `timestamps`.
+///
+/// The buckets start at multiples of `interval` and cover all timestamps
+/// from the earliest to the latest bucket, so the result has one value per
+/// interval. Buckets without values, because there are no timestamps in the
+/// interval or all values are null, are null. Null values are skipped, and
+/// the timestamps don't have to be sorted.
+///
+/// # Errors
+///
+/// Returns an error if the start of the earliest bucket doesn't fit in an
+/// `i64`, or if the buckets from the earliest to the latest timestamp don't
+/// fit in memory, e.g. for timestamps `0` and `i64::MAX` with an `interval`
+/// of `1`.
+///
+/// # Panics
+///
+/// Panics if `interval` is not positive, or if the number of timestamps
+/// differs from the number of values.
+///
+/// ```
+/// use narrow::{
+///     array::{Float64Array, Int64Array},
+///     compute::{resample, Aggregation},
+/// };
+///
+/// let timestamps = [0, 5, 12, 31].into_iter().collect::<Int64Array>();
+/// let values = [Some(1.0), Some(3.0), None, Some(4.0)]
+///     .into_iter()
+///     .collect::<Float64Array<true>>();
+/// let resampled = resample(&timestamps, &values, 10, Aggregation::Mean)?;
+/// assert_eq!(resampled.timestamps.0, [0, 10, 20, 30]);
+/// assert_eq!(
+///     resampled.values.into_iter().collect::<Vec<_>>(),
+///     [Some(2.0), None, None, Some(4.0)]
+/// );
+/// # Ok::<_, narrow::compute::ResampleError>(())
+/// ```
+pub fn resample<Buf: BufferType, A: ColumnValues + Length>(
+    timestamps: &FixedSizePrimitiveArray<i64, false, Buf>,
+    values: &A,
+    interval: i64,
+    aggregation: Aggregation,
+) -> Result<Resampled, ResampleError>
+where
+    A::Scalar: Copy + Into<f64>,
+{
+    assert!(interval > 0, "interval (is {interval}) should be positive");
+    let times = timestamps.0.as_slice();
+    assert_eq!(
+        times.len(),
+        values.len(),
+        "number of timestamps (is {}) should be equal to number of values (is {})",
+        times.len(),
+        values.len()
+    );
+
+    let bucket = |timestamp: i64| timestamp.div_euclid(interval);
+    let (Some(min), Some(max)) = (times.iter().copied().min(), times.iter().copied().max()) else {
+        return Ok(Resampled {
+            timestamps: Int64Array::default(),
+            values: Float64Array::<true>::default(),
+        });
+    };
+    let (first, last) = (bucket(min), bucket(max));
+
+    // The start of the last bucket is at most `max`, and the starts of all
+    // other buckets are between the starts of the first and the last bucket.
+    first
+        .checked_mul(interval)
+        .ok_or(ResampleError::BucketOverflow { timestamp: min })?;
+    let too_many = ResampleError::TooManyBuckets {
+        first: min,
+        last: max,
+    };
+    let buckets = last
+        .checked_sub(first)
+        .and_then(|span| span.checked_add(1))
+        .and_then(|count| usize::try_from(count).ok())
+        .ok_or(too_many)?;
+    let mut accumulators: Vec<Option<Accumulator>> = Vec::new();
+    accumulators
+        .try_reserve_exact(buckets)
+        .map_err(|_| too_many)?;
+    accumulators.resize(buckets, None);
+    let mut rows = times.iter().copied();
+    values.for_each_value(|value_opt| {
+        let timestamp = rows.next().expect("timestamp for every value");
+        if let Some(&value) = value_opt {
+            let index = usize::try_from(bucket(timestamp) - first).expect("bucket after first");
+            let float = value.into();
+            match accumulators[index] {
+                Some(ref mut accumulator) => accumulator.push(timestamp, float),
+                None => accumulators[index] = Some(Accumulator::new(timestamp, float)),
+            }
+        }
+    });
+
+    Ok(Resampled {
+        timestamps: (first..=last).map(|index| index * interval).collect(),
+        values: accumulators
+            .into_iter()
+            .map(|accumulator| accumulator.map(|inner| inner.finish(aggregation)))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint8Array;
+
+    #[test]
+    fn aggregations() {
+        let timestamps = [-3, 4, 1, 9, 25].into_iter().collect::<Int64Array>();
+        let values = [1, 2, 3, 4, 5].into_iter().collect::<Uint8Array>();
+        let aggregate = |aggregation| {
+            resample(&timestamps, &values, 5, aggregation)
+                .expect("few buckets")
+                .values
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            resample(&timestamps, &values, 5, Aggregation::Count)
+                .expect("few buckets")
+                .timestamps
+                .0,
+            [-5, 0, 5, 10, 15, 20, 25]
+        );
+        assert_eq!(
+            aggregate(Aggregation::Count),
+            [Some(1.0), Some(2.0), Some(1.0), None, None, None, Some(1.0)]
+        );
+        assert_eq!(aggregate(Aggregation::Sum)[1], Some(5.0));
+        assert_eq!(aggregate(Aggregation::Min)[1], Some(2.0));
+        assert_eq!(aggregate(Aggregation::Max)[1], Some(3.0));
+        assert_eq!(aggregate(Aggregation::First)[1], Some(3.0));
+        assert_eq!(aggregate(Aggregation::Last)[1], Some(2.0));
+    }
+
+    #[test]
+    fn empty() {
+        let resampled = resample(
+            &<Int64Array>::default(),
+            &<Uint8Array>::default(),
+            10,
+            Aggregation::Sum,
+        )
+        .expect("no buckets");
+        assert!(resampled.timestamps.is_empty());
+        assert!(resampled.values.is_empty());
+    }
+
+    #[test]
+    fn overflow() {
+        let values = [1_u8, 2].into_iter().collect::<Uint8Array>();
+        let resample_range = |first, last, interval| {
+            let timestamps = [first, last].into_iter().collect::<Int64Array>();
+            resample(&timestamps, &values, interval, Aggregation::Sum).err()
+        };
+        assert_eq!(
+            resample_range(0, i64::MAX, 1),
+            Some(ResampleError::TooManyBuckets {
+                first: 0,
+                last: i64::MAX
+            })
+        );
+        assert_eq!(
+            resample_range(i64::MIN, i64::MAX, 1),
+            Some(ResampleError::TooManyBuckets {
+                first: i64::MIN,
+                last: i64::MAX
+            })
+        );
+        assert_eq!(
+            resample_range(i64::MIN, 0, 3),
+            Some(ResampleError::BucketOverflow {
+                timestamp: i64::MIN
+            })
+        );
+        assert_eq!(resample_range(i64::MAX - 1, i64::MAX, i64::MAX), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval (is 0) should be positive")]
+    fn zero_interval() {
+        let _ = resample(
+            &<Int64Array>::default(),
+            &<Uint8Array>::default(),
+            0,
+            Aggregation::Sum,
+        );
+    }
+}
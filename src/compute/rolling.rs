@@ -0,0 +1,412 @@
+//! Aggregations over moving windows.
+
+use super::ColumnValues;
+use crate::array::Float64Array;
+use std::collections::VecDeque;
+
+/// Returns the values of `array` as floating point values, with `None` for
+/// null values.
+fn float_values<A: ColumnValues>(array: &A) -> Vec<Option<f64>>
+where
+    A::Scalar: Copy + Into<f64>,
+{
+    let mut values = Vec::new();
+    array.for_each_value(|value| values.push(value.map(|&inner| inner.into())));
+    values
+}
+
+/// Checks the window size and the minimum number of values per window.
+fn check_window(window: usize, min_periods: usize) {
+    assert!(
+        (1..=window).contains(&min_periods),
+        "min_periods (is {min_periods}) should be between 1 and window (is {window})"
+    );
+}
+
+/// The aggregated state of the non-null values in a window.
+#[derive(Clone, Copy, Debug, Default)]
+struct Window {
+    /// The sum of the finite values, compensated with `compensation`.
+    sum: f64,
+    /// The compensation of the rounding errors of `sum`, using Neumaier's
+    /// variant of Kahan summation.
+    compensation: f64,
+    /// The number of non-null values.
+    count: usize,
+    /// The number of NaN values.
+    nan_count: usize,
+    /// The number of positive infinite values.
+    pos_inf_count: usize,
+    /// The number of negative infinite values.
+    neg_inf_count: usize,
+}
+
+impl Window {
+    /// Adds `term` to the compensated sum of the finite values.
+    fn add_finite(&mut self, term: f64) {
+        let total = self.sum + term;
+        self.compensation += if self.sum.abs() >= term.abs() {
+            (self.sum - total) + term
+        } else {
+            (term - total) + self.sum
+        };
+        self.sum = total;
+    }
+
+    /// Adds `value` to the window, or removes it when `add` is not set.
+    ///
+    /// Non-finite values are counted instead of summed, so they do not
+    /// affect the sum of the window after they are removed.
+    fn update(&mut self, value: f64, add: bool) {
+        let counter = if value.is_nan() {
+            Some(&mut self.nan_count)
+        } else if value.is_infinite() && value.is_sign_positive() {
+            Some(&mut self.pos_inf_count)
+        } else if value.is_infinite() {
+            Some(&mut self.neg_inf_count)
+        } else {
+            None
+        };
+        if add {
+            self.count += 1;
+            match counter {
+                Some(count) => *count += 1,
+                None => self.add_finite(value),
+            }
+        } else {
+            self.count -= 1;
+            match counter {
+                Some(count) => *count -= 1,
+                None => self.add_finite(-value),
+            }
+        }
+        if self.count == 0 {
+            // Drop the rounding errors of the values that left the window.
+            self.sum = 0.0;
+            self.compensation = 0.0;
+        }
+    }
+
+    /// Returns the sum of the values in the window.
+    ///
+    /// The sum is NaN when the window contains NaN, or both positive and
+    /// negative infinity, and infinite when it contains an infinite value or
+    /// when the sum of the finite values overflows.
+    fn sum(&self) -> f64 {
+        if self.nan_count > 0 || (self.pos_inf_count > 0 && self.neg_inf_count > 0) {
+            f64::NAN
+        } else if self.pos_inf_count > 0 {
+            f64::INFINITY
+        } else if self.neg_inf_count > 0 {
+            f64::NEG_INFINITY
+        } else if self.sum.is_finite() {
+            self.sum + self.compensation
+        } else {
+            self.sum
+        }
+    }
+}
+
+/// Returns the aggregated state of the non-null values in every window.
+fn rolling_windows(values: &[Option<f64>], window: usize) -> Vec<Window> {
+    let mut state = Window::default();
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            if let Some(added) = *value {
+                state.update(added, true);
+            }
+            if let Some(&Some(removed)) = index.checked_sub(window).map(|start| &values[start]) {
+                state.update(removed, false);
+            }
+            if !state.sum.is_finite() {
+                // The sum of the finite values overflowed in this window or
+                // in a previous one, so it is recomputed.
+                state.sum = 0.0;
+                state.compensation = 0.0;
+                values[(index + 1).saturating_sub(window)..=index]
+                    .iter()
+                    .flatten()
+                    .filter(|term| term.is_finite())
+                    .for_each(|&term| state.add_finite(term));
+            }
+            state
+        })
+        .collect()
+}
+
+/// Returns the minimum, or the maximum when `max` is set, of the non-null
+/// values in every window, or `None` for windows with fewer than
+/// `min_periods` non-null values. The result is NaN for windows with NaN
+/// values.
+fn rolling_extreme(
+    values: &[Option<f64>],
+    window: usize,
+    min_periods: usize,
+    max: bool,
+) -> Float64Array<true> {
+    // Indices of the values in the window that can still become the extreme,
+    // with monotonic values.
+    let mut candidates = VecDeque::new();
+    let windows = rolling_windows(values, window);
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value_opt)| {
+            if let Some(value) = value_opt.filter(|value| !value.is_nan()) {
+                while candidates.back().is_some_and(|&last: &usize| {
+                    let candidate = values[last].expect("candidates are non-null");
+                    if max {
+                        candidate <= value
+                    } else {
+                        candidate >= value
+                    }
+                }) {
+                    candidates.pop_back();
+                }
+                candidates.push_back(index);
+            }
+            while candidates
+                .front()
+                .is_some_and(|&first| first + window <= index)
+            {
+                candidates.pop_front();
+            }
+            let state = windows[index];
+            if state.count < min_periods {
+                None
+            } else if state.nan_count > 0 {
+                Some(f64::NAN)
+            } else {
+                candidates.front().and_then(|&first| values[first])
+            }
+        })
+        .collect()
+}
+
+/// Returns the sum of the non-null values in every window of `window` rows,
+/// ending at the current row.
+///
+/// The result is null for windows with fewer than `min_periods` non-null
+/// values, which includes the first rows when `min_periods` is larger than
+/// the number of rows seen so far.
+///
+/// The sum is NaN for windows with NaN values, or with both positive and
+/// negative infinity, and infinite for windows with an infinite value.
+/// Non-finite values only affect the windows that contain them. The sum is
+/// compensated for rounding errors, so values that leave the window do not
+/// affect the sum of later windows.
+///
+/// # Panics
+///
+/// Panics if `min_periods` is not between 1 and `window`.
+///
+/// ```
+/// use narrow::{array::Uint8Array, compute::rolling_sum};
+///
+/// let array = [1, 2, 3, 4].into_iter().collect::<Uint8Array>();
+/// assert_eq!(
+///     rolling_sum(&array, 2, 2).into_iter().collect::<Vec<_>>(),
+///     [None, Some(3.0), Some(5.0), Some(7.0)]
+/// );
+/// ```
+pub fn rolling_sum<A: ColumnValues>(
+    array: &A,
+    window: usize,
+    min_periods: usize,
+) -> Float64Array<true>
+where
+    A::Scalar: Copy + Into<f64>,
+{
+    check_window(window, min_periods);
+    rolling_windows(&float_values(array), window)
+        .into_iter()
+        .map(|state| (state.count >= min_periods).then(|| state.sum()))
+        .collect()
+}
+
+/// Returns the mean of the non-null values in every window of `window` rows,
+/// ending at the current row.
+///
+/// See [`rolling_sum`] for the handling of nulls, non-finite values and
+/// `min_periods`.
+///
+/// # Panics
+///
+/// Panics if `min_periods` is not between 1 and `window`.
+pub fn rolling_mean<A: ColumnValues>(
+    array: &A,
+    window: usize,
+    min_periods: usize,
+) -> Float64Array<true>
+where
+    A::Scalar: Copy + Into<f64>,
+{
+    check_window(window, min_periods);
+    rolling_windows(&float_values(array), window)
+        .into_iter()
+        .map(|state| {
+            // Window sizes up to 2^53 are exact.
+            #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+            let mean = state.sum() / state.count as f64;
+            (state.count >= min_periods).then_some(mean)
+        })
+        .collect()
+}
+
+/// Returns the minimum of the non-null values in every window of `window`
+/// rows, ending at the current row.
+///
+/// See [`rolling_sum`] for the handling of nulls and `min_periods`. The
+/// minimum is NaN for windows with NaN values, like the sum.
+///
+/// # Panics
+///
+/// Panics if `min_periods` is not between 1 and `window`.
+pub fn rolling_min<A: ColumnValues>(
+    array: &A,
+    window: usize,
+    min_periods: usize,
+) -> Float64Array<true>
+where
+    A::Scalar: Copy + Into<f64>,
+{
+    check_window(window, min_periods);
+    rolling_extreme(&float_values(array), window, min_periods, false)
+}
+
+/// Returns the maximum of the non-null values in every window of `window`
+/// rows, ending at the current row.
+///
+/// See [`rolling_sum`] for the handling of nulls and `min_periods`. The
+/// maximum is NaN for windows with NaN values, like the sum.
+///
+/// # Panics
+///
+/// Panics if `min_periods` is not between 1 and `window`.
+pub fn rolling_max<A: ColumnValues>(
+    array: &A,
+    window: usize,
+    min_periods: usize,
+) -> Float64Array<true>
+where
+    A::Scalar: Copy + Into<f64>,
+{
+    check_window(window, min_periods);
+    rolling_extreme(&float_values(array), window, min_periods, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    #[test]
+    fn rolling() {
+        let array = [Some(3), None, Some(1), Some(4), Some(1), Some(5)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            rolling_sum(&array, 3, 1).into_iter().collect::<Vec<_>>(),
+            [
+                Some(3.0),
+                Some(3.0),
+                Some(4.0),
+                Some(5.0),
+                Some(6.0),
+                Some(10.0)
+            ]
+        );
+        assert_eq!(
+            rolling_mean(&array, 3, 2).into_iter().collect::<Vec<_>>(),
+            [
+                None,
+                None,
+                Some(2.0),
+                Some(2.5),
+                Some(2.0),
+                Some(10.0 / 3.0)
+            ]
+        );
+        assert_eq!(
+            rolling_min(&array, 3, 2).into_iter().collect::<Vec<_>>(),
+            [None, None, Some(1.0), Some(1.0), Some(1.0), Some(1.0)]
+        );
+        assert_eq!(
+            rolling_max(&array, 2, 1).into_iter().collect::<Vec<_>>(),
+            [
+                Some(3.0),
+                Some(3.0),
+                Some(1.0),
+                Some(4.0),
+                Some(4.0),
+                Some(5.0)
+            ]
+        );
+    }
+
+    /// Returns the rolling sums of `values`, with NaN for null values.
+    fn sums(values: &[f64], window: usize) -> Vec<f64> {
+        let array = values.iter().copied().collect::<Float64Array>();
+        rolling_sum(&array, window, 1)
+            .into_iter()
+            .map(|sum| sum.unwrap_or(f64::NAN))
+            .collect()
+    }
+
+    #[test]
+    fn rolling_sum_cancellation() {
+        assert_eq!(sums(&[1e20, 1.0, 1.0, 1.0], 1), [1e20, 1.0, 1.0, 1.0]);
+        assert_eq!(sums(&[1e20, 1.0, 1.0, 1.0], 2), [1e20, 1e20, 2.0, 2.0]);
+        assert_eq!(
+            sums(&[0.1, 0.2, 0.3, 0.4, 0.5], 2),
+            [0.1, 0.1 + 0.2, 0.2 + 0.3, 0.3 + 0.4, 0.4 + 0.5]
+        );
+    }
+
+    #[test]
+    fn rolling_non_finite() {
+        assert_eq!(
+            sums(&[f64::INFINITY, 1.0, 2.0, 3.0], 1),
+            [f64::INFINITY, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            sums(&[f64::INFINITY, 1.0, 2.0, 3.0], 2),
+            [f64::INFINITY, f64::INFINITY, 3.0, 5.0]
+        );
+        let mixed = sums(&[f64::INFINITY, f64::NEG_INFINITY, 1.0], 2);
+        assert!(mixed[1].is_nan());
+        assert_eq!(mixed[..1], [f64::INFINITY]);
+        assert_eq!(mixed[2..], [f64::NEG_INFINITY]);
+        let overflow = sums(&[f64::MAX, f64::MAX, 1.0, 2.0], 2);
+        assert_eq!(overflow, [f64::MAX, f64::INFINITY, f64::MAX, 3.0]);
+
+        let array = [1.0, f64::NAN, 2.0, 3.0]
+            .into_iter()
+            .collect::<Float64Array>();
+        for rolled in [
+            rolling_sum(&array, 2, 1),
+            rolling_mean(&array, 2, 1),
+            rolling_min(&array, 2, 1),
+            rolling_max(&array, 2, 1),
+        ] {
+            let nan = rolled
+                .into_iter()
+                .map(|value| value.is_some_and(f64::is_nan))
+                .collect::<Vec<_>>();
+            assert_eq!(nan, [false, true, true, false]);
+        }
+        assert_eq!(
+            rolling_max(&array, 2, 1).into_iter().nth(3),
+            Some(Some(3.0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "min_periods (is 3) should be between 1 and window (is 2)")]
+    fn min_periods() {
+        let array = [1, 2].into_iter().collect::<Int32Array>();
+        let _ = rolling_sum(&array, 2, 3);
+    }
+}
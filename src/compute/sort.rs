@@ -0,0 +1,323 @@
+//! Sorting of struct arrays by the values of one or more columns.
+
+#[cfg(feature = "structs")]
+use super::{predicate::Children, Take};
+use super::{Column, ColumnValues, Ordered};
+#[cfg(feature = "structs")]
+use crate::{
+    array::{StructArray, StructArrayType},
+    Length,
+};
+use std::{borrow::Borrow, cmp::Ordering};
+
+/// Values with a total order, that columns can be sorted by.
+///
+/// Floating point values use the IEEE 754 total order of [`Ordered`], so NaN
+/// values sort after positive infinity and before negative infinity.
+pub trait SortValue {
+    /// Returns the ordering between `self` and `other` using a total order.
+    fn sort_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl<T: Ordered> SortValue for T {
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+impl SortValue for bool {
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl SortValue for str {
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+/// Columns of a set of columns `X` that rows can be sorted by.
+pub trait SortColumn<X: ?Sized> {
+    /// Returns the rank of every row of `columns` in the sort order of this
+    /// column. Rows with equal values have equal ranks.
+    fn ranks(&self, columns: &X, descending: bool, nulls_first: bool) -> Vec<usize>;
+
+    /// Stably sorts `indices`, the row indices of `columns`, by the values of
+    /// this column.
+    fn sort_indices(
+        &self,
+        columns: &X,
+        indices: &mut [usize],
+        descending: bool,
+        nulls_first: bool,
+    ) {
+        let ranks = self.ranks(columns, descending, nulls_first);
+        indices.sort_by_key(|&index| ranks[index]);
+    }
+}
+
+impl<X: ?Sized, A: ColumnValues + ?Sized, F: Fn(&X) -> &A> SortColumn<X> for Column<X, A, F>
+where
+    A::Scalar: SortValue + ToOwned,
+{
+    fn ranks(&self, columns: &X, descending: bool, nulls_first: bool) -> Vec<usize> {
+        let mut values = Vec::new();
+        self.values(columns)
+            .for_each_value(|value| values.push(value.map(ToOwned::to_owned)));
+        let compare =
+            |left: usize, right: usize| match (values[left].as_ref(), values[right].as_ref()) {
+                (Some(left_value), Some(right_value)) => {
+                    let ordering = left_value.borrow().sort_cmp(right_value.borrow());
+                    if descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                }
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) if nulls_first => Ordering::Less,
+                (Some(_), None) if nulls_first => Ordering::Greater,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+            };
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        order.sort_unstable_by(|&left, &right| compare(left, right));
+        let mut ranks = vec![0; values.len()];
+        for window in order.windows(2) {
+            ranks[window[1]] =
+                ranks[window[0]] + usize::from(compare(window[0], window[1]).is_ne());
+        }
+        ranks
+    }
+}
+
+/// The sort keys of the rows of a batch, encoded as one rank per key, so
+/// rows compare by comparing their encoded keys.
+#[cfg(feature = "structs")]
+struct Rows {
+    /// The number of keys per row.
+    width: usize,
+    /// The ranks of the keys, row by row.
+    ranks: Vec<usize>,
+}
+
+#[cfg(feature = "structs")]
+impl Rows {
+    /// Encodes the `keys` of the `len` rows of `columns`.
+    fn new<X: ?Sized>(columns: &X, len: usize, keys: &[SortKey<'_, X>]) -> Self {
+        let width = keys.len();
+        let mut ranks = vec![0; len * width];
+        for (position, key) in keys.iter().enumerate() {
+            let key_ranks = key.column.ranks(columns, key.descending, key.nulls_first);
+            for (row, rank) in key_ranks.into_iter().enumerate() {
+                ranks[row * width + position] = rank;
+            }
+        }
+        Self { width, ranks }
+    }
+
+    /// Returns the encoded keys of the row at `index`.
+    fn row(&self, index: usize) -> &[usize] {
+        &self.ranks[index * self.width..(index + 1) * self.width]
+    }
+}
+
+/// A column to sort by, with its sort order.
+pub struct SortKey<'a, X: ?Sized> {
+    /// The column to sort by.
+    pub column: &'a dyn SortColumn<X>,
+    /// Sort the values in descending instead of ascending order.
+    pub descending: bool,
+    /// Sort null values before instead of after the non-null values.
+    pub nulls_first: bool,
+}
+
+/// Returns the rows of `batch` sorted by the given `keys`.
+///
+/// Rows are ordered by the first key, then rows with equal values for the
+/// first key by the second key, and so on. The sort is stable, so rows that
+/// are equal for all keys keep their order. Values are compared using the
+/// total order of [`SortValue`], so floating point NaN values sort after all
+/// other values in ascending order.
+///
+/// The keys of every row are encoded once, and the sorted rows are gathered
+/// with [`Take`].
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use narrow::{
+///     array::StructArray,
+///     compute::{col, sort_batch, SortKey},
+///     ArrayType,
+/// };
+///
+/// #[derive(ArrayType, Debug, PartialEq)]
+/// struct Event {
+///     tenant: u16,
+///     value: Option<u32>,
+/// }
+///
+/// let batch = [(2, Some(1)), (1, None), (1, Some(3)), (2, Some(2))]
+///     .into_iter()
+///     .map(|(tenant, value)| Event { tenant, value })
+///     .collect::<StructArray<Event>>();
+/// let sorted = sort_batch(
+///     &batch,
+///     &[
+///         SortKey {
+///             column: &col::<Event, _>(|event| &event.tenant),
+///             descending: false,
+///             nulls_first: false,
+///         },
+///         SortKey {
+///             column: &col::<Event, _>(|event| &event.value),
+///             descending: true,
+///             nulls_first: true,
+///         },
+///     ],
+/// );
+/// assert_eq!(
+///     sorted
+///         .into_iter()
+///         .map(|event| (event.tenant, event.value))
+///         .collect::<Vec<_>>(),
+///     [(1, None), (1, Some(3)), (2, Some(2)), (2, Some(1))]
+/// );
+/// # }
+/// ```
+#[cfg(feature = "structs")]
+pub fn sort_batch<T: StructArrayType>(
+    batch: &StructArray<T>,
+    keys: &[SortKey<'_, Children<T>>],
+) -> StructArray<T>
+where
+    Children<T>: Length,
+    StructArray<T>: Take,
+{
+    let len = batch.0.len();
+    let rows = Rows::new(&batch.0, len, keys);
+    let mut indices = (0..len).collect::<Vec<_>>();
+    indices.sort_by(|&left, &right| rows.row(left).cmp(rows.row(right)));
+    batch.take(&indices)
+}
+
+#[cfg(test)]
+#[cfg(feature = "strings")]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Int32Array, StringArray};
+    #[cfg(feature = "structs")]
+    use crate::compute::col;
+
+    /// Columns used in tests.
+    struct Columns {
+        /// Nullable integers.
+        a: Int32Array<true>,
+        /// Strings.
+        b: StringArray,
+    }
+
+    #[test]
+    fn sort_indices() {
+        let columns = Columns {
+            a: [Some(2), None, Some(1), Some(2)].into_iter().collect(),
+            b: ["b", "a", "d", "c"].into_iter().collect(),
+        };
+        let a = Column::new(|set: &Columns| &set.a);
+        let b = Column::new(|set: &Columns| &set.b);
+
+        let mut indices = vec![0, 1, 2, 3];
+        a.sort_indices(&columns, &mut indices, false, false);
+        assert_eq!(indices, [2, 0, 3, 1]);
+        a.sort_indices(&columns, &mut indices, true, true);
+        assert_eq!(indices, [1, 0, 3, 2]);
+
+        let mut multiple = vec![0, 1, 2, 3];
+        b.sort_indices(&columns, &mut multiple, true, false);
+        a.sort_indices(&columns, &mut multiple, false, true);
+        assert_eq!(multiple, [1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn nan_and_nulls() {
+        let values: Float64Array<true> =
+            [Some(1.0), Some(f64::NAN), None, Some(-1.0), Some(f64::NAN)]
+                .into_iter()
+                .collect();
+        let column = Column::new(|array: &Float64Array<true>| array);
+
+        let mut indices = vec![0, 1, 2, 3, 4];
+        column.sort_indices(&values, &mut indices, false, false);
+        assert_eq!(indices, [3, 0, 1, 4, 2]);
+        column.sort_indices(&values, &mut indices, true, true);
+        assert_eq!(indices, [2, 1, 4, 0, 3]);
+        assert_eq!(column.ranks(&values, false, true), [2, 3, 0, 1, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "structs")]
+    fn sort_batch() {
+        use crate::{
+            array::{ArrayType, UnionType},
+            buffer::BufferType,
+            offset::OffsetElement,
+        };
+
+        /// A row with a nullable float.
+        struct Row;
+
+        impl ArrayType for Row {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                StructArray<Row, false, Buffer>;
+        }
+
+        impl StructArrayType for Row {
+            type Array<Buffer: BufferType> = RowArray;
+        }
+
+        /// The array of [`Row`].
+        struct RowArray(Float64Array<true>);
+
+        impl Length for RowArray {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        impl Take for RowArray {
+            fn take(&self, indices: &[usize]) -> Self {
+                Self(self.0.take(indices))
+            }
+        }
+
+        let batch = StructArray::<Row>(RowArray(
+            [Some(2.0), None, Some(f64::NAN), Some(-0.0), Some(2.0)]
+                .into_iter()
+                .collect(),
+        ));
+        let column = col::<Row, _>(|row| &row.0);
+        let sorted = super::sort_batch(
+            &batch,
+            &[SortKey {
+                column: &column,
+                descending: true,
+                nulls_first: false,
+            }],
+        );
+        assert_eq!(
+            sorted
+                .0
+                 .0
+                .into_iter()
+                .map(|value| value.map(f64::to_bits))
+                .collect::<Vec<_>>(),
+            [f64::NAN, 2.0, 2.0, -0.0]
+                .map(|value| Some(value.to_bits()))
+                .into_iter()
+                .chain([None])
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,461 @@
+//! Column statistics.
+
+use super::{ColumnValues, MinMax, MinMaxOptions, Ordered};
+#[cfg(feature = "structs")]
+use crate::array::{StructArray, StructArrayType};
+#[cfg(feature = "strings")]
+use crate::{array::StringArray, offset::OffsetElement};
+use crate::{
+    array::{FixedSizePrimitiveArray, SparseArray},
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferType},
+    validity::Validity,
+    Length,
+};
+
+/// Statistics of the values in a column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Statistics<T> {
+    /// The minimum value, skipping nulls and NaN values.
+    pub min: Option<T>,
+    /// The maximum value, skipping nulls and NaN values.
+    pub max: Option<T>,
+    /// The number of null values.
+    pub null_count: usize,
+    /// The number of (non-null) NaN values.
+    pub nan_count: usize,
+    /// The number of values, including nulls.
+    pub row_count: usize,
+}
+
+/// Arrays that can compute [`Statistics`] of their values.
+pub trait ColumnStats {
+    /// The type of the values in this array.
+    type Value;
+
+    /// Returns the [`Statistics`] of the values in this array.
+    fn column_stats(&self) -> Statistics<Self::Value>;
+}
+
+impl<T: Ordered, Buffer: BufferType> ColumnStats for FixedSizePrimitiveArray<T, false, Buffer> {
+    type Value = T;
+
+    fn column_stats(&self) -> Statistics<T> {
+        let (min, max) = self.min_max_with(MinMaxOptions::default()).unzip();
+        Statistics {
+            min,
+            max,
+            null_count: 0,
+            nan_count: self
+                .0
+                .as_slice()
+                .iter()
+                .filter(|value| value.is_nan())
+                .count(),
+            row_count: self.len(),
+        }
+    }
+}
+
+impl<T: Ordered, Buffer: BufferType> ColumnStats for FixedSizePrimitiveArray<T, true, Buffer> {
+    type Value = T;
+
+    fn column_stats(&self) -> Statistics<T> {
+        let (min, max) = self.min_max_with(MinMaxOptions::default()).unzip();
+        Statistics {
+            min,
+            max,
+            null_count: self.null_count(),
+            nan_count: self
+                .0
+                .data
+                .as_slice()
+                .iter()
+                .zip(self.0.validity.iter())
+                .filter(|&(value, valid)| valid && value.is_nan())
+                .count(),
+            row_count: self.len(),
+        }
+    }
+}
+
+impl<T: Ordered, Buffer: BufferType> ColumnStats for SparseArray<T, Buffer> {
+    type Value = T;
+
+    fn column_stats(&self) -> Statistics<T> {
+        let (min, max) = self.min_max_with(MinMaxOptions::default()).unzip();
+        let default_count = self.len() - self.non_default_count();
+        Statistics {
+            min,
+            max,
+            null_count: 0,
+            nan_count: self.values().iter().filter(|value| value.is_nan()).count()
+                + if self.default_value().is_nan() {
+                    default_count
+                } else {
+                    0
+                },
+            row_count: self.len(),
+        }
+    }
+}
+
+/// Statistics of the values in a string column, e.g. for column chunk
+/// metadata.
+///
+/// The minimum and maximum are compared by their bytes, and can be truncated
+/// to bound the size of the statistics. A truncated minimum is a prefix of
+/// the minimum value. A truncated maximum is a prefix of the maximum value
+/// with its last byte incremented, so it remains an upper bound. Truncated
+/// values may not be valid UTF-8.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StringStatistics {
+    /// The (lower bound of the) minimum value, skipping nulls.
+    pub min: Option<Vec<u8>>,
+    /// The (upper bound of the) maximum value, skipping nulls.
+    pub max: Option<Vec<u8>>,
+    /// Whether `min` is truncated.
+    pub min_truncated: bool,
+    /// Whether `max` is truncated.
+    pub max_truncated: bool,
+    /// The sum of the lengths of the non-null values in bytes.
+    pub total_byte_len: usize,
+    /// The number of null values.
+    pub null_count: usize,
+    /// The number of values, including nulls.
+    pub row_count: usize,
+}
+
+impl StringStatistics {
+    /// Returns the average length of the non-null values in bytes, or `None`
+    /// if there are no non-null values.
+    #[must_use]
+    pub fn avg_byte_len(&self) -> Option<f64> {
+        let count = self.row_count - self.null_count;
+        // Lengths up to 2^53 are exact.
+        #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+        let avg = (count != 0).then(|| self.total_byte_len as f64 / count as f64);
+        avg
+    }
+}
+
+/// Returns `value` truncated to at most `max_len` bytes, as a lower bound,
+/// and whether it was truncated.
+fn truncate_min(value: &[u8], max_len: usize) -> (Vec<u8>, bool) {
+    if value.len() <= max_len {
+        (value.to_vec(), false)
+    } else {
+        (value[..max_len].to_vec(), true)
+    }
+}
+
+/// Returns `value` truncated to at most `max_len` bytes, as an upper bound,
+/// and whether it was truncated.
+///
+/// The last byte of the prefix that can be incremented is incremented, and
+/// the bytes after it are dropped. When no byte can be incremented the value
+/// is not truncated.
+fn truncate_max(value: &[u8], max_len: usize) -> (Vec<u8>, bool) {
+    if value.len() > max_len {
+        let mut prefix = value[..max_len].to_vec();
+        while let Some(last) = prefix.pop() {
+            if last < u8::MAX {
+                prefix.push(last + 1);
+                return (prefix, true);
+            }
+        }
+    }
+    (value.to_vec(), false)
+}
+
+/// Arrays with string values that can compute [`StringStatistics`].
+pub trait StringColumnStats {
+    /// Returns the [`StringStatistics`] of the values in this array, with
+    /// the minimum and maximum truncated to at most `max_len` bytes.
+    fn string_stats(&self, max_len: usize) -> StringStatistics;
+}
+
+impl<A: ColumnValues<Scalar = str>> StringColumnStats for A {
+    fn string_stats(&self, max_len: usize) -> StringStatistics {
+        // The values are only borrowed in the closure, so the current
+        // minimum and maximum are copied.
+        let mut min: Option<Vec<u8>> = None;
+        let mut max: Option<Vec<u8>> = None;
+        let mut stats = StringStatistics::default();
+        self.for_each_value(|value_opt| {
+            stats.row_count += 1;
+            match value_opt {
+                Some(value) => {
+                    let bytes = value.as_bytes();
+                    stats.total_byte_len += bytes.len();
+                    if min.as_deref().map_or(true, |current| bytes < current) {
+                        min = Some(bytes.to_vec());
+                    }
+                    if max.as_deref().map_or(true, |current| bytes > current) {
+                        max = Some(bytes.to_vec());
+                    }
+                }
+                None => stats.null_count += 1,
+            }
+        });
+        if let Some(value) = min {
+            let (bytes, truncated) = truncate_min(&value, max_len);
+            stats.min = Some(bytes);
+            stats.min_truncated = truncated;
+        }
+        if let Some(value) = max {
+            let (bytes, truncated) = truncate_max(&value, max_len);
+            stats.max = Some(bytes);
+            stats.max_truncated = truncated;
+        }
+        stats
+    }
+}
+
+/// The statistics of a column of any supported type, e.g. of a field of a
+/// struct array.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum AnyStatistics {
+    /// Statistics of a column of signed 8-bit integers.
+    Int8(Statistics<i8>),
+    /// Statistics of a column of signed 16-bit integers.
+    Int16(Statistics<i16>),
+    /// Statistics of a column of signed 32-bit integers.
+    Int32(Statistics<i32>),
+    /// Statistics of a column of signed 64-bit integers.
+    Int64(Statistics<i64>),
+    /// Statistics of a column of unsigned 8-bit integers.
+    UInt8(Statistics<u8>),
+    /// Statistics of a column of unsigned 16-bit integers.
+    UInt16(Statistics<u16>),
+    /// Statistics of a column of unsigned 32-bit integers.
+    UInt32(Statistics<u32>),
+    /// Statistics of a column of unsigned 64-bit integers.
+    UInt64(Statistics<u64>),
+    /// Statistics of a column of 32-bit floating point numbers.
+    Float32(Statistics<f32>),
+    /// Statistics of a column of 64-bit floating point numbers.
+    Float64(Statistics<f64>),
+    /// Statistics of a column of strings.
+    Utf8(StringStatistics),
+}
+
+/// Implements `From<Statistics<T>>` for [`AnyStatistics`].
+macro_rules! impl_any_statistics {
+    ($($ty:ty => $variant:ident),+) => {
+        $(
+            impl From<Statistics<$ty>> for AnyStatistics {
+                fn from(stats: Statistics<$ty>) -> Self {
+                    Self::$variant(stats)
+                }
+            }
+        )+
+    };
+}
+
+impl_any_statistics!(
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64
+);
+
+impl From<StringStatistics> for AnyStatistics {
+    fn from(stats: StringStatistics) -> Self {
+        Self::Utf8(stats)
+    }
+}
+
+/// Arrays that can compute [`AnyStatistics`] of their values.
+///
+/// This is used to compute the statistics of the fields of struct arrays,
+/// which have different types.
+pub trait AnyColumnStats {
+    /// Returns the statistics of the values in this array.
+    fn any_column_stats(&self) -> AnyStatistics;
+}
+
+impl<T: Ordered, const NULLABLE: bool, Buffer: BufferType> AnyColumnStats
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: ColumnStats<Value = T>,
+    Statistics<T>: Into<AnyStatistics>,
+{
+    fn any_column_stats(&self) -> AnyStatistics {
+        self.column_stats().into()
+    }
+}
+
+// The minimum and maximum of string columns are not truncated.
+#[cfg(feature = "strings")]
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> AnyColumnStats
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Self: StringColumnStats,
+{
+    fn any_column_stats(&self) -> AnyStatistics {
+        self.string_stats(usize::MAX).into()
+    }
+}
+
+/// Struct arrays that can compute the statistics of their fields.
+///
+/// This is implemented by the derive macro for the [`StructArrayType::Array`]
+/// of structs with fields that implement [`AnyColumnStats`].
+///
+/// ```
+/// # #[cfg(all(feature = "derive", feature = "strings"))]
+/// # {
+/// use narrow::{
+///     array::StructArray,
+///     compute::{AnyStatistics, StructColumnStats},
+///     ArrayType,
+/// };
+///
+/// #[derive(ArrayType)]
+/// struct Reading {
+///     sensor: String,
+///     value: f64,
+/// }
+///
+/// let batch = [
+///     Reading { sensor: "b".to_owned(), value: 1.5 },
+///     Reading { sensor: "a".to_owned(), value: -0.5 },
+/// ]
+/// .into_iter()
+/// .collect::<StructArray<Reading>>();
+/// let stats = batch.column_stats();
+/// assert_eq!(stats[0].0, "sensor");
+/// assert!(matches!(
+///     stats[1],
+///     ("value", AnyStatistics::Float64(ref value)) if value.min == Some(-0.5)
+/// ));
+/// # }
+/// ```
+#[cfg(feature = "structs")]
+pub trait StructColumnStats {
+    /// Returns the name and statistics of every field, in field order.
+    fn column_stats(&self) -> Vec<(&'static str, AnyStatistics)>;
+}
+
+// The statistics of the fields do not account for the validity of the
+// struct array, so this is only implemented for non-nullable struct arrays.
+#[cfg(feature = "structs")]
+impl<T: StructArrayType, Buffer: BufferType> StructColumnStats for StructArray<T, false, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: StructColumnStats,
+{
+    fn column_stats(&self) -> Vec<(&'static str, AnyStatistics)> {
+        self.0.column_stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "strings")]
+    use crate::array::StringArray;
+    use crate::array::{Float32Array, Uint8Array};
+
+    #[test]
+    fn column_stats() {
+        let array = [1, 2, 3].into_iter().collect::<Uint8Array>();
+        assert_eq!(
+            array.column_stats(),
+            Statistics {
+                min: Some(1),
+                max: Some(3),
+                null_count: 0,
+                nan_count: 0,
+                row_count: 3
+            }
+        );
+    }
+
+    #[test]
+    fn column_stats_nullable() {
+        let array = [Some(1.5), None, Some(f32::NAN), Some(-0.5)]
+            .into_iter()
+            .collect::<Float32Array<true>>();
+        let stats = array.column_stats();
+        assert_eq!(stats.min, Some(-0.5));
+        assert_eq!(stats.max, Some(1.5));
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.nan_count, 1);
+        assert_eq!(stats.row_count, 4);
+    }
+
+    #[test]
+    fn column_stats_sparse() {
+        let array = SparseArray::<f64>::from_dense([f64::NAN, 1.0, f64::NAN, f64::NAN], f64::NAN);
+        let stats = array.column_stats();
+        assert_eq!(stats.min, Some(1.0));
+        assert_eq!(stats.max, Some(1.0));
+        assert_eq!(stats.nan_count, 3);
+        assert_eq!(stats.row_count, 4);
+    }
+
+    #[test]
+    fn any_column_stats() {
+        let array = [Some(2), None, Some(-1)]
+            .into_iter()
+            .collect::<crate::array::Int16Array<true>>();
+        assert_eq!(
+            array.any_column_stats(),
+            AnyStatistics::Int16(Statistics {
+                min: Some(-1),
+                max: Some(2),
+                null_count: 1,
+                nan_count: 0,
+                row_count: 3
+            })
+        );
+        #[cfg(feature = "strings")]
+        let strings = ["b", "a"].into_iter().collect::<StringArray>();
+        #[cfg(feature = "strings")]
+        assert!(matches!(
+            strings.any_column_stats(),
+            AnyStatistics::Utf8(StringStatistics { min: Some(ref min), .. }) if min == b"a"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn string_stats() {
+        let array = [Some("apple"), None, Some("banana"), Some("b\u{ff}")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let stats = array.string_stats(8);
+        assert_eq!(stats.min.as_deref(), Some(&b"apple"[..]));
+        assert_eq!(stats.max.as_deref(), Some("b\u{ff}".as_bytes()));
+        assert!(!stats.min_truncated && !stats.max_truncated);
+        assert_eq!(stats.total_byte_len, 14);
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.row_count, 4);
+        assert_eq!(stats.avg_byte_len(), Some(14.0 / 3.0));
+
+        let truncated = array.string_stats(2);
+        assert_eq!(truncated.min.as_deref(), Some(&b"ap"[..]));
+        assert!(truncated.min_truncated);
+        assert_eq!(truncated.max.as_deref(), Some(&[b'b', 0xc4][..]));
+        assert!(truncated.max_truncated);
+
+        assert_eq!(truncate_max(&[1, 0xff, 0xff, 3], 3), (vec![2], true));
+        assert_eq!(truncate_max(&[0xff, 0xff], 1), (vec![0xff, 0xff], false));
+        assert_eq!(
+            StringArray::<true>::default()
+                .string_stats(8)
+                .avg_byte_len(),
+            None
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! Gathering of the values of arrays at given indices.
+
+#[cfg(feature = "strings")]
+use crate::array::StringArray;
+#[cfg(feature = "lists")]
+use crate::array::{Array, FixedSizeListArray, VariableSizeListArray};
+#[cfg(feature = "structs")]
+use crate::array::{StructArray, StructArrayType};
+use crate::{
+    array::{
+        BooleanArray, FixedSizePrimitiveArray, NullArray, Nulls, Unit, VariableSizeBinaryArray,
+    },
+    bitmap::Bitmap,
+    buffer::VecBuffer,
+    nullable::Nullable,
+    offset::{Offset, OffsetElement},
+    validity::Validity,
+    FixedSize, Length,
+};
+
+/// Arrays that can gather their values at given indices.
+///
+/// This is the kernel that materializes the results of kernels that compute
+/// row indices, e.g. [`sort_batch`](super::sort_batch).
+pub trait Take {
+    /// Returns an array with the values of this array at `indices`, in the
+    /// order of `indices`.
+    ///
+    /// Indices may be repeated. The validity of the values is kept, and the
+    /// offsets of variable-size values are rebased.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    #[must_use]
+    fn take(&self, indices: &[usize]) -> Self;
+}
+
+impl<T: Copy> Take for Vec<T> {
+    fn take(&self, indices: &[usize]) -> Self {
+        indices
+            .iter()
+            .map(|&index| {
+                *self.get(index).unwrap_or_else(|| {
+                    panic!("index (is {index}) should be < len (is {})", self.len())
+                })
+            })
+            .collect()
+    }
+}
+
+impl Take for Bitmap {
+    fn take(&self, indices: &[usize]) -> Self {
+        indices
+            .iter()
+            .map(|&index| {
+                self.get(index).unwrap_or_else(|| {
+                    panic!("index (is {index}) should be < len (is {})", self.len())
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T: Take> Take for Nullable<T> {
+    fn take(&self, indices: &[usize]) -> Self {
+        Self {
+            data: self.data.take(indices),
+            validity: self.validity.take(indices),
+        }
+    }
+}
+
+impl<T: Unit> Take for Nulls<T> {
+    fn take(&self, indices: &[usize]) -> Self {
+        let len = self.len();
+        indices
+            .iter()
+            .map(|&index| {
+                assert!(index < len, "index (is {index}) should be < len (is {len})");
+                T::default()
+            })
+            .collect()
+    }
+}
+
+impl<T: FixedSize, const NULLABLE: bool> Take for FixedSizePrimitiveArray<T, NULLABLE>
+where
+    Vec<T>: Validity<NULLABLE>,
+    <Vec<T> as Validity<NULLABLE>>::Storage<VecBuffer>: Take,
+{
+    fn take(&self, indices: &[usize]) -> Self {
+        Self(self.0.take(indices))
+    }
+}
+
+impl<const NULLABLE: bool> Take for BooleanArray<NULLABLE>
+where
+    Bitmap: Validity<NULLABLE>,
+    <Bitmap as Validity<NULLABLE>>::Storage<VecBuffer>: Take,
+{
+    fn take(&self, indices: &[usize]) -> Self {
+        Self(self.0.take(indices))
+    }
+}
+
+impl<T: Unit, const NULLABLE: bool> Take for NullArray<T, NULLABLE>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    <Nulls<T> as Validity<NULLABLE>>::Storage<VecBuffer>: Take,
+{
+    fn take(&self, indices: &[usize]) -> Self {
+        Self(self.0.take(indices))
+    }
+}
+
+/// Returns the rebased offsets of the values at `indices` and the indices of
+/// their child values.
+fn take_offsets<OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+    indices: &[usize],
+) -> (Vec<OffsetItem>, Vec<usize>) {
+    /// Returns the offset as a `usize`.
+    fn position<OffsetItem: OffsetElement>(offset: OffsetItem) -> usize {
+        offset.try_into().expect("offset should fit in usize")
+    }
+
+    let mut taken = Vec::with_capacity(indices.len() + 1);
+    let mut child_indices = Vec::new();
+    taken.push(OffsetItem::default());
+    for &index in indices {
+        assert!(
+            index + 1 < offsets.len(),
+            "index (is {index}) should be < len (is {})",
+            offsets.len() - 1
+        );
+        child_indices.extend(position(offsets[index])..position(offsets[index + 1]));
+        taken.push(
+            OffsetItem::try_from(child_indices.len())
+                .expect("taken offsets should fit in offset type"),
+        );
+    }
+    (taken, child_indices)
+}
+
+impl<T: Take, OffsetItem: OffsetElement> Take for Offset<T, false, OffsetItem> {
+    fn take(&self, indices: &[usize]) -> Self {
+        let (offsets, child_indices) = take_offsets(&self.offsets, indices);
+        Self {
+            data: self.data.take(&child_indices),
+            offsets,
+        }
+    }
+}
+
+impl<T: Take, OffsetItem: OffsetElement> Take for Offset<T, true, OffsetItem> {
+    fn take(&self, indices: &[usize]) -> Self {
+        let (offsets, child_indices) = take_offsets(&self.offsets.data, indices);
+        Self {
+            data: self.data.take(&child_indices),
+            offsets: Nullable {
+                data: offsets,
+                validity: self.offsets.validity.take(indices),
+            },
+        }
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement> Take
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem>
+where
+    Vec<OffsetItem>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8>, NULLABLE, OffsetItem>: Take,
+{
+    fn take(&self, indices: &[usize]) -> Self {
+        Self(self.0.take(indices))
+    }
+}
+
+#[cfg(feature = "strings")]
+impl<const NULLABLE: bool, OffsetItem: OffsetElement> Take for StringArray<NULLABLE, OffsetItem>
+where
+    Vec<OffsetItem>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem>: Take,
+{
+    fn take(&self, indices: &[usize]) -> Self {
+        Self(self.0.take(indices))
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement> Take
+    for VariableSizeListArray<T, NULLABLE, OffsetItem>
+where
+    Vec<OffsetItem>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem>: Take,
+{
+    fn take(&self, indices: &[usize]) -> Self {
+        Self(self.0.take(indices))
+    }
+}
+
+/// Returns the indices of the child values of the lists of size `N` at
+/// `indices`.
+#[cfg(feature = "lists")]
+fn fixed_size_child_indices<const N: usize>(indices: &[usize], len: usize) -> Vec<usize> {
+    indices
+        .iter()
+        .flat_map(|&index| {
+            assert!(index < len, "index (is {index}) should be < len (is {len})");
+            index * N..(index + 1) * N
+        })
+        .collect()
+}
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + Take + Length> Take for FixedSizeListArray<N, T> {
+    fn take(&self, indices: &[usize]) -> Self {
+        let len = self.0.len() / N.max(1);
+        Self(self.0.take(&fixed_size_child_indices::<N>(indices, len)))
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + Take> Take for FixedSizeListArray<N, T, true> {
+    fn take(&self, indices: &[usize]) -> Self {
+        let len = self.0.validity.len();
+        Self(Nullable {
+            data: self
+                .0
+                .data
+                .take(&fixed_size_child_indices::<N>(indices, len)),
+            validity: self.0.validity.take(indices),
+        })
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType, const NULLABLE: bool> Take for StructArray<T, NULLABLE>
+where
+    <T as StructArrayType>::Array<VecBuffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<VecBuffer> as Validity<NULLABLE>>::Storage<VecBuffer>: Take,
+{
+    fn take(&self, indices: &[usize]) -> Self {
+        Self(self.0.take(indices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, Uint8Array};
+
+    #[test]
+    fn fixed_size_primitive() {
+        let array = [1, 2, 3, 4].into_iter().collect::<Int32Array>();
+        assert_eq!(array.take(&[3, 0, 0]).0, [4, 1, 1]);
+        let nullable = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            nullable.take(&[1, 2]).into_iter().collect::<Vec<_>>(),
+            [None, Some(3)]
+        );
+        assert!(nullable.take(&[]).0.data.is_empty());
+    }
+
+    #[test]
+    fn boolean() {
+        let array = [Some(true), None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            array.take(&[2, 1, 0]).into_iter().collect::<Vec<_>>(),
+            [Some(false), None, Some(true)]
+        );
+    }
+
+    #[test]
+    fn variable_size_binary() {
+        let array = [Some(vec![1_u8, 2]), None, Some(vec![3])]
+            .into_iter()
+            .collect::<VariableSizeBinaryArray<true>>();
+        let taken = array.take(&[2, 1, 0, 2]);
+        assert_eq!(taken.0.offsets.data, [0, 1, 1, 3, 4]);
+        assert_eq!(taken.0.offsets.validity.len(), 4);
+        assert_eq!(taken.0.data.0, [3, 1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "lists")]
+    fn lists() {
+        let array = [vec![1_u8, 2], vec![], vec![3]]
+            .into_iter()
+            .collect::<VariableSizeListArray<Uint8Array>>();
+        let taken = array.take(&[2, 0]);
+        assert_eq!(taken.0.offsets, [0, 1, 3]);
+        assert_eq!(taken.0.data.0, [3, 1, 2]);
+        let fixed = [Some([1_u8, 2]), None, Some([3, 4])]
+            .into_iter()
+            .collect::<FixedSizeListArray<2, Uint8Array, true>>();
+        assert_eq!(
+            fixed.take(&[2, 1]).into_iter().collect::<Vec<_>>(),
+            [Some([&3, &4]), None]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn string() {
+        let array = ["a", "bc", "d"].into_iter().collect::<StringArray>();
+        assert_eq!(
+            array.take(&[1, 1, 2]).into_iter().collect::<Vec<_>>(),
+            ["bc", "bc", "d"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index (is 3) should be < len (is 3)")]
+    fn out_of_bounds() {
+        let _ = [1_u8, 2, 3].into_iter().collect::<Uint8Array>().take(&[3]);
+    }
+}
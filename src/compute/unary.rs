@@ -0,0 +1,338 @@
+//! Element-wise transforms of fixed-size primitive arrays.
+//!
+//! The fallible variants [`try_unary`] and [`try_binary`] turn failures into
+//! nulls, which is useful for cleaning data.
+
+use crate::{
+    array::FixedSizePrimitiveArray,
+    bitmap::Bitmap,
+    buffer::{Buffer, BufferType, VecBuffer},
+    nullable::Nullable,
+    FixedSize, Length,
+};
+
+/// Fixed-size primitive arrays that can be transformed element-wise with
+/// [`unary`] and [`binary`].
+///
+/// The output of a transform has the same nullability as the input.
+pub trait Elementwise: Length {
+    /// The type of the values.
+    type Item: FixedSize;
+
+    /// The array type with values of type `U` and the nullability of this
+    /// array.
+    type Output<U: FixedSize>;
+
+    /// Returns the values of this array, including the values of null
+    /// elements.
+    fn values(&self) -> &[Self::Item];
+
+    /// Returns the validity bitmap of this array, or `None` if this array is
+    /// not nullable.
+    fn validity(&self) -> Option<Bitmap<VecBuffer, u64>>;
+
+    /// Returns an output array with `values` and `validity`.
+    ///
+    /// The validity is `None` if and only if this array is not nullable.
+    fn output<U: FixedSize>(
+        values: Vec<U>,
+        validity: Option<Bitmap<VecBuffer, u64>>,
+    ) -> Self::Output<U>;
+}
+
+impl<T: FixedSize, Buffer: BufferType> Elementwise for FixedSizePrimitiveArray<T, false, Buffer> {
+    type Item = T;
+    type Output<U: FixedSize> = FixedSizePrimitiveArray<U>;
+
+    fn values(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    fn validity(&self) -> Option<Bitmap<VecBuffer, u64>> {
+        None
+    }
+
+    fn output<U: FixedSize>(
+        values: Vec<U>,
+        _validity: Option<Bitmap<VecBuffer, u64>>,
+    ) -> Self::Output<U> {
+        FixedSizePrimitiveArray(values)
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Elementwise for FixedSizePrimitiveArray<T, true, Buffer> {
+    type Item = T;
+    type Output<U: FixedSize> = FixedSizePrimitiveArray<U, true>;
+
+    fn values(&self) -> &[T] {
+        self.0.data.as_slice()
+    }
+
+    fn validity(&self) -> Option<Bitmap<VecBuffer, u64>> {
+        Some(Bitmap::<VecBuffer, u64>::from_bytes(&self.0.validity))
+    }
+
+    fn output<U: FixedSize>(
+        values: Vec<U>,
+        validity: Option<Bitmap<VecBuffer, u64>>,
+    ) -> Self::Output<U> {
+        FixedSizePrimitiveArray(Nullable {
+            data: values,
+            validity: validity.expect("validity of nullable array").to_bytes(),
+        })
+    }
+}
+
+/// Returns an array with the result of `f` for every value of `array`.
+///
+/// The validity of `array` is kept. `f` is also applied to the values of null
+/// elements, which are unspecified, so it should not panic for any value.
+///
+/// ```
+/// use narrow::{array::Uint8Array, compute::unary};
+///
+/// let array = [Some(1), None, Some(3)]
+///     .into_iter()
+///     .collect::<Uint8Array<true>>();
+/// let doubled = unary(&array, |value| u16::from(value) * 2);
+/// assert_eq!(
+///     doubled.into_iter().collect::<Vec<_>>(),
+///     [Some(2), None, Some(6)]
+/// );
+/// ```
+pub fn unary<A: Elementwise, U: FixedSize>(array: &A, f: impl FnMut(A::Item) -> U) -> A::Output<U> {
+    let values = array.values().iter().copied().map(f).collect();
+    A::output(values, array.validity())
+}
+
+/// Returns an array with the result of `f` for every pair of values of
+/// `left` and `right`.
+///
+/// Both arrays must have the same nullability. An element of the output is
+/// null when it is null in either array. `f` is also applied to the values of
+/// null elements, which are unspecified, so it should not panic for any
+/// value.
+///
+/// # Panics
+///
+/// Panics if the lengths of the arrays differ.
+///
+/// ```
+/// use narrow::{array::{Float32Array, Uint8Array}, compute::binary};
+///
+/// let left = [1, 2, 3].into_iter().collect::<Uint8Array>();
+/// let right = [0.5, 0.25, 2.0].into_iter().collect::<Float32Array>();
+/// let product = binary(&left, &right, |l, r| f32::from(l) * r);
+/// assert_eq!(product.into_iter().collect::<Vec<_>>(), [0.5, 0.5, 6.0]);
+/// ```
+pub fn binary<A: Elementwise, B: Elementwise<Output<V> = A::Output<V>>, V: FixedSize>(
+    left: &A,
+    right: &B,
+    mut f: impl FnMut(A::Item, B::Item) -> V,
+) -> A::Output<V> {
+    assert_eq!(
+        left.len(),
+        right.len(),
+        "len of right array (is {}) should be equal to len of left array (is {})",
+        right.len(),
+        left.len()
+    );
+    let values = left
+        .values()
+        .iter()
+        .zip(right.values())
+        .map(|(&l, &r)| f(l, r))
+        .collect();
+    let validity = match (left.validity(), right.validity()) {
+        (Some(l), Some(r)) => Some(l.and(&r)),
+        (l, r) => l.or(r),
+    };
+    A::output(values, validity)
+}
+
+/// Results of fallible transforms, for [`try_unary`] and [`try_binary`].
+pub trait Fallible<T> {
+    /// Returns the value, or `None` if the transform failed.
+    fn into_option(self) -> Option<T>;
+}
+
+impl<T> Fallible<T> for Option<T> {
+    fn into_option(self) -> Option<T> {
+        self
+    }
+}
+
+impl<T, E> Fallible<T> for Result<T, E> {
+    fn into_option(self) -> Option<T> {
+        self.ok()
+    }
+}
+
+/// The output of [`try_unary`] and [`try_binary`].
+pub struct TryOutput<U: FixedSize> {
+    /// The transformed values, with nulls for the null inputs and the
+    /// failures.
+    pub array: FixedSizePrimitiveArray<U, true>,
+    /// The number of valid inputs for which the transform failed.
+    pub failures: usize,
+}
+
+/// Returns the validity bit at `index`, which is set for arrays that are not
+/// nullable.
+fn is_valid(validity: Option<&Bitmap<VecBuffer, u64>>, index: usize) -> bool {
+    validity.map_or(true, |bitmap| bitmap.get(index).unwrap_or_default())
+}
+
+/// Returns a nullable array with the result of `f` for every valid value of
+/// `array`, where the values for which `f` fails become null.
+///
+/// `f` returns an [`Option`] or a [`Result`], and isn't called for null
+/// elements. The number of failures is returned with the array.
+///
+/// ```
+/// use narrow::{array::Int32Array, compute::try_unary};
+///
+/// let array = [Some(200), None, Some(-1)]
+///     .into_iter()
+///     .collect::<Int32Array<true>>();
+/// let output = try_unary(&array, u8::try_from);
+/// assert_eq!(
+///     output.array.into_iter().collect::<Vec<_>>(),
+///     [Some(200), None, None]
+/// );
+/// assert_eq!(output.failures, 1);
+/// ```
+pub fn try_unary<A: Elementwise, U: FixedSize + Default, R: Fallible<U>>(
+    array: &A,
+    mut f: impl FnMut(A::Item) -> R,
+) -> TryOutput<U> {
+    let validity = array.validity();
+    let mut failures = 0;
+    let output = array
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(index, &value)| {
+            if is_valid(validity.as_ref(), index) {
+                let result = f(value).into_option();
+                failures += usize::from(result.is_none());
+                result
+            } else {
+                None
+            }
+        })
+        .collect();
+    TryOutput {
+        array: output,
+        failures,
+    }
+}
+
+/// Returns a nullable array with the result of `f` for every pair of valid
+/// values of `left` and `right`, where the values for which `f` fails become
+/// null.
+///
+/// `f` returns an [`Option`] or a [`Result`], and isn't called when either
+/// element is null. The arrays can have different nullability. The number of
+/// failures is returned with the array.
+///
+/// # Panics
+///
+/// Panics if the lengths of the arrays differ.
+pub fn try_binary<A: Elementwise, B: Elementwise, V: FixedSize + Default, R: Fallible<V>>(
+    left: &A,
+    right: &B,
+    mut f: impl FnMut(A::Item, B::Item) -> R,
+) -> TryOutput<V> {
+    assert_eq!(
+        left.len(),
+        right.len(),
+        "len of right array (is {}) should be equal to len of left array (is {})",
+        right.len(),
+        left.len()
+    );
+    let (left_validity, right_validity) = (left.validity(), right.validity());
+    let mut failures = 0;
+    let array = left
+        .values()
+        .iter()
+        .zip(right.values())
+        .enumerate()
+        .map(|(index, (&l, &r))| {
+            if is_valid(left_validity.as_ref(), index) && is_valid(right_validity.as_ref(), index) {
+                let result = f(l, r).into_option();
+                failures += usize::from(result.is_none());
+                result
+            } else {
+                None
+            }
+        })
+        .collect();
+    TryOutput { array, failures }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, Uint8Array};
+
+    #[test]
+    fn unary_nullability() {
+        let array = [1, 2, 3].into_iter().collect::<Uint8Array>();
+        let negated: Int32Array = unary(&array, |value| -i32::from(value));
+        assert_eq!(negated.into_iter().collect::<Vec<_>>(), [-1, -2, -3]);
+
+        let nullable = [None, Some(200)].into_iter().collect::<Uint8Array<true>>();
+        let wrapped: Uint8Array<true> = unary(&nullable, |value| value.wrapping_add(100));
+        assert_eq!(wrapped.into_iter().collect::<Vec<_>>(), [None, Some(44)]);
+    }
+
+    #[test]
+    fn binary_validity() {
+        let left = [Some(1), None, Some(3), Some(4)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let right = [Some(10), Some(20), None, Some(40)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            binary(&left, &right, |l, r| l + r)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(11), None, None, Some(44)]
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "len of right array (is 1) should be equal to len of left array (is 2)"
+    )]
+    fn binary_len_mismatch() {
+        let left = [1, 2].into_iter().collect::<Int32Array>();
+        let right = [1].into_iter().collect::<Int32Array>();
+        let _ = binary(&left, &right, |l, r| l + r);
+    }
+
+    #[test]
+    fn try_variants() {
+        let array = [Some(4), Some(0), None, Some(2)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let output = try_unary(&array, |value| 8_i32.checked_div(value));
+        assert_eq!(
+            output.array.into_iter().collect::<Vec<_>>(),
+            [Some(2), None, None, Some(4)]
+        );
+        assert_eq!(output.failures, 1);
+
+        let right = [1, 2, 3, 4].into_iter().collect::<Uint8Array>();
+        let output_binary = try_binary(&array, &right, |l, r| {
+            u8::try_from(l).map(|value| value.saturating_sub(r))
+        });
+        assert_eq!(
+            output_binary.array.into_iter().collect::<Vec<_>>(),
+            [Some(3), Some(0), None, Some(0)]
+        );
+        assert_eq!(output_binary.failures, 0);
+    }
+}
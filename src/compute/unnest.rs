@@ -0,0 +1,212 @@
+//! Kernels that flatten list arrays.
+
+use super::Take;
+use crate::{
+    array::{Array, VariableSizeListArray},
+    offset::OffsetElement,
+    Length,
+};
+use std::ops::Range;
+
+/// List arrays that can be flattened into their child array.
+pub trait Unnest {
+    /// The child array with the elements of the lists.
+    type Child;
+
+    /// Returns the child array with the elements of all lists, and for every
+    /// element the index of the list it belongs to.
+    ///
+    /// Null lists and empty lists have no elements in the child array. When
+    /// the offsets don't span the whole child array, e.g. for a sliced list
+    /// array, only the elements in the range of the offsets are returned.
+    fn unnest(self) -> (Self::Child, Vec<usize>);
+}
+
+impl<T: Array + Length + Take, OffsetItem: OffsetElement> Unnest
+    for VariableSizeListArray<T, false, OffsetItem>
+{
+    type Child = T;
+
+    fn unnest(self) -> (T, Vec<usize>) {
+        let parents = parent_indices(&self.0.offsets);
+        (child_values(self.0.data, &self.0.offsets), parents)
+    }
+}
+
+impl<T: Array + Length + Take, OffsetItem: OffsetElement> Unnest
+    for VariableSizeListArray<T, true, OffsetItem>
+{
+    type Child = T;
+
+    fn unnest(self) -> (T, Vec<usize>) {
+        let parents = parent_indices(&self.0.offsets.data);
+        (child_values(self.0.data, &self.0.offsets.data), parents)
+    }
+}
+
+/// Returns the child array with the elements of all lists in `list`, and for
+/// every element the index of the list it belongs to.
+///
+/// The parent indices can be used to repeat the values of other columns for
+/// every element.
+pub fn unnest<L: Unnest>(list: L) -> (L::Child, Vec<usize>) {
+    list.unnest()
+}
+
+/// Returns a batch with the rows returned by `f` for every row of `batch`.
+///
+/// This is used to flatten a list field of a batch: `f` returns a row for
+/// every element of the list, repeating the values of the other fields. Rows
+/// with empty lists are dropped.
+pub fn explode<A: IntoIterator, I: IntoIterator, Output: FromIterator<I::Item>>(
+    batch: A,
+    f: impl FnMut(A::Item) -> I,
+) -> Output {
+    batch.into_iter().flat_map(f).collect()
+}
+
+/// Returns a list array with `num_parents` lists, where every value is added
+/// to the list at its parent index.
+///
+/// This is the inverse of [`unnest`]. Values keep their relative order within
+/// a list, and parents without values become empty lists.
+///
+/// # Panics
+///
+/// Panics if a parent index is out of bounds, or if the number of values is
+/// not equal to the number of parent indices.
+pub fn collect_lists<U, Output: FromIterator<Vec<U>>>(
+    values: impl IntoIterator<Item = U>,
+    parent_indices: impl IntoIterator<Item = usize>,
+    num_parents: usize,
+) -> Output {
+    let mut lists = Vec::new();
+    lists.resize_with(num_parents, Vec::new);
+    let mut value_iter = values.into_iter();
+    let mut parent_iter = parent_indices.into_iter();
+    loop {
+        match (value_iter.next(), parent_iter.next()) {
+            (Some(value), Some(parent)) => match lists.get_mut(parent) {
+                Some(list) => list.push(value),
+                None => {
+                    panic!("parent index (is {parent}) should be < num parents (is {num_parents})")
+                }
+            },
+            (None, None) => break lists.into_iter().collect(),
+            _ => panic!("number of values should be equal to number of parent indices"),
+        }
+    }
+}
+
+/// Converts an offset to a `usize`.
+fn to_usize<OffsetItem: OffsetElement>(offset: OffsetItem) -> usize {
+    offset
+        .try_into()
+        .expect("offset should be a non-negative usize")
+}
+
+/// Returns the range of the child values referenced by `offsets`.
+fn offsets_range<OffsetItem: OffsetElement>(offsets: &[OffsetItem]) -> Range<usize> {
+    match (offsets.first(), offsets.last()) {
+        (Some(&first), Some(&last)) => to_usize(first)..to_usize(last),
+        _ => 0..0,
+    }
+}
+
+/// Returns the values of `child` referenced by `offsets`, without copying
+/// when the offsets span the whole child array.
+fn child_values<T: Length + Take, OffsetItem: OffsetElement>(
+    child: T,
+    offsets: &[OffsetItem],
+) -> T {
+    let range = offsets_range(offsets);
+    if range.start == 0 && range.end == child.len() {
+        child
+    } else {
+        child.take(&range.collect::<Vec<_>>())
+    }
+}
+
+/// Returns the index of the list of every element, given the offsets of a
+/// list array.
+fn parent_indices<OffsetItem: OffsetElement>(offsets: &[OffsetItem]) -> Vec<usize> {
+    let mut parents = Vec::with_capacity(offsets_range(offsets).len());
+    offsets.windows(2).enumerate().for_each(|(index, window)| {
+        if let [start, end] = *window {
+            parents.extend(std::iter::repeat(index).take(to_usize(end) - to_usize(start)));
+        }
+    });
+    parents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "strings")]
+    use crate::array::StringArray;
+    use crate::array::Uint32Array;
+
+    #[test]
+    fn non_nullable() {
+        let list = [vec![1, 2], vec![], vec![3]]
+            .into_iter()
+            .collect::<VariableSizeListArray<Uint32Array>>();
+        let (child, parents) = unnest(list);
+        assert_eq!(child.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(parents, [0, 0, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn nullable() {
+        let list = [Some(vec!["a"]), None, Some(vec!["b", "c"])]
+            .into_iter()
+            .collect::<VariableSizeListArray<StringArray, true>>();
+        let (child, parents) = unnest(list);
+        assert_eq!(child.into_iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(parents, [0, 2, 2]);
+    }
+
+    #[test]
+    fn sliced_offsets() {
+        let mut list = [vec![1, 2], vec![3], vec![4, 5, 6], vec![7]]
+            .into_iter()
+            .collect::<VariableSizeListArray<Uint32Array>>();
+        list.0.offsets = vec![2, 3, 6];
+        let (child, parents) = unnest(list);
+        assert_eq!(child.into_iter().collect::<Vec<_>>(), [3, 4, 5, 6]);
+        assert_eq!(parents, [0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn collect() {
+        let lists: VariableSizeListArray<Uint32Array> =
+            collect_lists([1, 2, 3, 4], [2, 0, 2, 0], 4);
+        assert_eq!(lists.0.offsets.as_slice(), [0, 2, 2, 4, 4]);
+        assert_eq!(lists.0.data.as_ref(), [2, 4, 1, 3]);
+
+        let list = [vec![1, 2], vec![], vec![3]]
+            .into_iter()
+            .collect::<VariableSizeListArray<Uint32Array>>();
+        let (child, parents) = unnest(list);
+        let round_trip: VariableSizeListArray<Uint32Array> = collect_lists(child, parents, 3);
+        assert_eq!(round_trip.0.offsets.as_slice(), [0, 2, 2, 3]);
+        assert_eq!(round_trip.0.data.as_ref(), [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "parent index (is 1) should be < num parents (is 1)")]
+    fn collect_out_of_bounds() {
+        let _: VariableSizeListArray<Uint32Array> = collect_lists([1], [1], 1);
+    }
+
+    #[test]
+    fn explode_rows() {
+        let rows = [(1, vec!["a", "b"]), (2, vec![]), (3, vec!["c"])];
+        let (ids, tags): (Vec<u32>, Vec<&str>) = explode(rows, |(id, tags)| {
+            tags.into_iter().map(move |tag| (id, tag))
+        });
+        assert_eq!(ids, [1, 1, 3]);
+        assert_eq!(tags, ["a", "b", "c"]);
+    }
+}
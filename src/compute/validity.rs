@@ -0,0 +1,133 @@
+//! Kernels that modify the validity of arrays.
+
+#[cfg(feature = "structs")]
+use crate::array::{StructArray, StructArrayType};
+#[cfg(feature = "strings")]
+use crate::{array::StringArray, offset::OffsetElement};
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray},
+    bitmap::{Bitmap, BitmapRef, BitmapRefMut},
+    buffer::BufferType,
+    validity::Validity,
+    FixedSize, Length,
+};
+
+/// Arrays with a nullable variant.
+///
+/// For nullable arrays the nullable variant is the array itself.
+pub trait IntoNullable: Sized {
+    /// The nullable variant of this array.
+    type Nullable: BitmapRefMut + From<Self>;
+}
+
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> IntoNullable
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<T, true, Buffer>: From<Self>,
+{
+    type Nullable = FixedSizePrimitiveArray<T, true, Buffer>;
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> IntoNullable for BooleanArray<NULLABLE, Buffer>
+where
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    BooleanArray<true, Buffer>: From<Self>,
+{
+    type Nullable = BooleanArray<true, Buffer>;
+}
+
+#[cfg(feature = "strings")]
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> IntoNullable
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    StringArray<true, OffsetItem, Buffer>: From<Self>,
+{
+    type Nullable = StringArray<true, OffsetItem, Buffer>;
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> IntoNullable
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, true, Buffer>: From<Self>,
+{
+    type Nullable = StructArray<T, true, Buffer>;
+}
+
+/// Returns the nullable variant of `array`, with its validity combined with
+/// `validity` using a logical AND.
+///
+/// Values that are valid in `array` but not set in `validity` become null.
+/// This is used to nullify rows conditionally, e.g. for the unmatched rows of
+/// an outer join.
+///
+/// # Panics
+///
+/// Panics if the length of `validity` is not equal to the length of `array`.
+pub fn apply_validity<A: IntoNullable, Buffer: BufferType>(
+    array: A,
+    validity: &Bitmap<Buffer>,
+) -> A::Nullable
+where
+    Bitmap<<A::Nullable as BitmapRef>::Buffer>: FromIterator<bool>,
+{
+    let mut nullable = A::Nullable::from(array);
+    let bitmap = nullable.bitmap_ref_mut();
+    assert_eq!(
+        bitmap.len(),
+        validity.len(),
+        "validity len (is {}) should be equal to array len (is {})",
+        validity.len(),
+        bitmap.len()
+    );
+    *bitmap = bitmap
+        .iter()
+        .zip(validity)
+        .map(|(valid, mask)| valid && mask)
+        .collect();
+    nullable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+    #[cfg(feature = "strings")]
+    use crate::{array::StringArray, bitmap::ValidityBitmap};
+
+    #[test]
+    fn non_nullable() {
+        let array = [1, 2, 3].into_iter().collect::<Int32Array>();
+        let mask = [true, false, true].into_iter().collect::<Bitmap>();
+        let nullable = apply_validity(array, &mask);
+        assert_eq!(
+            nullable.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn nullable() {
+        let array = [Some("a"), None, Some("c")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let mask = [true, true, false].into_iter().collect::<Bitmap>();
+        let masked = apply_validity(array, &mask);
+        assert_eq!(masked.null_count(), 2);
+        assert_eq!(
+            masked.into_iter().collect::<Vec<_>>(),
+            [Some("a"), None, None]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "validity len (is 1) should be equal to array len (is 2)")]
+    fn len_mismatch() {
+        let array = [1, 2].into_iter().collect::<Int32Array>();
+        let _ = apply_validity(array, &[true].into_iter().collect::<Bitmap>());
+    }
+}
@@ -0,0 +1,131 @@
+//! Access to the values of arrays.
+
+#[cfg(feature = "strings")]
+use crate::{array::StringArray, offset::OffsetElement};
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray},
+    bitmap::Bitmap,
+    buffer::{Buffer as _, BufferType},
+    FixedSize,
+};
+
+/// Arrays with values that can be compared and visited by compute kernels.
+///
+/// This trait is available without the `compute` feature, because the
+/// [`Debug`](std::fmt::Debug) output of arrays is based on it.
+pub trait ColumnValues {
+    /// The type of the values in this array.
+    type Scalar: PartialOrd + ?Sized;
+
+    /// Returns a bitmap with a set bit for every non-null value for which
+    /// `predicate` returns `true`.
+    fn select(&self, predicate: impl FnMut(&Self::Scalar) -> bool) -> Bitmap;
+
+    /// Calls `f` for every value in this array, with `None` for null values.
+    fn for_each_value(&self, f: impl FnMut(Option<&Self::Scalar>));
+}
+
+impl<T: FixedSize + PartialOrd, Buffer: BufferType> ColumnValues
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    type Scalar = T;
+
+    fn select(&self, predicate: impl FnMut(&T) -> bool) -> Bitmap {
+        self.0.as_slice().iter().map(predicate).collect()
+    }
+
+    fn for_each_value(&self, f: impl FnMut(Option<&T>)) {
+        self.0.as_slice().iter().map(Some).for_each(f);
+    }
+}
+
+impl<T: FixedSize + PartialOrd, Buffer: BufferType> ColumnValues
+    for FixedSizePrimitiveArray<T, true, Buffer>
+{
+    type Scalar = T;
+
+    fn select(&self, mut predicate: impl FnMut(&T) -> bool) -> Bitmap {
+        self.0
+            .data
+            .as_slice()
+            .iter()
+            .zip(self.0.validity.iter())
+            .map(|(value, valid)| valid && predicate(value))
+            .collect()
+    }
+
+    fn for_each_value(&self, f: impl FnMut(Option<&T>)) {
+        self.0
+            .data
+            .as_slice()
+            .iter()
+            .zip(self.0.validity.iter())
+            .map(|(value, valid)| valid.then_some(value))
+            .for_each(f);
+    }
+}
+
+impl<Buffer: BufferType> ColumnValues for BooleanArray<false, Buffer> {
+    type Scalar = bool;
+
+    fn select(&self, mut predicate: impl FnMut(&bool) -> bool) -> Bitmap {
+        self.0.iter().map(|value| predicate(&value)).collect()
+    }
+
+    fn for_each_value(&self, mut f: impl FnMut(Option<&bool>)) {
+        self.0.iter().for_each(|value| f(Some(&value)));
+    }
+}
+
+impl<Buffer: BufferType> ColumnValues for BooleanArray<true, Buffer> {
+    type Scalar = bool;
+
+    fn select(&self, mut predicate: impl FnMut(&bool) -> bool) -> Bitmap {
+        self.0
+            .data
+            .iter()
+            .zip(self.0.validity.iter())
+            .map(|(value, valid)| valid && predicate(&value))
+            .collect()
+    }
+
+    fn for_each_value(&self, mut f: impl FnMut(Option<&bool>)) {
+        self.0
+            .data
+            .iter()
+            .zip(self.0.validity.iter())
+            .for_each(|(value, valid)| f(valid.then_some(&value)));
+    }
+}
+
+#[cfg(feature = "strings")]
+impl<OffsetItem: OffsetElement, Buffer: BufferType> ColumnValues
+    for StringArray<false, OffsetItem, Buffer>
+{
+    type Scalar = str;
+
+    fn select(&self, predicate: impl FnMut(&str) -> bool) -> Bitmap {
+        self.into_iter().map(predicate).collect()
+    }
+
+    fn for_each_value(&self, f: impl FnMut(Option<&str>)) {
+        self.into_iter().map(Some).for_each(f);
+    }
+}
+
+#[cfg(feature = "strings")]
+impl<OffsetItem: OffsetElement, Buffer: BufferType> ColumnValues
+    for StringArray<true, OffsetItem, Buffer>
+{
+    type Scalar = str;
+
+    fn select(&self, mut predicate: impl FnMut(&str) -> bool) -> Bitmap {
+        self.into_iter()
+            .map(|value| value.is_some_and(&mut predicate))
+            .collect()
+    }
+
+    fn for_each_value(&self, f: impl FnMut(Option<&str>)) {
+        self.into_iter().for_each(f);
+    }
+}
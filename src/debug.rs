@@ -0,0 +1,493 @@
+//! Utilities to inspect the memory layout of arrays.
+
+#[cfg(feature = "strings")]
+use crate::array::StringArray;
+#[cfg(feature = "lists")]
+use crate::array::{Array, VariableSizeListArray};
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray, VariableSizeBinaryArray},
+    bitmap::Bitmap,
+    buffer::{Buffer, BufferType},
+    compute::ColumnValues,
+    offset::OffsetElement,
+    validity::Validity,
+    FixedSize,
+};
+use std::{
+    cell::Cell,
+    fmt::{self, Debug, Formatter, Write},
+};
+
+/// The number of bytes on a line of a hex dump.
+const BYTES_PER_LINE: usize = 16;
+
+/// What the [`Debug`] output of arrays shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DebugMode {
+    /// The values of the array, with `null` for null values.
+    #[default]
+    Values,
+    /// The bytes of the buffers of the array.
+    Buffers,
+}
+
+impl DebugMode {
+    /// Returns the other mode.
+    #[must_use]
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Values => Self::Buffers,
+            Self::Buffers => Self::Values,
+        }
+    }
+}
+
+/// Options for the [`Debug`] output of arrays.
+///
+/// The options are set per thread with [`set_debug_options`]. The alternate
+/// flag (`{:#?}`) toggles the [`DebugMode`] for a single call.
+///
+/// The options only apply to fixed-size primitive, boolean and string arrays.
+/// Lists, structs and [`Bitmap`]s keep their own [`Debug`] output, which
+/// shows every value and ignores `max_items`. The buffers of lists can be
+/// inspected with [`hexdump`] instead.
+///
+/// ```
+/// use narrow::{
+///     array::Uint16Array,
+///     debug::{set_debug_options, DebugMode, DebugOptions},
+/// };
+///
+/// let array = (0..1000).collect::<Uint16Array>();
+/// set_debug_options(
+///     DebugOptions::default()
+///         .with_max_items(3)
+///         .with_mode(DebugMode::Values),
+/// );
+/// assert_eq!(
+///     format!("{array:?}"),
+///     "FixedSizePrimitiveArray [0, 1, 2, ... 997 more]"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DebugOptions {
+    /// The maximum number of values, or bytes per buffer, to show.
+    pub max_items: usize,
+    /// What to show.
+    pub mode: DebugMode,
+}
+
+impl Default for DebugOptions {
+    fn default() -> Self {
+        Self {
+            max_items: 32,
+            mode: DebugMode::default(),
+        }
+    }
+}
+
+impl DebugOptions {
+    /// Sets the maximum number of values, or bytes per buffer, to show.
+    #[must_use]
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items;
+        self
+    }
+
+    /// Sets what to show.
+    #[must_use]
+    pub fn with_mode(mut self, mode: DebugMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+thread_local! {
+    /// The debug options of the current thread.
+    static DEBUG_OPTIONS: Cell<DebugOptions> = Cell::new(DebugOptions::default());
+}
+
+/// Returns the [`DebugOptions`] of the current thread.
+#[must_use]
+pub fn debug_options() -> DebugOptions {
+    DEBUG_OPTIONS.with(Cell::get)
+}
+
+/// Sets the [`DebugOptions`] of the current thread.
+pub fn set_debug_options(options: DebugOptions) {
+    DEBUG_OPTIONS.with(|cell| cell.set(options));
+}
+
+/// A buffer of an array, visited by [`Buffers::visit_buffers`].
+#[derive(Clone, Copy, Debug)]
+pub struct BufferView<'a> {
+    /// The path of the buffer, e.g. `offsets` or `values.data`.
+    pub path: &'a str,
+    /// A description of the contents of the buffer.
+    pub annotation: &'a str,
+    /// The bytes of the buffer.
+    pub bytes: &'a [u8],
+}
+
+/// Arrays with buffers that can be inspected.
+pub trait Buffers {
+    /// Calls `visit` for every buffer of this array, in a fixed order.
+    ///
+    /// The paths of the buffers are prefixed with `path`, which is used for
+    /// the buffers of child arrays.
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>));
+}
+
+/// Returns the path of the buffer or child `name` of the array at `path`.
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+/// Visits the buffer of a bitmap.
+fn visit_bitmap<Buffer: BufferType>(
+    path: &str,
+    name: &str,
+    bitmap: &Bitmap<Buffer>,
+    visit: &mut dyn FnMut(BufferView<'_>),
+) {
+    visit(BufferView {
+        path: &join(path, name),
+        annotation: &format!("{} bits, offset {}", bitmap.bits, bitmap.offset),
+        bytes: bitmap.buffer.as_slice(),
+    });
+}
+
+/// Visits a buffer of fixed-size values.
+fn visit_values<T: FixedSize>(
+    path: &str,
+    name: &str,
+    values: &impl Buffer<T>,
+    visit: &mut dyn FnMut(BufferView<'_>),
+) {
+    visit(BufferView {
+        path: &join(path, name),
+        annotation: &format!("{} items", values.as_slice().len()),
+        bytes: values.as_bytes(),
+    });
+}
+
+impl<T: FixedSize, Buffer: BufferType> Buffers for FixedSizePrimitiveArray<T, false, Buffer> {
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_values(path, "data", &self.0, visit);
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Buffers for FixedSizePrimitiveArray<T, true, Buffer> {
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_bitmap(path, "validity", &self.0.validity, visit);
+        visit_values(path, "data", &self.0.data, visit);
+    }
+}
+
+impl<Buffer: BufferType> Buffers for BooleanArray<false, Buffer> {
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_bitmap(path, "data", &self.0, visit);
+    }
+}
+
+impl<Buffer: BufferType> Buffers for BooleanArray<true, Buffer> {
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_bitmap(path, "validity", &self.0.validity, visit);
+        visit_bitmap(path, "data", &self.0.data, visit);
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Buffers
+    for VariableSizeBinaryArray<false, OffsetItem, Buffer>
+{
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_values(path, "offsets", &self.0.offsets, visit);
+        self.0.data.visit_buffers(path, visit);
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Buffers
+    for VariableSizeBinaryArray<true, OffsetItem, Buffer>
+{
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_bitmap(path, "validity", &self.0.offsets.validity, visit);
+        visit_values(path, "offsets", &self.0.offsets.data, visit);
+        self.0.data.visit_buffers(path, visit);
+    }
+}
+
+#[cfg(feature = "strings")]
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Buffers
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: Buffers,
+{
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        self.0.visit_buffers(path, visit);
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<T: Array + Buffers, OffsetItem: OffsetElement, Buffer: BufferType> Buffers
+    for VariableSizeListArray<T, false, OffsetItem, Buffer>
+{
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_values(path, "offsets", &self.0.offsets, visit);
+        self.0.data.visit_buffers(&join(path, "values"), visit);
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<T: Array + Buffers, OffsetItem: OffsetElement, Buffer: BufferType> Buffers
+    for VariableSizeListArray<T, true, OffsetItem, Buffer>
+{
+    fn visit_buffers(&self, path: &str, visit: &mut dyn FnMut(BufferView<'_>)) {
+        visit_bitmap(path, "validity", &self.0.offsets.validity, visit);
+        visit_values(path, "offsets", &self.0.offsets.data, visit);
+        self.0.data.visit_buffers(&join(path, "values"), visit);
+    }
+}
+
+/// The bytes of a buffer, formatted as hex, limited to `max_items` bytes.
+struct BytesDebug<'a> {
+    /// The buffer.
+    buffer: BufferView<'a>,
+    /// The maximum number of bytes to show.
+    max_items: usize,
+}
+
+impl Debug for BytesDebug<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [", self.buffer.annotation)?;
+        for (index, byte) in self.buffer.bytes.iter().take(self.max_items).enumerate() {
+            if index != 0 {
+                f.write_char(' ')?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        if let Some(more) = self.buffer.bytes.len().checked_sub(self.max_items) {
+            if more != 0 {
+                write!(f, " ... {more} more")?;
+            }
+        }
+        f.write_char(']')
+    }
+}
+
+/// Formats `array` with the given `name`, using the [`DebugOptions`] of the
+/// current thread.
+fn fmt_array<A: Buffers + ColumnValues>(array: &A, name: &str, f: &mut Formatter<'_>) -> fmt::Result
+where
+    A::Scalar: Debug,
+{
+    let options = debug_options();
+    let mode = if f.alternate() {
+        options.mode.toggle()
+    } else {
+        options.mode
+    };
+    match mode {
+        DebugMode::Values => {
+            write!(f, "{name} ")?;
+            let mut list = f.debug_list();
+            let mut count = 0;
+            array.for_each_value(|value_opt| {
+                if count < options.max_items {
+                    match value_opt {
+                        Some(value) => list.entry(&value),
+                        None => list.entry(&format_args!("null")),
+                    };
+                }
+                count += 1;
+            });
+            if count > options.max_items {
+                list.entry(&format_args!("... {} more", count - options.max_items));
+            }
+            list.finish()
+        }
+        DebugMode::Buffers => {
+            let mut debug = f.debug_struct(name);
+            array.visit_buffers("", &mut |buffer| {
+                debug.field(
+                    buffer.path,
+                    &BytesDebug {
+                        buffer,
+                        max_items: options.max_items,
+                    },
+                );
+            });
+            debug.finish()
+        }
+    }
+}
+
+impl<T: FixedSize + PartialOrd + Debug, const NULLABLE: bool, Buffer: BufferType> Debug
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: Buffers + ColumnValues<Scalar = T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_array(self, "FixedSizePrimitiveArray", f)
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Debug for BooleanArray<NULLABLE, Buffer>
+where
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    Self: Buffers + ColumnValues<Scalar = bool>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_array(self, "BooleanArray", f)
+    }
+}
+
+#[cfg(feature = "strings")]
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Debug
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Self: Buffers + ColumnValues<Scalar = str>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_array(self, "StringArray", f)
+    }
+}
+
+/// Returns a hex dump of all the buffers of `array`.
+///
+/// The output is stable, so it can be used in snapshot tests. Every buffer
+/// starts with a header with its path, its annotation and its length in
+/// bytes, followed by lines with the offset, the bytes in hex and the bytes
+/// as ASCII.
+///
+/// ```
+/// use narrow::{array::Uint16Array, debug::hexdump};
+///
+/// let array = [1, 2, 3].into_iter().collect::<Uint16Array>();
+/// assert_eq!(
+///     hexdump(&array),
+///     "data: 3 items, 6 bytes\n00000000  01 00 02 00 03 00                                |......|\n"
+/// );
+/// ```
+pub fn hexdump(array: &impl Buffers) -> String {
+    let mut output = String::new();
+    array.visit_buffers("", &mut |buffer| {
+        write_buffer(&mut output, buffer);
+    });
+    output
+}
+
+/// Writes the hex dump of a single buffer to `output`.
+fn write_buffer(output: &mut String, buffer: BufferView<'_>) {
+    // Writing to a string is infallible.
+    let _ = writeln!(
+        output,
+        "{}: {}, {} bytes",
+        buffer.path,
+        buffer.annotation,
+        buffer.bytes.len()
+    );
+    for (line, chunk) in buffer.bytes.chunks(BYTES_PER_LINE).enumerate() {
+        let _ = write!(output, "{:08x} ", line * BYTES_PER_LINE);
+        for index in 0..BYTES_PER_LINE {
+            if index % 8 == 0 {
+                output.push(' ');
+            }
+            match chunk.get(index) {
+                Some(byte) => {
+                    let _ = write!(output, "{byte:02x} ");
+                }
+                None => output.push_str("   "),
+            }
+        }
+        output.push('|');
+        output.extend(chunk.iter().map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                char::from(byte)
+            } else {
+                '.'
+            }
+        }));
+        output.push_str("|\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint32Array;
+    #[cfg(feature = "lists")]
+    use crate::Length;
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn nullable_string() {
+        let array = [Some("hello"), None, Some("world!")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        assert_eq!(
+            hexdump(&array),
+            "\
+validity: 3 bits, offset 0, 1 bytes
+00000000  05                                               |.|
+offsets: 4 items, 16 bytes
+00000000  00 00 00 00 05 00 00 00  05 00 00 00 0b 00 00 00 |................|
+data: 11 items, 11 bytes
+00000000  68 65 6c 6c 6f 77 6f 72  6c 64 21                |helloworld!|
+"
+        );
+    }
+
+    #[test]
+    fn debug_options() {
+        assert_eq!(super::debug_options(), DebugOptions::default());
+        set_debug_options(DebugOptions {
+            max_items: 2,
+            mode: DebugMode::Buffers,
+        });
+
+        let array = [1, 2, 3].into_iter().collect::<Uint32Array>();
+        assert_eq!(
+            format!("{array:?}"),
+            "FixedSizePrimitiveArray { data: 3 items [01 00 ... 10 more] }"
+        );
+        #[cfg(feature = "strings")]
+        let strings = [Some("a"), None].into_iter().collect::<StringArray<true>>();
+        #[cfg(feature = "strings")]
+        assert_eq!(
+            format!("{strings:#?}"),
+            "StringArray [\n    \"a\",\n    null,\n]"
+        );
+
+        set_debug_options(DebugOptions::default());
+        let booleans = [true, false, true].into_iter().collect::<BooleanArray>();
+        assert_eq!(format!("{booleans:?}"), "BooleanArray [true, false, true]");
+    }
+
+    #[test]
+    #[cfg(feature = "lists")]
+    fn list() {
+        let array = [vec![1], vec![2, 3]]
+            .into_iter()
+            .collect::<VariableSizeListArray<Uint32Array>>();
+        let mut paths = Vec::new();
+        array.visit_buffers("list", &mut |buffer| {
+            paths.push((buffer.path.to_owned(), buffer.bytes.len()));
+        });
+        assert_eq!(
+            paths,
+            [
+                ("list.offsets".to_owned(), 12),
+                ("list.values.data".to_owned(), 12)
+            ]
+        );
+        assert_eq!(array.len(), 2);
+    }
+}
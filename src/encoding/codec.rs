@@ -0,0 +1,381 @@
+//! Buffer-level codecs.
+
+use super::{DeltaArray, Integer, TruncatedArray};
+use crate::{buffer::SliceBuffer, Length};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// Error returned when a [`BufferCodec`] fails to encode or decode its
+/// input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CodecError {
+    /// The length of the input is not valid for the codec.
+    InvalidLength {
+        /// The length of the input in bytes.
+        len: usize,
+        /// The length of the input must be a multiple of this value.
+        multiple_of: usize,
+    },
+    /// A codec specific error.
+    Other(String),
+}
+
+impl Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidLength { len, multiple_of } => write!(
+                f,
+                "invalid input length (is {len}), should be a multiple of {multiple_of}"
+            ),
+            Self::Other(ref message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error for CodecError {}
+
+/// Encoding of raw buffer bytes.
+///
+/// Implementations must guarantee that decoding the encoded bytes returns
+/// the original input.
+pub trait BufferCodec {
+    /// Returns the identifier of this codec, used to look it up in a
+    /// [`CodecRegistry`].
+    fn id(&self) -> &str;
+
+    /// Appends the encoding of `input` to `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `input` can not be encoded by this codec, e.g.
+    /// when its length is not a multiple of the size of the values.
+    fn encode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError>;
+
+    /// Appends the decoding of `input` to `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `input` is not a valid encoding.
+    fn decode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError>;
+}
+
+/// Returns the values of the little-endian encoded integers in `input`.
+fn read_integers<T: Integer>(input: &[u8]) -> Result<impl Iterator<Item = T> + '_, CodecError> {
+    if input.len() % T::SIZE == 0 {
+        Ok(input.chunks_exact(T::SIZE).map(T::from_le_slice))
+    } else {
+        Err(CodecError::InvalidLength {
+            len: input.len(),
+            multiple_of: T::SIZE,
+        })
+    }
+}
+
+/// Appends the byte width, the number of values (as little-endian `u64`)
+/// and the bytes of `values` to `output`.
+fn write_truncated<T: Integer>(values: &TruncatedArray<T>, output: &mut Vec<u8>) {
+    output.push(u8::try_from(values.width()).expect("width fits in a byte"));
+    output.extend_from_slice(
+        &u64::try_from(values.len())
+            .expect("len fits in 64 bits")
+            .to_le_bytes(),
+    );
+    output.extend_from_slice(values.as_bytes());
+}
+
+/// Returns the little-endian integer at the start of `input`, and the
+/// remaining bytes.
+fn split_first_value<T: Integer>(input: &[u8]) -> Result<(T, &[u8]), CodecError> {
+    if input.len() < T::SIZE {
+        return Err(CodecError::Other("missing first value".to_owned()));
+    }
+    let (value, rest) = input.split_at(T::SIZE);
+    Ok((T::from_le_slice(value), rest))
+}
+
+/// Returns the values written by [`write_truncated`] to `input`.
+fn read_truncated<T: Integer>(
+    input: &[u8],
+) -> Result<TruncatedArray<T, SliceBuffer<'_>>, CodecError> {
+    let invalid = || CodecError::Other("invalid truncated integers".to_owned());
+    let (&width, rest) = input.split_first().ok_or_else(invalid)?;
+    let (len_bytes, bytes) = (rest.get(..8).ok_or_else(invalid)?, &rest[8..]);
+    let len = usize::try_from(u64::from_le_bytes(len_bytes.try_into().expect("8 bytes")))
+        .map_err(|_| invalid())?;
+    TruncatedArray::from_parts(bytes, usize::from(width), len).ok_or_else(invalid)
+}
+
+/// [`BufferCodec`] for [delta encoding](super::DeltaArray) of little-endian
+/// integer buffers.
+///
+/// The identifier of the codec includes the value type, e.g. `delta-i32`, so
+/// codecs for different widths can be registered side by side.
+///
+/// The encoded buffer starts with the first value, followed by the zigzag
+/// encoded deltas in the narrowest byte width that fits all of them, prefixed
+/// by the width (one byte) and the number of values (little-endian `u64`).
+/// Empty buffers are encoded as empty buffers.
+#[derive(Clone, Debug)]
+pub struct DeltaCodec<T> {
+    /// The identifier of this codec, e.g. `delta-i32`.
+    id: String,
+    /// The type of the values.
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T: Integer> Default for DeltaCodec<T> {
+    fn default() -> Self {
+        Self {
+            id: format!("delta-{}", T::NAME),
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<T: Integer> BufferCodec for DeltaCodec<T> {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn encode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+        let array = read_integers::<T>(input)?.collect::<DeltaArray<T>>();
+        if !input.is_empty() {
+            array.first.write_le(output);
+            write_truncated(&array.deltas, output);
+        }
+        Ok(())
+    }
+
+    fn decode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+        if !input.is_empty() {
+            let (first, deltas) = split_first_value::<T>(input)?;
+            let array = DeltaArray {
+                first,
+                deltas: read_truncated::<T>(deltas)?,
+            };
+            for value in &array {
+                value.write_le(output);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// [`BufferCodec`] for [frame-of-reference
+/// encoding](super::FrameOfReferenceArray) of little-endian integer buffers.
+///
+/// The identifier of the codec includes the value type, e.g.
+/// `frame-of-reference-i32`.
+///
+/// The encoded buffer starts with the reference value, followed by the
+/// offsets of the values in the narrowest byte width that fits all of them,
+/// prefixed by the width (one byte) and the number of values (little-endian
+/// `u64`). Empty buffers are encoded as empty buffers.
+#[derive(Clone, Debug)]
+pub struct FrameOfReferenceCodec<T> {
+    /// The identifier of this codec, e.g. `frame-of-reference-i32`.
+    id: String,
+    /// The type of the values.
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T: Integer> Default for FrameOfReferenceCodec<T> {
+    fn default() -> Self {
+        Self {
+            id: format!("frame-of-reference-{}", T::NAME),
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<T: Integer> BufferCodec for FrameOfReferenceCodec<T> {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn encode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+        let reference = read_integers::<T>(input)?.min().unwrap_or_default();
+        if !input.is_empty() {
+            reference.write_le(output);
+            let offsets = read_integers::<T>(input)?
+                .map(|value| value.wrapping_sub(reference))
+                .collect();
+            write_truncated(&offsets, output);
+        }
+        Ok(())
+    }
+
+    fn decode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+        if !input.is_empty() {
+            let (reference, offsets) = split_first_value::<T>(input)?;
+            for offset in &read_truncated::<T>(offsets)? {
+                reference.wrapping_add(offset).write_le(output);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Registry of [`BufferCodec`]s by their identifier.
+///
+/// This allows registering custom codecs without modifying this crate.
+#[derive(Clone, Default)]
+pub struct CodecRegistry {
+    /// The registered codecs.
+    codecs: HashMap<String, Arc<dyn BufferCodec + Send + Sync>>,
+}
+
+impl CodecRegistry {
+    /// Registers a codec, returning the codec that was previously registered
+    /// with the same identifier.
+    pub fn register(
+        &mut self,
+        codec: impl BufferCodec + Send + Sync + 'static,
+    ) -> Option<Arc<dyn BufferCodec + Send + Sync>> {
+        self.codecs.insert(codec.id().to_owned(), Arc::new(codec))
+    }
+
+    /// Returns the codec registered with the given identifier.
+    pub fn get(&self, id: &str) -> Option<&(dyn BufferCodec + Send + Sync)> {
+        self.codecs.get(id).map(AsRef::as_ref)
+    }
+
+    /// Returns an iterator over the identifiers of the registered codecs.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.codecs.keys().map(String::as_str)
+    }
+}
+
+impl fmt::Debug for CodecRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.ids()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(codec: &dyn BufferCodec, input: &[u8]) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        codec.encode(input, &mut encoded).expect("a valid input");
+        let mut decoded = Vec::new();
+        codec
+            .decode(&encoded, &mut decoded)
+            .expect("a valid encoding");
+        decoded
+    }
+
+    #[test]
+    fn delta() {
+        let input = [10_u32, 12, 15]
+            .into_iter()
+            .flat_map(u32::to_le_bytes)
+            .collect::<Vec<_>>();
+        assert_eq!(round_trip(&DeltaCodec::<u32>::default(), &input), input);
+        assert!(round_trip(&DeltaCodec::<u32>::default(), &[]).is_empty());
+        assert_eq!(
+            DeltaCodec::<u32>::default().decode(&[1, 2, 3], &mut Vec::new()),
+            Err(CodecError::Other("missing first value".to_owned()))
+        );
+        let mut encoded = Vec::new();
+        assert_eq!(
+            DeltaCodec::<u32>::default().encode(&[1, 0, 0, 0, 2], &mut encoded),
+            Err(CodecError::InvalidLength {
+                len: 5,
+                multiple_of: 4
+            })
+        );
+        assert!(encoded.is_empty());
+    }
+
+    #[test]
+    fn delta_width() {
+        let input = (0..100_u64)
+            .map(|second| 1_700_000_000 + 60 * second)
+            .flat_map(u64::to_le_bytes)
+            .collect::<Vec<_>>();
+        let codec = DeltaCodec::<u64>::default();
+        let mut encoded = Vec::new();
+        codec.encode(&input, &mut encoded).expect("a valid input");
+        // The first value, the width, the number of values and the deltas.
+        assert_eq!(encoded.len(), 8 + 1 + 8 + 100);
+        assert_eq!(encoded[8], 1);
+        assert_eq!(round_trip(&codec, &input), input);
+        assert_eq!(
+            codec.decode(&encoded[..20], &mut Vec::new()),
+            Err(CodecError::Other("invalid truncated integers".to_owned()))
+        );
+    }
+
+    #[test]
+    fn frame_of_reference() {
+        let input = [-5_i16, 7, 0]
+            .into_iter()
+            .flat_map(i16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let codec = FrameOfReferenceCodec::<i16>::default();
+        assert_eq!(round_trip(&codec, &input), input);
+        assert!(round_trip(&codec, &[]).is_empty());
+        let mut encoded = Vec::new();
+        codec.encode(&input, &mut encoded).expect("a valid input");
+        assert_eq!(encoded, [251, 255, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 12, 5]);
+        assert_eq!(
+            codec.decode(&encoded[..1], &mut Vec::new()),
+            Err(CodecError::Other("missing first value".to_owned()))
+        );
+        assert_eq!(
+            codec.decode(&encoded[..13], &mut Vec::new()),
+            Err(CodecError::Other("invalid truncated integers".to_owned()))
+        );
+        assert_eq!(
+            codec.encode(&input[1..], &mut Vec::new()),
+            Err(CodecError::InvalidLength {
+                len: 5,
+                multiple_of: 2
+            })
+        );
+    }
+
+    #[test]
+    fn registry() {
+        struct Reverse;
+        impl BufferCodec for Reverse {
+            fn id(&self) -> &'static str {
+                "reverse"
+            }
+            fn encode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+                output.extend(input.iter().rev());
+                Ok(())
+            }
+            fn decode(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+                output.extend(input.iter().rev());
+                Ok(())
+            }
+        }
+
+        let mut registry = CodecRegistry::default();
+        assert!(registry.register(Reverse).is_none());
+        assert!(registry.register(DeltaCodec::<u8>::default()).is_none());
+        assert!(registry.register(DeltaCodec::<i64>::default()).is_none());
+        assert!(registry
+            .register(FrameOfReferenceCodec::<u16>::default())
+            .is_none());
+        assert_eq!(registry.ids().count(), 4);
+        let input = [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        let wide = registry.get("delta-i64").expect("a registered codec");
+        assert_eq!(round_trip(wide, &input), input);
+        let narrow = registry.get("delta-u8").expect("a registered codec");
+        assert_eq!(round_trip(narrow, &input), input);
+        assert!(registry.get("frame-of-reference-u16").is_some());
+        let codec = registry.get("reverse").expect("a registered codec");
+        assert_eq!(round_trip(codec, &[1, 2, 3]), [1, 2, 3]);
+        assert!(registry.get("fsst").is_none());
+    }
+}
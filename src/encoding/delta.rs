@@ -0,0 +1,248 @@
+//! Delta encoding.
+
+use super::{Integer, TruncatedArray, TruncatedIter};
+use crate::{
+    array::{Array, FixedSizePrimitiveArray},
+    buffer::{Buffer, BufferType, VecBuffer},
+    Length,
+};
+use std::iter::{FusedIterator, Map};
+
+/// Returns the delta encoding of the values in the array.
+///
+/// The first value is stored as is, followed by the (wrapping) differences
+/// between consecutive values. This works for any integer array, but is most
+/// effective for monotonically increasing values like timestamps, where the
+/// deltas are small.
+pub fn delta_encode<T: Integer, Buffer: BufferType>(
+    array: &FixedSizePrimitiveArray<T, false, Buffer>,
+) -> FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+{
+    let mut previous = T::default();
+    array
+        .0
+        .as_slice()
+        .iter()
+        .map(|&value| {
+            let delta = value.wrapping_sub(previous);
+            previous = value;
+            delta
+        })
+        .collect()
+}
+
+/// Returns the values of a delta encoded array, as returned by
+/// [`delta_encode`].
+pub fn delta_decode<T: Integer, Buffer: BufferType>(
+    deltas: &FixedSizePrimitiveArray<T, false, Buffer>,
+) -> FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+{
+    DeltaIter::new(T::default(), deltas.0.as_slice().iter().copied()).collect()
+}
+
+/// Returns the mask of the bits of `T`.
+fn mask<T: Integer>() -> u128 {
+    u128::MAX >> (128 - 8 * T::SIZE)
+}
+
+/// Returns the zigzag encoding of `delta`, which maps deltas close to zero,
+/// in both directions, to small unsigned values: 0, -1, 1, -2, ... map to 0,
+/// 1, 2, 3, ...
+fn zigzag<T: Integer>(delta: T) -> T {
+    let bits = delta.to_bits();
+    let sign = if bits >> (8 * T::SIZE - 1) == 1 {
+        mask::<T>()
+    } else {
+        0
+    };
+    T::from_bits((bits << 1) ^ sign)
+}
+
+/// Returns the delta of a zigzag encoded value, as returned by [`zigzag`].
+fn unzigzag<T: Integer>(value: T) -> T {
+    let bits = value.to_bits();
+    let sign = if bits & 1 == 1 { mask::<T>() } else { 0 };
+    T::from_bits((bits >> 1) ^ sign)
+}
+
+/// Array with delta encoded integer values.
+///
+/// The first value is stored as is, and the differences between consecutive
+/// values are zigzag encoded, mapping small negative deltas to small unsigned
+/// values, and stored in a [`TruncatedArray`] with the narrowest byte width
+/// that fits all of them. Monotonic values with small steps, like timestamps,
+/// take a fraction of the size of their type. The values are decoded when
+/// iterated.
+///
+/// ```
+/// use narrow::{encoding::DeltaArray, Length};
+///
+/// let input = [1_700_000_000_i64, 1_700_000_060, 1_700_000_030];
+/// let array = input.into_iter().collect::<DeltaArray<i64>>();
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.first, 1_700_000_000);
+/// assert_eq!(array.deltas.width(), 1);
+/// assert_eq!(array.iter().collect::<Vec<_>>(), input);
+/// ```
+pub struct DeltaArray<T: Integer, Buffer: BufferType = VecBuffer> {
+    /// The first value, or the default value for empty arrays.
+    pub first: T,
+    /// The zigzag encoded differences of the values with their previous
+    /// value, starting with the first value.
+    pub deltas: TruncatedArray<T, Buffer>,
+}
+
+impl<T: Integer, Buffer: BufferType> DeltaArray<T, Buffer> {
+    /// Returns a new [`DeltaArray`] with the delta encoded values of `array`.
+    pub fn encode(array: &FixedSizePrimitiveArray<T, false, Buffer>) -> Self
+    where
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        array.0.as_slice().iter().copied().collect()
+    }
+
+    /// Returns the decoded values of this array.
+    pub fn decode(&self) -> FixedSizePrimitiveArray<T, false, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+    {
+        self.iter().collect()
+    }
+
+    /// Returns an iterator over the decoded values of this array.
+    pub fn iter(&self) -> DeltaArrayIter<'_, T, Buffer> {
+        DeltaIter::new(self.first, self.deltas.iter().map(unzigzag))
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> Array for DeltaArray<T, Buffer> {
+    type Item = T;
+}
+
+impl<T: Integer, Buffer: BufferType> FromIterator<T> for DeltaArray<T, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values = iter.into_iter().peekable();
+        let first = values.peek().copied().unwrap_or_default();
+        let mut previous = first;
+        let deltas = values
+            .map(|value| {
+                let delta = value.wrapping_sub(previous);
+                previous = value;
+                zigzag(delta)
+            })
+            .collect();
+        Self { first, deltas }
+    }
+}
+
+impl<'a, T: Integer, Buffer: BufferType> IntoIterator for &'a DeltaArray<T, Buffer> {
+    type Item = T;
+    type IntoIter = DeltaArrayIter<'a, T, Buffer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> Length for DeltaArray<T, Buffer> {
+    fn len(&self) -> usize {
+        self.deltas.len()
+    }
+}
+
+/// Iterator over the decoded values of a [`DeltaArray`].
+pub type DeltaArrayIter<'a, T, Buffer> = DeltaIter<Map<TruncatedIter<'a, T, Buffer>, fn(T) -> T>>;
+
+/// Iterator over the decoded values of delta encoded data.
+#[derive(Clone, Debug)]
+pub struct DeltaIter<I: Iterator> {
+    /// The remaining deltas.
+    deltas: I,
+    /// The previously decoded value.
+    previous: I::Item,
+}
+
+impl<T: Integer, I: Iterator<Item = T>> DeltaIter<I> {
+    /// Returns a new iterator decoding the given deltas, which are the
+    /// differences with the previous value, starting with `initial`.
+    fn new(initial: T, deltas: I) -> Self {
+        Self {
+            deltas,
+            previous: initial,
+        }
+    }
+}
+
+impl<T: Integer, I: Iterator<Item = T>> Iterator for DeltaIter<I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.deltas.next().map(|delta| {
+            self.previous = self.previous.wrapping_add(delta);
+            self.previous
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.deltas.size_hint()
+    }
+}
+
+impl<T: Integer, I: ExactSizeIterator<Item = T>> ExactSizeIterator for DeltaIter<I> {}
+
+impl<T: Integer, I: FusedIterator<Item = T>> FusedIterator for DeltaIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int64Array, Uint8Array};
+
+    #[test]
+    fn round_trip() {
+        let input = [1_000_i64, 1_005, 1_005, 1_020, 1_021];
+        let array = input.into_iter().collect::<Int64Array>();
+        let encoded = delta_encode(&array);
+        assert_eq!(encoded.as_ref(), [1_000, 5, 0, 15, 1]);
+        assert_eq!(delta_decode(&encoded).as_ref(), input);
+    }
+
+    #[test]
+    fn wrapping() {
+        let input = [250_u8, 3, 255];
+        let array = input.into_iter().collect::<Uint8Array>();
+        assert_eq!(delta_decode(&delta_encode(&array)).as_ref(), input);
+    }
+
+    #[test]
+    fn delta_array() {
+        let input = [10_u32, 11, 13, 16, 15];
+        let array = input.into_iter().collect::<DeltaArray<u32>>();
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.first, 10);
+        assert_eq!(array.deltas.width(), 1);
+        assert_eq!(array.deltas.as_bytes(), [0, 2, 4, 6, 1]);
+        assert_eq!(array.iter().collect::<Vec<_>>(), input);
+        assert_eq!(array.decode().as_ref(), input);
+    }
+
+    #[test]
+    fn zigzag_round_trip() {
+        for delta in [0_i8, -1, 1, -2, i8::MIN, i8::MAX] {
+            assert_eq!(unzigzag(zigzag(delta)), delta);
+        }
+        assert_eq!([0_i8, -1, 1, -2].map(zigzag), [0, 1, 2, 3]);
+        assert_eq!(zigzag(u64::MAX), 1);
+        assert_eq!(zigzag(i128::MIN), -1);
+
+        let input = [u8::MAX, 0, 128, 127];
+        let array = input.into_iter().collect::<DeltaArray<u8>>();
+        assert_eq!(array.iter().collect::<Vec<_>>(), input);
+    }
+}
@@ -0,0 +1,158 @@
+//! Frame-of-reference encoding.
+
+use super::{Integer, TruncatedArray};
+use crate::{
+    array::{Array, FixedSizePrimitiveArray},
+    buffer::{Buffer, BufferType, VecBuffer},
+    Index, Length,
+};
+
+/// Returns the frame-of-reference encoding of the values in the array.
+///
+/// The reference is the minimum value, and the values are stored as their
+/// offset from the reference. Returns the default value as reference for
+/// empty arrays.
+pub fn for_encode<T: Integer, Buffer: BufferType>(
+    array: &FixedSizePrimitiveArray<T, false, Buffer>,
+) -> (T, FixedSizePrimitiveArray<T, false, Buffer>)
+where
+    <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+{
+    let values = array.0.as_slice();
+    let reference = values.iter().copied().min().unwrap_or_default();
+    (
+        reference,
+        values
+            .iter()
+            .map(|&value| value.wrapping_sub(reference))
+            .collect(),
+    )
+}
+
+/// Returns the values of a frame-of-reference encoded array, as returned by
+/// [`for_encode`].
+pub fn for_decode<T: Integer, Buffer: BufferType>(
+    reference: T,
+    offsets: &FixedSizePrimitiveArray<T, false, Buffer>,
+) -> FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+{
+    offsets
+        .0
+        .as_slice()
+        .iter()
+        .map(|&offset| reference.wrapping_add(offset))
+        .collect()
+}
+
+/// Array with frame-of-reference encoded integer values.
+///
+/// The reference is the minimum value, and the offsets of the values from
+/// the reference are stored in a [`TruncatedArray`] with the narrowest byte
+/// width that fits all of them. Values in a small range, e.g. around a large
+/// base value, take a fraction of the size of their type. Unlike delta
+/// encoding this supports random access.
+///
+/// ```
+/// use narrow::{array::Int64Array, encoding::FrameOfReferenceArray, Index};
+///
+/// let array = [1_000_200_i64, 1_000_000, 1_000_010]
+///     .into_iter()
+///     .collect::<Int64Array>();
+/// let encoded = FrameOfReferenceArray::encode(&array);
+/// assert_eq!(encoded.reference, 1_000_000);
+/// assert_eq!(encoded.offsets.width(), 1);
+/// assert_eq!(encoded.index_checked(2), 1_000_010);
+/// ```
+pub struct FrameOfReferenceArray<T: Integer, Buffer: BufferType = VecBuffer> {
+    /// The reference value.
+    pub reference: T,
+    /// The offsets of the values from the reference value.
+    pub offsets: TruncatedArray<T, Buffer>,
+}
+
+impl<T: Integer, Buffer: BufferType> FrameOfReferenceArray<T, Buffer> {
+    /// Returns a new [`FrameOfReferenceArray`] with the encoded values of
+    /// `array`.
+    pub fn encode(array: &FixedSizePrimitiveArray<T, false, Buffer>) -> Self
+    where
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        let values = array.0.as_slice();
+        let reference = values.iter().copied().min().unwrap_or_default();
+        Self {
+            reference,
+            offsets: values
+                .iter()
+                .map(|&value| value.wrapping_sub(reference))
+                .collect(),
+        }
+    }
+
+    /// Returns the decoded values of this array.
+    pub fn decode(&self) -> FixedSizePrimitiveArray<T, false, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+    {
+        self.iter().collect()
+    }
+
+    /// Returns an iterator over the decoded values of this array.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        self.offsets
+            .iter()
+            .map(|offset| self.reference.wrapping_add(offset))
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> Array for FrameOfReferenceArray<T, Buffer> {
+    type Item = T;
+}
+
+impl<T: Integer, Buffer: BufferType> Index for FrameOfReferenceArray<T, Buffer> {
+    type Item<'a>
+        = T
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.reference
+            .wrapping_add(self.offsets.index_unchecked(index))
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> Length for FrameOfReferenceArray<T, Buffer> {
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    #[test]
+    fn round_trip() {
+        let input = [105_i32, 101, 110, 100];
+        let array = input.into_iter().collect::<Int32Array>();
+        let (reference, offsets) = for_encode(&array);
+        assert_eq!(reference, 100);
+        assert_eq!(offsets.as_ref(), [5, 1, 10, 0]);
+        assert_eq!(for_decode(reference, &offsets).as_ref(), input);
+    }
+
+    #[test]
+    fn frame_of_reference_array() {
+        let input = [-3_i32, 4, 0];
+        let array = FrameOfReferenceArray::encode(&input.into_iter().collect::<Int32Array>());
+        assert_eq!(array.reference, -3);
+        assert_eq!(array.offsets.width(), 1);
+        assert_eq!(array.offsets.as_bytes(), [0, 7, 3]);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index_checked(1), 4);
+        assert_eq!(array.iter().collect::<Vec<_>>(), input);
+        assert_eq!(array.decode().as_ref(), input);
+    }
+}
@@ -0,0 +1,93 @@
+//! Lightweight encodings for array data.
+
+use crate::FixedSize;
+
+mod codec;
+pub use self::codec::*;
+
+mod delta;
+pub use self::delta::*;
+
+mod frame_of_reference;
+pub use self::frame_of_reference::*;
+
+mod truncated;
+pub use self::truncated::*;
+
+/// Integer types that can be used with the encodings in this module.
+pub trait Integer: FixedSize + Ord + Default {
+    /// The name of this type, e.g. `i32`.
+    const NAME: &'static str;
+
+    /// Wrapping (modular) addition.
+    #[must_use]
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Wrapping (modular) subtraction.
+    #[must_use]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Returns the value of the little-endian bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bytes` is not equal to [`FixedSize::SIZE`].
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Appends the little-endian bytes of this value to `output`.
+    fn write_le(self, output: &mut Vec<u8>);
+
+    /// Returns the bits of this value, zero-extended to 128 bits.
+    fn to_bits(self) -> u128;
+
+    /// Returns the value with the given bits, truncated to the size of this
+    /// type.
+    fn from_bits(bits: u128) -> Self;
+}
+
+/// Implement [`Integer`] for integer types.
+macro_rules! impl_integer {
+    ($($ty:ty),+) => {
+        $(
+            impl Integer for $ty {
+                const NAME: &'static str = stringify!($ty);
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_add(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_sub(self, rhs)
+                }
+
+                #[inline]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    <$ty>::from_le_bytes(bytes.try_into().expect("slice length mismatch"))
+                }
+
+                #[inline]
+                fn write_le(self, output: &mut Vec<u8>) {
+                    output.extend_from_slice(&self.to_le_bytes());
+                }
+
+                #[inline]
+                fn to_bits(self) -> u128 {
+                    let mut bytes = [0; 16];
+                    bytes[..Self::SIZE].copy_from_slice(&self.to_le_bytes());
+                    u128::from_le_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_bits(bits: u128) -> Self {
+                    Self::from_le_slice(&bits.to_le_bytes()[..Self::SIZE])
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+#[cfg(not(feature = "arrow-rs"))]
+impl_integer!(u128, isize, usize);
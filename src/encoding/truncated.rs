@@ -0,0 +1,205 @@
+//! Integers stored in the narrowest byte width that fits their values.
+
+use super::Integer;
+use crate::{
+    array::Array,
+    buffer::{Buffer, BufferType, VecBuffer},
+    Index, Length,
+};
+use std::{iter::FusedIterator, marker::PhantomData};
+
+/// Returns the number of bytes needed to store `value`.
+fn byte_width(value: u128) -> usize {
+    value
+        .to_le_bytes()
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .map_or(0, |index| index + 1)
+}
+
+/// Array with integer values stored in the narrowest byte width that fits
+/// all values.
+///
+/// Every value is stored in its `width` least significant bytes, in
+/// little-endian byte order, where `width` is the number of bytes needed for
+/// the largest value (as unsigned integer). Arrays of small values, like the
+/// offsets of [`FrameOfReferenceArray`](super::FrameOfReferenceArray), take
+/// a fraction of the size of their type, and arrays with only zeros take no
+/// space at all. Values can be accessed randomly.
+///
+/// ```
+/// use narrow::{encoding::TruncatedArray, Length};
+///
+/// let array = [3_u64, 0, 300].into_iter().collect::<TruncatedArray<u64>>();
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.width(), 2);
+/// assert_eq!(array.as_bytes(), [3, 0, 0, 0, 44, 1]);
+/// assert_eq!(array.iter().collect::<Vec<_>>(), [3, 0, 300]);
+/// ```
+pub struct TruncatedArray<T: Integer, Buffer: BufferType = VecBuffer> {
+    /// The truncated little-endian bytes of the values.
+    bytes: <Buffer as BufferType>::Buffer<u8>,
+    /// The number of bytes per value.
+    width: usize,
+    /// The number of values.
+    len: usize,
+    /// The type of the values.
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T: Integer, Buffer: BufferType> TruncatedArray<T, Buffer> {
+    /// Returns an array with `len` values stored in `width` bytes each in
+    /// `bytes`, or `None` if the length of `bytes` does not match or `width`
+    /// exceeds the size of `T`.
+    pub(crate) fn from_parts(
+        bytes: <Buffer as BufferType>::Buffer<u8>,
+        width: usize,
+        len: usize,
+    ) -> Option<Self> {
+        (width <= T::SIZE && width.checked_mul(len) == Some(bytes.as_slice().len())).then_some(
+            Self {
+                bytes,
+                width,
+                len,
+                _ty: PhantomData,
+            },
+        )
+    }
+
+    /// Returns the number of bytes per value.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the truncated bytes of the values.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Returns an iterator over the values in this array.
+    pub fn iter(&self) -> TruncatedIter<'_, T, Buffer> {
+        TruncatedIter {
+            array: self,
+            position: 0,
+        }
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> Array for TruncatedArray<T, Buffer> {
+    type Item = T;
+}
+
+impl<T: Integer, Buffer: BufferType> FromIterator<T> for TruncatedArray<T, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    /// The values are buffered to find the width that fits all values.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values = iter.into_iter().map(T::to_bits).collect::<Vec<_>>();
+        let width = values.iter().copied().max().map_or(0, byte_width);
+        Self {
+            bytes: values
+                .iter()
+                .flat_map(|value| value.to_le_bytes().into_iter().take(width))
+                .collect(),
+            width,
+            len: values.len(),
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> Index for TruncatedArray<T, Buffer> {
+    type Item<'a>
+        = T
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        let start = index * self.width;
+        let mut bytes = [0; 16];
+        bytes[..self.width].copy_from_slice(
+            self.bytes
+                .as_slice()
+                .get_unchecked(start..start + self.width),
+        );
+        T::from_bits(u128::from_le_bytes(bytes))
+    }
+}
+
+impl<'a, T: Integer, Buffer: BufferType> IntoIterator for &'a TruncatedArray<T, Buffer> {
+    type Item = T;
+    type IntoIter = TruncatedIter<'a, T, Buffer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> Length for TruncatedArray<T, Buffer> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Iterator over the values of a [`TruncatedArray`].
+pub struct TruncatedIter<'a, T: Integer, Buffer: BufferType> {
+    /// The array with the values.
+    array: &'a TruncatedArray<T, Buffer>,
+    /// The position of the next value.
+    position: usize,
+}
+
+impl<T: Integer, Buffer: BufferType> Iterator for TruncatedIter<'_, T, Buffer> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.array.index(self.position).map(|value| {
+            self.position += 1;
+            value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.array.len - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Integer, Buffer: BufferType> ExactSizeIterator for TruncatedIter<'_, T, Buffer> {}
+
+impl<T: Integer, Buffer: BufferType> FusedIterator for TruncatedIter<'_, T, Buffer> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::SliceBuffer;
+
+    #[test]
+    fn widths() {
+        let zeros = [0_u32; 5].into_iter().collect::<TruncatedArray<u32>>();
+        assert_eq!(zeros.width(), 0);
+        assert!(zeros.as_bytes().is_empty());
+        assert_eq!(zeros.iter().collect::<Vec<_>>(), [0; 5]);
+
+        let full = [-1_i16, 1].into_iter().collect::<TruncatedArray<i16>>();
+        assert_eq!(full.width(), 2);
+        assert_eq!(full.iter().collect::<Vec<_>>(), [-1, 1]);
+
+        let wide = [i128::MAX >> 8, 1]
+            .into_iter()
+            .collect::<TruncatedArray<i128>>();
+        assert_eq!(wide.width(), 15);
+        assert_eq!(wide.index_checked(0), i128::MAX >> 8);
+    }
+
+    #[test]
+    fn from_parts() {
+        let bytes = [1, 0, 2, 0];
+        let array =
+            TruncatedArray::<u32, SliceBuffer<'_>>::from_parts(&bytes, 2, 2).expect("valid parts");
+        assert_eq!(array.iter().collect::<Vec<_>>(), [1, 2]);
+        assert!(TruncatedArray::<u32, SliceBuffer<'_>>::from_parts(&bytes, 2, 3).is_none());
+        assert!(TruncatedArray::<u8, SliceBuffer<'_>>::from_parts(&bytes, 2, 2).is_none());
+    }
+}
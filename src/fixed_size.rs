@@ -24,7 +24,13 @@ use arrow_rs::_arrow_rs_trait;
 /// of this trait are required, and to restrict certain implementations to
 /// fixed-size types.
 ///
-/// This trait is sealed to prevent downstream implementations.
+/// This is implemented for the fixed-size types of the standard library, for
+/// newtypes registered with [`impl_primitive`](crate::impl_primitive) and for
+/// user-defined plain old data types registered with
+/// [`impl_plain_old_data`](crate::impl_plain_old_data).
+///
+/// Fixed-size types are not necessarily valid for any bit pattern. Operations
+/// that create values from bytes require [`PlainOldData`].
 pub trait FixedSize:
     ArrayType + Copy + Debug + Sized + sealed::Sealed + 'static + _arrow_rs_trait
 {
@@ -41,28 +47,249 @@ mod sealed {
     impl<T> Sealed for T where T: super::FixedSize {}
 }
 
-impl FixedSize for i8 {}
-impl FixedSize for i16 {}
-impl FixedSize for i32 {}
-impl FixedSize for i64 {}
-impl FixedSize for i128 {}
-impl FixedSize for u8 {}
-impl FixedSize for u16 {}
-impl FixedSize for u32 {}
-impl FixedSize for u64 {}
-#[cfg(not(feature = "arrow-rs"))]
-impl FixedSize for u128 {}
+/// Fixed-size types that are valid for any bit pattern and have no padding
+/// bytes, e.g. integers and floating point numbers.
+///
+/// Values of these types can be created from arbitrary bytes, e.g. when
+/// decoding buffers or reinterpreting the values of an array as another type.
+///
+/// This trait is sealed. It is implemented for the integer and floating point
+/// types of the standard library and arrays of them, and for user-defined
+/// types registered with [`impl_plain_old_data`](crate::impl_plain_old_data).
+///
+/// # Safety
+///
+/// Implementors must be valid for any bit pattern and must not have padding
+/// bytes.
+pub unsafe trait PlainOldData: FixedSize + pod::Sealed {}
 
-#[cfg(not(feature = "arrow-rs"))]
-impl FixedSize for isize {}
-#[cfg(not(feature = "arrow-rs"))]
-impl FixedSize for usize {}
+/// Private module for [`pod::Sealed`] trait.
+mod pod {
+    /// Used to seal [`super::PlainOldData`].
+    pub trait Sealed {}
+}
+
+// Re-export the seal of `PlainOldData` for the `impl_plain_old_data` macro.
+#[doc(hidden)]
+pub use pod::Sealed as __PlainOldDataSealed;
+
+/// Implements [`PlainOldData`] for the given types of the standard library.
+macro_rules! impl_std_plain_old_data {
+    ($($ty:ty),+) => {
+        $(
+            impl pod::Sealed for $ty {}
+            // Safety:
+            // - Integers and floating point numbers are valid for any bit
+            //   pattern and have no padding bytes.
+            unsafe impl PlainOldData for $ty {}
+        )+
+    };
+}
 
-impl FixedSize for f32 {}
-impl FixedSize for f64 {}
+impl_std_plain_old_data!(i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64);
 
 #[cfg(not(feature = "arrow-rs"))]
-impl<const N: usize, T: super::FixedSize> FixedSize for [T; N] {}
+impl_std_plain_old_data!(u128, isize, usize);
+
+#[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
+impl<const N: usize, T: PlainOldData> pod::Sealed for [T; N] {}
+
+#[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
+// Safety:
+// - Arrays have no padding between their items, which are plain old data.
+unsafe impl<const N: usize, T: PlainOldData> PlainOldData for [T; N] {}
+
+/// Implementations of [`FixedSize`] for the fixed-size types of the standard
+/// library.
+mod std_types {
+    use super::FixedSize;
+
+    impl FixedSize for i8 {}
+    impl FixedSize for i16 {}
+    impl FixedSize for i32 {}
+    impl FixedSize for i64 {}
+    impl FixedSize for i128 {}
+    impl FixedSize for u8 {}
+    impl FixedSize for u16 {}
+    impl FixedSize for u32 {}
+    impl FixedSize for u64 {}
+    #[cfg(not(feature = "arrow-rs"))]
+    impl FixedSize for u128 {}
+
+    #[cfg(not(feature = "arrow-rs"))]
+    impl FixedSize for isize {}
+    #[cfg(not(feature = "arrow-rs"))]
+    impl FixedSize for usize {}
+
+    impl FixedSize for f32 {}
+    impl FixedSize for f64 {}
+
+    #[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
+    impl<const N: usize, T: FixedSize> FixedSize for [T; N] {}
+}
+
+/// Registers a user-defined plain old data type as a fixed-size type, so it
+/// can be stored in a
+/// [`FixedSizePrimitiveArray`](crate::array::FixedSizePrimitiveArray).
+///
+/// The plain old data guarantees are checked by another crate:
+///
+/// - `impl_plain_old_data!(bytemuck: Ty)` requires the `bytemuck` feature
+///   and a [`bytemuck::Pod`] implementation.
+/// - `impl_plain_old_data!(zerocopy: Ty)` requires the `zerocopy` feature and
+///   [`zerocopy::FromBytes`] and [`zerocopy::AsBytes`] implementations.
+///
+/// The type must also implement `Clone`, `Copy` and `Debug`. This implements
+/// [`FixedSize`], [`PlainOldData`] and [`ArrayType`] for the type, and
+/// [`ArrayType`] for `Option` of the type. Enabling these features does not
+/// change the implementations for other types. This macro is not available
+/// with the `arrow-rs` feature.
+///
+/// ```
+/// # #[cfg(all(feature = "bytemuck", not(feature = "arrow-rs")))]
+/// # {
+/// use narrow::{array::FixedSizePrimitiveArray, impl_plain_old_data};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(C)]
+/// struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// // Safety:
+/// // - All fields are `u8`, which is zeroable.
+/// unsafe impl bytemuck::Zeroable for Rgb {}
+/// // Safety:
+/// // - The struct is `repr(C)` without padding and all fields are `Pod`.
+/// unsafe impl bytemuck::Pod for Rgb {}
+///
+/// impl_plain_old_data!(bytemuck: Rgb);
+///
+/// let array = [Rgb { r: 1, g: 2, b: 3 }]
+///     .into_iter()
+///     .collect::<FixedSizePrimitiveArray<Rgb>>();
+/// assert_eq!(array.as_ref(), [Rgb { r: 1, g: 2, b: 3 }]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_plain_old_data {
+    (bytemuck: $ty:ident) => {
+        const _: fn() = || {
+            fn pod<T: $crate::__bytemuck::Pod>() {}
+            pod::<$ty>();
+        };
+        // Safety:
+        // - Pod types are valid for any bit pattern and have no padding bytes.
+        $crate::__impl_plain_old_data!($ty);
+    };
+    (zerocopy: $ty:ident) => {
+        const _: fn() = || {
+            fn pod<T: $crate::__zerocopy::FromBytes + $crate::__zerocopy::AsBytes>() {}
+            pod::<$ty>();
+        };
+        // Safety:
+        // - `FromBytes` types are valid for any bit pattern, and `AsBytes`
+        //   types have no padding bytes.
+        $crate::__impl_plain_old_data!($ty);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// Implements [`FixedSize`], [`PlainOldData`] and [`ArrayType`] for a type
+/// registered with [`impl_plain_old_data`].
+macro_rules! __impl_plain_old_data {
+    ($ty:ident) => {
+        impl $crate::FixedSize for $ty {}
+        impl $crate::__PlainOldDataSealed for $ty {}
+        // Safety:
+        // - Checked by the caller.
+        unsafe impl $crate::PlainOldData for $ty {}
+        impl $crate::array::ArrayType for $ty {
+            type Array<
+                Buffer: $crate::buffer::BufferType,
+                OffsetItem: $crate::offset::OffsetElement,
+                UnionLayout: $crate::array::UnionType,
+            > = $crate::array::FixedSizePrimitiveArray<$ty, false, Buffer>;
+        }
+        impl $crate::array::ArrayType<$ty> for ::std::option::Option<$ty> {
+            type Array<
+                Buffer: $crate::buffer::BufferType,
+                OffsetItem: $crate::offset::OffsetElement,
+                UnionLayout: $crate::array::UnionType,
+            > = $crate::array::FixedSizePrimitiveArray<$ty, true, Buffer>;
+        }
+    };
+}
+
+/// Registers a newtype of a fixed-size type as a fixed-size type, so it can be
+/// stored in a [`FixedSizePrimitiveArray`](crate::array::FixedSizePrimitiveArray)
+/// without a wrapper struct.
+///
+/// The newtype must be a tuple struct with a single field of a fixed-size type,
+/// and it must implement `Clone`, `Copy` and `Debug`. Marking it
+/// `#[repr(transparent)]` is recommended. Compilation fails if the size or the
+/// alignment of the newtype differs from the wrapped type.
+///
+/// This implements [`FixedSize`] and [`ArrayType`] for the newtype, and
+/// [`ArrayType`] for `Option` of the newtype, regardless of the enabled
+/// features. This macro is not available with the `arrow-rs` feature.
+///
+/// ```
+/// # #[cfg(not(feature = "arrow-rs"))]
+/// # {
+/// use narrow::{array::FixedSizePrimitiveArray, impl_primitive};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// impl_primitive!(Meters(f64));
+///
+/// let array = [Meters(1.5), Meters(2.0)]
+///     .into_iter()
+///     .collect::<FixedSizePrimitiveArray<Meters>>();
+/// assert_eq!(array.as_ref(), [Meters(1.5), Meters(2.0)]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_primitive {
+    ($ty:ident($inner:ty)) => {
+        const _: () = {
+            assert!(
+                ::std::mem::size_of::<$ty>() == ::std::mem::size_of::<$inner>(),
+                "size of the newtype should be equal to size of the wrapped type"
+            );
+            assert!(
+                ::std::mem::align_of::<$ty>() == ::std::mem::align_of::<$inner>(),
+                "alignment of the newtype should be equal to alignment of the wrapped type"
+            );
+        };
+        const _: fn() = || {
+            fn fixed_size<T: $crate::FixedSize>() {}
+            fixed_size::<$inner>();
+            // The newtype has a single field of the wrapped type.
+            let _ = |value: $inner| $ty(value);
+        };
+        impl $crate::FixedSize for $ty {}
+        impl $crate::array::ArrayType for $ty {
+            type Array<
+                Buffer: $crate::buffer::BufferType,
+                OffsetItem: $crate::offset::OffsetElement,
+                UnionLayout: $crate::array::UnionType,
+            > = $crate::array::FixedSizePrimitiveArray<$ty, false, Buffer>;
+        }
+        impl $crate::array::ArrayType<$ty> for ::std::option::Option<$ty> {
+            type Array<
+                Buffer: $crate::buffer::BufferType,
+                OffsetItem: $crate::offset::OffsetElement,
+                UnionLayout: $crate::array::UnionType,
+            > = $crate::array::FixedSizePrimitiveArray<$ty, true, Buffer>;
+        }
+    };
+}
 
 #[cfg(test)]
 mod tests {
@@ -71,9 +298,99 @@ mod tests {
     #[test]
     fn size() {
         assert_eq!(u8::SIZE, 1);
-        #[cfg(not(feature = "arrow-rs"))]
+        #[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
         assert_eq!(<[u16; 21]>::SIZE, 42);
-        #[cfg(not(feature = "arrow-rs"))]
+        #[cfg(all(feature = "lists", not(feature = "arrow-rs")))]
         assert_eq!(<[u8; 1234]>::SIZE, 1234);
     }
+
+    #[cfg(not(feature = "arrow-rs"))]
+    #[test]
+    fn newtype() {
+        use crate::array::FixedSizePrimitiveArray;
+
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(transparent)]
+        struct Meters(f64);
+
+        crate::impl_primitive!(Meters(f64));
+
+        assert_eq!(Meters::SIZE, 8);
+        let array = [Some(Meters(1.5)), None]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<Meters, true>>();
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [Some(Meters(1.5)), None]
+        );
+    }
+
+    #[cfg(all(feature = "bytemuck", not(feature = "arrow-rs")))]
+    #[test]
+    fn bytemuck() {
+        use crate::{array::FixedSizePrimitiveArray, PlainOldData};
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C)]
+        struct Rgb {
+            r: u8,
+            g: u8,
+            b: u8,
+        }
+
+        // Safety:
+        // - All fields are `u8`, which is zeroable.
+        unsafe impl bytemuck::Zeroable for Rgb {}
+        // Safety:
+        // - The struct is `repr(C)` without padding and all fields are `Pod`.
+        unsafe impl bytemuck::Pod for Rgb {}
+
+        crate::impl_plain_old_data!(bytemuck: Rgb);
+
+        /// Returns the size of a plain old data type.
+        fn pod_size<T: PlainOldData>() -> usize {
+            T::SIZE
+        }
+
+        assert_eq!(pod_size::<Rgb>(), 3);
+        assert_eq!(pod_size::<u32>(), 4);
+        let pixels = [Rgb { r: 1, g: 2, b: 3 }, Rgb { r: 4, g: 5, b: 6 }];
+        let array = pixels.into_iter().collect::<FixedSizePrimitiveArray<Rgb>>();
+        assert_eq!(array.as_ref(), pixels);
+    }
+
+    #[cfg(all(feature = "zerocopy", not(feature = "arrow-rs")))]
+    #[test]
+    fn zerocopy() {
+        use crate::{array::FixedSizePrimitiveArray, PlainOldData};
+
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            zerocopy::FromZeroes,
+            zerocopy::FromBytes,
+            zerocopy::AsBytes,
+        )]
+        #[repr(C)]
+        struct Rgb {
+            r: u8,
+            g: u8,
+            b: u8,
+        }
+
+        crate::impl_plain_old_data!(zerocopy: Rgb);
+
+        /// Returns the size of a plain old data type.
+        fn pod_size<T: PlainOldData>() -> usize {
+            T::SIZE
+        }
+
+        assert_eq!(pod_size::<Rgb>(), 3);
+        assert_eq!(pod_size::<f64>(), 8);
+        let pixels = [Rgb { r: 1, g: 2, b: 3 }, Rgb { r: 4, g: 5, b: 6 }];
+        let array = pixels.into_iter().collect::<FixedSizePrimitiveArray<Rgb>>();
+        assert_eq!(array.as_ref(), pixels);
+    }
 }
@@ -0,0 +1,194 @@
+//! Separation of arrays under construction and immutable shared arrays.
+
+use crate::{Length, ShrinkToFit, WithCapacity};
+use std::{ops, sync::Arc};
+
+/// An array under construction.
+///
+/// This is the only handle that allows mutation of the wrapped array. Use
+/// [`Mutable::freeze`] to convert it into a [`Frozen`] array that can be
+/// shared cheaply.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Mutable<A>(A);
+
+impl<A> Mutable<A> {
+    /// Returns a new [`Mutable`] wrapping `array`.
+    pub const fn new(array: A) -> Self {
+        Self(array)
+    }
+
+    /// Converts this array into a [`Frozen`] array.
+    ///
+    /// This moves the array behind an [`Arc`] and does not copy any buffers.
+    pub fn freeze(self) -> Frozen<A> {
+        Frozen(Arc::new(self.0))
+    }
+
+    /// Returns the wrapped array.
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+impl<A> ops::Deref for Mutable<A> {
+    type Target = A;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<A> ops::DerefMut for Mutable<A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<A: Extend<T>, T> Extend<T> for Mutable<A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<A> From<A> for Mutable<A> {
+    fn from(value: A) -> Self {
+        Self(value)
+    }
+}
+
+impl<A: FromIterator<T>, T> FromIterator<T> for Mutable<A> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<A: Length> Length for Mutable<A> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<A: WithCapacity> WithCapacity for Mutable<A> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self(A::with_capacity(capacity))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+}
+
+impl<A: ShrinkToFit> ShrinkToFit for Mutable<A> {
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
+/// An immutable array.
+///
+/// Cloning a [`Frozen`] array is cheap and does not copy any buffers, which
+/// makes it suitable for sharing between threads.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Frozen<A>(Arc<A>);
+
+impl<A> Frozen<A> {
+    /// Converts this array back into a [`Mutable`] array.
+    ///
+    /// This reuses the buffers of the array and only succeeds when this is the
+    /// only handle to the array. Otherwise this returns `Err(self)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(self)` when there are other handles to this array.
+    pub fn thaw(self) -> Result<Mutable<A>, Self> {
+        Arc::try_unwrap(self.0).map(Mutable).map_err(Self)
+    }
+
+    /// Returns a reference to the shared array.
+    #[must_use]
+    pub fn as_arc(&self) -> &Arc<A> {
+        &self.0
+    }
+}
+
+impl<A: Clone> Frozen<A> {
+    /// Converts this array back into a [`Mutable`] array, cloning the array
+    /// when there are other handles to it.
+    #[must_use]
+    pub fn thaw_or_clone(self) -> Mutable<A> {
+        Mutable(Arc::try_unwrap(self.0).unwrap_or_else(|shared| A::clone(&shared)))
+    }
+}
+
+impl<A> Clone for Frozen<A> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<A> ops::Deref for Frozen<A> {
+    type Target = A;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<A> From<A> for Frozen<A> {
+    fn from(value: A) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl<A> From<Mutable<A>> for Frozen<A> {
+    fn from(value: Mutable<A>) -> Self {
+        value.freeze()
+    }
+}
+
+impl<A: Length> Length for Frozen<A> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    #[test]
+    fn freeze_thaw() {
+        let mut mutable = Mutable::<Int32Array>::default();
+        mutable.extend([1, 2, 3]);
+        assert_eq!(mutable.len(), 3);
+
+        let frozen = mutable.freeze();
+        let shared = frozen.clone();
+        assert_eq!(shared.as_ref(), &[1, 2, 3]);
+
+        let Err(still_shared) = frozen.thaw() else {
+            panic!("expected shared array")
+        };
+        drop(shared);
+        let Ok(mut thawed) = still_shared.thaw() else {
+            panic!("expected unique array")
+        };
+        thawed.extend([4]);
+        assert_eq!(thawed.into_inner().as_ref(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn thaw_or_clone() {
+        let frozen = Frozen::from([1_u8, 2].into_iter().collect::<Vec<_>>());
+        let shared = frozen.clone();
+        let mut thawed = frozen.thaw_or_clone();
+        thawed.push(3);
+        assert_eq!(thawed.as_slice(), &[1, 2, 3]);
+        assert_eq!(shared.as_slice(), &[1, 2]);
+    }
+}
@@ -0,0 +1,477 @@
+//! Random data generators for benchmarks.
+//!
+//! A [`Generator`] produces arrays and batches with random values, with a
+//! configurable fraction of null values, distribution of string and list
+//! lengths, and depth of nested types:
+//!
+//! ```
+//! use narrow::{
+//!     gen::{GenOptions, Generator},
+//!     layout::{DataType, Field},
+//! };
+//!
+//! let mut generator = Generator::new(42, GenOptions::default());
+//! let fields = [
+//!     Field {
+//!         name: "id".to_owned(),
+//!         data_type: DataType::UInt64,
+//!         nullable: false,
+//!     },
+//!     Field {
+//!         name: "tags".to_owned(),
+//!         data_type: DataType::List(Box::new(DataType::Utf8)),
+//!         nullable: true,
+//!     },
+//! ];
+//! let batch = generator.batch(&fields, 1000);
+//! assert_eq!(batch.len, 1000);
+//! ```
+
+use crate::{
+    array::{
+        BooleanArray, FixedSizePrimitiveArray, NullArray, StringArray, VariableSizeBinaryArray,
+    },
+    bitmap::Bitmap,
+    buffer::Buffer,
+    layout::{ArrayData, DataType, Field, PrimitiveType, UnionMode},
+    nullable::Nullable,
+    offset::OffsetElement,
+};
+use rand::{
+    distributions::{Distribution, Standard},
+    rngs::SmallRng,
+    Rng, SeedableRng,
+};
+use std::ops::RangeInclusive;
+
+/// A distribution of lengths, of strings or lists.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LenDistribution {
+    /// All lengths are equal to the given length.
+    Fixed(usize),
+    /// Lengths are uniformly distributed in the given range.
+    Uniform(RangeInclusive<usize>),
+    /// Lengths are geometrically distributed with the given mean, which
+    /// results in many short and few long values.
+    Geometric {
+        /// The mean length.
+        mean: f64,
+        /// The maximum length.
+        max: usize,
+    },
+}
+
+impl LenDistribution {
+    /// Returns a random length.
+    fn sample(&self, rng: &mut impl Rng) -> usize {
+        match *self {
+            Self::Fixed(len) => len,
+            Self::Uniform(ref range) => rng.gen_range(range.clone()),
+            Self::Geometric { mean, max } => {
+                let continue_probability = mean / (mean + 1.0);
+                (0..max)
+                    .take_while(|_| rng.gen_bool(continue_probability))
+                    .count()
+            }
+        }
+    }
+}
+
+/// Options for a [`Generator`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct GenOptions {
+    /// The fraction of null values in nullable arrays, between 0 and 1.
+    pub null_fraction: f64,
+    /// The distribution of the lengths of strings and binary values.
+    pub string_len: LenDistribution,
+    /// The distribution of the lengths of variable-size lists.
+    pub list_len: LenDistribution,
+    /// The maximum depth of the nested types returned by
+    /// [`Generator::data_type`].
+    pub max_depth: usize,
+}
+
+impl Default for GenOptions {
+    fn default() -> Self {
+        Self {
+            null_fraction: 0.1,
+            string_len: LenDistribution::Uniform(0..=16),
+            list_len: LenDistribution::Uniform(0..=4),
+            max_depth: 2,
+        }
+    }
+}
+
+impl GenOptions {
+    /// Sets the fraction of null values in nullable arrays, between 0 and 1.
+    #[must_use]
+    pub fn with_null_fraction(mut self, null_fraction: f64) -> Self {
+        self.null_fraction = null_fraction;
+        self
+    }
+
+    /// Sets the distribution of the lengths of strings and binary values.
+    #[must_use]
+    pub fn with_string_len(mut self, string_len: LenDistribution) -> Self {
+        self.string_len = string_len;
+        self
+    }
+
+    /// Sets the distribution of the lengths of variable-size lists.
+    #[must_use]
+    pub fn with_list_len(mut self, list_len: LenDistribution) -> Self {
+        self.list_len = list_len;
+        self
+    }
+
+    /// Sets the maximum depth of the nested types returned by
+    /// [`Generator::data_type`].
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+/// Generator of random arrays and batches.
+///
+/// The generated data is the same for the same seed and options, so
+/// benchmarks are reproducible.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    /// The source of randomness.
+    rng: SmallRng,
+    /// The options of this generator.
+    options: GenOptions,
+}
+
+impl Generator {
+    /// Returns a new generator with the given seed and options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the null fraction is not between 0 and 1.
+    #[must_use]
+    pub fn new(seed: u64, options: GenOptions) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&options.null_fraction),
+            "null fraction (is {}) should be between 0 and 1",
+            options.null_fraction
+        );
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+            options,
+        }
+    }
+
+    /// Returns a validity bitmap with `len` bits, or `None` if the values
+    /// are not `nullable`.
+    fn nulls(&mut self, len: usize, nullable: bool) -> Option<Bitmap> {
+        nullable.then(|| {
+            (0..len)
+                .map(|_| !self.rng.gen_bool(self.options.null_fraction))
+                .collect()
+        })
+    }
+
+    /// Returns an array with `len` random primitive values, of which a
+    /// fraction is null.
+    pub fn primitive<T: PrimitiveType + Default>(
+        &mut self,
+        len: usize,
+    ) -> FixedSizePrimitiveArray<T, true>
+    where
+        Standard: Distribution<T>,
+    {
+        let validity = self.nulls(len, true).unwrap_or_default();
+        let data = validity
+            .iter()
+            .map(|valid| if valid { self.rng.gen() } else { T::default() })
+            .collect();
+        FixedSizePrimitiveArray(Nullable { data, validity })
+    }
+
+    /// Returns a random string with a length from the string length
+    /// distribution.
+    fn string(&mut self) -> String {
+        let len = self.options.string_len.sample(&mut self.rng);
+        (0..len)
+            .map(|_| char::from(self.rng.gen_range(b'a'..=b'z')))
+            .collect()
+    }
+
+    /// Returns an array with `len` random strings, of which a fraction is
+    /// null.
+    pub fn strings(&mut self, len: usize) -> StringArray<true> {
+        (0..len)
+            .map(|_| (!self.rng.gen_bool(self.options.null_fraction)).then(|| self.string()))
+            .collect()
+    }
+
+    /// Returns a random data type, with nested types up to the maximum depth.
+    pub fn data_type(&mut self) -> DataType {
+        self.data_type_at(0)
+    }
+
+    /// Returns a random data type at the given depth.
+    fn data_type_at(&mut self, depth: usize) -> DataType {
+        /// The number of types without children.
+        const LEAVES: usize = 6;
+
+        let choices = if depth < self.options.max_depth {
+            LEAVES + 3
+        } else {
+            LEAVES
+        };
+        match self.rng.gen_range(0..choices) {
+            0 => DataType::Boolean,
+            1 => DataType::Int32,
+            2 => DataType::Int64,
+            3 => DataType::Float64,
+            4 => DataType::Utf8,
+            5 => DataType::Binary,
+            6 => DataType::List(Box::new(self.data_type_at(depth + 1))),
+            7 => DataType::FixedSizeList(
+                Box::new(self.data_type_at(depth + 1)),
+                self.rng.gen_range(1..=4),
+            ),
+            _ => DataType::Struct(
+                (0..self.rng.gen_range(1..=3))
+                    .map(|index| Field {
+                        name: format!("f{index}"),
+                        data_type: self.data_type_at(depth + 1),
+                        nullable: self.rng.gen(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Returns a struct array with `len` rows of random values for the given
+    /// fields.
+    pub fn batch(&mut self, fields: &[Field], len: usize) -> ArrayData {
+        self.array(&DataType::Struct(fields.to_vec()), len, false)
+    }
+
+    /// Returns an array with `len` random values of the given data type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offsets of a variable-size array don't fit in the offset
+    /// type.
+    pub fn array(&mut self, data_type: &DataType, len: usize, nullable: bool) -> ArrayData {
+        let nulls = self.nulls(len, nullable);
+        let mut data = match *data_type {
+            DataType::Null => return (0..len).map(|_| ()).collect::<NullArray>().into(),
+            DataType::Boolean => (0..len)
+                .map(|_| self.rng.gen::<bool>())
+                .collect::<BooleanArray>()
+                .into(),
+            DataType::Int8 => self.values::<i8>(len),
+            DataType::Int16 => self.values::<i16>(len),
+            DataType::Int32 => self.values::<i32>(len),
+            DataType::Int64 => self.values::<i64>(len),
+            DataType::UInt8 => self.values::<u8>(len),
+            DataType::UInt16 => self.values::<u16>(len),
+            DataType::UInt32 => self.values::<u32>(len),
+            DataType::UInt64 => self.values::<u64>(len),
+            DataType::Float32 => self.values::<f32>(len),
+            DataType::Float64 => self.values::<f64>(len),
+            DataType::Binary => (0..len)
+                .map(|_| self.string().into_bytes())
+                .collect::<VariableSizeBinaryArray<false, i32>>()
+                .into(),
+            DataType::LargeBinary => (0..len)
+                .map(|_| self.string().into_bytes())
+                .collect::<VariableSizeBinaryArray<false, i64>>()
+                .into(),
+            DataType::Utf8 => (0..len)
+                .map(|_| self.string())
+                .collect::<StringArray<false, i32>>()
+                .into(),
+            DataType::LargeUtf8 => (0..len)
+                .map(|_| self.string())
+                .collect::<StringArray<false, i64>>()
+                .into(),
+            DataType::List(ref item) => self.list::<i32>(data_type, item, len),
+            DataType::LargeList(ref item) => self.list::<i64>(data_type, item, len),
+            DataType::FixedSizeList(ref item, size) => ArrayData {
+                data_type: data_type.clone(),
+                len,
+                nulls: None,
+                buffers: Vec::new(),
+                children: vec![self.array(item, len * size, true)],
+            },
+            DataType::Struct(ref fields) => ArrayData {
+                data_type: data_type.clone(),
+                len,
+                nulls: None,
+                buffers: Vec::new(),
+                children: fields
+                    .iter()
+                    .map(|field| self.array(&field.data_type, len, field.nullable))
+                    .collect(),
+            },
+            DataType::Union(ref fields, mode) => return self.union(data_type, fields, mode, len),
+        };
+        data.nulls = nulls;
+        data
+    }
+
+    /// Returns a union array with `len` random values of the given variants.
+    ///
+    /// Unions have no validity bitmap, so the values are nullable only if
+    /// their variants are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no variants, or more variants than type ids.
+    fn union(
+        &mut self,
+        data_type: &DataType,
+        fields: &[Field],
+        mode: UnionMode,
+        len: usize,
+    ) -> ArrayData {
+        let variants = i8::try_from(fields.len()).expect("variants should fit in type ids");
+        let types = (0..len)
+            .map(|_| self.rng.gen_range(0..variants))
+            .collect::<Vec<_>>();
+        let (buffers, children) = match mode {
+            UnionMode::Dense => {
+                let mut counts = vec![0; fields.len()];
+                let offsets = types
+                    .iter()
+                    .map(|&type_id| {
+                        let count = &mut counts[usize::from(type_id.unsigned_abs())];
+                        *count += 1;
+                        i32::try_from(*count - 1).expect("offset should fit in i32")
+                    })
+                    .collect::<Vec<_>>();
+                (
+                    vec![types.as_bytes().to_vec(), offsets.as_bytes().to_vec()],
+                    fields
+                        .iter()
+                        .zip(counts)
+                        .map(|(field, count)| self.array(&field.data_type, count, field.nullable))
+                        .collect(),
+                )
+            }
+            UnionMode::Sparse => (
+                vec![types.as_bytes().to_vec()],
+                fields
+                    .iter()
+                    .map(|field| self.array(&field.data_type, len, field.nullable))
+                    .collect(),
+            ),
+        };
+        ArrayData {
+            data_type: data_type.clone(),
+            len,
+            nulls: None,
+            buffers,
+            children,
+        }
+    }
+
+    /// Returns the array data of `len` random primitive values.
+    fn values<T: PrimitiveType>(&mut self, len: usize) -> ArrayData
+    where
+        Standard: Distribution<T>,
+    {
+        (0..len)
+            .map(|_| self.rng.gen::<T>())
+            .collect::<FixedSizePrimitiveArray<T>>()
+            .into()
+    }
+
+    /// Returns the array data of `len` random lists with items of type
+    /// `item`.
+    fn list<OffsetItem: OffsetElement>(
+        &mut self,
+        data_type: &DataType,
+        item: &DataType,
+        len: usize,
+    ) -> ArrayData {
+        let mut offsets = vec![0];
+        for _ in 0..len {
+            let list_len = self.options.list_len.sample(&mut self.rng);
+            offsets.push(offsets[offsets.len() - 1] + list_len);
+        }
+        let buffer = offsets
+            .iter()
+            .map(|&offset| OffsetItem::try_from(offset).expect("offset should fit in offset type"))
+            .collect::<FixedSizePrimitiveArray<OffsetItem>>();
+        ArrayData {
+            data_type: data_type.clone(),
+            len,
+            nulls: None,
+            buffers: vec![Buffer::as_bytes(&buffer.0).to_vec()],
+            children: vec![self.array(item, offsets[len], true)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{array::Int32Array, Length};
+
+    #[test]
+    fn seeded() {
+        let mut first = Generator::new(1, GenOptions::default());
+        let mut second = Generator::new(1, GenOptions::default());
+        assert_eq!(
+            first.primitive::<u32>(16).into_iter().collect::<Vec<_>>(),
+            second.primitive::<u32>(16).into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(first.data_type(), second.data_type());
+    }
+
+    #[test]
+    fn options() {
+        let mut generator = Generator::new(
+            2,
+            GenOptions::default()
+                .with_null_fraction(1.0)
+                .with_string_len(LenDistribution::Fixed(3))
+                .with_list_len(LenDistribution::Geometric { mean: 2.0, max: 8 })
+                .with_max_depth(0),
+        );
+        assert!(generator
+            .strings(8)
+            .into_iter()
+            .all(|value| value.is_none()));
+        assert!(!matches!(
+            generator.data_type(),
+            DataType::List(_) | DataType::FixedSizeList(..) | DataType::Struct(_)
+        ));
+    }
+
+    #[test]
+    fn batch() {
+        let mut generator = Generator::new(3, GenOptions::default());
+        let fields = [
+            Field {
+                name: "a".to_owned(),
+                data_type: DataType::Int32,
+                nullable: true,
+            },
+            Field {
+                name: "b".to_owned(),
+                data_type: DataType::List(Box::new(DataType::Utf8)),
+                nullable: false,
+            },
+        ];
+        let batch = generator.batch(&fields, 100);
+        assert_eq!(batch.len, 100);
+        let mut children = batch.children.into_iter();
+        let a = Int32Array::<true>::try_from(children.next().expect("a")).expect("int32");
+        assert_eq!(a.len(), 100);
+        let b = children.next().expect("b");
+        assert!(b.nulls.is_none());
+        assert_eq!(b.children[0].data_type, DataType::Utf8);
+        assert!(b.children[0].len <= 400);
+    }
+}
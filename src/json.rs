@@ -0,0 +1,582 @@
+//! Ingestion of schemaless JSON values.
+//!
+//! The fields of a sample of JSON objects are inferred with [`infer_fields`],
+//! after which the objects are converted to [`ArrayData`] with
+//! [`to_array_data`]. When the objects match a Rust type, they can be
+//! deserialized into a typed [`StructArray`] with [`to_struct_array`]
+//! instead.
+//!
+//! The other way around, [`ArrayData::to_json_value`] and
+//! [`ArrayData::json_display`] render the elements of arrays, including
+//! nested lists and structs, as JSON values.
+
+use crate::{
+    array::{
+        BooleanArray, Float64Array, Int64Array, NullArray, StringArray, StructArray,
+        StructArrayType,
+    },
+    bitmap::Bitmap,
+    layout::{ArrayData, DataType, Field, UnionMode},
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    iter,
+    ops::Range,
+};
+
+/// Error returned when JSON values can't be converted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsonError {
+    /// A value is not a JSON object.
+    NotAnObject {
+        /// The index of the value.
+        index: usize,
+    },
+    /// The values of a field have incompatible types.
+    IncompatibleTypes {
+        /// The path of the field, with the names of the parent fields
+        /// separated by dots.
+        field: String,
+        /// The type of the value.
+        found: DataType,
+        /// The type of the field.
+        expected: DataType,
+    },
+    /// A value can't be deserialized into the requested type.
+    Deserialize {
+        /// The index of the value.
+        index: usize,
+        /// The deserialization error message.
+        message: String,
+    },
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::NotAnObject { index } => write!(f, "value {index} should be an object"),
+            Self::IncompatibleTypes {
+                ref field,
+                ref found,
+                ref expected,
+            } => write!(
+                f,
+                "type of field `{field}` (is {found:?}) should be compatible with {expected:?}"
+            ),
+            Self::Deserialize { index, ref message } => {
+                write!(f, "value {index} should deserialize: {message}")
+            }
+        }
+    }
+}
+
+impl Error for JsonError {}
+
+/// Returns the fields of the given JSON objects.
+///
+/// Integers are inferred as [`DataType::Int64`], or as [`DataType::Float64`]
+/// when a field also has floating point numbers. Fields that are null or
+/// missing in some of the objects are nullable, and fields that are null in
+/// all objects have [`DataType::Null`].
+///
+/// # Errors
+///
+/// Returns an error if a value is not an object, or if the values of a field
+/// have incompatible types.
+pub fn infer_fields<'a>(
+    values: impl IntoIterator<Item = &'a Value>,
+) -> Result<Vec<Field>, JsonError> {
+    values
+        .into_iter()
+        .enumerate()
+        .try_fold(None, |fields, (index, value)| match *value {
+            Value::Object(_) => {
+                let DataType::Struct(object) = infer(value, "")? else {
+                    unreachable!("objects are inferred as structs")
+                };
+                fields
+                    .map_or(Ok(object.clone()), |previous| {
+                        merge_fields(previous, object, "")
+                    })
+                    .map(Some)
+            }
+            _ => Err(JsonError::NotAnObject { index }),
+        })
+        .map(Option::unwrap_or_default)
+}
+
+/// Returns the data type of `value`.
+fn infer(value: &Value, path: &str) -> Result<DataType, JsonError> {
+    Ok(match *value {
+        Value::Null => DataType::Null,
+        Value::Bool(_) => DataType::Boolean,
+        Value::Number(ref number) if number.is_i64() => DataType::Int64,
+        Value::Number(_) => DataType::Float64,
+        Value::String(_) => DataType::Utf8,
+        Value::Array(ref items) => DataType::List(Box::new(
+            items.iter().try_fold(DataType::Null, |data_type, item| {
+                merge(data_type, infer(item, path)?, path)
+            })?,
+        )),
+        Value::Object(ref object) => DataType::Struct(
+            object
+                .iter()
+                .map(|(name, field_value)| {
+                    Ok(Field {
+                        name: name.clone(),
+                        data_type: infer(field_value, &join(path, name))?,
+                        nullable: field_value.is_null(),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+    })
+}
+
+/// Returns the data type that is compatible with both `left` and `right`.
+fn merge(left: DataType, right: DataType, path: &str) -> Result<DataType, JsonError> {
+    match (left, right) {
+        (DataType::Null, data_type) | (data_type, DataType::Null) => Ok(data_type),
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            Ok(DataType::Float64)
+        }
+        (DataType::List(left_items), DataType::List(right_items)) => Ok(DataType::List(Box::new(
+            merge(*left_items, *right_items, path)?,
+        ))),
+        (DataType::Struct(left_fields), DataType::Struct(right_fields)) => Ok(DataType::Struct(
+            merge_fields(left_fields, right_fields, path)?,
+        )),
+        (left_type, right_type) if left_type == right_type => Ok(left_type),
+        (left_type, right_type) => Err(JsonError::IncompatibleTypes {
+            field: path.to_owned(),
+            found: right_type,
+            expected: left_type,
+        }),
+    }
+}
+
+/// Returns the union of the fields in `left` and `right`. Fields that are
+/// missing on one side become nullable.
+fn merge_fields(left: Vec<Field>, right: Vec<Field>, path: &str) -> Result<Vec<Field>, JsonError> {
+    let mut fields = left;
+    fields
+        .iter_mut()
+        .filter(|field| !right.iter().any(|other| other.name == field.name))
+        .for_each(|field| field.nullable = true);
+    for field in right {
+        match fields.iter_mut().find(|other| other.name == field.name) {
+            Some(existing) => {
+                let field_path = join(path, &field.name);
+                let data_type = std::mem::replace(&mut existing.data_type, DataType::Null);
+                existing.data_type = merge(data_type, field.data_type, &field_path)?;
+                existing.nullable |= field.nullable;
+            }
+            None => fields.push(Field {
+                nullable: true,
+                ..field
+            }),
+        }
+    }
+    Ok(fields)
+}
+
+/// Returns the path of the field `name` of the field at `path`.
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+/// Converts JSON objects to struct [`ArrayData`] with the given fields.
+///
+/// Fields that are missing in an object are null. Fields of the objects that
+/// are not in `fields` are ignored.
+///
+/// # Errors
+///
+/// Returns an error if a value is not an object, or if a value of a field
+/// does not match the type of the field.
+pub fn to_array_data(values: &[Value], fields: &[Field]) -> Result<ArrayData, JsonError> {
+    if let Some(index) = values.iter().position(|value| !value.is_object()) {
+        return Err(JsonError::NotAnObject { index });
+    }
+    let mut data = build(
+        &DataType::Struct(fields.to_vec()),
+        &values.iter().map(Some).collect::<Vec<_>>(),
+        "",
+    )?;
+    data.nulls = None;
+    Ok(data)
+}
+
+/// Returns the array data of type `data_type` with the given values, where
+/// missing values are `None`.
+fn build(
+    data_type: &DataType,
+    values: &[Option<&Value>],
+    path: &str,
+) -> Result<ArrayData, JsonError> {
+    /// Returns the value, or `None` for missing and null values.
+    fn present(value: Option<&Value>) -> Option<&Value> {
+        value.filter(|inner| !inner.is_null())
+    }
+
+    /// Returns the values converted with `f`, or an error if a value can't be
+    /// converted.
+    fn convert<'a, T>(
+        values: &[Option<&'a Value>],
+        data_type: &DataType,
+        path: &str,
+        f: impl Fn(&'a Value) -> Option<T>,
+    ) -> Result<Vec<Option<T>>, JsonError> {
+        values
+            .iter()
+            .copied()
+            .map(present)
+            .map(|value| {
+                value
+                    .map(|inner| {
+                        f(inner).ok_or_else(|| JsonError::IncompatibleTypes {
+                            field: path.to_owned(),
+                            found: infer(inner, path).unwrap_or(DataType::Null),
+                            expected: data_type.clone(),
+                        })
+                    })
+                    .transpose()
+            })
+            .collect()
+    }
+
+    let validity = || {
+        values
+            .iter()
+            .map(|value| present(*value).is_some())
+            .collect::<Bitmap>()
+    };
+
+    match *data_type {
+        DataType::Null => Ok(values.iter().map(|_| ()).collect::<NullArray>().into()),
+        DataType::Boolean => Ok(convert(values, data_type, path, Value::as_bool)?
+            .into_iter()
+            .collect::<BooleanArray<true>>()
+            .into()),
+        DataType::Int64 => Ok(convert(values, data_type, path, Value::as_i64)?
+            .into_iter()
+            .collect::<Int64Array<true>>()
+            .into()),
+        DataType::Float64 => Ok(convert(values, data_type, path, Value::as_f64)?
+            .into_iter()
+            .collect::<Float64Array<true>>()
+            .into()),
+        DataType::Utf8 => Ok(convert(values, data_type, path, Value::as_str)?
+            .into_iter()
+            .collect::<StringArray<true>>()
+            .into()),
+        DataType::List(ref item_type) => {
+            let lists = convert(values, data_type, path, Value::as_array)?;
+            let mut offsets = vec![0_i32];
+            let mut items = Vec::new();
+            for list in &lists {
+                items.extend(list.iter().copied().flatten().map(Some));
+                offsets.push(
+                    i32::try_from(items.len()).expect("number of list items should fit in i32"),
+                );
+            }
+            Ok(ArrayData {
+                data_type: data_type.clone(),
+                len: values.len(),
+                nulls: Some(validity()),
+                buffers: vec![offsets
+                    .iter()
+                    .flat_map(|offset| offset.to_ne_bytes())
+                    .collect()],
+                children: vec![build(item_type, &items, path)?],
+            })
+        }
+        DataType::Struct(ref fields) => {
+            let objects = convert(values, data_type, path, Value::as_object)?;
+            Ok(ArrayData {
+                data_type: data_type.clone(),
+                len: values.len(),
+                nulls: Some(validity()),
+                buffers: Vec::new(),
+                children: fields
+                    .iter()
+                    .map(|field| {
+                        let field_values = objects
+                            .iter()
+                            .map(|object| object.and_then(|inner| inner.get(&field.name)))
+                            .collect::<Vec<_>>();
+                        build(&field.data_type, &field_values, &join(path, &field.name))
+                    })
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+        ref other => Err(JsonError::IncompatibleTypes {
+            field: path.to_owned(),
+            found: other.clone(),
+            expected: DataType::Utf8,
+        }),
+    }
+}
+
+/// Deserializes JSON objects into a typed struct array.
+///
+/// This is used when the JSON objects match a Rust type, e.g. after checking
+/// the fields returned by [`infer_fields`].
+///
+/// # Errors
+///
+/// Returns an error if a value can't be deserialized into `T`.
+pub fn to_struct_array<T: StructArrayType + DeserializeOwned>(
+    values: &[Value],
+) -> Result<StructArray<T>, JsonError>
+where
+    StructArray<T>: FromIterator<T>,
+{
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            T::deserialize(value).map_err(|error| JsonError::Deserialize {
+                index,
+                message: error.to_string(),
+            })
+        })
+        .collect()
+}
+
+impl ArrayData {
+    /// Returns the element at `index` as a JSON value.
+    ///
+    /// Null elements are [`Value::Null`], lists are arrays and structs are
+    /// objects. Binary values are arrays of bytes, and floating point values
+    /// that are NaN or infinite are null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds, or if the buffers don't match
+    /// the data type.
+    ///
+    /// ```
+    /// use narrow::{array::VariableSizeListArray, array::Uint8Array, layout::ArrayData};
+    ///
+    /// let array = [vec![1, 2], vec![3]]
+    ///     .into_iter()
+    ///     .collect::<VariableSizeListArray<Uint8Array>>();
+    /// let data = ArrayData::from(array);
+    /// assert_eq!(data.to_json_value(0), serde_json::json!([1, 2]));
+    /// assert_eq!(data.json_display(1).to_string(), "[3]");
+    /// ```
+    #[must_use]
+    pub fn to_json_value(&self, index: usize) -> Value {
+        assert!(
+            index < self.len,
+            "index (is {index}) should be less than len (is {})",
+            self.len
+        );
+        if self
+            .nulls
+            .as_ref()
+            .is_some_and(|nulls| nulls.get(index) == Some(false))
+        {
+            return Value::Null;
+        }
+        match self.data_type {
+            DataType::Null => Value::Null,
+            DataType::Boolean => Value::Bool(self.buffers[0][index / 8] >> (index % 8) & 1 == 1),
+            DataType::Int8 => Value::from(i8::from_ne_bytes(self.fixed(index))),
+            DataType::Int16 => Value::from(i16::from_ne_bytes(self.fixed(index))),
+            DataType::Int32 => Value::from(i32::from_ne_bytes(self.fixed(index))),
+            DataType::Int64 => Value::from(i64::from_ne_bytes(self.fixed(index))),
+            DataType::UInt8 => Value::from(u8::from_ne_bytes(self.fixed(index))),
+            DataType::UInt16 => Value::from(u16::from_ne_bytes(self.fixed(index))),
+            DataType::UInt32 => Value::from(u32::from_ne_bytes(self.fixed(index))),
+            DataType::UInt64 => Value::from(u64::from_ne_bytes(self.fixed(index))),
+            DataType::Float32 => Value::from(f32::from_ne_bytes(self.fixed(index))),
+            DataType::Float64 => Value::from(f64::from_ne_bytes(self.fixed(index))),
+            DataType::Binary => Value::from(&self.buffers[1][self.value_range(index, false)]),
+            DataType::LargeBinary => Value::from(&self.buffers[1][self.value_range(index, true)]),
+            DataType::Utf8 => Value::from(String::from_utf8_lossy(
+                &self.buffers[1][self.value_range(index, false)],
+            )),
+            DataType::LargeUtf8 => Value::from(String::from_utf8_lossy(
+                &self.buffers[1][self.value_range(index, true)],
+            )),
+            DataType::List(_) => self.items(self.value_range(index, false)),
+            DataType::LargeList(_) => self.items(self.value_range(index, true)),
+            DataType::FixedSizeList(_, size) => self.items(index * size..(index + 1) * size),
+            DataType::Struct(ref fields) => Value::Object(
+                fields
+                    .iter()
+                    .zip(&self.children)
+                    .map(|(field, child)| (field.name.clone(), child.to_json_value(index)))
+                    .collect(),
+            ),
+            DataType::Union(ref fields, mode) => {
+                let variant = usize::from(self.buffers[0][index]);
+                let child_index = match mode {
+                    UnionMode::Dense => usize::try_from(i32::from_ne_bytes(
+                        self.buffers[1][index * 4..(index + 1) * 4]
+                            .try_into()
+                            .expect("offset should have 4 bytes"),
+                    ))
+                    .expect("offset should be non-negative"),
+                    UnionMode::Sparse => index,
+                };
+                Value::Object(
+                    iter::once((
+                        fields[variant].name.clone(),
+                        self.children[variant].to_json_value(child_index),
+                    ))
+                    .collect(),
+                )
+            }
+        }
+    }
+
+    /// Returns an adapter that displays the element at `index` as JSON.
+    ///
+    /// See [`ArrayData::to_json_value`].
+    #[must_use]
+    pub fn json_display(&self, index: usize) -> JsonDisplay<'_> {
+        JsonDisplay { data: self, index }
+    }
+
+    /// Returns the bytes of the fixed-size value at `index`.
+    fn fixed<const N: usize>(&self, index: usize) -> [u8; N] {
+        self.buffers[0][index * N..(index + 1) * N]
+            .try_into()
+            .expect("value should have N bytes")
+    }
+
+    /// Returns the range of the variable-size value at `index`, with 64-bit
+    /// offsets when `large` is set.
+    fn value_range(&self, index: usize, large: bool) -> Range<usize> {
+        let offset = |position: usize| {
+            if large {
+                usize::try_from(i64::from_ne_bytes(self.fixed(position)))
+            } else {
+                usize::try_from(i32::from_ne_bytes(self.fixed(position)))
+            }
+            .expect("offset should be non-negative")
+        };
+        offset(index)..offset(index + 1)
+    }
+
+    /// Returns the elements of the first child in `range` as a JSON array.
+    fn items(&self, range: Range<usize>) -> Value {
+        Value::Array(
+            range
+                .map(|index| self.children[0].to_json_value(index))
+                .collect(),
+        )
+    }
+}
+
+/// Displays an element of an array as JSON.
+///
+/// Returned by [`ArrayData::json_display`].
+#[derive(Clone, Copy, Debug)]
+pub struct JsonDisplay<'a> {
+    /// The array.
+    data: &'a ArrayData,
+    /// The index of the element.
+    index: usize,
+}
+
+impl Display for JsonDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.data.to_json_value(self.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn infer() {
+        let values = [
+            json!({ "id": 1, "name": "a", "tags": ["x"], "point": { "x": 1 } }),
+            json!({ "id": 2, "name": null, "tags": [], "point": { "x": 1.5, "y": 2 } }),
+            json!({ "id": 3, "score": true }),
+        ];
+        let fields = infer_fields(&values).expect("compatible values");
+        let field = |name: &str, data_type, nullable| Field {
+            name: name.to_owned(),
+            data_type,
+            nullable,
+        };
+        assert_eq!(
+            fields,
+            [
+                field("id", DataType::Int64, false),
+                field("name", DataType::Utf8, true),
+                field(
+                    "point",
+                    DataType::Struct(vec![
+                        field("x", DataType::Float64, false),
+                        field("y", DataType::Int64, true),
+                    ]),
+                    true
+                ),
+                field("tags", DataType::List(Box::new(DataType::Utf8)), true),
+                field("score", DataType::Boolean, true),
+            ]
+        );
+
+        let data = to_array_data(&values, &fields).expect("values match fields");
+        assert_eq!(data.len, 3);
+        let mut children = data.children.into_iter();
+        let ids =
+            Int64Array::<false>::try_from(children.next().expect("id")).expect("non-null integers");
+        assert_eq!(ids.as_ref(), [1, 2, 3]);
+        let names = StringArray::<true>::try_from(children.next().expect("name"))
+            .expect("nullable strings");
+        assert_eq!(
+            names.into_iter().collect::<Vec<_>>(),
+            [Some("a"), None, None]
+        );
+    }
+
+    #[test]
+    fn incompatible() {
+        let values = [json!({ "a": { "b": 1 } }), json!({ "a": { "b": "1" } })];
+        assert_eq!(
+            infer_fields(&values),
+            Err(JsonError::IncompatibleTypes {
+                field: "a.b".to_owned(),
+                found: DataType::Utf8,
+                expected: DataType::Int64
+            })
+        );
+        assert_eq!(
+            infer_fields(&[json!(1)]),
+            Err(JsonError::NotAnObject { index: 0 })
+        );
+    }
+
+    #[test]
+    fn to_json_value() {
+        let values = [
+            json!({ "id": 1, "name": "a", "tags": ["x", "y"], "point": { "x": 1.5 } }),
+            json!({ "id": 2, "name": null, "tags": [], "point": null }),
+        ];
+        let fields = infer_fields(&values).expect("compatible values");
+        let data = to_array_data(&values, &fields).expect("values match fields");
+        assert_eq!(data.to_json_value(0), values[0]);
+        assert_eq!(data.to_json_value(1), values[1]);
+        assert_eq!(
+            data.json_display(0).to_string(),
+            r#"{"id":1,"name":"a","point":{"x":1.5},"tags":["x","y"]}"#
+        );
+    }
+}
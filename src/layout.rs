@@ -0,0 +1,2856 @@
+//! Uniform access to the physical layout of arrays.
+
+#[cfg(feature = "strings")]
+use crate::array::{self, StringArray, StringArrayError};
+#[cfg(feature = "lists")]
+use crate::array::{Array, FixedSizeListArray, VariableSizeListArray};
+#[cfg(feature = "unions")]
+use crate::array::{
+    DenseLayout, DenseUnionArray, SparseLayout, SparseUnionArray, UnionArray, UnionArrayType,
+};
+#[cfg(feature = "structs")]
+use crate::array::{StructArray, StructArrayType};
+#[cfg(any(feature = "structs", feature = "unions"))]
+use crate::buffer::VecBuffer;
+#[cfg(any(feature = "strings", all(feature = "lists", feature = "structs")))]
+use crate::validity::Validity;
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray, NullArray, Unit, VariableSizeBinaryArray},
+    bitmap::{self, Bitmap},
+    buffer::{Buffer, BufferType},
+    nullable::Nullable,
+    offset::{Offset, OffsetElement},
+    FixedSize, Length, PlainOldData,
+};
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{self, Display},
+    io::{self, Read, Write},
+    iter, ptr,
+};
+
+/// A borrowed validity bitmap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidityView<'a> {
+    /// The bytes storing the validity bits, LSB-first.
+    pub bytes: &'a [u8],
+    /// The offset of the first validity bit in `bytes`, in bits.
+    pub offset: usize,
+    /// The number of validity bits.
+    pub len: usize,
+}
+
+impl<'a, Buffer: BufferType> From<&'a Bitmap<Buffer>> for ValidityView<'a> {
+    fn from(bitmap: &'a Bitmap<Buffer>) -> Self {
+        Self {
+            bytes: bitmap.buffer.as_slice(),
+            offset: bitmap.offset,
+            len: bitmap.bits,
+        }
+    }
+}
+
+/// Arrays with a physical layout that can be inspected without knowing the
+/// concrete array type.
+///
+/// The order of the buffers and children follows the Arrow columnar format,
+/// excluding the validity bitmap, which is returned by
+/// [`ArrayLayout::null_buffer`].
+pub trait ArrayLayout: Length {
+    /// Returns the buffers of this array, in order, as bytes.
+    fn buffers(&self) -> Vec<&[u8]>;
+
+    /// Returns the child arrays of this array, in order.
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        Vec::new()
+    }
+
+    /// Returns the validity bitmap of this array, if it is nullable.
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        None
+    }
+
+    /// Returns the offset of the first value in the buffer at `index`, in
+    /// bits.
+    ///
+    /// This is only nonzero for bit-packed buffers that don't start at a byte
+    /// boundary, e.g. the values of a sliced boolean array. The bits before
+    /// the offset are not part of the array.
+    fn buffer_bit_offset(&self, _index: usize) -> usize {
+        0
+    }
+}
+
+/// Arrays of the fields of a struct, or of the variants of a union, with a
+/// physical layout that can be inspected.
+///
+/// This is implemented by the `ArrayType` derive macro. It is used to
+/// implement [`ArrayLayout`] for [`StructArray`](crate::array::StructArray)
+/// and [`UnionArray`](crate::array::UnionArray).
+pub trait ChildrenLayout {
+    /// Returns the arrays of the children, in order.
+    fn child_layouts(&self) -> Vec<&dyn ArrayLayout>;
+}
+
+impl<T: FixedSize, Buffer: BufferType> ArrayLayout for FixedSizePrimitiveArray<T, false, Buffer> {
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.as_bytes()]
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> ArrayLayout for FixedSizePrimitiveArray<T, true, Buffer> {
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.data.as_bytes()]
+    }
+
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        Some((&self.0.validity).into())
+    }
+}
+
+impl<Buffer: BufferType> ArrayLayout for BooleanArray<false, Buffer> {
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.buffer.as_slice()]
+    }
+
+    fn buffer_bit_offset(&self, index: usize) -> usize {
+        if index == 0 {
+            self.0.offset
+        } else {
+            0
+        }
+    }
+}
+
+impl<Buffer: BufferType> ArrayLayout for BooleanArray<true, Buffer> {
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.data.buffer.as_slice()]
+    }
+
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        Some((&self.0.validity).into())
+    }
+
+    fn buffer_bit_offset(&self, index: usize) -> usize {
+        if index == 0 {
+            self.0.data.offset
+        } else {
+            0
+        }
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> ArrayLayout
+    for VariableSizeBinaryArray<false, OffsetItem, Buffer>
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.offsets.as_bytes(), self.0.data.0.as_bytes()]
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> ArrayLayout
+    for VariableSizeBinaryArray<true, OffsetItem, Buffer>
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.offsets.data.as_bytes(), self.0.data.0.as_bytes()]
+    }
+
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        Some((&self.0.offsets.validity).into())
+    }
+}
+
+#[cfg(feature = "strings")]
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ArrayLayout
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: ArrayLayout,
+    Self: Length,
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        self.0.buffers()
+    }
+
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        self.0.null_buffer()
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<T: Array + ArrayLayout, OffsetItem: OffsetElement, Buffer: BufferType> ArrayLayout
+    for VariableSizeListArray<T, false, OffsetItem, Buffer>
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.offsets.as_bytes()]
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        vec![&self.0.data]
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<T: Array + ArrayLayout, OffsetItem: OffsetElement, Buffer: BufferType> ArrayLayout
+    for VariableSizeListArray<T, true, OffsetItem, Buffer>
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.offsets.data.as_bytes()]
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        vec![&self.0.data]
+    }
+
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        Some((&self.0.offsets.validity).into())
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + ArrayLayout, Buffer: BufferType> ArrayLayout
+    for FixedSizeListArray<N, T, false, Buffer>
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        Vec::new()
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        vec![&self.0]
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + ArrayLayout, Buffer: BufferType> ArrayLayout
+    for FixedSizeListArray<N, T, true, Buffer>
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        Vec::new()
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        vec![&self.0.data]
+    }
+
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        Some((&self.0.validity).into())
+    }
+}
+
+impl<T: Unit, Buffer: BufferType> ArrayLayout for NullArray<T, false, Buffer> {
+    fn buffers(&self) -> Vec<&[u8]> {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType, Buffer: BufferType> ArrayLayout for StructArray<T, false, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: ChildrenLayout,
+    Self: Length,
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        Vec::new()
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        self.0.child_layouts()
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType, Buffer: BufferType> ArrayLayout for StructArray<T, true, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: ChildrenLayout,
+    Self: Length,
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        Vec::new()
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        self.0.data.child_layouts()
+    }
+
+    fn null_buffer(&self) -> Option<ValidityView<'_>> {
+        Some((&self.0.validity).into())
+    }
+}
+
+#[cfg(feature = "unions")]
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > ArrayLayout for UnionArray<T, VARIANTS, DenseLayout, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>: ChildrenLayout,
+    Self: Length,
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.types.0.as_bytes(), self.0.offsets.0.as_bytes()]
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        self.0.variants.child_layouts()
+    }
+}
+
+#[cfg(feature = "unions")]
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > ArrayLayout for UnionArray<T, VARIANTS, SparseLayout, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>: ChildrenLayout,
+    Self: Length,
+{
+    fn buffers(&self) -> Vec<&[u8]> {
+        vec![self.0.types.0.as_bytes()]
+    }
+
+    fn children(&self) -> Vec<&dyn ArrayLayout> {
+        self.0.variants.child_layouts()
+    }
+}
+
+/// The logical type of an [`ArrayData`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DataType {
+    /// Null values.
+    Null,
+    /// Boolean values, stored as bits.
+    Boolean,
+    /// Signed 8-bit integers.
+    Int8,
+    /// Signed 16-bit integers.
+    Int16,
+    /// Signed 32-bit integers.
+    Int32,
+    /// Signed 64-bit integers.
+    Int64,
+    /// Unsigned 8-bit integers.
+    UInt8,
+    /// Unsigned 16-bit integers.
+    UInt16,
+    /// Unsigned 32-bit integers.
+    UInt32,
+    /// Unsigned 64-bit integers.
+    UInt64,
+    /// 32-bit floating point numbers.
+    Float32,
+    /// 64-bit floating point numbers.
+    Float64,
+    /// Variable-size binary values with 32-bit offsets.
+    Binary,
+    /// Variable-size binary values with 64-bit offsets.
+    LargeBinary,
+    /// UTF-8 encoded strings with 32-bit offsets.
+    Utf8,
+    /// UTF-8 encoded strings with 64-bit offsets.
+    LargeUtf8,
+    /// Variable-size lists with 32-bit offsets, with the type of the elements.
+    List(Box<DataType>),
+    /// Variable-size lists with 64-bit offsets, with the type of the elements.
+    LargeList(Box<DataType>),
+    /// Fixed-size lists, with the type of the elements and the size of the
+    /// lists.
+    FixedSizeList(Box<DataType>, usize),
+    /// Structs, with the fields of the struct.
+    Struct(Vec<Field>),
+    /// Unions, with the variants of the union as fields and the layout of
+    /// the union.
+    Union(Vec<Field>, UnionMode),
+}
+
+/// The layout of the values of a union [`ArrayData`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnionMode {
+    /// Every variant has an array with only the values of that variant. A
+    /// buffer of offsets stores the position of every value in its variant
+    /// array.
+    Dense,
+    /// Every variant has an array with the length of the union.
+    Sparse,
+}
+
+/// A named field of a struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    /// The name of the field.
+    pub name: String,
+    /// The data type of the field.
+    pub data_type: DataType,
+    /// Whether the field can contain nulls.
+    pub nullable: bool,
+}
+
+/// Fixed-size primitive types with a [`DataType`].
+pub trait PrimitiveType: PlainOldData {
+    /// The data type of arrays with values of this type.
+    const DATA_TYPE: DataType;
+}
+
+/// Implements [`PrimitiveType`] for the given types.
+macro_rules! impl_primitive_type {
+    ($($ty:ty => $variant:ident),+) => {
+        $(
+            impl PrimitiveType for $ty {
+                const DATA_TYPE: DataType = DataType::$variant;
+            }
+        )+
+    };
+}
+
+impl_primitive_type!(
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64
+);
+
+/// Arrays of the fields of a struct, or of the variants of a union, that
+/// convert to and from [`ArrayData`].
+///
+/// This is implemented by the `ArrayType` derive macro. It is used to convert
+/// [`StructArray`](crate::array::StructArray) and
+/// [`UnionArray`](crate::array::UnionArray) values to and from array data.
+pub trait ChildrenArrayData: Sized {
+    /// The names of the children, in order.
+    const NAMES: &'static [&'static str];
+
+    /// Returns the array data of the children, in order.
+    fn into_children(self) -> Vec<ArrayData>;
+
+    /// Returns the children converted from their array data, in order.
+    ///
+    /// The `len` is the length of the parent array, which is used for
+    /// children without array data, e.g. the fields of unit structs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the number of children differs from the number
+    /// of names, or when a child can't be converted.
+    fn try_from_children(len: usize, children: Vec<ArrayData>) -> Result<Self, ArrayDataError>;
+}
+
+/// Owned array data, without the static type of the array.
+///
+/// This is an intermediate representation to move arrays across boundaries
+/// where the concrete array type is not known statically, e.g. to and from
+/// FFI or IPC. Typed arrays convert into array data with [`From`], and back
+/// with [`TryFrom`], which validates the data type, the buffers and the
+/// children.
+///
+/// The buffers and children follow the same order as in [`ArrayLayout`].
+#[derive(Debug)]
+pub struct ArrayData {
+    /// The data type of the array.
+    pub data_type: DataType,
+    /// The number of elements in the array.
+    pub len: usize,
+    /// The validity bitmap of the array. `None` when all elements are valid.
+    pub nulls: Option<Bitmap>,
+    /// The buffers of the array, in order, as bytes.
+    pub buffers: Vec<Vec<u8>>,
+    /// The child arrays of the array, in order.
+    pub children: Vec<ArrayData>,
+}
+
+/// Error returned when [`ArrayData`] can't be converted to a typed array.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArrayDataError {
+    /// The data type differs from the data type of the array.
+    DataType {
+        /// The data type of the array data.
+        found: DataType,
+        /// The data type of the array.
+        expected: DataType,
+    },
+    /// The number of buffers differs from the number of buffers of the array.
+    BufferCount {
+        /// The number of buffers of the array data.
+        count: usize,
+        /// The number of buffers of the array.
+        expected: usize,
+    },
+    /// A buffer is too short for the length of the array.
+    BufferLength {
+        /// The index of the buffer.
+        index: usize,
+        /// The length of the buffer in bytes.
+        len: usize,
+        /// The minimum length of the buffer in bytes.
+        expected: usize,
+    },
+    /// The number of children differs from the number of children of the
+    /// array.
+    ChildCount {
+        /// The number of children of the array data.
+        count: usize,
+        /// The number of children of the array.
+        expected: usize,
+    },
+    /// The length of a child differs from the length required by the array.
+    ChildLength {
+        /// The length of the child.
+        len: usize,
+        /// The required length of the child.
+        expected: usize,
+    },
+    /// The length of the validity bitmap differs from the length of the
+    /// array.
+    NullsLength {
+        /// The length of the validity bitmap.
+        len: usize,
+        /// The length of the array.
+        expected: usize,
+    },
+    /// The array data has nulls, but the array is not nullable.
+    Nulls {
+        /// The number of nulls.
+        null_count: usize,
+    },
+    /// The offsets are negative, decreasing or out of bounds.
+    InvalidOffsets {
+        /// The length of the values referenced by the offsets.
+        values_len: usize,
+    },
+    /// A string value is not valid UTF-8.
+    InvalidUtf8 {
+        /// The index of the value.
+        index: usize,
+    },
+    /// The offsets of a child differ from the offsets of the first child.
+    OffsetsMismatch {
+        /// The index of the child.
+        index: usize,
+    },
+    /// The number of bytes of a buffer for the length of the array overflows
+    /// `usize`.
+    LengthOverflow {
+        /// The length of the array.
+        len: usize,
+    },
+    /// The name of a field differs from the name of the field of the array.
+    FieldName {
+        /// The index of the field.
+        index: usize,
+        /// The name of the field of the array data.
+        found: String,
+        /// The name of the field of the array.
+        expected: &'static str,
+    },
+    /// A type id of a union is not the index of a variant.
+    InvalidTypeId {
+        /// The index of the value.
+        index: usize,
+    },
+}
+
+impl Display for ArrayDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::DataType {
+                ref found,
+                ref expected,
+            } => write!(f, "data type (is {found:?}) should be {expected:?}"),
+            Self::BufferCount { count, expected } => {
+                write!(f, "number of buffers (is {count}) should be {expected}")
+            }
+            Self::BufferLength {
+                index,
+                len,
+                expected,
+            } => write!(
+                f,
+                "length of buffer {index} (is {len}) should be at least {expected}"
+            ),
+            Self::ChildCount { count, expected } => {
+                write!(f, "number of children (is {count}) should be {expected}")
+            }
+            Self::ChildLength { len, expected } => {
+                write!(f, "length of child (is {len}) should be {expected}")
+            }
+            Self::NullsLength { len, expected } => write!(
+                f,
+                "length of validity bitmap (is {len}) should be equal to {expected}"
+            ),
+            Self::Nulls { null_count } => write!(
+                f,
+                "null count (is {null_count}) should be 0 for a non-nullable array"
+            ),
+            Self::InvalidOffsets { values_len } => write!(
+                f,
+                "offsets should be non-negative, non-decreasing and at most {values_len}"
+            ),
+            Self::InvalidUtf8 { index } => write!(f, "value {index} should be valid UTF-8"),
+            Self::OffsetsMismatch { index } => write!(
+                f,
+                "offsets of child {index} should be equal to the offsets of child 0"
+            ),
+            Self::LengthOverflow { len } => {
+                write!(f, "buffer length for array length {len} overflows usize")
+            }
+            Self::FieldName {
+                index,
+                ref found,
+                expected,
+            } => write!(f, "name of field {index} (is {found}) should be {expected}"),
+            Self::InvalidTypeId { index } => write!(
+                f,
+                "type id of value {index} should be the index of a variant"
+            ),
+        }
+    }
+}
+
+impl Error for ArrayDataError {}
+
+impl ArrayData {
+    /// Checks the data type and the number of buffers and children.
+    fn validate(
+        &self,
+        data_type: &DataType,
+        buffers: usize,
+        children: usize,
+    ) -> Result<(), ArrayDataError> {
+        if self.data_type != *data_type {
+            Err(ArrayDataError::DataType {
+                found: self.data_type.clone(),
+                expected: data_type.clone(),
+            })
+        } else if self.buffers.len() != buffers {
+            Err(ArrayDataError::BufferCount {
+                count: self.buffers.len(),
+                expected: buffers,
+            })
+        } else if self.children.len() != children {
+            Err(ArrayDataError::ChildCount {
+                count: self.children.len(),
+                expected: children,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the data type of the first child, or [`DataType::Null`]
+    /// without children.
+    #[cfg(feature = "lists")]
+    fn child_type(&self) -> DataType {
+        self.children
+            .first()
+            .map_or(DataType::Null, |child| child.data_type.clone())
+    }
+
+    /// Returns the buffer at `index`, after checking it has at least `len`
+    /// bytes.
+    fn buffer(&self, index: usize, len: usize) -> Result<&[u8], ArrayDataError> {
+        let buffer = &self.buffers[index];
+        if buffer.len() < len {
+            Err(ArrayDataError::BufferLength {
+                index,
+                len: buffer.len(),
+                expected: len,
+            })
+        } else {
+            Ok(buffer)
+        }
+    }
+
+    /// Returns the validity bitmap, with all bits set when there are no
+    /// nulls.
+    fn validity(&mut self) -> Result<Bitmap, ArrayDataError> {
+        match self.nulls.take() {
+            Some(nulls) if nulls.len() != self.len => Err(ArrayDataError::NullsLength {
+                len: nulls.len(),
+                expected: self.len,
+            }),
+            Some(nulls) => Ok(nulls),
+            None => Ok(iter::repeat(true).take(self.len).collect()),
+        }
+    }
+
+    /// Checks that there are no nulls.
+    fn non_null(&mut self) -> Result<(), ArrayDataError> {
+        match self.nulls.take() {
+            Some(nulls) if nulls.len() != self.len => Err(ArrayDataError::NullsLength {
+                len: nulls.len(),
+                expected: self.len,
+            }),
+            Some(nulls) => match nulls.len() - nulls.count_ones() {
+                0 => Ok(()),
+                null_count => Err(ArrayDataError::Nulls { null_count }),
+            },
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the only child, converted to `T`.
+    #[cfg(feature = "lists")]
+    fn child<T: TryFrom<ArrayData, Error = ArrayDataError>>(
+        &mut self,
+        len: usize,
+    ) -> Result<T, ArrayDataError> {
+        let child = self.children.remove(0);
+        if child.len == len {
+            T::try_from(child)
+        } else {
+            Err(ArrayDataError::ChildLength {
+                len: child.len,
+                expected: len,
+            })
+        }
+    }
+}
+
+impl ArrayData {
+    /// Converts a list of structs to a struct of lists.
+    ///
+    /// Every field of the struct becomes a list with the offsets and the
+    /// validity of the list. Null struct items become null items in all
+    /// these lists, so the validity of both levels is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is not a list of structs.
+    pub fn into_struct_of_lists(self) -> Result<Self, ArrayDataError> {
+        let expected = || DataType::List(Box::new(DataType::Struct(Vec::new())));
+        self.validate(&self.data_type, 1, 1)?;
+        let Self {
+            data_type,
+            len,
+            nulls,
+            mut buffers,
+            mut children,
+        } = self;
+        let large = match data_type {
+            DataType::List(_) => false,
+            DataType::LargeList(_) => true,
+            found => {
+                return Err(ArrayDataError::DataType {
+                    found,
+                    expected: expected(),
+                })
+            }
+        };
+        let list = |item: DataType| {
+            if large {
+                DataType::LargeList(Box::new(item))
+            } else {
+                DataType::List(Box::new(item))
+            }
+        };
+        let offsets = buffers.remove(0);
+        let Self {
+            data_type: item_type,
+            nulls: item_nulls,
+            children: fields_data,
+            ..
+        } = children.remove(0);
+        let DataType::Struct(fields) = item_type else {
+            return Err(ArrayDataError::DataType {
+                found: list(item_type),
+                expected: expected(),
+            });
+        };
+        Ok(Self {
+            data_type: DataType::Struct(
+                fields
+                    .into_iter()
+                    .map(|field| Field {
+                        data_type: list(field.data_type),
+                        nullable: nulls.is_some(),
+                        ..field
+                    })
+                    .collect(),
+            ),
+            len,
+            nulls: nulls.as_ref().map(copy_bitmap),
+            buffers: Vec::new(),
+            children: fields_data
+                .into_iter()
+                .map(|mut child| {
+                    child.nulls = match (child.nulls.take(), item_nulls.as_ref()) {
+                        (Some(child_nulls), Some(items)) => Some(
+                            child_nulls
+                                .into_iter()
+                                .zip(items)
+                                .map(|(child_valid, item_valid)| child_valid && item_valid)
+                                .collect(),
+                        ),
+                        (child_nulls, items) => child_nulls.or_else(|| items.map(copy_bitmap)),
+                    };
+                    Self {
+                        data_type: list(child.data_type.clone()),
+                        len,
+                        nulls: nulls.as_ref().map(copy_bitmap),
+                        buffers: vec![offsets.clone()],
+                        children: vec![child],
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    /// Converts a struct of lists with equal offsets to a list of structs.
+    ///
+    /// This is the inverse of [`ArrayData::into_struct_of_lists`]. The
+    /// validity of the struct becomes the validity of the list. Struct items
+    /// are never null, null items of the lists become null fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is not a struct of lists, or if the lists
+    /// have different offsets.
+    pub fn into_list_of_structs(self) -> Result<Self, ArrayDataError> {
+        let Self {
+            data_type,
+            len,
+            nulls,
+            children,
+            ..
+        } = self;
+        let DataType::Struct(fields) = data_type else {
+            return Err(ArrayDataError::DataType {
+                found: data_type,
+                expected: DataType::Struct(Vec::new()),
+            });
+        };
+        let Some(first) = children.first() else {
+            return Err(ArrayDataError::ChildCount {
+                count: 0,
+                expected: 1,
+            });
+        };
+        let first_type = first.data_type.clone();
+        let large = matches!(first_type, DataType::LargeList(_));
+        let offsets = first.buffers.first().cloned().unwrap_or_default();
+        let values_len = first.children.first().map_or(0, |values| values.len);
+
+        let mut item_fields = Vec::with_capacity(fields.len());
+        let mut items = Vec::with_capacity(children.len());
+        for (index, (field, mut child)) in fields.into_iter().zip(children).enumerate() {
+            let item_type = match child.data_type {
+                DataType::List(ref item) if !large => item,
+                DataType::LargeList(ref item) if large => item,
+                _ => {
+                    return Err(ArrayDataError::DataType {
+                        found: child.data_type.clone(),
+                        expected: first_type.clone(),
+                    })
+                }
+            };
+            child.validate(&child.data_type, 1, 1)?;
+            if child.buffers[0] != offsets {
+                return Err(ArrayDataError::OffsetsMismatch { index });
+            }
+            let values = child.children.remove(0);
+            if values.len != values_len {
+                return Err(ArrayDataError::ChildLength {
+                    len: values.len,
+                    expected: values_len,
+                });
+            }
+            item_fields.push(Field {
+                data_type: (**item_type).clone(),
+                nullable: values.nulls.is_some(),
+                ..field
+            });
+            items.push(values);
+        }
+        let item_type = DataType::Struct(item_fields);
+        Ok(Self {
+            data_type: if large {
+                DataType::LargeList(Box::new(item_type.clone()))
+            } else {
+                DataType::List(Box::new(item_type.clone()))
+            },
+            len,
+            nulls,
+            buffers: vec![offsets],
+            children: vec![Self {
+                data_type: item_type,
+                len: values_len,
+                nulls: None,
+                buffers: Vec::new(),
+                children: items,
+            }],
+        })
+    }
+}
+
+#[cfg(all(feature = "lists", feature = "structs"))]
+impl<T: StructArrayType, const NULLABLE: bool, OffsetItem: OffsetElement>
+    VariableSizeListArray<StructArray<T>, NULLABLE, OffsetItem>
+where
+    Vec<OffsetItem>: Validity<NULLABLE>,
+    Self: Into<ArrayData> + TryFrom<ArrayData, Error = ArrayDataError>,
+{
+    /// Converts this list of structs to a struct of lists, e.g. a
+    /// [`StructArray`] of a struct with a list field for every field of `T`.
+    ///
+    /// See [`ArrayData::into_struct_of_lists`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the struct of lists can't be converted to `S`.
+    pub fn into_struct_of_lists<S: TryFrom<ArrayData, Error = ArrayDataError>>(
+        self,
+    ) -> Result<S, ArrayDataError> {
+        S::try_from(self.into().into_struct_of_lists()?)
+    }
+
+    /// Converts a struct of lists with equal offsets to a list of structs.
+    ///
+    /// This is the inverse of
+    /// [`VariableSizeListArray::into_struct_of_lists`]. See
+    /// [`ArrayData::into_list_of_structs`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lists` is not a struct of lists, if the lists
+    /// have different offsets, or if the list of structs can't be converted
+    /// to this array.
+    pub fn try_from_struct_of_lists(lists: impl Into<ArrayData>) -> Result<Self, ArrayDataError> {
+        Self::try_from(lists.into().into_list_of_structs()?)
+    }
+}
+
+/// Returns a copy of `bitmap`.
+fn copy_bitmap(bitmap: &Bitmap) -> Bitmap {
+    bitmap.into_iter().collect()
+}
+
+/// Returns the first `len` values of type `T` stored in `bytes`.
+fn values<T: PlainOldData>(bytes: &[u8], len: usize) -> Vec<T> {
+    bytes
+        .chunks_exact(T::SIZE)
+        .take(len)
+        .map(|chunk| {
+            // Safety:
+            // - Plain old data is valid for any bit pattern.
+            // - The chunk has the size of `T`, the read is unaligned.
+            unsafe { ptr::read_unaligned(chunk.as_ptr().cast::<T>()) }
+        })
+        .collect()
+}
+
+/// Returns the bytes of a bitmap, without an offset.
+fn bitmap_bytes(bitmap: Bitmap) -> Vec<u8> {
+    let aligned = if bitmap.offset == 0 {
+        bitmap
+    } else {
+        bitmap.into_iter().collect()
+    };
+    let mut bytes = aligned.buffer;
+    bytes.truncate(bytes_for_bits(aligned.bits));
+    bytes
+}
+
+/// Returns the `len` bits of `bytes` starting at bit `offset`, without an
+/// offset.
+fn aligned_bits(bytes: &[u8], offset: usize, len: usize) -> Cow<'_, [u8]> {
+    if offset == 0 {
+        Cow::Borrowed(&bytes[..bytes_for_bits(len)])
+    } else {
+        Cow::Owned(bitmap::read_bits(bytes, offset, len))
+    }
+}
+
+/// Returns `values * size`, the number of bytes or child values required by
+/// array data with length `len`, or an error when it overflows.
+fn checked_len(len: usize, values: Option<usize>, size: usize) -> Result<usize, ArrayDataError> {
+    values
+        .and_then(|count| count.checked_mul(size))
+        .ok_or(ArrayDataError::LengthOverflow { len })
+}
+
+/// Returns the number of bytes required to store `bits` bits.
+fn bytes_for_bits(bits: usize) -> usize {
+    bits / 8 + usize::from(bits % 8 != 0)
+}
+
+/// Returns the offsets of the `len` elements of a variable-size array, after
+/// checking them against the length of the values.
+fn offsets<OffsetItem: OffsetElement>(
+    bytes: &[u8],
+    len: usize,
+    values_len: usize,
+) -> Result<Vec<OffsetItem>, ArrayDataError> {
+    let offsets = values::<OffsetItem>(bytes, len + 1);
+    let mut previous = 0;
+    for &offset in &offsets {
+        match offset.try_into() {
+            Ok(current) if previous <= current && current <= values_len => previous = current,
+            _ => return Err(ArrayDataError::InvalidOffsets { values_len }),
+        }
+    }
+    Ok(offsets)
+}
+
+/// Checks that the values of a string array are valid UTF-8.
+#[cfg(feature = "strings")]
+fn validate_utf8<OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+    data: &[u8],
+) -> Result<(), ArrayDataError> {
+    array::validate_utf8(offsets, data).map_err(|error| match error {
+        StringArrayError::InvalidUtf8 { index } => ArrayDataError::InvalidUtf8 { index },
+    })
+}
+
+impl<T: PrimitiveType> From<FixedSizePrimitiveArray<T>> for ArrayData {
+    fn from(array: FixedSizePrimitiveArray<T>) -> Self {
+        Self {
+            data_type: T::DATA_TYPE,
+            len: array.len(),
+            nulls: None,
+            buffers: vec![array.0.as_bytes().to_vec()],
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<T: PrimitiveType> From<FixedSizePrimitiveArray<T, true>> for ArrayData {
+    fn from(array: FixedSizePrimitiveArray<T, true>) -> Self {
+        Self {
+            data_type: T::DATA_TYPE,
+            len: array.len(),
+            buffers: vec![array.0.data.as_bytes().to_vec()],
+            nulls: Some(array.0.validity),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<T: PrimitiveType> TryFrom<ArrayData> for FixedSizePrimitiveArray<T> {
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(&T::DATA_TYPE, 1, 0)?;
+        data.non_null()?;
+        let bytes = data.buffer(0, checked_len(data.len, Some(data.len), T::SIZE)?)?;
+        Ok(Self(values(bytes, data.len)))
+    }
+}
+
+impl<T: PrimitiveType> TryFrom<ArrayData> for FixedSizePrimitiveArray<T, true> {
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(&T::DATA_TYPE, 1, 0)?;
+        let values = values(
+            data.buffer(0, checked_len(data.len, Some(data.len), T::SIZE)?)?,
+            data.len,
+        );
+        Ok(Self(Nullable {
+            data: values,
+            validity: data.validity()?,
+        }))
+    }
+}
+
+impl From<BooleanArray> for ArrayData {
+    fn from(array: BooleanArray) -> Self {
+        Self {
+            data_type: DataType::Boolean,
+            len: array.len(),
+            nulls: None,
+            buffers: vec![bitmap_bytes(array.0)],
+            children: Vec::new(),
+        }
+    }
+}
+
+impl From<BooleanArray<true>> for ArrayData {
+    fn from(array: BooleanArray<true>) -> Self {
+        Self {
+            data_type: DataType::Boolean,
+            len: array.len(),
+            nulls: Some(array.0.validity),
+            buffers: vec![bitmap_bytes(array.0.data)],
+            children: Vec::new(),
+        }
+    }
+}
+
+impl ArrayData {
+    /// Returns the data of a boolean array as a bitmap.
+    fn boolean_data(&self) -> Result<Bitmap, ArrayDataError> {
+        self.validate(&DataType::Boolean, 1, 0)?;
+        let bytes = self.buffer(0, bytes_for_bits(self.len))?;
+        // Safety:
+        // - The buffer has enough bytes for `len` bits.
+        Ok(unsafe { Bitmap::from_raw_parts(bytes.to_vec(), self.len, 0) })
+    }
+}
+
+impl TryFrom<ArrayData> for BooleanArray {
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        let bitmap = data.boolean_data()?;
+        data.non_null()?;
+        Ok(Self(bitmap))
+    }
+}
+
+impl TryFrom<ArrayData> for BooleanArray<true> {
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        let bitmap = data.boolean_data()?;
+        Ok(Self(Nullable {
+            validity: data.validity()?,
+            data: bitmap,
+        }))
+    }
+}
+
+/// Implements the conversions between [`ArrayData`] and the variable-size
+/// arrays with the given offset type.
+macro_rules! impl_variable_size {
+    ($offset:ty, $binary:ident, $utf8:ident, $list:ident) => {
+        impl From<VariableSizeBinaryArray<false, $offset>> for ArrayData {
+            fn from(array: VariableSizeBinaryArray<false, $offset>) -> Self {
+                Self {
+                    data_type: DataType::$binary,
+                    len: array.len(),
+                    nulls: None,
+                    buffers: vec![array.0.offsets.as_bytes().to_vec(), array.0.data.0],
+                    children: Vec::new(),
+                }
+            }
+        }
+
+        impl From<VariableSizeBinaryArray<true, $offset>> for ArrayData {
+            fn from(array: VariableSizeBinaryArray<true, $offset>) -> Self {
+                Self {
+                    data_type: DataType::$binary,
+                    len: array.len(),
+                    buffers: vec![array.0.offsets.data.as_bytes().to_vec(), array.0.data.0],
+                    nulls: Some(array.0.offsets.validity),
+                    children: Vec::new(),
+                }
+            }
+        }
+
+        impl TryFrom<ArrayData> for VariableSizeBinaryArray<false, $offset> {
+            type Error = ArrayDataError;
+
+            fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+                data.validate(&DataType::$binary, 2, 0)?;
+                data.non_null()?;
+                let values = data.buffers.pop().unwrap_or_default();
+                let offsets = offsets(
+                    data.buffer(
+                        0,
+                        checked_len(data.len, data.len.checked_add(1), <$offset>::SIZE)?,
+                    )?,
+                    data.len,
+                    values.len(),
+                )?;
+                Ok(Self(Offset {
+                    data: FixedSizePrimitiveArray(values),
+                    offsets,
+                }))
+            }
+        }
+
+        impl TryFrom<ArrayData> for VariableSizeBinaryArray<true, $offset> {
+            type Error = ArrayDataError;
+
+            fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+                data.validate(&DataType::$binary, 2, 0)?;
+                let values = data.buffers.pop().unwrap_or_default();
+                let offsets = offsets(
+                    data.buffer(
+                        0,
+                        checked_len(data.len, data.len.checked_add(1), <$offset>::SIZE)?,
+                    )?,
+                    data.len,
+                    values.len(),
+                )?;
+                Ok(Self(Offset {
+                    data: FixedSizePrimitiveArray(values),
+                    offsets: Nullable {
+                        data: offsets,
+                        validity: data.validity()?,
+                    },
+                }))
+            }
+        }
+
+        #[cfg(feature = "strings")]
+        impl<const NULLABLE: bool> From<StringArray<NULLABLE, $offset>> for ArrayData
+        where
+            Vec<$offset>: Validity<NULLABLE>,
+            ArrayData: From<VariableSizeBinaryArray<NULLABLE, $offset>>,
+        {
+            fn from(array: StringArray<NULLABLE, $offset>) -> Self {
+                Self {
+                    data_type: DataType::$utf8,
+                    ..array.0.into()
+                }
+            }
+        }
+
+        #[cfg(feature = "strings")]
+        impl TryFrom<ArrayData> for StringArray<false, $offset> {
+            type Error = ArrayDataError;
+
+            fn try_from(data: ArrayData) -> Result<Self, Self::Error> {
+                data.validate(&DataType::$utf8, 2, 0)?;
+                let binary = VariableSizeBinaryArray::<false, $offset>::try_from(ArrayData {
+                    data_type: DataType::$binary,
+                    ..data
+                })?;
+                validate_utf8(&binary.0.offsets, &binary.0.data.0)?;
+                Ok(Self(binary))
+            }
+        }
+
+        #[cfg(feature = "strings")]
+        impl TryFrom<ArrayData> for StringArray<true, $offset> {
+            type Error = ArrayDataError;
+
+            fn try_from(data: ArrayData) -> Result<Self, Self::Error> {
+                data.validate(&DataType::$utf8, 2, 0)?;
+                let binary = VariableSizeBinaryArray::<true, $offset>::try_from(ArrayData {
+                    data_type: DataType::$binary,
+                    ..data
+                })?;
+                validate_utf8(&binary.0.offsets.data, &binary.0.data.0)?;
+                Ok(Self(binary))
+            }
+        }
+
+        #[cfg(feature = "lists")]
+        impl<T: Array + Into<ArrayData>> From<VariableSizeListArray<T, false, $offset>>
+            for ArrayData
+        {
+            fn from(array: VariableSizeListArray<T, false, $offset>) -> Self {
+                let child = array.0.data.into();
+                Self {
+                    data_type: DataType::$list(Box::new(child.data_type.clone())),
+                    len: array.0.offsets.len() - 1,
+                    nulls: None,
+                    buffers: vec![array.0.offsets.as_bytes().to_vec()],
+                    children: vec![child],
+                }
+            }
+        }
+
+        #[cfg(feature = "lists")]
+        impl<T: Array + Into<ArrayData>> From<VariableSizeListArray<T, true, $offset>>
+            for ArrayData
+        {
+            fn from(array: VariableSizeListArray<T, true, $offset>) -> Self {
+                let child = array.0.data.into();
+                Self {
+                    data_type: DataType::$list(Box::new(child.data_type.clone())),
+                    len: array.0.offsets.data.len() - 1,
+                    nulls: Some(array.0.offsets.validity),
+                    buffers: vec![array.0.offsets.data.as_bytes().to_vec()],
+                    children: vec![child],
+                }
+            }
+        }
+
+        #[cfg(feature = "lists")]
+        impl<T: Array + TryFrom<ArrayData, Error = ArrayDataError>> TryFrom<ArrayData>
+            for VariableSizeListArray<T, false, $offset>
+        {
+            type Error = ArrayDataError;
+
+            fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+                data.validate(&DataType::$list(Box::new(data.child_type())), 1, 1)?;
+                data.non_null()?;
+                let values_len = data.children[0].len;
+                let offsets = offsets(
+                    data.buffer(
+                        0,
+                        checked_len(data.len, data.len.checked_add(1), <$offset>::SIZE)?,
+                    )?,
+                    data.len,
+                    values_len,
+                )?;
+                Ok(Self(Offset {
+                    data: data.child(values_len)?,
+                    offsets,
+                }))
+            }
+        }
+
+        #[cfg(feature = "lists")]
+        impl<T: Array + TryFrom<ArrayData, Error = ArrayDataError>> TryFrom<ArrayData>
+            for VariableSizeListArray<T, true, $offset>
+        {
+            type Error = ArrayDataError;
+
+            fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+                data.validate(&DataType::$list(Box::new(data.child_type())), 1, 1)?;
+                let values_len = data.children[0].len;
+                let offsets = offsets(
+                    data.buffer(
+                        0,
+                        checked_len(data.len, data.len.checked_add(1), <$offset>::SIZE)?,
+                    )?,
+                    data.len,
+                    values_len,
+                )?;
+                Ok(Self(Offset {
+                    data: data.child(values_len)?,
+                    offsets: Nullable {
+                        data: offsets,
+                        validity: data.validity()?,
+                    },
+                }))
+            }
+        }
+    };
+}
+
+impl_variable_size!(i32, Binary, Utf8, List);
+impl_variable_size!(i64, LargeBinary, LargeUtf8, LargeList);
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + Into<ArrayData> + Length> From<FixedSizeListArray<N, T>>
+    for ArrayData
+{
+    fn from(array: FixedSizeListArray<N, T>) -> Self {
+        let len = array.len();
+        let child: ArrayData = array.0.into();
+        Self {
+            data_type: DataType::FixedSizeList(Box::new(child.data_type.clone()), N),
+            len,
+            nulls: None,
+            buffers: Vec::new(),
+            children: vec![child],
+        }
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + Into<ArrayData>> From<FixedSizeListArray<N, T, true>>
+    for ArrayData
+{
+    fn from(array: FixedSizeListArray<N, T, true>) -> Self {
+        let child: ArrayData = array.0.data.into();
+        Self {
+            data_type: DataType::FixedSizeList(Box::new(child.data_type.clone()), N),
+            len: array.0.validity.len(),
+            nulls: Some(array.0.validity),
+            buffers: Vec::new(),
+            children: vec![child],
+        }
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + TryFrom<ArrayData, Error = ArrayDataError>> TryFrom<ArrayData>
+    for FixedSizeListArray<N, T>
+{
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(
+            &DataType::FixedSizeList(Box::new(data.child_type()), N),
+            0,
+            1,
+        )?;
+        data.non_null()?;
+        Ok(Self(data.child(checked_len(
+            data.len,
+            Some(data.len),
+            N,
+        )?)?))
+    }
+}
+
+#[cfg(feature = "lists")]
+impl<const N: usize, T: Array + TryFrom<ArrayData, Error = ArrayDataError>> TryFrom<ArrayData>
+    for FixedSizeListArray<N, T, true>
+{
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(
+            &DataType::FixedSizeList(Box::new(data.child_type()), N),
+            0,
+            1,
+        )?;
+        let child = data.child(checked_len(data.len, Some(data.len), N)?)?;
+        Ok(Self(Nullable {
+            data: child,
+            validity: data.validity()?,
+        }))
+    }
+}
+
+impl From<NullArray> for ArrayData {
+    fn from(array: NullArray) -> Self {
+        Self {
+            data_type: DataType::Null,
+            len: array.len(),
+            nulls: None,
+            buffers: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl TryFrom<ArrayData> for NullArray {
+    type Error = ArrayDataError;
+
+    fn try_from(data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(&DataType::Null, 0, 0)?;
+        Ok(iter::repeat(()).take(data.len).collect())
+    }
+}
+
+/// Returns the fields of a struct or union with the given names and children.
+#[cfg(any(feature = "structs", feature = "unions"))]
+fn fields(names: &[&str], children: &[ArrayData]) -> Vec<Field> {
+    names
+        .iter()
+        .zip(children)
+        .map(|(name, child)| Field {
+            name: (*name).to_owned(),
+            data_type: child.data_type.clone(),
+            nullable: child.nulls.is_some(),
+        })
+        .collect()
+}
+
+impl ArrayData {
+    /// Returns the children of struct or union array data, converted to `T`,
+    /// after checking the names of the fields and the lengths of the
+    /// children.
+    ///
+    /// The `child_len` is the required length of every child, or `None` when
+    /// the lengths are checked by the caller.
+    #[cfg(any(feature = "structs", feature = "unions"))]
+    fn named_children<T: ChildrenArrayData>(
+        &mut self,
+        child_len: Option<usize>,
+    ) -> Result<T, ArrayDataError> {
+        let (DataType::Struct(ref fields) | DataType::Union(ref fields, _)) = self.data_type else {
+            unreachable!("data type is checked by the caller")
+        };
+        if fields.len() != T::NAMES.len() {
+            return Err(ArrayDataError::ChildCount {
+                count: fields.len(),
+                expected: T::NAMES.len(),
+            });
+        }
+        if let Some((index, (field, &expected))) = fields
+            .iter()
+            .zip(T::NAMES)
+            .enumerate()
+            .find(|&(_, (field, &expected))| field.name != expected)
+        {
+            return Err(ArrayDataError::FieldName {
+                index,
+                found: field.name.clone(),
+                expected,
+            });
+        }
+        if let Some(expected) = child_len {
+            if let Some(child) = self.children.iter().find(|child| child.len != expected) {
+                return Err(ArrayDataError::ChildLength {
+                    len: child.len,
+                    expected,
+                });
+            }
+        }
+        T::try_from_children(self.len, std::mem::take(&mut self.children))
+    }
+}
+
+/// Returns the array data of a struct array with the given length, validity
+/// and field arrays.
+#[cfg(feature = "structs")]
+fn struct_data<T: ChildrenArrayData>(len: usize, nulls: Option<Bitmap>, array: T) -> ArrayData {
+    let children = array.into_children();
+    ArrayData {
+        data_type: DataType::Struct(fields(T::NAMES, &children)),
+        len,
+        nulls,
+        buffers: Vec::new(),
+        children,
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType> From<StructArray<T>> for ArrayData
+where
+    <T as StructArrayType>::Array<VecBuffer>: ChildrenArrayData,
+    StructArray<T>: Length,
+{
+    fn from(array: StructArray<T>) -> Self {
+        struct_data(array.len(), None, array.0)
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType> From<StructArray<T, true>> for ArrayData
+where
+    <T as StructArrayType>::Array<VecBuffer>: ChildrenArrayData,
+{
+    fn from(array: StructArray<T, true>) -> Self {
+        let Nullable { data, validity } = array.0;
+        struct_data(validity.len(), Some(validity), data)
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType> TryFrom<ArrayData> for StructArray<T>
+where
+    <T as StructArrayType>::Array<VecBuffer>: ChildrenArrayData,
+{
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(
+            &DataType::Struct(data.fields().to_vec()),
+            0,
+            data.fields().len(),
+        )?;
+        data.non_null()?;
+        Ok(Self(data.named_children(Some(data.len))?))
+    }
+}
+
+#[cfg(feature = "structs")]
+impl<T: StructArrayType> TryFrom<ArrayData> for StructArray<T, true>
+where
+    <T as StructArrayType>::Array<VecBuffer>: ChildrenArrayData,
+{
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(
+            &DataType::Struct(data.fields().to_vec()),
+            0,
+            data.fields().len(),
+        )?;
+        let children = data.named_children(Some(data.len))?;
+        Ok(Self(Nullable {
+            data: children,
+            validity: data.validity()?,
+        }))
+    }
+}
+
+/// Returns the array data of a union array with the given layout, length,
+/// buffers and variant arrays.
+#[cfg(feature = "unions")]
+fn union_data<T: ChildrenArrayData>(
+    mode: UnionMode,
+    len: usize,
+    buffers: Vec<Vec<u8>>,
+    array: T,
+) -> ArrayData {
+    let children = array.into_children();
+    ArrayData {
+        data_type: DataType::Union(fields(T::NAMES, &children), mode),
+        len,
+        nulls: None,
+        buffers,
+        children,
+    }
+}
+
+#[cfg(feature = "unions")]
+impl<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, OffsetItem: OffsetElement>
+    From<UnionArray<T, VARIANTS, DenseLayout, VecBuffer, OffsetItem>> for ArrayData
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<VecBuffer, OffsetItem, DenseLayout>: ChildrenArrayData,
+{
+    fn from(array: UnionArray<T, VARIANTS, DenseLayout, VecBuffer, OffsetItem>) -> Self {
+        let DenseUnionArray {
+            variants,
+            types,
+            offsets,
+        } = array.0;
+        union_data(
+            UnionMode::Dense,
+            types.len(),
+            vec![types.0.as_bytes().to_vec(), offsets.0.as_bytes().to_vec()],
+            variants,
+        )
+    }
+}
+
+#[cfg(feature = "unions")]
+impl<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, OffsetItem: OffsetElement>
+    From<UnionArray<T, VARIANTS, SparseLayout, VecBuffer, OffsetItem>> for ArrayData
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<VecBuffer, OffsetItem, SparseLayout>: ChildrenArrayData,
+{
+    fn from(array: UnionArray<T, VARIANTS, SparseLayout, VecBuffer, OffsetItem>) -> Self {
+        let SparseUnionArray { variants, types } = array.0;
+        union_data(
+            UnionMode::Sparse,
+            types.len(),
+            vec![types.0.as_bytes().to_vec()],
+            variants,
+        )
+    }
+}
+
+impl ArrayData {
+    /// Returns the fields of struct or union array data, or no fields for
+    /// other data types.
+    #[cfg(any(feature = "structs", feature = "unions"))]
+    fn fields(&self) -> &[Field] {
+        match self.data_type {
+            DataType::Struct(ref fields) | DataType::Union(ref fields, _) => fields,
+            _ => &[],
+        }
+    }
+
+    /// Returns the type ids of union array data with `variants` variants,
+    /// after checking that every type id is the index of a variant.
+    #[cfg(feature = "unions")]
+    fn type_ids(&self, variants: usize) -> Result<Vec<i8>, ArrayDataError> {
+        let type_ids = values::<i8>(self.buffer(0, self.len)?, self.len);
+        match type_ids
+            .iter()
+            .position(|&id| usize::try_from(id).map_or(true, |variant| variant >= variants))
+        {
+            Some(index) => Err(ArrayDataError::InvalidTypeId { index }),
+            None => Ok(type_ids),
+        }
+    }
+}
+
+#[cfg(feature = "unions")]
+impl<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, OffsetItem: OffsetElement>
+    TryFrom<ArrayData> for UnionArray<T, VARIANTS, DenseLayout, VecBuffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<VecBuffer, OffsetItem, DenseLayout>: ChildrenArrayData,
+{
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(
+            &DataType::Union(data.fields().to_vec(), UnionMode::Dense),
+            2,
+            data.fields().len(),
+        )?;
+        data.non_null()?;
+        let types = data.type_ids(VARIANTS)?;
+        let offsets = values::<i32>(
+            data.buffer(1, checked_len(data.len, Some(data.len), i32::SIZE)?)?,
+            data.len,
+        );
+        for (&type_id, &offset) in types.iter().zip(&offsets) {
+            let values_len = data.children[usize::from(type_id.unsigned_abs())].len;
+            if usize::try_from(offset).map_or(true, |position| position >= values_len) {
+                return Err(ArrayDataError::InvalidOffsets { values_len });
+            }
+        }
+        Ok(Self(DenseUnionArray {
+            variants: data.named_children(None)?,
+            types: FixedSizePrimitiveArray(types),
+            offsets: FixedSizePrimitiveArray(offsets),
+        }))
+    }
+}
+
+#[cfg(feature = "unions")]
+impl<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, OffsetItem: OffsetElement>
+    TryFrom<ArrayData> for UnionArray<T, VARIANTS, SparseLayout, VecBuffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<VecBuffer, OffsetItem, SparseLayout>: ChildrenArrayData,
+{
+    type Error = ArrayDataError;
+
+    fn try_from(mut data: ArrayData) -> Result<Self, Self::Error> {
+        data.validate(
+            &DataType::Union(data.fields().to_vec(), UnionMode::Sparse),
+            1,
+            data.fields().len(),
+        )?;
+        data.non_null()?;
+        let types = data.type_ids(VARIANTS)?;
+        Ok(Self(SparseUnionArray {
+            variants: data.named_children(Some(data.len))?,
+            types: FixedSizePrimitiveArray(types),
+        }))
+    }
+}
+
+/// Returns an [`io::Error`] for invalid serialized array data.
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Writes `value` as a little-endian `u64`.
+fn write_len(value: usize, writer: &mut impl Write) -> io::Result<()> {
+    let len = u64::try_from(value).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("length (is {value}) should fit in a u64"),
+        )
+    })?;
+    writer.write_all(&len.to_le_bytes())
+}
+
+/// Writes `bytes` prefixed with their length.
+fn write_bytes(bytes: &[u8], writer: &mut impl Write) -> io::Result<()> {
+    write_len(bytes.len(), writer)?;
+    writer.write_all(bytes)
+}
+
+/// Reads a single byte.
+fn read_u8(reader: &mut impl Read) -> io::Result<u8> {
+    let mut byte = [0; 1];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+/// Reads a length written by [`write_len`].
+fn read_len(reader: &mut impl Read) -> io::Result<usize> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    let len = u64::from_le_bytes(bytes);
+    usize::try_from(len).map_err(|_| invalid_data(format!("length (is {len}) should fit in usize")))
+}
+
+/// Reads bytes written by [`write_bytes`].
+///
+/// The bytes are read incrementally, so a corrupt length doesn't allocate
+/// more memory than the input contains.
+fn read_bytes(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let len = read_len(reader)?;
+    let mut bytes = Vec::new();
+    reader
+        .take(u64::try_from(len).unwrap_or(u64::MAX))
+        .read_to_end(&mut bytes)?;
+    if bytes.len() == len {
+        Ok(bytes)
+    } else {
+        Err(io::ErrorKind::UnexpectedEof.into())
+    }
+}
+
+/// Writes a boolean as a single byte.
+fn write_bool(value: bool, writer: &mut impl Write) -> io::Result<()> {
+    writer.write_all(&[u8::from(value)])
+}
+
+/// Reads a boolean written by [`write_bool`].
+fn read_bool(reader: &mut impl Read) -> io::Result<bool> {
+    match read_u8(reader)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        byte => Err(invalid_data(format!(
+            "boolean (is {byte}) should be 0 or 1"
+        ))),
+    }
+}
+
+/// The maximum nesting depth of serialized data types and array data.
+///
+/// Reading deeper input is rejected, so corrupt input can't overflow the
+/// stack.
+const MAX_DEPTH: usize = 64;
+
+/// Returns the nesting depth of the children of a value nested in `depth`
+/// other values, or an error when it exceeds [`MAX_DEPTH`].
+fn nested(depth: usize) -> io::Result<usize> {
+    if depth < MAX_DEPTH {
+        Ok(depth + 1)
+    } else {
+        Err(invalid_data(format!(
+            "nesting depth should be at most {MAX_DEPTH}"
+        )))
+    }
+}
+
+/// Writes the fields of a struct or union data type.
+fn write_fields(fields: &[Field], writer: &mut impl Write) -> io::Result<()> {
+    write_len(fields.len(), writer)?;
+    fields.iter().try_for_each(|field| {
+        write_bytes(field.name.as_bytes(), writer)?;
+        write_bool(field.nullable, writer)?;
+        field.data_type.write_to(writer)
+    })
+}
+
+/// Reads fields written by [`write_fields`], of a data type nested in
+/// `depth` other data types.
+fn read_fields(reader: &mut impl Read, depth: usize) -> io::Result<Vec<Field>> {
+    let count = read_len(reader)?;
+    (0..count)
+        .map(|_| {
+            let name = String::from_utf8(read_bytes(reader)?)
+                .map_err(|_| invalid_data("field name should be valid UTF-8"))?;
+            Ok(Field {
+                name,
+                nullable: read_bool(reader)?,
+                data_type: DataType::read_from(reader, depth)?,
+            })
+        })
+        .collect()
+}
+
+impl DataType {
+    /// Writes this data type, as a tag followed by the parameters of the
+    /// type.
+    fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        let tag: u8 = match *self {
+            Self::Null => 0,
+            Self::Boolean => 1,
+            Self::Int8 => 2,
+            Self::Int16 => 3,
+            Self::Int32 => 4,
+            Self::Int64 => 5,
+            Self::UInt8 => 6,
+            Self::UInt16 => 7,
+            Self::UInt32 => 8,
+            Self::UInt64 => 9,
+            Self::Float32 => 10,
+            Self::Float64 => 11,
+            Self::Binary => 12,
+            Self::LargeBinary => 13,
+            Self::Utf8 => 14,
+            Self::LargeUtf8 => 15,
+            Self::List(_) => 16,
+            Self::LargeList(_) => 17,
+            Self::FixedSizeList(..) => 18,
+            Self::Struct(_) => 19,
+            Self::Union(..) => 20,
+        };
+        writer.write_all(&[tag])?;
+        match *self {
+            Self::List(ref item) | Self::LargeList(ref item) => item.write_to(writer),
+            Self::FixedSizeList(ref item, size) => {
+                item.write_to(writer)?;
+                write_len(size, writer)
+            }
+            Self::Struct(ref fields) => write_fields(fields, writer),
+            Self::Union(ref fields, mode) => {
+                write_bool(mode == UnionMode::Dense, writer)?;
+                write_fields(fields, writer)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads a data type written by [`DataType::write_to`], nested in `depth`
+    /// other data types.
+    fn read_from(reader: &mut impl Read, depth: usize) -> io::Result<Self> {
+        let child_depth = nested(depth)?;
+        Ok(match read_u8(reader)? {
+            0 => Self::Null,
+            1 => Self::Boolean,
+            2 => Self::Int8,
+            3 => Self::Int16,
+            4 => Self::Int32,
+            5 => Self::Int64,
+            6 => Self::UInt8,
+            7 => Self::UInt16,
+            8 => Self::UInt32,
+            9 => Self::UInt64,
+            10 => Self::Float32,
+            11 => Self::Float64,
+            12 => Self::Binary,
+            13 => Self::LargeBinary,
+            14 => Self::Utf8,
+            15 => Self::LargeUtf8,
+            16 => Self::List(Box::new(Self::read_from(reader, child_depth)?)),
+            17 => Self::LargeList(Box::new(Self::read_from(reader, child_depth)?)),
+            18 => {
+                let item = Self::read_from(reader, child_depth)?;
+                Self::FixedSizeList(Box::new(item), read_len(reader)?)
+            }
+            19 => Self::Struct(read_fields(reader, child_depth)?),
+            20 => {
+                let mode = if read_bool(reader)? {
+                    UnionMode::Dense
+                } else {
+                    UnionMode::Sparse
+                };
+                Self::Union(read_fields(reader, child_depth)?, mode)
+            }
+            tag => return Err(invalid_data(format!("data type tag (is {tag}) is unknown"))),
+        })
+    }
+}
+
+impl ArrayData {
+    /// Returns a canonical description of the physical layout of this array
+    /// data, to detect layout changes between versions of this crate.
+    ///
+    /// See [`LayoutSnapshot`] for the format.
+    #[must_use]
+    pub fn layout_snapshot(&self) -> LayoutSnapshot {
+        LayoutSnapshot::from(self)
+    }
+
+    /// Writes this array data in a compact binary format, e.g. to checkpoint
+    /// a partially built array.
+    ///
+    /// The data is read back with [`ArrayData::read_from`]. The format is
+    /// specific to this crate and may change between versions, so it is not
+    /// suited for long-term storage or exchange with other implementations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when writing to `writer` fails.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        self.data_type.write_to(writer)?;
+        write_len(self.len, writer)?;
+        match self.nulls {
+            Some(ref nulls) => {
+                write_bool(true, writer)?;
+                write_len(nulls.len(), writer)?;
+                write_bytes(&bitmap_bytes(copy_bitmap(nulls)), writer)?;
+            }
+            None => write_bool(false, writer)?,
+        }
+        write_len(self.buffers.len(), writer)?;
+        self.buffers
+            .iter()
+            .try_for_each(|buffer| write_bytes(buffer, writer))?;
+        write_len(self.children.len(), writer)?;
+        self.children
+            .iter()
+            .try_for_each(|child| child.write_to(writer))
+    }
+
+    /// Writes the array data of `array`, which has the given data type, in
+    /// the format of [`ArrayData::write_to`].
+    ///
+    /// The buffers are written from the [`ArrayLayout`] of the array, so
+    /// unlike converting the array to array data first, this doesn't copy or
+    /// move the array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when writing to `writer` fails, or when the number of
+    /// children of `array` doesn't match `data_type`.
+    pub fn write_layout(
+        data_type: &DataType,
+        array: &dyn ArrayLayout,
+        writer: &mut impl Write,
+    ) -> io::Result<()> {
+        data_type.write_to(writer)?;
+        let len = array.len();
+        write_len(len, writer)?;
+        match array.null_buffer() {
+            Some(view) => {
+                write_bool(true, writer)?;
+                write_len(view.len, writer)?;
+                write_bytes(&aligned_bits(view.bytes, view.offset, view.len), writer)?;
+            }
+            None => write_bool(false, writer)?,
+        }
+        let buffers = array.buffers();
+        write_len(buffers.len(), writer)?;
+        buffers.iter().enumerate().try_for_each(|(index, buffer)| {
+            match array.buffer_bit_offset(index) {
+                0 => write_bytes(buffer, writer),
+                offset => write_bytes(&aligned_bits(buffer, offset, len), writer),
+            }
+        })?;
+        let children = array.children();
+        let child_types = data_type.child_types();
+        if children.len() != child_types.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "number of children (is {}) should be {} for {}",
+                    children.len(),
+                    child_types.len(),
+                    data_type.name()
+                ),
+            ));
+        }
+        write_len(children.len(), writer)?;
+        children
+            .into_iter()
+            .zip(child_types)
+            .try_for_each(|(child, child_type)| Self::write_layout(child_type, child, writer))
+    }
+
+    /// Reads array data written by [`ArrayData::write_to`].
+    ///
+    /// Only the encoding is checked. The contents are validated when the
+    /// array data is converted to a typed array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when reading from `reader` fails, or when the input
+    /// is not valid array data, including data nested deeper than 64 levels.
+    pub fn read_from(reader: &mut impl Read) -> io::Result<Self> {
+        Self::read_nested(reader, 0)
+    }
+
+    /// Reads array data written by [`ArrayData::write_to`], nested in `depth`
+    /// other array data.
+    fn read_nested(reader: &mut impl Read, depth: usize) -> io::Result<Self> {
+        let data_type = DataType::read_from(reader, depth)?;
+        let len = read_len(reader)?;
+        let nulls = if read_bool(reader)? {
+            let bits = read_len(reader)?;
+            let bytes = read_bytes(reader)?;
+            if bytes.len() != bytes_for_bits(bits) {
+                return Err(invalid_data(format!(
+                    "validity bitmap length (is {}) should be {} bytes",
+                    bytes.len(),
+                    bytes_for_bits(bits)
+                )));
+            }
+            // Safety:
+            // - The buffer has enough bytes for `bits` bits.
+            Some(unsafe { Bitmap::from_raw_parts(bytes, bits, 0) })
+        } else {
+            None
+        };
+        let buffer_count = read_len(reader)?;
+        let buffers = (0..buffer_count)
+            .map(|_| read_bytes(reader))
+            .collect::<io::Result<_>>()?;
+        let child_count = read_len(reader)?;
+        let child_depth = nested(depth)?;
+        let children = (0..child_count)
+            .map(|_| Self::read_nested(reader, child_depth))
+            .collect::<io::Result<_>>()?;
+        Ok(Self {
+            data_type,
+            len,
+            nulls,
+            buffers,
+            children,
+        })
+    }
+}
+
+impl DataType {
+    /// Returns the name of this data type, without the types of its
+    /// children.
+    fn name(&self) -> String {
+        match *self {
+            Self::List(_) => "List".to_owned(),
+            Self::LargeList(_) => "LargeList".to_owned(),
+            Self::FixedSizeList(_, size) => format!("FixedSizeList({size})"),
+            Self::Struct(_) => "Struct".to_owned(),
+            Self::Union(_, mode) => format!("Union({mode:?})"),
+            ref data_type => format!("{data_type:?}"),
+        }
+    }
+
+    /// Returns the data types of the children of arrays with this data type.
+    fn child_types(&self) -> Vec<&DataType> {
+        match *self {
+            Self::List(ref item) | Self::LargeList(ref item) | Self::FixedSizeList(ref item, _) => {
+                vec![item]
+            }
+            Self::Struct(ref fields) | Self::Union(ref fields, _) => {
+                fields.iter().map(|field| &field.data_type).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the alignment required by the buffer at `index` of arrays with
+    /// this data type, which is the size of its elements.
+    fn buffer_alignment(&self, index: usize) -> usize {
+        match *self {
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float32 => 4,
+            Self::Int64 | Self::UInt64 | Self::Float64 => 8,
+            Self::Binary | Self::Utf8 | Self::List(_) if index == 0 => 4,
+            Self::LargeBinary | Self::LargeUtf8 | Self::LargeList(_) if index == 0 => 8,
+            Self::Union(_, UnionMode::Dense) if index == 1 => 4,
+            _ => 1,
+        }
+    }
+}
+
+/// A canonical text description of the physical layout of [`ArrayData`].
+///
+/// The snapshot lists the data type and length of the array and its
+/// children, and the length and alignment of their buffers, but not the
+/// values. Comparing a snapshot with one that was stored earlier, e.g. with
+/// [`LayoutSnapshot::check`], detects layout changes between versions of this
+/// crate that code depending on the layout over FFI would otherwise miss.
+///
+/// A snapshot of a nullable `u16` array with 3 values looks like this:
+///
+/// ```text
+/// UInt16 len=3
+///   nulls len=1 offset=0
+///   buffer 0 len=6 align=2
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LayoutSnapshot(String);
+
+impl LayoutSnapshot {
+    /// Returns the snapshot as text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Compares this snapshot with the `expected` snapshot text, ignoring
+    /// trailing whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first line that differs when the snapshots are not equal.
+    pub fn check(&self, expected: &str) -> Result<(), LayoutMismatch> {
+        let mut found_lines = self.0.lines();
+        let mut expected_lines = expected.trim_end().lines().map(str::trim_end);
+        for line in 1.. {
+            match (found_lines.next(), expected_lines.next()) {
+                (None, None) => return Ok(()),
+                (found_line, expected_line) if found_line != expected_line => {
+                    return Err(LayoutMismatch {
+                        line,
+                        found: found_line.map(str::to_owned),
+                        expected: expected_line.map(str::to_owned),
+                    })
+                }
+                _ => {}
+            }
+        }
+        unreachable!("the snapshots have a finite number of lines")
+    }
+}
+
+impl Display for LayoutSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&ArrayData> for LayoutSnapshot {
+    fn from(data: &ArrayData) -> Self {
+        Self(
+            Layout {
+                data,
+                label: "",
+                depth: 0,
+            }
+            .to_string(),
+        )
+    }
+}
+
+/// The layout of array data, labeled with `label` and indented by `depth`,
+/// formatted as a [`LayoutSnapshot`].
+struct Layout<'a> {
+    /// The array data.
+    data: &'a ArrayData,
+    /// The label before the data type, e.g. the name of a field.
+    label: &'a str,
+    /// The nesting depth of the array data.
+    depth: usize,
+}
+
+impl Layout<'_> {
+    /// Writes the layout of the child array `data`, labeled with `label`.
+    fn write_child(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        data: &ArrayData,
+        label: &str,
+    ) -> fmt::Result {
+        Display::fmt(
+            &Layout {
+                data,
+                label,
+                depth: self.depth + 1,
+            },
+            f,
+        )
+    }
+}
+
+impl Display for Layout<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { data, label, depth } = *self;
+        let indent = "  ".repeat(depth);
+        writeln!(
+            f,
+            "{indent}{label}{} len={}",
+            data.data_type.name(),
+            data.len
+        )?;
+        match data.nulls {
+            Some(ref nulls) => {
+                let view = ValidityView::from(nulls);
+                writeln!(
+                    f,
+                    "{indent}  nulls len={} offset={}",
+                    view.bytes.len(),
+                    view.offset
+                )?;
+            }
+            None => writeln!(f, "{indent}  nulls none")?,
+        }
+        for (index, buffer) in data.buffers.iter().enumerate() {
+            writeln!(
+                f,
+                "{indent}  buffer {index} len={} align={}",
+                buffer.len(),
+                data.data_type.buffer_alignment(index)
+            )?;
+        }
+        match data.data_type {
+            DataType::Struct(ref fields) => {
+                for (field, child) in fields.iter().zip(&data.children) {
+                    self.write_child(f, child, &format!("field {}: ", field.name))?;
+                }
+            }
+            DataType::Union(ref fields, _) => {
+                for (field, child) in fields.iter().zip(&data.children) {
+                    self.write_child(f, child, &format!("variant {}: ", field.name))?;
+                }
+            }
+            _ => {
+                for child in &data.children {
+                    self.write_child(f, child, "item: ")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a [`LayoutSnapshot`] differs from the expected
+/// snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LayoutMismatch {
+    /// The number of the first line that differs, starting at 1.
+    pub line: usize,
+    /// The line of the snapshot, or `None` if the snapshot has fewer lines.
+    pub found: Option<String>,
+    /// The line of the expected snapshot, or `None` if the expected snapshot
+    /// has fewer lines.
+    pub expected: Option<String>,
+}
+
+impl Display for LayoutMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |line: &Option<String>| {
+            line.as_deref()
+                .map_or_else(|| "end of snapshot".to_owned(), |text| format!("`{text}`"))
+        };
+        write!(
+            f,
+            "layout differs at line {}: found {}, expected {}",
+            self.line,
+            describe(&self.found),
+            describe(&self.expected)
+        )
+    }
+}
+
+impl Error for LayoutMismatch {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Uint16Array, Uint8Array};
+
+    #[test]
+    fn primitive() {
+        let array = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Uint16Array<true>>();
+        assert_eq!(array.buffers(), [&[1, 0, 0, 0, 3, 0][..]]);
+        assert!(array.children().is_empty());
+        assert_eq!(
+            array.null_buffer(),
+            Some(ValidityView {
+                bytes: &[0b101],
+                offset: 0,
+                len: 3
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "lists", feature = "strings"))]
+    fn nested() {
+        let array = [Some(vec!["a", "bc"]), None]
+            .into_iter()
+            .collect::<VariableSizeListArray<StringArray, true>>();
+        assert_eq!(array.buffers(), [&[0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0][..]]);
+        let children = array.children();
+        assert_eq!(children.len(), 1);
+        let strings = children[0];
+        assert_eq!(strings.len(), 2);
+        assert_eq!(strings.buffers()[1], b"abc");
+        assert_eq!(strings.null_buffer(), None);
+
+        let fixed = [[1_u8, 2], [3, 4]]
+            .into_iter()
+            .collect::<FixedSizeListArray<2, Uint8Array>>();
+        assert_eq!(fixed.children()[0].buffers(), [&[1, 2, 3, 4][..]]);
+    }
+
+    #[test]
+    fn boolean_bit_offset() {
+        // Safety:
+        // - The buffer has enough bits for 3 bits at offset 5.
+        let array =
+            BooleanArray::<false>(unsafe { Bitmap::from_raw_parts(vec![0b1010_0000, 1], 3, 5) });
+        assert_eq!(array.buffers(), [&[0b1010_0000, 1][..]]);
+        assert_eq!(array.buffer_bit_offset(0), 5);
+        assert_eq!(array.0.into_iter().collect::<Vec<_>>(), [true, false, true]);
+        let nullable = [Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(nullable.buffer_bit_offset(0), 0);
+    }
+
+    #[test]
+    fn primitive_data() {
+        let array = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Uint16Array<true>>();
+        let data = ArrayData::from(array);
+        assert_eq!(data.data_type, DataType::UInt16);
+        assert_eq!(data.buffers, [[1, 0, 0, 0, 3, 0]]);
+        let round_trip = Uint16Array::<true>::try_from(data).expect("valid array data");
+        assert_eq!(
+            round_trip.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+    }
+
+    #[test]
+    fn boolean_data() {
+        let array = [true, false, true].into_iter().collect::<BooleanArray>();
+        let round_trip =
+            BooleanArray::<false>::try_from(ArrayData::from(array)).expect("valid array data");
+        assert_eq!(
+            round_trip.into_iter().collect::<Vec<_>>(),
+            [true, false, true]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "lists", feature = "strings"))]
+    fn list_data() {
+        let array = [Some(vec!["a", "bc"]), None, Some(vec![])]
+            .into_iter()
+            .collect::<VariableSizeListArray<StringArray, true>>();
+        let data = ArrayData::from(array);
+        assert_eq!(data.data_type, DataType::List(Box::new(DataType::Utf8)));
+        assert_eq!(data.children[0].len, 2);
+        let round_trip =
+            VariableSizeListArray::<StringArray, true>::try_from(data).expect("valid array data");
+        assert_eq!(round_trip.0.offsets.data.as_slice(), [0, 2, 2, 2]);
+        assert_eq!(
+            round_trip.0.data.into_iter().collect::<Vec<_>>(),
+            ["a", "bc"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lists")]
+    fn fixed_size_list_data() {
+        let array = [[1_u8, 2], [3, 4]]
+            .into_iter()
+            .collect::<FixedSizeListArray<2, Uint8Array>>();
+        let data = ArrayData::from(array);
+        assert_eq!(
+            data.data_type,
+            DataType::FixedSizeList(Box::new(DataType::UInt8), 2)
+        );
+        let round_trip =
+            FixedSizeListArray::<2, Uint8Array>::try_from(data).expect("valid array data");
+        assert_eq!(round_trip.0.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn data_type_mismatch() {
+        let data = ArrayData::from([1_u16, 2].into_iter().collect::<Uint16Array>());
+        assert_eq!(
+            Uint8Array::<false>::try_from(data).map(|_| ()),
+            Err(ArrayDataError::DataType {
+                found: DataType::UInt16,
+                expected: DataType::UInt8
+            })
+        );
+    }
+
+    #[test]
+    fn nulls_in_non_nullable() {
+        let array = [Some(1_u8), None].into_iter().collect::<Uint8Array<true>>();
+        assert_eq!(
+            Uint8Array::<false>::try_from(ArrayData::from(array)).map(|_| ()),
+            Err(ArrayDataError::Nulls { null_count: 1 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn invalid_utf8() {
+        let data = ArrayData {
+            data_type: DataType::Utf8,
+            len: 1,
+            nulls: None,
+            buffers: vec![vec![0, 0, 0, 0, 2, 0, 0, 0], vec![0xc3, 0x28]],
+            children: Vec::new(),
+        };
+        assert_eq!(
+            StringArray::<false>::try_from(data).map(|_| ()),
+            Err(ArrayDataError::InvalidUtf8 { index: 0 })
+        );
+    }
+
+    #[test]
+    fn invalid_offsets() {
+        let data = ArrayData {
+            data_type: DataType::Binary,
+            len: 1,
+            nulls: None,
+            buffers: vec![vec![0, 0, 0, 0, 3, 0, 0, 0], vec![1, 2]],
+            children: Vec::new(),
+        };
+        assert_eq!(
+            VariableSizeBinaryArray::<false>::try_from(data).map(|_| ()),
+            Err(ArrayDataError::InvalidOffsets { values_len: 2 })
+        );
+    }
+
+    #[test]
+    fn length_overflow() {
+        let primitive = ArrayData {
+            data_type: DataType::UInt64,
+            len: usize::MAX,
+            nulls: None,
+            buffers: vec![Vec::new()],
+            children: Vec::new(),
+        };
+        assert_eq!(
+            FixedSizePrimitiveArray::<u64>::try_from(primitive).map(|_| ()),
+            Err(ArrayDataError::LengthOverflow { len: usize::MAX })
+        );
+        let binary = ArrayData {
+            data_type: DataType::Binary,
+            len: usize::MAX,
+            nulls: None,
+            buffers: vec![Vec::new(), Vec::new()],
+            children: Vec::new(),
+        };
+        assert_eq!(
+            VariableSizeBinaryArray::<false>::try_from(binary).map(|_| ()),
+            Err(ArrayDataError::LengthOverflow { len: usize::MAX })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "derive", feature = "structs"))]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn struct_data() {
+        use crate::{array::StructArray, ArrayType};
+
+        #[derive(ArrayType, Clone, Copy, Debug, Default, PartialEq)]
+        struct Foo {
+            r#type: u8,
+            b: Option<u16>,
+        }
+
+        let input = [
+            Foo { r#type: 1, b: None },
+            Foo {
+                r#type: 2,
+                b: Some(3),
+            },
+        ];
+        let array = input.into_iter().collect::<StructArray<Foo>>();
+        assert!(array.buffers().is_empty());
+        assert_eq!(array.children()[0].buffers(), [&[1, 2][..]]);
+        assert_eq!(
+            array.children()[1].null_buffer(),
+            Some(ValidityView {
+                bytes: &[0b10],
+                offset: 0,
+                len: 2
+            })
+        );
+        let data = ArrayData::from(array);
+        assert_eq!(
+            data.data_type,
+            DataType::Struct(vec![
+                Field {
+                    name: "type".to_owned(),
+                    data_type: DataType::UInt8,
+                    nullable: false,
+                },
+                Field {
+                    name: "b".to_owned(),
+                    data_type: DataType::UInt16,
+                    nullable: true,
+                },
+            ])
+        );
+        let round_trip = StructArray::<Foo>::try_from(data).expect("valid array data");
+        assert_eq!(round_trip.into_iter().collect::<Vec<_>>(), input);
+
+        let nullable = [Some(input[0]), None]
+            .into_iter()
+            .collect::<StructArray<Foo, true>>();
+        let nullable_round_trip = StructArray::<Foo, true>::try_from(ArrayData::from(nullable))
+            .expect("valid array data");
+        assert_eq!(
+            nullable_round_trip.into_iter().collect::<Vec<_>>(),
+            [Some(input[0]), None]
+        );
+
+        let mut renamed = ArrayData::from(input.into_iter().collect::<StructArray<Foo>>());
+        if let DataType::Struct(ref mut fields) = renamed.data_type {
+            fields[1].name = "c".to_owned();
+        }
+        assert_eq!(
+            StructArray::<Foo>::try_from(renamed).map(|_| ()),
+            Err(ArrayDataError::FieldName {
+                index: 1,
+                found: "c".to_owned(),
+                expected: "b",
+            })
+        );
+
+        let mut short = ArrayData::from(input.into_iter().collect::<StructArray<Foo>>());
+        short.len = 3;
+        assert_eq!(
+            StructArray::<Foo>::try_from(short).map(|_| ()),
+            Err(ArrayDataError::ChildLength {
+                len: 2,
+                expected: 3,
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "derive", feature = "unions"))]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn union_data() {
+        use crate::{
+            array::{SparseLayout, UnionArray},
+            ArrayType,
+        };
+
+        #[derive(ArrayType, Clone, Copy)]
+        enum Test {
+            Foo { bar: u8 },
+            Bar(bool),
+            None,
+        }
+
+        let input = [
+            Test::None,
+            Test::Bar(true),
+            Test::Foo { bar: 123 },
+            Test::None,
+        ];
+        let dense_array = input
+            .into_iter()
+            .collect::<UnionArray<Test, { Test::VARIANTS }>>();
+        assert_eq!(dense_array.buffers()[0], [2, 1, 0, 2]);
+        assert_eq!(
+            dense_array.buffers()[1],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0]
+        );
+        assert_eq!(
+            dense_array
+                .children()
+                .iter()
+                .map(|child| child.len())
+                .collect::<Vec<_>>(),
+            [1, 1, 2]
+        );
+        assert_eq!(
+            dense_array.children()[0].children()[0].buffers(),
+            [&[123][..]]
+        );
+        let dense = ArrayData::from(dense_array);
+        let DataType::Union(ref fields, UnionMode::Dense) = dense.data_type else {
+            panic!("dense union data type");
+        };
+        assert_eq!(
+            fields.iter().map(|field| &field.name).collect::<Vec<_>>(),
+            ["Foo", "Bar", "None"]
+        );
+        assert_eq!(dense.buffers[0], [2, 1, 0, 2]);
+        let round_trip =
+            UnionArray::<Test, { Test::VARIANTS }>::try_from(dense).expect("valid array data");
+        assert_eq!(round_trip.0.types.0, [2, 1, 0, 2]);
+        assert_eq!(round_trip.0.offsets.0, [0, 0, 0, 1]);
+        assert_eq!(round_trip.0.variants.0 .0.bar.0, [123]);
+        assert_eq!(round_trip.0.variants.2.len(), 2);
+
+        let sparse = input
+            .into_iter()
+            .collect::<UnionArray<Test, { Test::VARIANTS }, SparseLayout>>();
+        assert_eq!(sparse.buffers(), [&[2, 1, 0, 2][..]]);
+        assert_eq!(sparse.children()[2].len(), 4);
+        let mut sparse_data = ArrayData::from(sparse);
+        let mut bytes = Vec::new();
+        sparse_data.write_to(&mut bytes).expect("write to vec");
+        let read = ArrayData::read_from(&mut bytes.as_slice()).expect("valid array data");
+        assert_eq!(read.data_type, sparse_data.data_type);
+        let sparse_round_trip =
+            UnionArray::<Test, { Test::VARIANTS }, SparseLayout>::try_from(read)
+                .expect("valid array data");
+        assert_eq!(sparse_round_trip.0.variants.0 .0.bar.0, [0, 0, 123, 0]);
+
+        sparse_data.buffers[0][1] = 3;
+        assert_eq!(
+            UnionArray::<Test, { Test::VARIANTS }, SparseLayout>::try_from(sparse_data).map(|_| ()),
+            Err(ArrayDataError::InvalidTypeId { index: 1 })
+        );
+    }
+
+    #[test]
+    fn nesting_depth() {
+        // A null type nested in lists, followed by an empty length, nulls,
+        // buffers and children.
+        let mut nested = vec![16_u8; MAX_DEPTH - 1];
+        nested.extend([0; 26]);
+        assert!(ArrayData::read_from(&mut nested.as_slice()).is_ok());
+        let too_deep = vec![16_u8; MAX_DEPTH + 1];
+        assert_eq!(
+            ArrayData::read_from(&mut too_deep.as_slice())
+                .expect_err("too deep")
+                .to_string(),
+            "nesting depth should be at most 64"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "derive", feature = "lists", feature = "structs"))]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn reshape_typed() {
+        use crate::{array::StructArray, ArrayType};
+
+        #[derive(ArrayType, Clone, Copy, Debug, Default, PartialEq)]
+        struct Event {
+            id: u8,
+            value: Option<u16>,
+        }
+
+        #[derive(ArrayType)]
+        struct Events {
+            id: Option<Vec<u8>>,
+            value: Option<Vec<Option<u16>>>,
+        }
+
+        let event = |id, value| Event { id, value };
+        let lists = [
+            Some(vec![event(1, Some(2)), event(3, None)]),
+            None,
+            Some(vec![]),
+        ]
+        .into_iter()
+        .collect::<VariableSizeListArray<StructArray<Event>, true>>();
+        let columns = lists
+            .into_struct_of_lists::<StructArray<Events, true>>()
+            .expect("struct of lists");
+        assert_eq!(
+            columns.0.validity.iter().collect::<Vec<_>>(),
+            [true, false, true]
+        );
+        assert_eq!(columns.0.data.id.0.offsets.data, [0, 2, 2, 2]);
+        assert_eq!(columns.0.data.id.0.data.0, [1, 3]);
+        assert_eq!(
+            columns.0.data.value.0.data.iter().collect::<Vec<_>>(),
+            [Some(&2), None]
+        );
+
+        let rows =
+            VariableSizeListArray::<StructArray<Event>, true>::try_from_struct_of_lists(columns)
+                .expect("list of structs");
+        assert_eq!(rows.0.offsets.data, [0, 2, 2, 2]);
+        assert_eq!(
+            rows.0.offsets.validity.iter().collect::<Vec<_>>(),
+            [true, false, true]
+        );
+        assert_eq!(
+            rows.0.data.into_iter().collect::<Vec<_>>(),
+            [event(1, Some(2)), event(3, None)]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "lists", feature = "strings"))]
+    fn reshape() {
+        let field = |name: &str, data_type, nullable| Field {
+            name: name.to_owned(),
+            data_type,
+            nullable,
+        };
+        let numbers = ArrayData::from(
+            [Some(1), None, Some(3)]
+                .into_iter()
+                .collect::<Uint8Array<true>>(),
+        );
+        let strings = ArrayData::from(["x", "y", "z"].into_iter().collect::<StringArray>());
+        let item_type = DataType::Struct(vec![
+            field("a", DataType::UInt8, true),
+            field("b", DataType::Utf8, false),
+        ]);
+        let list = ArrayData {
+            data_type: DataType::List(Box::new(item_type.clone())),
+            len: 2,
+            nulls: Some([true, false].into_iter().collect()),
+            buffers: vec![[0_i32, 2, 3].iter().flat_map(|x| x.to_ne_bytes()).collect()],
+            children: vec![ArrayData {
+                data_type: item_type,
+                len: 3,
+                nulls: Some([true, true, false].into_iter().collect()),
+                buffers: Vec::new(),
+                children: vec![numbers, strings],
+            }],
+        };
+
+        let pivoted = list.into_struct_of_lists().expect("list of structs");
+        assert_eq!(
+            pivoted.data_type,
+            DataType::Struct(vec![
+                field("a", DataType::List(Box::new(DataType::UInt8)), true),
+                field("b", DataType::List(Box::new(DataType::Utf8)), true),
+            ])
+        );
+        let valid = |data: &ArrayData| {
+            data.nulls
+                .as_ref()
+                .map(|nulls| nulls.into_iter().collect::<Vec<_>>())
+        };
+        assert_eq!(valid(&pivoted.children[0]), Some(vec![true, false]));
+        assert_eq!(
+            valid(&pivoted.children[0].children[0]),
+            Some(vec![true, false, false])
+        );
+        assert_eq!(
+            valid(&pivoted.children[1].children[0]),
+            Some(vec![true, true, false])
+        );
+
+        let unpivoted = pivoted.into_list_of_structs().expect("struct of lists");
+        assert_eq!(
+            unpivoted.data_type,
+            DataType::List(Box::new(DataType::Struct(vec![
+                field("a", DataType::UInt8, true),
+                field("b", DataType::Utf8, true),
+            ])))
+        );
+        assert_eq!(valid(&unpivoted), Some(vec![true, false]));
+        assert_eq!(unpivoted.children[0].len, 3);
+
+        let mut mismatch = ArrayData::from(
+            [vec![1_u8], vec![2]]
+                .into_iter()
+                .collect::<VariableSizeListArray<Uint8Array>>(),
+        );
+        mismatch.data_type = DataType::Struct(Vec::new());
+        assert!(mismatch.into_struct_of_lists().is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "lists", feature = "strings"))]
+    fn write_read() {
+        let array = [Some(vec![Some("a"), None]), None, Some(vec![])]
+            .into_iter()
+            .collect::<VariableSizeListArray<StringArray<true>, true>>();
+        let mut bytes = Vec::new();
+        ArrayData::from(array)
+            .write_to(&mut bytes)
+            .expect("write to vec");
+        let data = ArrayData::read_from(&mut bytes.as_slice()).expect("valid array data");
+        assert_eq!(data.data_type, DataType::List(Box::new(DataType::Utf8)));
+        let round_trip = VariableSizeListArray::<StringArray<true>, true>::try_from(data)
+            .expect("valid array data");
+        assert_eq!(round_trip.0.offsets.data.as_slice(), [0, 2, 2, 2]);
+        assert_eq!(
+            round_trip
+                .0
+                .offsets
+                .validity
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [true, false, true]
+        );
+        assert_eq!(
+            round_trip.0.data.into_iter().collect::<Vec<_>>(),
+            [Some("a"), None]
+        );
+
+        assert_eq!(
+            ArrayData::read_from(&mut &bytes[..bytes.len() - 1])
+                .expect_err("truncated")
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(
+            ArrayData::read_from(&mut [42_u8].as_slice())
+                .expect_err("unknown tag")
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "lists", feature = "strings"))]
+    fn layout_snapshot() {
+        let array = [Some(vec![Some("a"), None]), None]
+            .into_iter()
+            .collect::<VariableSizeListArray<StringArray<true>, true>>();
+        let snapshot = ArrayData::from(array).layout_snapshot();
+        let expected = "
+List len=2
+  nulls len=1 offset=0
+  buffer 0 len=12 align=4
+  item: Utf8 len=2
+    nulls len=1 offset=0
+    buffer 0 len=12 align=4
+    buffer 1 len=1 align=1
+";
+        assert_eq!(snapshot.check(expected.trim_start()), Ok(()));
+        assert_eq!(snapshot.to_string(), expected.trim_start());
+
+        let numbers = ArrayData::from([1_u16, 2, 3].into_iter().collect::<Uint16Array>());
+        assert_eq!(
+            numbers
+                .layout_snapshot()
+                .check("UInt16 len=3\n  nulls none\n"),
+            Err(LayoutMismatch {
+                line: 3,
+                found: Some("  buffer 0 len=6 align=2".to_owned()),
+                expected: None
+            })
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! The length (number of elements) of a collection.
 
-use std::{collections::VecDeque, rc::Rc, sync::Arc};
+use crate::FixedSize;
+use std::{collections::VecDeque, mem, rc::Rc, sync::Arc};
 
 /// The length (or number of elements) of a collection.
 pub trait Length {
@@ -94,3 +95,62 @@ impl<T: Length> Length for Option<T> {
         self.as_ref().map_or(0, Length::len)
     }
 }
+
+/// The length in bytes of the memory used by a collection.
+///
+/// Where [`Length`] returns the number of elements (or rows), this returns
+/// the number of bytes of the buffers that store them, including validity
+/// bitmaps and offsets. This can be used for memory accounting and to size
+/// batches.
+pub trait ByteLength {
+    /// Returns the number of bytes used by the buffers of this collection.
+    fn byte_len(&self) -> usize;
+}
+
+impl<T: FixedSize, const N: usize> ByteLength for [T; N] {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(self)
+    }
+}
+
+impl<T: FixedSize> ByteLength for [T] {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(self)
+    }
+}
+
+impl<T: FixedSize> ByteLength for &[T] {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(*self)
+    }
+}
+
+impl<T: FixedSize> ByteLength for &mut [T] {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(&**self)
+    }
+}
+
+impl<T: FixedSize> ByteLength for Vec<T> {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(self.as_slice())
+    }
+}
+
+impl<T: FixedSize> ByteLength for Box<[T]> {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(&**self)
+    }
+}
+
+impl<T: FixedSize> ByteLength for Rc<[T]> {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(&**self)
+    }
+}
+
+impl<T: FixedSize> ByteLength for Arc<[T]> {
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(&**self)
+    }
+}
@@ -44,7 +44,6 @@
     clippy::shadow_unrelated,
     clippy::str_to_string,
     clippy::string_add,
-    clippy::string_to_string,
     clippy::tests_outside_test_module,
     clippy::undocumented_unsafe_blocks,
     clippy::unnecessary_safety_comment,
@@ -59,14 +58,25 @@
 #![allow(clippy::module_name_repetitions, clippy::pub_use)]
 
 mod fixed_size;
-pub use self::fixed_size::FixedSize;
+#[doc(hidden)]
+pub use self::fixed_size::__PlainOldDataSealed;
+pub use self::fixed_size::{FixedSize, PlainOldData};
 
 mod length;
-pub use self::length::Length;
+pub use self::length::{ByteLength, Length};
 
 mod index;
 pub use self::index::Index;
 
+mod capacity;
+pub use self::capacity::{CollectWithCapacity, GrowthStrategy, ShrinkToFit, WithCapacity};
+
+mod collect;
+pub use self::collect::TryCollect;
+
+mod freeze;
+pub use self::freeze::{Frozen, Mutable};
+
 pub mod buffer;
 
 pub mod bitmap;
@@ -77,12 +87,43 @@ pub mod offset;
 pub(crate) mod validity;
 
 pub mod array;
+pub mod builder;
+pub mod channel;
 
+pub mod layout;
 pub mod logical;
+pub mod prelude;
+
+pub mod compute;
+pub mod debug;
+pub mod projection;
+#[cfg(feature = "structs")]
+pub mod table;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "io-text")]
+pub mod text;
+
+pub mod encoding;
+#[cfg(feature = "gen")]
+pub mod gen;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "queue")]
+pub mod queue;
+pub mod wire;
 
 #[cfg(feature = "arrow-rs")]
 pub mod arrow;
 
+// Re-export `bytemuck` and `zerocopy` for the `impl_plain_old_data` macro.
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck as __bytemuck;
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+pub use zerocopy as __zerocopy;
+
 // Re-export `narrow_derive` macros when the `derive` feature is enabled.
 #[cfg(feature = "derive")]
 pub use narrow_derive::ArrayType;
@@ -3,11 +3,11 @@
 use crate::{
     bitmap::{Bitmap, BitmapIntoIter, BitmapIter, BitmapRef, BitmapRefMut, ValidityBitmap},
     buffer::{self, BufferMut, BufferRef, BufferRefMut, BufferType, VecBuffer},
-    FixedSize, Index, Length,
+    capacity, ByteLength, FixedSize, Index, Length, ShrinkToFit, WithCapacity,
 };
 use std::{
     borrow::Borrow,
-    iter::{Map, Zip},
+    iter::{self, Map, Zip},
 };
 
 /// Wrapper for nullable data.
@@ -89,20 +89,38 @@ where
     }
 }
 
+impl<T: WithCapacity, Buffer: BufferType> WithCapacity for Nullable<T, Buffer>
+where
+    Bitmap<Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: T::with_capacity(capacity),
+            validity: Bitmap::with_capacity(capacity),
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+        self.validity.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.data.reserve_exact(additional);
+        self.validity.reserve_exact(additional);
+    }
+}
+
 impl<T: Extend<U>, U: Default, V: Borrow<bool>, Buffer: BufferType> Extend<(V, U)>
     for Nullable<T, Buffer>
 where
     <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Extend<u8>,
 {
     fn extend<I: IntoIterator<Item = (V, U)>>(&mut self, iter: I) {
-        // https://github.com/rust-lang/rust-clippy/issues/9378
-        #[allow(clippy::pattern_type_mismatch)]
-        self.data.extend(
-            iter.into_iter()
-                .inspect(|(valid, _value)| {
-                    self.validity.extend(std::iter::once(valid.borrow()));
-                })
-                .map(|(_, item)| item),
+        capacity::extend_unzip(
+            &mut self.data,
+            &mut self.validity,
+            iter.into_iter().map(|(valid, item)| (item, valid)),
         );
     }
 }
@@ -111,11 +129,27 @@ impl<T: Extend<U>, U: Default, Buffer: BufferType> Extend<Option<U>> for Nullabl
 where
     <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Extend<u8>,
 {
+    /// Runs of valid and null values are appended in bulk, so that the values
+    /// of runs of nulls are appended at once and their validity bits are
+    /// unset in whole bytes.
     fn extend<I: IntoIterator<Item = Option<U>>>(&mut self, iter: I) {
-        self.extend(
-            iter.into_iter()
-                .map(|opt| (opt.is_some(), opt.unwrap_or_default())),
-        );
+        let mut items = iter.into_iter().peekable();
+        while items.peek().is_some() {
+            let mut valid = 0;
+            self.data.extend(
+                iter::from_fn(|| items.next_if(Option::is_some))
+                    .flatten()
+                    .inspect(|_| valid += 1),
+            );
+            self.validity.extend_constant(true, valid);
+
+            let mut nulls = 0;
+            while items.next_if(Option::is_none).is_some() {
+                nulls += 1;
+            }
+            self.data.extend(iter::repeat_with(U::default).take(nulls));
+            self.validity.extend_constant(false, nulls);
+        }
     }
 }
 
@@ -126,11 +160,9 @@ where
     <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Default + Extend<u8>,
 {
     fn from_iter<I: IntoIterator<Item = &'a Option<U>>>(iter: I) -> Self {
-        let (validity, data) = iter
-            .into_iter()
-            .map(|opt| (opt.is_some(), opt.as_ref().copied().unwrap_or_default()))
-            .unzip();
-        Self { data, validity }
+        let mut nullable = Self::default();
+        nullable.extend(iter.into_iter().copied());
+        nullable
     }
 }
 
@@ -141,11 +173,9 @@ where
     <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Default + Extend<u8>,
 {
     fn from_iter<I: IntoIterator<Item = Option<U>>>(iter: I) -> Self {
-        let (validity, data) = iter
-            .into_iter()
-            .map(|opt| (opt.is_some(), opt.unwrap_or_default()))
-            .unzip();
-        Self { data, validity }
+        let mut nullable = Self::default();
+        nullable.extend(iter);
+        nullable
     }
 }
 
@@ -153,7 +183,8 @@ impl<T, Buffer: BufferType> Index for Nullable<T, Buffer>
 where
     T: Index,
 {
-    type Item<'a> = Option<<T as Index>::Item<'a>>
+    type Item<'a>
+        = Option<<T as Index>::Item<'a>>
     where
         Self: 'a;
 
@@ -209,6 +240,22 @@ impl<T, Buffer: BufferType> Length for Nullable<T, Buffer> {
     }
 }
 
+impl<T: ByteLength, Buffer: BufferType> ByteLength for Nullable<T, Buffer> {
+    fn byte_len(&self) -> usize {
+        self.data.byte_len() + self.validity.byte_len()
+    }
+}
+
+impl<T: ShrinkToFit, Buffer: BufferType> ShrinkToFit for Nullable<T, Buffer>
+where
+    Bitmap<Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+        self.validity.shrink_to_fit();
+    }
+}
+
 impl<T, Buffer: BufferType> ValidityBitmap for Nullable<T, Buffer> {}
 
 #[cfg(test)]
@@ -236,6 +283,71 @@ mod tests {
         assert_eq!(nullable.len(), 6);
     }
 
+    #[test]
+    fn extend_without_with_capacity() {
+        /// A buffer that can't reserve capacity up front.
+        #[derive(Default)]
+        struct Growing<T>(Vec<T>);
+
+        impl<T> Length for Growing<T> {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        impl<T> Index for Growing<T> {
+            type Item<'a>
+                = &'a T
+            where
+                Self: 'a;
+
+            unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+                self.0.get_unchecked(index)
+            }
+        }
+
+        impl<T: FixedSize> buffer::Buffer<T> for Growing<T> {
+            fn as_slice(&self) -> &[T] {
+                &self.0
+            }
+        }
+
+        impl<T: FixedSize> BufferMut<T> for Growing<T> {
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                &mut self.0
+            }
+        }
+
+        impl<T> Extend<T> for Growing<T> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                self.0.extend(iter);
+            }
+        }
+
+        struct GrowingBuffer;
+
+        impl BufferType for GrowingBuffer {
+            type Buffer<T: FixedSize> = Growing<T>;
+        }
+
+        let mut nullable = Nullable::<Vec<u8>, GrowingBuffer>::default();
+        nullable.extend([(true, 1), (false, 0), (true, 3)]);
+        nullable.extend([None, Some(4)]);
+        assert_eq!(nullable.data, [1, 0, 3, 0, 4]);
+        assert_eq!(nullable.validity.buffer.0, [0b0001_0101]);
+    }
+
+    #[test]
+    fn extend_runs() {
+        let mut nullable = Nullable::<Vec<u16>>::default();
+        nullable.extend(iter::repeat(None::<u16>).take(10));
+        nullable.extend([Some(1), Some(2), None, Some(3)]);
+        assert_eq!(nullable.len(), 14);
+        assert_eq!(nullable.data[10..], [1, 2, 0, 3]);
+        assert_eq!(nullable.bitmap_ref().buffer_ref(), &[0, 0b0010_1100]);
+        assert_eq!(nullable.null_count(), 11);
+    }
+
     #[test]
     fn from_iter_array() {
         let input = [Some([1234, 1234]), None, Some([42, 42])];
@@ -5,10 +5,10 @@ use crate::{
     buffer::{Buffer, BufferType, VecBuffer},
     nullable::Nullable,
     validity::Validity,
-    FixedSize, Index, Length,
+    ByteLength, Index, Length, PlainOldData, ShrinkToFit, WithCapacity,
 };
 use std::{
-    iter,
+    iter, mem,
     num::TryFromIntError,
     ops::{AddAssign, Range, Sub},
 };
@@ -19,7 +19,7 @@ use std::{
 ///
 /// This trait is sealed to prevent downstream implementations.
 pub trait OffsetElement:
-    FixedSize
+    PlainOldData
     + AddAssign
     + Default
     + TryFrom<usize, Error = TryFromIntError>
@@ -82,7 +82,7 @@ pub struct OffsetSlot<'a, OffsetItem: OffsetElement, Buffer: BufferType> {
     index: usize,
 }
 
-impl<'a, OffsetItem: OffsetElement, Buffer: BufferType> OffsetSlot<'a, OffsetItem, Buffer> {
+impl<OffsetItem: OffsetElement, Buffer: BufferType> OffsetSlot<'_, OffsetItem, Buffer> {
     /// Returns the position of this slot in the buffer i.e. the index.
     #[must_use]
     pub fn position(&self) -> usize {
@@ -203,6 +203,106 @@ where
     }
 }
 
+impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Offset<T, false, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+{
+    /// Appends `n` empty items, writing their offsets in bulk.
+    // The offsets buffer has at least one value.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn append_empty_n(&mut self, n: usize) {
+        let end = self
+            .offsets
+            .as_slice()
+            .last()
+            .copied()
+            .expect("at least one value in the offsets buffer");
+        self.offsets.extend(iter::repeat(end).take(n));
+    }
+
+    /// Extends with `items`, appending runs of consecutive empty items with
+    /// [`Offset::append_empty_n`].
+    ///
+    /// This is equivalent to [`Extend::extend`], with less overhead per item
+    /// for items that are mostly empty, e.g. sparse list columns.
+    pub fn extend_dedup<U: IntoIterator + Length, I: IntoIterator<Item = U>>(&mut self, items: I)
+    where
+        Self: Extend<U>,
+    {
+        let mut empty = 0;
+        for item in items {
+            if item.is_empty() {
+                empty += 1;
+            } else {
+                self.append_empty_n(mem::take(&mut empty));
+                self.extend(iter::once(item));
+            }
+        }
+        self.append_empty_n(empty);
+    }
+}
+
+impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Offset<T, true, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+    Bitmap<Buffer>: Extend<bool>,
+{
+    /// Appends `n` empty valid items, writing their offsets in bulk.
+    pub fn append_empty_n(&mut self, n: usize) {
+        self.append_n(true, n);
+    }
+
+    /// Appends `n` null items, writing their offsets in bulk.
+    pub fn append_null_n(&mut self, n: usize) {
+        self.append_n(false, n);
+    }
+
+    /// Extends with `items`, appending runs of consecutive null items and
+    /// consecutive empty valid items with [`Offset::append_null_n`] and
+    /// [`Offset::append_empty_n`].
+    ///
+    /// This is equivalent to [`Extend::extend`], with less overhead per item
+    /// for items that are mostly null or empty, e.g. sparse list columns.
+    pub fn extend_dedup<U: IntoIterator + Length, I: IntoIterator<Item = Option<U>>>(
+        &mut self,
+        items: I,
+    ) where
+        Self: Extend<Option<U>>,
+    {
+        // The validity and length of the current run of empty items.
+        let mut run = (true, 0);
+        for item in items {
+            match item {
+                Some(ref value) if !value.is_empty() => {
+                    self.append_n(run.0, mem::take(&mut run.1));
+                    self.extend(iter::once(item));
+                }
+                _ => {
+                    if run.0 != item.is_some() {
+                        self.append_n(run.0, run.1);
+                        run = (item.is_some(), 0);
+                    }
+                    run.1 += 1;
+                }
+            }
+        }
+        self.append_n(run.0, run.1);
+    }
+
+    /// Appends `n` empty items with the given validity.
+    fn append_n(&mut self, valid: bool, n: usize) {
+        let end = self
+            .offsets
+            .data
+            .as_slice()
+            .last()
+            .copied()
+            .expect("at least one value in the offsets buffer");
+        self.offsets.data.extend(iter::repeat(end).take(n));
+        self.offsets.validity.extend(iter::repeat(valid).take(n));
+    }
+}
+
 impl<T: Default, OffsetItem: OffsetElement, Buffer: BufferType> Default
     for Offset<T, false, OffsetItem, Buffer>
 where
@@ -236,6 +336,60 @@ where
     }
 }
 
+/// Allocates capacity for the offsets of `capacity` items. The capacity of the
+/// data can't be derived from the number of items.
+impl<T: Default, OffsetItem: OffsetElement, Buffer: BufferType> WithCapacity
+    for Offset<T, false, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: WithCapacity + Extend<OffsetItem>,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        let mut offsets = <Buffer as BufferType>::Buffer::<OffsetItem>::with_capacity(capacity + 1);
+        offsets.extend(iter::once(OffsetItem::default()));
+        Self {
+            data: T::default(),
+            offsets,
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.offsets.reserve_exact(additional);
+    }
+}
+
+/// Allocates capacity for the offsets and validity of `capacity` items. The
+/// capacity of the data can't be derived from the number of items.
+impl<T: Default, OffsetItem: OffsetElement, Buffer: BufferType> WithCapacity
+    for Offset<T, true, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: WithCapacity + Extend<OffsetItem>,
+    Bitmap<Buffer>: WithCapacity,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        let mut offsets = <Buffer as BufferType>::Buffer::<OffsetItem>::with_capacity(capacity + 1);
+        offsets.extend(iter::once(OffsetItem::default()));
+        Self {
+            data: T::default(),
+            offsets: Nullable {
+                data: offsets,
+                validity: Bitmap::with_capacity(capacity),
+            },
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.offsets.reserve_exact(additional);
+    }
+}
+
 impl<T, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType> Extend<U>
     for Offset<T, false, OffsetItem, Buffer>
 where
@@ -243,6 +397,10 @@ where
     <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
 {
     fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        let items = iter.into_iter();
+        // The offsets are buffered and appended at once, so that the offsets
+        // buffer can reserve capacity for them.
+        let mut offsets = Vec::with_capacity(items.size_hint().0);
         let mut state = self
             .offsets
             .as_slice()
@@ -250,17 +408,18 @@ where
             .copied()
             .expect("at least one value in the offsets buffer");
         self.data.extend(
-            iter.into_iter()
+            items
                 .inspect(|item| {
                     state = state
                         .checked_add_unsigned(
                             OffsetItem::Unsigned::try_from(item.len()).expect("len overflow"),
                         )
                         .expect("offset value overflow");
-                    self.offsets.extend(iter::once(state));
+                    offsets.push(state);
                 })
                 .flatten(),
         );
+        self.offsets.extend(offsets);
     }
 }
 
@@ -272,6 +431,10 @@ where
         Extend<(bool, OffsetItem)>,
 {
     fn extend<I: IntoIterator<Item = Option<U>>>(&mut self, iter: I) {
+        let items = iter.into_iter();
+        // The offsets are buffered and appended at once, so that the offsets
+        // and validity buffers can reserve capacity for them.
+        let mut offsets = Vec::with_capacity(items.size_hint().0);
         let mut state = self
             .offsets
             .as_ref()
@@ -280,18 +443,19 @@ where
             .copied()
             .expect("at least one value in the offsets buffer");
         self.data.extend(
-            iter.into_iter()
+            items
                 .inspect(|opt| {
                     state = state
                         .checked_add_unsigned(
                             OffsetItem::Unsigned::try_from(opt.len()).expect("len overflow"),
                         )
                         .expect("offset value overflow");
-                    self.offsets.extend(iter::once((opt.is_some(), state)));
+                    offsets.push((opt.is_some(), state));
                 })
                 .flatten()
                 .flatten(),
         );
+        self.offsets.extend(offsets);
     }
 }
 
@@ -359,8 +523,8 @@ where
 
 // TODO(mbrobbel): this is the remaining items in the iterator, maybe we want
 // this to be the original slot length?
-impl<'a, T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
-    for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
+impl<T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
+    for OffsetSlice<'_, T, NULLABLE, OffsetItem, Buffer>
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
 {
@@ -392,7 +556,8 @@ where
 impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Index
     for Offset<T, false, OffsetItem, Buffer>
 {
-    type Item<'a> = OffsetSlice<'a, T, false, OffsetItem, Buffer>
+    type Item<'a>
+        = OffsetSlice<'a, T, false, OffsetItem, Buffer>
     where
         Self: 'a;
 
@@ -412,7 +577,8 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Index
 impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Index
     for Offset<T, true, OffsetItem, Buffer>
 {
-    type Item<'a> = Option<OffsetSlice<'a, T, true, OffsetItem, Buffer>>
+    type Item<'a>
+        = Option<OffsetSlice<'a, T, true, OffsetItem, Buffer>>
     where
         Self: 'a;
 
@@ -511,6 +677,30 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Length
     }
 }
 
+impl<T: ByteLength, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ByteLength
+    for Offset<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<NULLABLE>>::Storage<Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.data.byte_len() + self.offsets.byte_len()
+    }
+}
+
+impl<T: ShrinkToFit, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    ShrinkToFit for Offset<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<NULLABLE>>::Storage<Buffer>:
+        ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+    }
+}
+
 impl<T, OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for Offset<T, true, OffsetItem, Buffer>
 {
@@ -656,4 +846,47 @@ mod tests {
         assert_eq!(offset_nullable.len(), 3);
         assert!(offset_nullable.all_valid());
     }
+
+    #[test]
+    fn extend_dedup() {
+        let input = [vec![], vec![], vec![1, 2], vec![], vec![3], vec![], vec![]];
+        let mut offset = Offset::<Vec<u8>>::default();
+        offset.extend_dedup(input.clone());
+        assert_eq!(offset.offsets.as_slice(), &[0, 0, 0, 2, 2, 3, 3, 3]);
+        assert_eq!(offset.data, &[1, 2, 3]);
+        assert_eq!(
+            offset.offsets.as_slice(),
+            input
+                .into_iter()
+                .collect::<Offset<Vec<u8>>>()
+                .offsets
+                .as_slice()
+        );
+    }
+
+    #[test]
+    fn extend_dedup_nullable() {
+        let input = [
+            None,
+            None,
+            Some(vec![]),
+            Some(vec![1]),
+            Some(vec![]),
+            None,
+            Some(vec![2, 3]),
+        ];
+        let mut offset = Offset::<Vec<u8>, true>::default();
+        offset.extend_dedup(input.clone());
+        offset.append_null_n(2);
+        assert_eq!(
+            offset.offsets.as_ref().as_slice(),
+            &[0, 0, 0, 0, 1, 1, 1, 3, 3, 3]
+        );
+        assert_eq!(
+            offset.offsets.validity.iter().collect::<Vec<_>>(),
+            [false, false, true, true, true, false, true, false, false]
+        );
+        let expected = input.into_iter().collect::<Offset<Vec<u8>, true>>();
+        assert_eq!(offset.data, expected.data);
+    }
 }
@@ -0,0 +1,46 @@
+//! The traits that are needed to work with arrays.
+//!
+//! Importing this module brings the traits in scope that are needed to
+//! construct arrays, query their length and validity, access their buffers,
+//! and, with the `compute` feature, run kernels on them.
+//!
+//! ```
+//! use narrow::{array::Uint8Array, prelude::*};
+//!
+//! let array = [Some(1), None, Some(3)]
+//!     .into_iter()
+//!     .collect::<Uint8Array<true>>();
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(array.null_count(), 1);
+//! assert_eq!(array.index_checked(2), Some(&3));
+//! ```
+//!
+//! The prelude only exports traits. Traits are only added to the prelude when
+//! they are needed in most code that uses arrays, and they are not removed
+//! from it without a major version bump.
+
+pub use crate::{
+    array::{Array, ArrayType},
+    bitmap::{BitmapRef, BitmapRefMut, ValidityBitmap},
+    buffer::{Buffer, BufferMut, BufferRef, BufferRefMut, BufferType},
+    compute::ColumnValues,
+    ByteLength, CollectWithCapacity, FixedSize, Index, Length, ShrinkToFit, TryCollect,
+    WithCapacity,
+};
+
+#[cfg(feature = "structs")]
+pub use crate::array::StructArrayType;
+
+#[cfg(feature = "compute")]
+pub use crate::compute::{
+    ColumnStats, Elementwise, IntoNullable, MinMax, Predicate, Rows, StringColumnStats,
+};
+
+#[cfg(all(feature = "compute", feature = "lists"))]
+pub use crate::compute::Unnest;
+
+#[cfg(all(feature = "compute", feature = "structs"))]
+pub use crate::compute::StructColumnStats;
+
+#[cfg(feature = "derive")]
+pub use narrow_derive::ArrayType;
@@ -0,0 +1,244 @@
+//! Selection of a subset of the fields of a struct.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Types with named fields.
+///
+/// This is implemented by the derive macro for structs. The names of unnamed
+/// fields are their index.
+pub trait FieldNames {
+    /// The names of the fields, in declaration order.
+    const FIELD_NAMES: &'static [&'static str];
+}
+
+/// Typed identifiers of the fields of a struct.
+///
+/// The derive macro generates an enum that implements this for structs with
+/// named fields and the `#[narrow(columns)]` attribute, e.g. `FooColumns` for
+/// a struct `Foo`, with a variant per field in upper camel case. Compilation
+/// fails if the names of two fields map to the same variant.
+pub trait ColumnId: Copy + 'static {
+    /// All columns, in declaration order.
+    const COLUMNS: &'static [Self];
+
+    /// Returns the name of this column.
+    fn column_name(self) -> &'static str;
+
+    /// Returns the index of this column.
+    fn column_index(self) -> usize;
+}
+
+/// Error returned when a requested field does not exist.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownField(pub String);
+
+impl Display for UnknownField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown field `{}`", self.0)
+    }
+}
+
+impl Error for UnknownField {}
+
+/// A selection of the top-level fields of a struct.
+///
+/// A mask is built from field indices, names, column identifiers or a subset
+/// struct, and lists the indices of the selected fields. No reader in this
+/// crate accepts a mask yet.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ProjectionMask {
+    /// Whether the field at each index is selected.
+    selected: Vec<bool>,
+}
+
+impl ProjectionMask {
+    /// Returns a mask that selects all of the `len` fields.
+    #[must_use]
+    pub fn all(len: usize) -> Self {
+        Self {
+            selected: vec![true; len],
+        }
+    }
+
+    /// Returns a mask that selects none of the `len` fields.
+    #[must_use]
+    pub fn none(len: usize) -> Self {
+        Self {
+            selected: vec![false; len],
+        }
+    }
+
+    /// Returns a mask over `len` fields that selects the fields at `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    #[must_use]
+    pub fn from_indices(len: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut mask = Self::none(len);
+        indices.into_iter().for_each(|index| mask.select(index));
+        mask
+    }
+
+    /// Returns a mask over `fields` that selects the fields in `names`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when one of the `names` is not in `fields`.
+    pub fn from_names<'a>(
+        fields: &[&str],
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, UnknownField> {
+        let mut mask = Self::none(fields.len());
+        names.into_iter().try_for_each(|name| {
+            fields
+                .iter()
+                .position(|field| *field == name)
+                .map(|index| mask.select(index))
+                .ok_or_else(|| UnknownField(name.to_owned()))
+        })?;
+        Ok(mask)
+    }
+
+    /// Returns a mask over all columns of `C` that selects `columns`.
+    #[must_use]
+    pub fn from_columns<C: ColumnId>(columns: impl IntoIterator<Item = C>) -> Self {
+        Self::from_indices(
+            C::COLUMNS.len(),
+            columns.into_iter().map(ColumnId::column_index),
+        )
+    }
+
+    /// Returns a mask over the fields of `T` that selects the fields of
+    /// `Subset`, e.g. a struct with a subset of the fields of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a field of `Subset` is not a field of `T`.
+    pub fn from_subset<T: FieldNames, Subset: FieldNames>() -> Result<Self, UnknownField> {
+        Self::from_names(T::FIELD_NAMES, Subset::FIELD_NAMES.iter().copied())
+    }
+
+    /// Selects the field at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn select(&mut self, index: usize) {
+        let len = self.selected.len();
+        match self.selected.get_mut(index) {
+            Some(selected) => *selected = true,
+            None => panic!("index (is {index}) should be < len (is {len})"),
+        }
+    }
+
+    /// Returns `true` if the field at `index` is selected. Returns `false`
+    /// if `index` is out of bounds.
+    #[must_use]
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.get(index).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over the indices of the selected fields.
+    pub fn selected_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected
+            .iter()
+            .enumerate()
+            .filter_map(|(index, selected)| selected.then_some(index))
+    }
+
+    /// Returns the number of fields this mask applies to.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    /// Returns `true` if this mask applies to no fields.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A struct with three fields.
+    struct Full;
+
+    impl FieldNames for Full {
+        const FIELD_NAMES: &'static [&'static str] = &["a", "b", "c"];
+    }
+
+    /// A struct with a subset of the fields of [`Full`].
+    struct Subset;
+
+    impl FieldNames for Subset {
+        const FIELD_NAMES: &'static [&'static str] = &["c", "a"];
+    }
+
+    /// Columns of [`Full`].
+    #[derive(Clone, Copy)]
+    enum FullColumns {
+        /// The `a` column.
+        A,
+        /// The `b` column.
+        B,
+        /// The `c` column.
+        C,
+    }
+
+    impl ColumnId for FullColumns {
+        const COLUMNS: &'static [Self] = &[Self::A, Self::B, Self::C];
+
+        fn column_name(self) -> &'static str {
+            match self {
+                Self::A => "a",
+                Self::B => "b",
+                Self::C => "c",
+            }
+        }
+
+        fn column_index(self) -> usize {
+            match self {
+                Self::A => 0,
+                Self::B => 1,
+                Self::C => 2,
+            }
+        }
+    }
+
+    #[test]
+    fn from_columns() {
+        let mask = ProjectionMask::from_columns([FullColumns::C]);
+        assert_eq!(mask, ProjectionMask::from_indices(3, [2]));
+        assert_eq!(FullColumns::A.column_name(), "a");
+    }
+
+    #[test]
+    fn from_indices() {
+        let mask = ProjectionMask::from_indices(3, [2, 0]);
+        assert_eq!(mask.len(), 3);
+        assert!(mask.is_selected(0));
+        assert!(!mask.is_selected(1));
+        assert!(!mask.is_selected(3));
+        assert_eq!(mask.selected_indices().collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(ProjectionMask::all(2).selected_indices().count(), 2);
+    }
+
+    #[test]
+    fn from_subset() {
+        assert_eq!(
+            ProjectionMask::from_subset::<Full, Subset>(),
+            Ok(ProjectionMask::from_indices(3, [0, 2]))
+        );
+        assert_eq!(
+            ProjectionMask::from_subset::<Subset, Full>(),
+            Err(UnknownField("b".to_owned()))
+        );
+    }
+}
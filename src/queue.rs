@@ -0,0 +1,528 @@
+//! Adapters between message queues and arrays.
+//!
+//! These adapters are independent of a specific message queue client. A
+//! [`QueueSink`] deserializes incoming messages into rows, collects the rows
+//! in an array and sends the array to a [`Producer`] when the batch is full
+//! or has been open for too long. A [`QueueSource`] does the reverse: it
+//! receives arrays from a [`Consumer`] and serializes their rows into
+//! outgoing messages.
+
+use crate::Length;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+/// Deserialization of message payloads into rows.
+pub trait Deserializer<T> {
+    /// The error returned when a message can't be deserialized.
+    type Error;
+
+    /// Deserializes the payload of a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is not valid.
+    fn deserialize(&mut self, payload: &[u8]) -> Result<T, Self::Error>;
+}
+
+impl<T, E, F: FnMut(&[u8]) -> Result<T, E>> Deserializer<T> for F {
+    type Error = E;
+
+    fn deserialize(&mut self, payload: &[u8]) -> Result<T, Self::Error> {
+        self(payload)
+    }
+}
+
+/// Serialization of rows into message payloads.
+pub trait Serializer<T> {
+    /// The error returned when a row can't be serialized.
+    type Error;
+
+    /// Serializes a row, appending the payload to `payload`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the row can't be serialized.
+    fn serialize(&mut self, row: &T, payload: &mut Vec<u8>) -> Result<(), Self::Error>;
+}
+
+impl<T, E, F: FnMut(&T, &mut Vec<u8>) -> Result<(), E>> Serializer<T> for F {
+    type Error = E;
+
+    fn serialize(&mut self, row: &T, payload: &mut Vec<u8>) -> Result<(), Self::Error> {
+        self(row, payload)
+    }
+}
+
+/// Source of batches, e.g. a message queue consumer.
+pub trait Consumer<A> {
+    /// The error returned when a batch can't be received.
+    type Error;
+
+    /// Receives the next batch, or `None` if no batch is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a batch can't be received.
+    fn receive(&mut self) -> Result<Option<A>, Self::Error>;
+}
+
+/// Destination of finished batches, e.g. a message queue producer.
+pub trait Producer<A> {
+    /// The error returned when a batch can't be sent.
+    type Error;
+
+    /// Sends a batch.
+    ///
+    /// When this returns an error the batch is handed back so it can be
+    /// retried later, e.g. because the destination applies backpressure.
+    ///
+    /// # Errors
+    ///
+    /// Returns the batch together with an error when it can't be sent.
+    fn send(&mut self, batch: A) -> Result<(), (A, Self::Error)>;
+}
+
+/// Error returned by a [`QueueSink`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QueueError<D, P> {
+    /// A message could not be deserialized.
+    Deserialize(D),
+    /// A batch could not be sent to the producer. The batch is retained and
+    /// sent again on the next flush.
+    Produce(P),
+    /// The current batch is full and can't be sent because the producer
+    /// rejected a batch.
+    Backpressure,
+}
+
+impl<D: Display, P: Display> Display for QueueError<D, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Deserialize(ref error) => write!(f, "failed to deserialize message: {error}"),
+            Self::Produce(ref error) => write!(f, "failed to send batch: {error}"),
+            Self::Backpressure => f.write_str("batch is full and can't be sent"),
+        }
+    }
+}
+
+impl<D: Error, P: Error> Error for QueueError<D, P> {}
+
+/// Error returned by a [`QueueSource`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QueueSourceError<S, C> {
+    /// A row could not be serialized. The row is skipped.
+    Serialize(S),
+    /// A batch could not be received from the consumer.
+    Consume(C),
+}
+
+impl<S: Display, C: Display> Display for QueueSourceError<S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Serialize(ref error) => write!(f, "failed to serialize row: {error}"),
+            Self::Consume(ref error) => write!(f, "failed to receive batch: {error}"),
+        }
+    }
+}
+
+impl<S: Error, C: Error> Error for QueueSourceError<S, C> {}
+
+/// Collects messages, deserialized as rows of type `T`, in batches of type `A`
+/// and sends them to a [`Producer`].
+///
+/// A batch is sent when it contains `max_rows` rows, or when the first row was
+/// added more than `max_age` ago. Time-based flushes only happen when calling
+/// [`QueueSink::push`] or [`QueueSink::poll`].
+#[derive(Debug)]
+pub struct QueueSink<T, A, D, P> {
+    /// The deserializer for incoming messages.
+    deserializer: D,
+    /// The producer for finished batches.
+    producer: P,
+    /// The batch being collected.
+    batch: A,
+    /// A finished batch that could not be sent yet.
+    pending: Option<A>,
+    /// The time the first row of the current batch was added.
+    opened: Option<Instant>,
+    /// The maximum number of rows in a batch.
+    max_rows: usize,
+    /// The maximum time between adding the first row and sending a batch.
+    max_age: Duration,
+    /// The type of the rows.
+    _row: PhantomData<fn(T)>,
+}
+
+impl<T, A: Default, D, P> QueueSink<T, A, D, P> {
+    /// Returns a new [`QueueSink`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_rows` is zero.
+    pub fn new(deserializer: D, producer: P, max_rows: usize, max_age: Duration) -> Self {
+        assert!(max_rows > 0, "max rows should be non-zero");
+        Self {
+            deserializer,
+            producer,
+            batch: A::default(),
+            pending: None,
+            opened: None,
+            max_rows,
+            max_age,
+            _row: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Default + Extend<T> + Length, D: Deserializer<T>, P: Producer<A>> QueueSink<T, A, D, P> {
+    /// Deserializes the payload of a message and adds it to the current batch,
+    /// sending the batch when it is full or too old.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Deserialize`] when the message can't be
+    /// deserialized. Returns [`QueueError::Backpressure`] when the current
+    /// batch is full and can't be sent; the message is not added, the full
+    /// batch is kept as the current batch, and the message should be pushed
+    /// again later. Returns [`QueueError::Produce`] when the message was added
+    /// but a batch could not be sent.
+    pub fn push(&mut self, payload: &[u8]) -> Result<(), QueueError<D::Error, P::Error>> {
+        if self.batch.len() >= self.max_rows {
+            self.send_full()?;
+        }
+        let row = self
+            .deserializer
+            .deserialize(payload)
+            .map_err(QueueError::Deserialize)?;
+        self.batch.extend(std::iter::once(row));
+        self.opened.get_or_insert_with(Instant::now);
+        self.poll()
+    }
+
+    /// Sends the current batch when it is full or too old, and retries
+    /// sending a batch that is waiting to be sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a batch can't be sent.
+    pub fn poll(&mut self) -> Result<(), QueueError<D::Error, P::Error>> {
+        let expired = self
+            .opened
+            .is_some_and(|opened| opened.elapsed() >= self.max_age);
+        if self.batch.len() >= self.max_rows || expired || self.pending.is_some() {
+            self.flush()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sends the pending batch and the current batch, if they are not empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Produce`] when a batch can't be sent. In that
+    /// case the batch is retained and sent again on the next flush.
+    pub fn flush(&mut self) -> Result<(), QueueError<D::Error, P::Error>> {
+        if let Some(pending) = self.pending.take() {
+            self.send(pending)?;
+        }
+        if !self.batch.is_empty() {
+            let batch = std::mem::take(&mut self.batch);
+            self.opened = None;
+            self.send(batch)?;
+        }
+        Ok(())
+    }
+
+    /// Sends the pending batch and the full current batch, without moving
+    /// the current batch to the pending batch on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Backpressure`] when a batch can't be sent.
+    fn send_full(&mut self) -> Result<(), QueueError<D::Error, P::Error>> {
+        if let Some(pending) = self.pending.take() {
+            self.send(pending).map_err(|_| QueueError::Backpressure)?;
+        }
+        let batch = std::mem::take(&mut self.batch);
+        match self.producer.send(batch) {
+            Ok(()) => {
+                self.opened = None;
+                Ok(())
+            }
+            Err((rejected, _)) => {
+                self.batch = rejected;
+                Err(QueueError::Backpressure)
+            }
+        }
+    }
+
+    /// Sends a batch, retaining it as the pending batch on failure.
+    fn send(&mut self, batch: A) -> Result<(), QueueError<D::Error, P::Error>> {
+        self.producer.send(batch).map_err(|(rejected, error)| {
+            self.pending = Some(rejected);
+            QueueError::Produce(error)
+        })
+    }
+
+    /// Returns the number of rows in the current batch.
+    pub fn len(&self) -> usize {
+        self.batch.len()
+    }
+
+    /// Returns `true` if the current batch has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.batch.is_empty()
+    }
+
+    /// Returns `true` if a batch is waiting to be sent.
+    pub fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Returns a reference to the producer.
+    pub fn producer(&self) -> &P {
+        &self.producer
+    }
+}
+
+/// The error returned by a [`QueueSource`] with rows of batch type `A`.
+type SourceError<A, S, C> = QueueSourceError<
+    <S as Serializer<<A as IntoIterator>::Item>>::Error,
+    <C as Consumer<A>>::Error,
+>;
+
+/// Receives batches of type `A` from a [`Consumer`] and serializes their rows
+/// into messages.
+///
+/// The next batch is received when all rows of the current batch are
+/// serialized.
+pub struct QueueSource<A: IntoIterator, S, C> {
+    /// The serializer for outgoing messages.
+    serializer: S,
+    /// The consumer of batches.
+    consumer: C,
+    /// The remaining rows of the current batch.
+    rows: Option<A::IntoIter>,
+    /// The payload of the last message.
+    payload: Vec<u8>,
+}
+
+impl<A: IntoIterator, S, C> QueueSource<A, S, C> {
+    /// Returns a new [`QueueSource`].
+    pub fn new(serializer: S, consumer: C) -> Self {
+        Self {
+            serializer,
+            consumer,
+            rows: None,
+            payload: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the consumer.
+    pub fn consumer(&self) -> &C {
+        &self.consumer
+    }
+}
+
+impl<A: IntoIterator, S: Serializer<A::Item>, C: Consumer<A>> QueueSource<A, S, C> {
+    /// Returns the payload of the next message, or `None` if the current batch
+    /// is done and the consumer has no batch available.
+    ///
+    /// The payload buffer is reused for every message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueSourceError::Serialize`] when a row can't be serialized;
+    /// the row is skipped. Returns [`QueueSourceError::Consume`] when a batch
+    /// can't be received.
+    pub fn next_message(&mut self) -> Result<Option<&[u8]>, SourceError<A, S, C>> {
+        loop {
+            if let Some(row) = self.rows.as_mut().and_then(Iterator::next) {
+                self.payload.clear();
+                self.serializer
+                    .serialize(&row, &mut self.payload)
+                    .map_err(QueueSourceError::Serialize)?;
+                return Ok(Some(&self.payload));
+            }
+            let Some(batch) = self.consumer.receive().map_err(QueueSourceError::Consume)? else {
+                self.rows = None;
+                return Ok(None);
+            };
+            self.rows = Some(batch.into_iter());
+        }
+    }
+}
+
+impl<A: IntoIterator, S: fmt::Debug, C: fmt::Debug> fmt::Debug for QueueSource<A, S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueueSource")
+            .field("serializer", &self.serializer)
+            .field("consumer", &self.consumer)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint32Array;
+
+    /// A producer that stores sent batches, and rejects batches when it
+    /// stored `capacity` batches.
+    struct Collect {
+        /// The sent batches.
+        batches: Vec<Vec<u32>>,
+        /// The maximum number of stored batches.
+        capacity: usize,
+    }
+
+    impl Collect {
+        /// Returns a producer that stores at most `capacity` batches.
+        fn new(capacity: usize) -> Self {
+            Self {
+                batches: Vec::new(),
+                capacity,
+            }
+        }
+    }
+
+    impl Producer<Uint32Array> for Collect {
+        type Error = &'static str;
+
+        fn send(&mut self, batch: Uint32Array) -> Result<(), (Uint32Array, Self::Error)> {
+            if self.batches.len() < self.capacity {
+                self.batches.push(batch.into_iter().collect());
+                Ok(())
+            } else {
+                Err((batch, "full"))
+            }
+        }
+    }
+
+    /// A consumer that hands out the given batches.
+    struct Batches(std::vec::IntoIter<Uint32Array>);
+
+    impl Consumer<Uint32Array> for Batches {
+        type Error = &'static str;
+
+        fn receive(&mut self) -> Result<Option<Uint32Array>, Self::Error> {
+            Ok(self.0.next())
+        }
+    }
+
+    /// Deserializes a little-endian `u32`.
+    fn deserialize(payload: &[u8]) -> Result<u32, &'static str> {
+        payload
+            .try_into()
+            .map(u32::from_le_bytes)
+            .map_err(|_| "invalid length")
+    }
+
+    #[test]
+    fn max_rows() {
+        let mut sink = QueueSink::<_, Uint32Array, _, _>::new(
+            deserialize,
+            Collect::new(usize::MAX),
+            2,
+            Duration::MAX,
+        );
+        for value in 1_u32..=5 {
+            sink.push(&value.to_le_bytes()).expect("push");
+        }
+        assert_eq!(sink.producer().batches, [vec![1, 2], vec![3, 4]]);
+        assert_eq!(sink.len(), 1);
+        sink.flush().expect("flush");
+        assert_eq!(sink.producer().batches.len(), 3);
+        assert!(sink.is_empty());
+        assert_eq!(
+            sink.push(&[1]),
+            Err(QueueError::Deserialize("invalid length"))
+        );
+    }
+
+    #[test]
+    fn max_age() {
+        let mut sink = QueueSink::<_, Uint32Array, _, _>::new(
+            deserialize,
+            Collect::new(usize::MAX),
+            usize::MAX,
+            Duration::ZERO,
+        );
+        sink.push(&1_u32.to_le_bytes()).expect("push");
+        assert_eq!(sink.producer().batches, [vec![1]]);
+    }
+
+    #[test]
+    fn backpressure() {
+        let mut sink =
+            QueueSink::<_, Uint32Array, _, _>::new(deserialize, Collect::new(0), 1, Duration::MAX);
+        assert_eq!(
+            sink.push(&1_u32.to_le_bytes()),
+            Err(QueueError::Produce("full"))
+        );
+        assert!(sink.has_pending());
+        assert_eq!(
+            sink.push(&2_u32.to_le_bytes()),
+            Err(QueueError::Produce("full"))
+        );
+        assert_eq!(sink.len(), 1);
+        assert_eq!(
+            sink.push(&3_u32.to_le_bytes()),
+            Err(QueueError::Backpressure)
+        );
+        assert_eq!(sink.len(), 1);
+
+        // The pending batch is sent, the full batch is rejected and kept.
+        sink.producer.capacity = 1;
+        assert_eq!(
+            sink.push(&3_u32.to_le_bytes()),
+            Err(QueueError::Backpressure)
+        );
+        assert!(!sink.has_pending());
+        assert_eq!(sink.len(), 1);
+
+        sink.producer.capacity = usize::MAX;
+        sink.push(&3_u32.to_le_bytes()).expect("push");
+        assert_eq!(sink.producer().batches, [vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn source() {
+        let batches = vec![
+            [2, 3].into_iter().collect::<Uint32Array>(),
+            Uint32Array::default(),
+            [4].into_iter().collect(),
+        ];
+        // Serializes even values as little-endian bytes.
+        let serialize = |row: &u32, payload: &mut Vec<u8>| {
+            if row % 2 == 0 {
+                payload.extend_from_slice(&row.to_le_bytes());
+                Ok(())
+            } else {
+                Err("odd")
+            }
+        };
+        let mut source = QueueSource::new(serialize, Batches(batches.into_iter()));
+        assert_eq!(
+            source.next_message(),
+            Ok(Some(2_u32.to_le_bytes().as_slice()))
+        );
+        assert_eq!(
+            source.next_message(),
+            Err(QueueSourceError::Serialize("odd"))
+        );
+        assert_eq!(
+            source.next_message(),
+            Ok(Some(4_u32.to_le_bytes().as_slice()))
+        );
+        assert_eq!(source.next_message(), Ok(None));
+        assert_eq!(source.next_message(), Ok(None));
+    }
+}
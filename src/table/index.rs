@@ -0,0 +1,209 @@
+//! Hash indexes over a key column of a table.
+
+use super::Table;
+use crate::{
+    array::{StructArray, StructArrayType},
+    buffer::{BufferType, VecBuffer},
+    compute::ColumnValues,
+    Length,
+};
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt::{self, Debug},
+    hash::Hash,
+};
+
+/// A hash index that maps keys to the indices of the rows with that key.
+///
+/// Null keys are not indexed.
+#[derive(Clone, Debug)]
+pub struct HashIndex<K> {
+    /// The indices of the rows of every key.
+    rows: HashMap<K, Vec<usize>>,
+    /// The number of indexed rows, including rows with null keys.
+    len: usize,
+}
+
+impl<K> Default for HashIndex<K> {
+    fn default() -> Self {
+        Self {
+            rows: HashMap::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq> HashIndex<K> {
+    /// Returns a new empty index.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the values of `column` to this index, as the rows following the
+    /// rows that are already indexed.
+    pub fn extend_from_column<A: ColumnValues + ?Sized>(&mut self, column: &A)
+    where
+        A::Scalar: ToOwned<Owned = K>,
+    {
+        column.for_each_value(|value| {
+            if let Some(key) = value {
+                self.rows.entry(key.to_owned()).or_default().push(self.len);
+            }
+            self.len += 1;
+        });
+    }
+
+    /// Returns the indices of the rows with the given key, in ascending order.
+    pub fn lookup<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> &[usize]
+    where
+        K: Borrow<Q>,
+    {
+        self.rows.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the number of distinct keys in this index.
+    #[must_use]
+    pub fn key_count(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl<K> Length for HashIndex<K> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// A [`Table`] with a [`HashIndex`] over one of its columns, which is updated
+/// when batches are appended.
+pub struct IndexedTable<
+    T: StructArrayType,
+    A: ColumnValues + ?Sized,
+    Buffer: BufferType = VecBuffer,
+> where
+    A::Scalar: ToOwned,
+{
+    /// The indexed table.
+    table: Table<T, false, Buffer>,
+    /// Returns the key column of a batch.
+    key: fn(&<T as StructArrayType>::Array<Buffer>) -> &A,
+    /// The index over the key column.
+    index: HashIndex<<A::Scalar as ToOwned>::Owned>,
+}
+
+impl<T: StructArrayType, Buffer: BufferType> Table<T, false, Buffer> {
+    /// Returns this table with a [`HashIndex`] over the column selected by
+    /// `key`.
+    pub fn build_index<A: ColumnValues + ?Sized>(
+        self,
+        key: fn(&<T as StructArrayType>::Array<Buffer>) -> &A,
+    ) -> IndexedTable<T, A, Buffer>
+    where
+        A::Scalar: ToOwned,
+        <A::Scalar as ToOwned>::Owned: Hash + Eq,
+    {
+        let mut index = HashIndex::new();
+        self.batches
+            .iter()
+            .for_each(|batch| index.extend_from_column(key(&batch.0)));
+        IndexedTable {
+            table: self,
+            key,
+            index,
+        }
+    }
+}
+
+impl<T: StructArrayType, A: ColumnValues + ?Sized, Buffer: BufferType> IndexedTable<T, A, Buffer>
+where
+    A::Scalar: ToOwned,
+    <A::Scalar as ToOwned>::Owned: Hash + Eq,
+    StructArray<T, false, Buffer>: Length,
+{
+    /// Appends a batch to the table and adds its keys to the index.
+    pub fn append(&mut self, batch: StructArray<T, false, Buffer>) {
+        self.index.extend_from_column((self.key)(&batch.0));
+        self.table.append(batch);
+    }
+
+    /// Returns the indices of the rows with the given key, in ascending order.
+    ///
+    /// Use [`Table::locate`] to find the batch of a row.
+    #[must_use]
+    pub fn lookup<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> &[usize]
+    where
+        <A::Scalar as ToOwned>::Owned: Borrow<Q>,
+    {
+        self.index.lookup(key)
+    }
+}
+
+impl<T: StructArrayType, A: ColumnValues + ?Sized, Buffer: BufferType> IndexedTable<T, A, Buffer>
+where
+    A::Scalar: ToOwned,
+{
+    /// Returns a reference to the table.
+    #[must_use]
+    pub fn table(&self) -> &Table<T, false, Buffer> {
+        &self.table
+    }
+
+    /// Returns a reference to the index.
+    #[must_use]
+    pub fn index(&self) -> &HashIndex<<A::Scalar as ToOwned>::Owned> {
+        &self.index
+    }
+
+    /// Returns the table, dropping the index.
+    #[must_use]
+    pub fn into_table(self) -> Table<T, false, Buffer> {
+        self.table
+    }
+}
+
+impl<T: StructArrayType, A: ColumnValues + ?Sized, Buffer: BufferType> Debug
+    for IndexedTable<T, A, Buffer>
+where
+    A::Scalar: ToOwned,
+    <A::Scalar as ToOwned>::Owned: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexedTable")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "strings")]
+    use crate::array::StringArray;
+    use crate::array::Uint32Array;
+
+    #[test]
+    fn extend_from_column() {
+        let mut index = HashIndex::new();
+        index.extend_from_column(&[1, 2, 1].into_iter().collect::<Uint32Array>());
+        index.extend_from_column(
+            &[Some(2), None, Some(1)]
+                .into_iter()
+                .collect::<Uint32Array<true>>(),
+        );
+        assert_eq!(index.len(), 6);
+        assert_eq!(index.key_count(), 2);
+        assert_eq!(index.lookup(&1), [0, 2, 5]);
+        assert_eq!(index.lookup(&2), [1, 3]);
+        assert!(index.lookup(&3).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn extend_from_string_column() {
+        let mut strings = HashIndex::<String>::new();
+        strings.extend_from_column(&["a", "b", "a"].into_iter().collect::<StringArray>());
+        assert_eq!(strings.lookup("a"), [0, 2]);
+    }
+}
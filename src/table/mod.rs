@@ -0,0 +1,366 @@
+//! Append-only tables of struct arrays.
+
+use crate::{
+    array::{StructArray, StructArrayType},
+    buffer::{BufferType, VecBuffer},
+    validity::Validity,
+    ByteLength, Length, ShrinkToFit, WithCapacity,
+};
+use std::ops::Range;
+
+mod index;
+pub use self::index::*;
+
+/// An append-only table with rows of type `T`, stored in one or more batches
+/// of [`StructArray`]s.
+///
+/// Appending a batch does not copy it. Use [`Table::consolidate`] to
+/// concatenate the batches into a single batch.
+pub struct Table<T: StructArrayType, const NULLABLE: bool = false, Buffer: BufferType = VecBuffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+{
+    /// The non-empty batches of this table.
+    batches: Vec<StructArray<T, NULLABLE, Buffer>>,
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+{
+    /// Returns a new empty table.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            batches: Vec::new(),
+        }
+    }
+
+    /// Returns the batches of this table.
+    #[must_use]
+    pub fn batches(&self) -> &[StructArray<T, NULLABLE, Buffer>] {
+        &self.batches
+    }
+
+    /// Returns the batches of this table.
+    #[must_use]
+    pub fn into_batches(self) -> Vec<StructArray<T, NULLABLE, Buffer>> {
+        self.batches
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: Length,
+{
+    /// Appends a batch to this table. Empty batches are dropped.
+    pub fn append(&mut self, batch: StructArray<T, NULLABLE, Buffer>) {
+        if !batch.is_empty() {
+            self.batches.push(batch);
+        }
+    }
+
+    /// Returns the index of the batch with the row at `index`, and the index
+    /// of the row in that batch. Returns `None` if `index` is out of bounds.
+    #[must_use]
+    pub fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        let mut offset = 0;
+        self.batches.iter().enumerate().find_map(|(batch, array)| {
+            let len = array.len();
+            if index < offset + len {
+                Some((batch, index - offset))
+            } else {
+                offset += len;
+                None
+            }
+        })
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: IntoIterator
+        + Length
+        + WithCapacity
+        + Extend<<StructArray<T, NULLABLE, Buffer> as IntoIterator>::Item>,
+{
+    /// Concatenates the batches of this table into a single batch.
+    pub fn consolidate(&mut self) {
+        if self.batches.len() > 1 {
+            let batch = Self::concat(std::mem::take(&mut self.batches), self.len());
+            self.batches.push(batch);
+        }
+    }
+
+    /// Returns the rows of this table as a single batch.
+    #[must_use]
+    pub fn into_batch(mut self) -> StructArray<T, NULLABLE, Buffer> {
+        self.consolidate();
+        self.batches
+            .pop()
+            .unwrap_or_else(|| WithCapacity::with_capacity(0))
+    }
+
+    /// Returns a table with the rows in `range`.
+    ///
+    /// Batches that are completely inside the range are moved to the new
+    /// table; the rows of the batches at the bounds of the range are copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    #[must_use]
+    pub fn slice(self, range: Range<usize>) -> Self {
+        let len = self.len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range (is {range:?}) should be within len (is {len})"
+        );
+        let mut table = Self::new();
+        let mut offset = 0;
+        for batch in self.batches {
+            let batch_len = batch.len();
+            let start = range.start.max(offset) - offset;
+            let end = range.end.min(offset + batch_len).saturating_sub(offset);
+            if start == 0 && end == batch_len {
+                table.append(batch);
+            } else if start < end {
+                table.append(Self::concat(
+                    [batch.into_iter().skip(start).take(end - start)],
+                    end - start,
+                ));
+            }
+            offset += batch_len;
+        }
+        table
+    }
+
+    /// Concatenates the rows of `batches` into a new batch with capacity for
+    /// `len` rows.
+    fn concat<I: IntoIterator<Item = <StructArray<T, NULLABLE, Buffer> as IntoIterator>::Item>>(
+        batches: impl IntoIterator<Item = I>,
+        len: usize,
+    ) -> StructArray<T, NULLABLE, Buffer> {
+        let mut batch = StructArray::<T, NULLABLE, Buffer>::with_capacity(len);
+        batches.into_iter().for_each(|rows| batch.extend(rows));
+        batch
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Default
+    for Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType>
+    Extend<StructArray<T, NULLABLE, Buffer>> for Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: Length,
+{
+    fn extend<I: IntoIterator<Item = StructArray<T, NULLABLE, Buffer>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|batch| self.append(batch));
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType>
+    FromIterator<StructArray<T, NULLABLE, Buffer>> for Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: Length,
+{
+    fn from_iter<I: IntoIterator<Item = StructArray<T, NULLABLE, Buffer>>>(iter: I) -> Self {
+        let mut table = Self::new();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType>
+    From<StructArray<T, NULLABLE, Buffer>> for Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: Length,
+{
+    fn from(batch: StructArray<T, NULLABLE, Buffer>) -> Self {
+        Self::from_iter([batch])
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Length
+    for Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: Length,
+{
+    fn len(&self) -> usize {
+        self.batches.iter().map(Length::len).sum()
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> ByteLength
+    for Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: ByteLength,
+{
+    fn byte_len(&self) -> usize {
+        self.batches.iter().map(ByteLength::byte_len).sum()
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> ShrinkToFit
+    for Table<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    StructArray<T, NULLABLE, Buffer>: ShrinkToFit,
+{
+    fn shrink_to_fit(&mut self) {
+        self.batches.iter_mut().for_each(ShrinkToFit::shrink_to_fit);
+        self.batches.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{ArrayType, Uint32Array, UnionType},
+        offset::OffsetElement,
+    };
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Row(u32);
+
+    impl ArrayType for Row {
+        type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+            StructArray<Row, false, Buffer>;
+    }
+
+    impl StructArrayType for Row {
+        type Array<Buffer: BufferType> = RowArray<Buffer>;
+    }
+
+    struct RowArray<Buffer: BufferType>(Uint32Array<false, Buffer>);
+
+    impl WithCapacity for RowArray<VecBuffer> {
+        fn with_capacity(capacity: usize) -> Self {
+            Self(Uint32Array::with_capacity(capacity))
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.0.reserve(additional);
+        }
+    }
+
+    impl Extend<Row> for RowArray<VecBuffer> {
+        fn extend<I: IntoIterator<Item = Row>>(&mut self, iter: I) {
+            self.0.extend(iter.into_iter().map(|Row(value)| value));
+        }
+    }
+
+    impl FromIterator<Row> for RowArray<VecBuffer> {
+        fn from_iter<I: IntoIterator<Item = Row>>(iter: I) -> Self {
+            Self(iter.into_iter().map(|Row(value)| value).collect())
+        }
+    }
+
+    impl IntoIterator for RowArray<VecBuffer> {
+        type Item = Row;
+        type IntoIter = std::iter::Map<std::vec::IntoIter<u32>, fn(u32) -> Row>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.into_iter().map(Row)
+        }
+    }
+
+    impl Length for RowArray<VecBuffer> {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl ByteLength for RowArray<VecBuffer> {
+        fn byte_len(&self) -> usize {
+            self.0.byte_len()
+        }
+    }
+
+    fn batch(values: &[u32]) -> StructArray<Row> {
+        values.iter().copied().map(Row).collect()
+    }
+
+    fn rows(table: Table<Row>) -> Vec<u32> {
+        table
+            .into_batch()
+            .into_iter()
+            .map(|Row(value)| value)
+            .collect()
+    }
+
+    #[test]
+    fn append() {
+        let mut table = Table::new();
+        table.append(batch(&[1, 2]));
+        table.append(batch(&[]));
+        table.extend([batch(&[3]), batch(&[])]);
+        assert_eq!(table.batches().len(), 2);
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.byte_len(), 12);
+        assert!(Table::<Row>::from(batch(&[])).is_empty());
+    }
+
+    #[test]
+    fn locate() {
+        let table = [batch(&[1, 2]), batch(&[3]), batch(&[4, 5])]
+            .into_iter()
+            .collect::<Table<Row>>();
+        assert_eq!(table.locate(0), Some((0, 0)));
+        assert_eq!(table.locate(2), Some((1, 0)));
+        assert_eq!(table.locate(4), Some((2, 1)));
+        assert_eq!(table.locate(5), None);
+        assert_eq!(Table::<Row>::new().locate(0), None);
+    }
+
+    #[test]
+    fn consolidate() {
+        let mut table = [batch(&[1, 2]), batch(&[3])]
+            .into_iter()
+            .collect::<Table<Row>>();
+        table.consolidate();
+        assert_eq!(table.batches().len(), 1);
+        assert_eq!(rows(table), [1, 2, 3]);
+        assert!(Table::<Row>::new().into_batch().is_empty());
+    }
+
+    #[test]
+    fn slice() {
+        let table = || {
+            [batch(&[1, 2]), batch(&[3]), batch(&[4, 5, 6])]
+                .into_iter()
+                .collect::<Table<Row>>()
+        };
+        let sliced = table().slice(1..5);
+        assert_eq!(
+            sliced.batches().iter().map(Length::len).collect::<Vec<_>>(),
+            [1, 1, 2]
+        );
+        assert_eq!(rows(sliced), [2, 3, 4, 5]);
+        assert_eq!(table().slice(2..3).batches().len(), 1);
+        assert!(table().slice(6..6).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "range (is 2..7) should be within len (is 6)")]
+    fn slice_out_of_bounds() {
+        let table = Table::from(batch(&[1, 2, 3, 4, 5, 6]));
+        let _ = table.slice(2..7);
+    }
+}
@@ -0,0 +1,176 @@
+//! Utilities to test [`ArrayType`] implementations.
+//!
+//! These helpers are meant for users writing their own [`ArrayType`]
+//! implementations, to validate them with a single call:
+//!
+//! ```
+//! use narrow::testing::{assert_roundtrip_via_iter, random_values};
+//!
+//! assert_roundtrip_via_iter(random_values::<Option<u32>>(100, 42));
+//! ```
+
+use crate::{
+    array::{union, ArrayType},
+    buffer::VecBuffer,
+    offset, Length,
+};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use std::fmt::Debug;
+#[cfg(feature = "arrow-rs")]
+use {
+    crate::arrow::{scalar_buffer::ArrowScalarBuffer, ArrowArray},
+    std::sync::Arc,
+};
+
+/// The array type of `T`, with the default generics.
+type ArrayOf<T> = <T as ArrayType>::Array<VecBuffer, offset::NA, union::NA>;
+
+/// The array type of `T`, with the `arrow-rs` buffers.
+#[cfg(feature = "arrow-rs")]
+type ArrowArrayOf<T> = <T as ArrayType>::Array<ArrowScalarBuffer, offset::NA, union::NA>;
+
+/// The maximum number of elements of generated collections.
+const MAX_LEN: usize = 4;
+
+/// Asserts that collecting `values` into the array of `T` and iterating over
+/// the array returns the same values.
+///
+/// # Panics
+///
+/// Panics if the length of the array or the values returned by its iterator
+/// are not equal to `values`.
+pub fn assert_roundtrip_via_iter<T: ArrayType + Clone + Debug + PartialEq>(
+    values: impl IntoIterator<Item = T>,
+) where
+    ArrayOf<T>: FromIterator<T> + IntoIterator<Item = T> + Length,
+{
+    let expected = values.into_iter().collect::<Vec<_>>();
+    let array = expected.iter().cloned().collect::<ArrayOf<T>>();
+    assert_eq!(
+        array.len(),
+        expected.len(),
+        "array len (is {}) should be equal to number of values (is {})",
+        array.len(),
+        expected.len()
+    );
+    assert_eq!(array.into_iter().collect::<Vec<_>>(), expected);
+}
+
+/// Asserts that converting the array of `values` to an `arrow-rs` array and
+/// back results in the same `arrow-rs` array.
+///
+/// The array is converted back to an array that uses the `arrow-rs` buffers,
+/// which is then converted to an `arrow-rs` array again to compare it.
+///
+/// # Panics
+///
+/// Panics if the `arrow-rs` arrays are not equal.
+#[cfg(feature = "arrow-rs")]
+pub fn assert_arrow_roundtrip<T: ArrayType>(values: impl IntoIterator<Item = T>)
+where
+    ArrayOf<T>: FromIterator<T> + ArrowArray + Into<<ArrayOf<T> as ArrowArray>::Array>,
+    <ArrayOf<T> as ArrowArray>::Array: 'static,
+    ArrowArrayOf<T>: From<Arc<dyn arrow_array::Array>> + ArrowArray,
+    ArrowArrayOf<T>: Into<<ArrowArrayOf<T> as ArrowArray>::Array>,
+{
+    use arrow_array::Array as _;
+
+    let array = values.into_iter().collect::<ArrayOf<T>>();
+    let expected: Arc<dyn arrow_array::Array> = Arc::new(array.into());
+    let round_trip = ArrowArrayOf::<T>::from(Arc::clone(&expected)).into();
+    assert_eq!(round_trip.to_data(), expected.to_data());
+}
+
+/// Types with values that can be generated randomly.
+pub trait RandomValue: Sized {
+    /// Returns a random value.
+    fn random_value(rng: &mut impl Rng) -> Self;
+}
+
+/// Implements [`RandomValue`] for types with a standard distribution.
+macro_rules! impl_random_value {
+    ($($ty:ty),+) => {
+        $(
+            impl RandomValue for $ty {
+                fn random_value(rng: &mut impl Rng) -> Self {
+                    rng.gen()
+                }
+            }
+        )+
+    };
+}
+
+impl_random_value!(bool, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+impl RandomValue for () {
+    fn random_value(_rng: &mut impl Rng) -> Self {}
+}
+
+impl RandomValue for String {
+    fn random_value(rng: &mut impl Rng) -> Self {
+        let len = rng.gen_range(0..=MAX_LEN);
+        (0..len)
+            .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+            .collect()
+    }
+}
+
+impl<T: RandomValue> RandomValue for Option<T> {
+    /// Returns `None` for one in four values.
+    fn random_value(rng: &mut impl Rng) -> Self {
+        rng.gen_ratio(3, 4).then(|| T::random_value(rng))
+    }
+}
+
+impl<T: RandomValue> RandomValue for Vec<T> {
+    fn random_value(rng: &mut impl Rng) -> Self {
+        let len = rng.gen_range(0..=MAX_LEN);
+        (0..len).map(|_| T::random_value(rng)).collect()
+    }
+}
+
+impl<T: RandomValue, const N: usize> RandomValue for [T; N] {
+    fn random_value(rng: &mut impl Rng) -> Self {
+        [(); N].map(|()| T::random_value(rng))
+    }
+}
+
+/// Returns `len` random values, generated from `seed`.
+///
+/// The values are the same for the same seed, so failing tests can be
+/// reproduced.
+#[must_use]
+pub fn random_values<T: RandomValue>(len: usize, seed: u64) -> Vec<T> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..len).map(|_| T::random_value(&mut rng)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        assert_eq!(
+            random_values::<Option<u32>>(10, 1),
+            random_values::<Option<u32>>(10, 1)
+        );
+        let nested = random_values::<Vec<[Option<u8>; 2]>>(16, 2);
+        assert_eq!(nested.len(), 16);
+        assert!(nested.iter().all(|list| list.len() <= MAX_LEN));
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_roundtrip_via_iter(random_values::<u8>(16, 0));
+        assert_roundtrip_via_iter(random_values::<Option<bool>>(16, 0));
+        assert_roundtrip_via_iter(random_values::<Option<u32>>(16, 0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "arrow-rs", feature = "strings"))]
+    fn arrow() {
+        assert_arrow_roundtrip(random_values::<Option<i64>>(16, 0));
+        assert_arrow_roundtrip(random_values::<Option<String>>(16, 0));
+    }
+}
@@ -0,0 +1,785 @@
+//! Streaming text output of rows.
+//!
+//! The writers in this module consume rows of borrowed values, e.g. the
+//! values of array references zipped together, and write them as CSV or
+//! newline-delimited JSON without materializing owned values.
+//!
+//! ```
+//! use narrow::{
+//!     array::{Float64Array, Uint32Array},
+//!     text::CsvWriter,
+//! };
+//!
+//! let id = [1, 2].into_iter().collect::<Uint32Array>();
+//! let value = [Some(0.5), None].into_iter().collect::<Float64Array<true>>();
+//! let mut writer = CsvWriter::new(Vec::new(), ["id", "value"]);
+//! writer.write_rows(id.into_iter().zip(&value))?;
+//! assert_eq!(writer.into_inner(), b"id,value\n1,0.5\n2,\n");
+//! # Ok::<_, std::io::Error>(())
+//! ```
+//!
+//! With the `serde` feature, the options of the writers can be deserialized,
+//! e.g. from pipeline configuration files.
+
+use std::io::{self, Write};
+
+/// A borrowed value of a row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell<'a> {
+    /// A null value.
+    Null,
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Integer(i128),
+    /// A floating point value.
+    Float(f64),
+    /// A string value.
+    Str(&'a str),
+}
+
+/// Values that can be written as a [`Cell`].
+pub trait ToCell {
+    /// Returns this value as a cell.
+    fn to_cell(&self) -> Cell<'_>;
+}
+
+/// Implements [`ToCell`] for integer types.
+macro_rules! impl_to_cell {
+    ($($ty:ty),+) => {
+        $(
+            impl ToCell for $ty {
+                fn to_cell(&self) -> Cell<'_> {
+                    Cell::Integer(i128::from(*self))
+                }
+            }
+        )+
+    };
+}
+
+impl_to_cell!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl ToCell for f32 {
+    fn to_cell(&self) -> Cell<'_> {
+        Cell::Float(f64::from(*self))
+    }
+}
+
+impl ToCell for f64 {
+    fn to_cell(&self) -> Cell<'_> {
+        Cell::Float(*self)
+    }
+}
+
+impl ToCell for bool {
+    fn to_cell(&self) -> Cell<'_> {
+        Cell::Bool(*self)
+    }
+}
+
+impl ToCell for str {
+    fn to_cell(&self) -> Cell<'_> {
+        Cell::Str(self)
+    }
+}
+
+impl ToCell for String {
+    fn to_cell(&self) -> Cell<'_> {
+        Cell::Str(self)
+    }
+}
+
+impl<T: ToCell + ?Sized> ToCell for &T {
+    fn to_cell(&self) -> Cell<'_> {
+        (**self).to_cell()
+    }
+}
+
+impl<T: ToCell> ToCell for Option<T> {
+    fn to_cell(&self) -> Cell<'_> {
+        self.as_ref().map_or(Cell::Null, ToCell::to_cell)
+    }
+}
+
+/// Rows that can be written by the writers in this module.
+///
+/// This is implemented for tuples of up to six values that implement
+/// [`ToCell`] and, with the `derive` feature, for structs that derive
+/// `ArrayType` with fields that implement [`ToCell`]. The rows of the arrays
+/// of these structs can be written directly.
+pub trait TextRow {
+    /// The number of cells of this row.
+    const LEN: usize;
+
+    /// Calls `f` for every cell of this row, in order, stopping at the first
+    /// error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`.
+    fn try_for_each_cell(&self, f: &mut dyn FnMut(Cell<'_>) -> io::Result<()>) -> io::Result<()>;
+}
+
+/// Implement [`TextRow`] for tuples of values.
+macro_rules! impl_text_row {
+    ($len:literal; $($value:ident: $index:tt),+) => {
+        impl<$($value: ToCell),+> TextRow for ($($value,)+) {
+            const LEN: usize = $len;
+
+            fn try_for_each_cell(
+                &self,
+                f: &mut dyn FnMut(Cell<'_>) -> io::Result<()>,
+            ) -> io::Result<()> {
+                $(f(self.$index.to_cell())?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_text_row!(1; A: 0);
+impl_text_row!(2; A: 0, B: 1);
+impl_text_row!(3; A: 0, B: 1, C: 2);
+impl_text_row!(4; A: 0, B: 1, C: 2, D: 3);
+impl_text_row!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_text_row!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+impl<R: TextRow + ?Sized> TextRow for &R {
+    const LEN: usize = R::LEN;
+
+    fn try_for_each_cell(&self, f: &mut dyn FnMut(Cell<'_>) -> io::Result<()>) -> io::Result<()> {
+        (**self).try_for_each_cell(f)
+    }
+}
+
+/// Returns `true` if `value` contains a delimiter, a quote or a line break,
+/// or a backslash when backslashes escape quotes.
+fn needs_quotes(value: &str, escape: Escape) -> bool {
+    value.contains([',', '"', '\n', '\r']) || (escape == Escape::Backslash && value.contains('\\'))
+}
+
+/// Returns the error message for the first null representation of `nulls`
+/// that needs quotes, since null values are written unquoted. Backslashes
+/// are allowed, so that e.g. `\N` can represent null values.
+fn csv_null_error(nulls: &[Option<NullValue>], options: &CsvOptions) -> Option<String> {
+    nulls
+        .iter()
+        .map(|null| null.as_ref().unwrap_or(&options.null).as_str())
+        .find(|null| needs_quotes(null, Escape::Double))
+        .map(|null| format!("null representation (is {null:?}) should not need quotes"))
+}
+
+/// Returns an error if the number of cells of `R` is not equal to the number
+/// of columns.
+fn check_len<R: TextRow>(columns: usize) -> io::Result<()> {
+    if R::LEN == columns {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "number of cells (is {}) should be equal to number of columns (is {columns})",
+                R::LEN
+            ),
+        ))
+    }
+}
+
+/// The representation of null values in text.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum NullValue {
+    /// Null values are empty fields.
+    #[default]
+    Empty,
+    /// Null values are written as `NULL`.
+    Null,
+    /// Null values are written as the given text.
+    ///
+    /// The text is written unquoted, so it can't contain a delimiter, a quote
+    /// or a line break. Strings equal to the text are quoted, but numbers and
+    /// booleans are not: with `Custom("0")`, a zero is written the same way
+    /// as a null value and is read back as null.
+    Custom(String),
+}
+
+impl NullValue {
+    /// Returns the text of this null representation.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Self::Empty => "",
+            Self::Null => "NULL",
+            Self::Custom(ref text) => text,
+        }
+    }
+
+    /// Returns `true` if a field with the given text is null.
+    ///
+    /// Quoted fields are never null, so that strings that are equal to the
+    /// null representation round-trip.
+    #[must_use]
+    pub fn is_null(&self, field: &str, quoted: bool) -> bool {
+        !quoted && field == self.as_str()
+    }
+}
+
+/// When CSV fields are quoted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum QuoteStyle {
+    /// Fields are quoted when they contain a delimiter, a quote or a line
+    /// break, a backslash when backslashes escape quotes, or when they are
+    /// equal to the null representation.
+    #[default]
+    Necessary,
+    /// All non-null fields are quoted.
+    Always,
+    /// Fields are never quoted. Fields that need quotes are not written
+    /// losslessly.
+    Never,
+}
+
+/// How quotes in quoted CSV fields are escaped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Escape {
+    /// Quotes are escaped by doubling them.
+    #[default]
+    Double,
+    /// Quotes and backslashes are escaped with a backslash.
+    Backslash,
+}
+
+/// Options for [`CsvWriter`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(default)
+)]
+#[non_exhaustive]
+pub struct CsvOptions {
+    /// The representation of null values, for columns without a
+    /// representation set with [`CsvWriter::with_column_null`].
+    pub null: NullValue,
+    /// When fields are quoted.
+    pub quote: QuoteStyle,
+    /// How quotes in quoted fields are escaped.
+    pub escape: Escape,
+}
+
+impl CsvOptions {
+    /// Sets the representation of null values.
+    #[must_use]
+    pub fn with_null(mut self, null: NullValue) -> Self {
+        self.null = null;
+        self
+    }
+
+    /// Sets when fields are quoted.
+    #[must_use]
+    pub fn with_quote(mut self, quote: QuoteStyle) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets how quotes in quoted fields are escaped.
+    #[must_use]
+    pub fn with_escape(mut self, escape: Escape) -> Self {
+        self.escape = escape;
+        self
+    }
+}
+
+/// Options for [`NdjsonWriter`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(default)
+)]
+#[non_exhaustive]
+pub struct NdjsonOptions {
+    /// Whether the keys of null values are left out of the objects.
+    pub skip_nulls: bool,
+}
+
+impl NdjsonOptions {
+    /// Sets whether the keys of null values are left out of the objects.
+    #[must_use]
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+}
+
+/// Writes `value` as a CSV field, quoted according to `options`. Fields
+/// equal to `null` are quoted to distinguish them from null values.
+fn write_csv_str(
+    inner: &mut impl Write,
+    value: &str,
+    null: Option<&str>,
+    options: &CsvOptions,
+) -> io::Result<()> {
+    let quoted = match options.quote {
+        QuoteStyle::Necessary => needs_quotes(value, options.escape) || null == Some(value),
+        QuoteStyle::Always => true,
+        QuoteStyle::Never => false,
+    };
+    if quoted {
+        let escaped = match options.escape {
+            Escape::Double => value.replace('"', "\"\""),
+            Escape::Backslash => value.replace('\\', "\\\\").replace('"', "\\\""),
+        };
+        write!(inner, "\"{escaped}\"")
+    } else {
+        inner.write_all(value.as_bytes())
+    }
+}
+
+/// Writes the display representation of `value` as a CSV field, quoted if
+/// all fields are quoted.
+fn write_csv_display(
+    inner: &mut impl Write,
+    value: impl std::fmt::Display,
+    options: &CsvOptions,
+) -> io::Result<()> {
+    if options.quote == QuoteStyle::Always {
+        write!(inner, "\"{value}\"")
+    } else {
+        write!(inner, "{value}")
+    }
+}
+
+/// Writes `value` as a quoted and escaped JSON string.
+fn write_json_str(inner: &mut impl Write, value: &str) -> io::Result<()> {
+    inner.write_all(b"\"")?;
+    for char in value.chars() {
+        match char {
+            '"' => inner.write_all(b"\\\"")?,
+            '\\' => inner.write_all(b"\\\\")?,
+            '\n' => inner.write_all(b"\\n")?,
+            '\r' => inner.write_all(b"\\r")?,
+            '\t' => inner.write_all(b"\\t")?,
+            control if control.is_control() => write!(inner, "\\u{:04x}", u32::from(control))?,
+            other => write!(inner, "{other}")?,
+        }
+    }
+    inner.write_all(b"\"")
+}
+
+/// Writes rows as CSV, with a header with the names of the columns.
+///
+/// By default, null values are written as empty fields, and empty strings as
+/// quoted empty fields. See [`NullValue::Custom`] for the fields that are
+/// ambiguous with custom null representations.
+#[derive(Debug)]
+pub struct CsvWriter<W> {
+    /// The underlying writer.
+    inner: W,
+    /// The names of the columns.
+    names: Vec<String>,
+    /// The representation of null values of every column, or `None` for the
+    /// representation of the options.
+    nulls: Vec<Option<NullValue>>,
+    /// The options of this writer.
+    options: CsvOptions,
+    /// Whether the header is written.
+    header_written: bool,
+    /// The error for a null representation that needs quotes, checked when
+    /// the options or the null representations are set.
+    null_error: Option<String>,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Returns a new [`CsvWriter`] writing to `inner`, for columns with the
+    /// given names.
+    ///
+    /// The header is written before the first row.
+    pub fn new(inner: W, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let columns = names.into_iter().map(Into::into).collect::<Vec<String>>();
+        Self {
+            inner,
+            nulls: vec![None; columns.len()],
+            names: columns,
+            options: CsvOptions::default(),
+            header_written: false,
+            null_error: None,
+        }
+    }
+
+    /// Sets the options of this writer.
+    #[must_use]
+    pub fn with_options(mut self, options: CsvOptions) -> Self {
+        self.options = options;
+        self.null_error = csv_null_error(&self.nulls, &self.options);
+        self
+    }
+
+    /// Sets the representation of null values of the column `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no column `name`.
+    #[must_use]
+    pub fn with_column_null(mut self, name: &str, null: NullValue) -> Self {
+        let index = self
+            .names
+            .iter()
+            .position(|other| other == name)
+            .unwrap_or_else(|| panic!("column `{name}` should exist"));
+        self.nulls[index] = Some(null);
+        self.null_error = csv_null_error(&self.nulls, &self.options);
+        self
+    }
+
+    /// Writes the header, if it is not written yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when writing to the underlying writer fails.
+    pub fn write_header(&mut self) -> io::Result<()> {
+        if !self.header_written {
+            for (index, name) in self.names.iter().enumerate() {
+                if index > 0 {
+                    self.inner.write_all(b",")?;
+                }
+                write_csv_str(&mut self.inner, name, None, &self.options)?;
+            }
+            self.inner.write_all(b"\n")?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+
+    /// Writes `row` as a record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the number of cells of `row` is not equal to the
+    /// number of columns, when a null representation needs quotes, or when
+    /// writing to the underlying writer fails.
+    pub fn write_row<R: TextRow>(&mut self, row: &R) -> io::Result<()> {
+        check_len::<R>(self.names.len())?;
+        if let Some(error) = self.null_error.as_ref() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, error.clone()));
+        }
+        self.write_header()?;
+        let inner = &mut self.inner;
+        let options = &self.options;
+        let mut nulls = self
+            .nulls
+            .iter()
+            .map(|null| null.as_ref().unwrap_or(&options.null).as_str())
+            .enumerate();
+        row.try_for_each_cell(&mut |cell| {
+            let Some((index, null)) = nulls.next() else {
+                unreachable!("number of cells is checked")
+            };
+            if index > 0 {
+                inner.write_all(b",")?;
+            }
+            match cell {
+                Cell::Null => inner.write_all(null.as_bytes()),
+                Cell::Bool(value) => write_csv_display(inner, value, options),
+                Cell::Integer(value) => write_csv_display(inner, value, options),
+                Cell::Float(value) => write_csv_display(inner, value, options),
+                Cell::Str(value) => write_csv_str(inner, value, Some(null), options),
+            }
+        })?;
+        self.inner.write_all(b"\n")
+    }
+
+    /// Writes all `rows` as records.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by [`CsvWriter::write_row`].
+    pub fn write_rows<R: TextRow>(&mut self, rows: impl IntoIterator<Item = R>) -> io::Result<()> {
+        rows.into_iter().try_for_each(|row| self.write_row(&row))
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when flushing the underlying writer fails.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Writes rows as newline-delimited JSON, with one object per row.
+///
+/// Null values and non-finite floating point values are written as `null`,
+/// or are left out when [`NdjsonOptions::skip_nulls`] is set.
+#[derive(Debug)]
+pub struct NdjsonWriter<W> {
+    /// The underlying writer.
+    inner: W,
+    /// The names of the columns.
+    names: Vec<String>,
+    /// The options of this writer.
+    options: NdjsonOptions,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    /// Returns a new [`NdjsonWriter`] writing to `inner`, for columns with
+    /// the given names.
+    pub fn new(inner: W, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            inner,
+            names: names.into_iter().map(Into::into).collect(),
+            options: NdjsonOptions::default(),
+        }
+    }
+
+    /// Sets the options of this writer.
+    #[must_use]
+    pub fn with_options(mut self, options: NdjsonOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets whether the keys of null values are left out of the objects.
+    #[must_use]
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.options.skip_nulls = skip_nulls;
+        self
+    }
+
+    /// Writes `row` as an object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the number of cells of `row` is not equal to the
+    /// number of columns, or when writing to the underlying writer fails.
+    pub fn write_row<R: TextRow>(&mut self, row: &R) -> io::Result<()> {
+        check_len::<R>(self.names.len())?;
+        let inner = &mut self.inner;
+        let skip_nulls = self.options.skip_nulls;
+        let mut names = self.names.iter();
+        let mut first = true;
+        inner.write_all(b"{")?;
+        row.try_for_each_cell(&mut |cell| {
+            let Some(name) = names.next() else {
+                unreachable!("number of cells is checked")
+            };
+            let null = match cell {
+                Cell::Null => true,
+                Cell::Float(value) => !value.is_finite(),
+                Cell::Bool(_) | Cell::Integer(_) | Cell::Str(_) => false,
+            };
+            if skip_nulls && null {
+                return Ok(());
+            }
+            if !first {
+                inner.write_all(b",")?;
+            }
+            first = false;
+            write_json_str(inner, name)?;
+            inner.write_all(b":")?;
+            match cell {
+                Cell::Bool(value) => write!(inner, "{value}"),
+                Cell::Integer(value) => write!(inner, "{value}"),
+                Cell::Float(value) if value.is_finite() => write!(inner, "{value}"),
+                Cell::Str(value) => write_json_str(inner, value),
+                Cell::Null | Cell::Float(_) => inner.write_all(b"null"),
+            }
+        })?;
+        inner.write_all(b"}\n")
+    }
+
+    /// Writes all `rows` as objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by [`NdjsonWriter::write_row`].
+    pub fn write_rows<R: TextRow>(&mut self, rows: impl IntoIterator<Item = R>) -> io::Result<()> {
+        rows.into_iter().try_for_each(|row| self.write_row(&row))
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when flushing the underlying writer fails.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "strings")]
+    use crate::array::{BooleanArray, Float64Array, StringArray};
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn csv() {
+        let name = [Some("a \"b\""), None, Some("c\nd")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let flag = [true, false, true].into_iter().collect::<BooleanArray>();
+        let mut writer = CsvWriter::new(Vec::new(), ["name", "flag"]);
+        writer
+            .write_rows(name.into_iter().zip(&flag))
+            .expect("write to vec");
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).expect("utf8"),
+            "name,flag\n\"a \"\"b\"\"\",true\n,false\n\"c\nd\",true\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn ndjson() {
+        let value = [Some(1.5), None, Some(f64::NAN)]
+            .into_iter()
+            .collect::<Float64Array<true>>();
+        let label = ["x", "\"y\"", "z\t"].into_iter().collect::<StringArray>();
+        let mut writer = NdjsonWriter::new(Vec::new(), ["value", "label"]);
+        writer
+            .write_rows(value.into_iter().zip(&label))
+            .expect("write to vec");
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).expect("utf8"),
+            "{\"value\":1.5,\"label\":\"x\"}\n{\"value\":null,\"label\":\"\\\"y\\\"\"}\n{\"value\":null,\"label\":\"z\\t\"}\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn csv_nulls() {
+        let a = [Some(""), None, Some("NULL")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let b = [None, Some("x"), Some("-")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let mut writer = CsvWriter::new(Vec::new(), ["a", "b"])
+            .with_column_null("b", NullValue::Custom("-".to_owned()));
+        writer
+            .write_rows(a.into_iter().zip(&b))
+            .expect("write to vec");
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).expect("utf8"),
+            "a,b\n\"\",-\n,x\nNULL,\"-\"\n"
+        );
+
+        let mut quoted = CsvWriter::new(Vec::new(), ["a", "b"]).with_options(CsvOptions {
+            null: NullValue::Null,
+            quote: QuoteStyle::Always,
+            escape: Escape::Backslash,
+        });
+        quoted
+            .write_row(&(Some("\"\\"), None::<u8>))
+            .expect("write to vec");
+        quoted
+            .write_row(&(None::<&str>, Some(1)))
+            .expect("write to vec");
+        assert_eq!(
+            String::from_utf8(quoted.into_inner()).expect("utf8"),
+            "\"a\",\"b\"\n\"\\\"\\\\\",NULL\nNULL,\"1\"\n"
+        );
+
+        let mut zero = CsvWriter::new(Vec::new(), ["a"])
+            .with_options(CsvOptions::default().with_null(NullValue::Custom("0".to_owned())));
+        zero.write_rows([(None::<u8>,), (Some(0),)])
+            .expect("write to vec");
+        assert_eq!(zero.into_inner(), b"a\n0\n0\n");
+
+        let mut invalid = CsvWriter::new(Vec::new(), ["a"])
+            .with_column_null("a", NullValue::Custom("a,b".to_owned()));
+        let error = invalid.write_row(&(1_u8,)).expect_err("null needs quotes");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(invalid.into_inner().is_empty());
+
+        let backslash = CsvOptions::default().with_escape(Escape::Backslash);
+        let mut necessary = CsvWriter::new(Vec::new(), ["a"]).with_options(backslash.clone());
+        necessary
+            .write_rows([(Some("a\\b"),), (Some("c"),)])
+            .expect("write to vec");
+        assert_eq!(necessary.into_inner(), b"a\n\"a\\\\b\"\nc\n");
+        let mut escaped_null = CsvWriter::new(Vec::new(), ["a"])
+            .with_options(backslash.with_null(NullValue::Custom("\\N".to_owned())));
+        escaped_null
+            .write_rows([(None::<&str>,), (Some("\\N"),)])
+            .expect("write to vec");
+        assert_eq!(escaped_null.into_inner(), b"a\n\\N\n\"\\\\N\"\n");
+
+        assert!(NullValue::Null.is_null("NULL", false));
+        assert!(!NullValue::Null.is_null("NULL", true));
+        assert!(NullValue::Empty.is_null("", false));
+    }
+
+    #[test]
+    fn ndjson_skip_nulls() {
+        let mut writer = NdjsonWriter::new(Vec::new(), ["a", "b"]).with_skip_nulls(true);
+        writer
+            .write_row(&(None::<u8>, Some(1)))
+            .expect("write to vec");
+        writer
+            .write_row(&(Some(2), None::<u8>))
+            .expect("write to vec");
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).expect("utf8"),
+            "{\"b\":1}\n{\"a\":2}\n"
+        );
+    }
+
+    #[test]
+    fn column_mismatch() {
+        let mut writer = CsvWriter::new(Vec::new(), ["a"]);
+        let error = writer.write_row(&(1_u8, 2_u8)).expect_err("two cells");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(writer.into_inner().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn options_serde() {
+        let options: CsvOptions =
+            serde_json::from_str(r#"{ "null": { "custom": "NA" }, "quote": "always" }"#)
+                .expect("valid options");
+        assert_eq!(
+            options,
+            CsvOptions {
+                null: NullValue::Custom("NA".to_owned()),
+                quote: QuoteStyle::Always,
+                escape: Escape::Double,
+            }
+        );
+        let ndjson: NdjsonOptions =
+            serde_json::from_str(r#"{ "skip_nulls": true }"#).expect("valid options");
+        assert!(ndjson.skip_nulls);
+        assert_eq!(
+            serde_json::from_str::<NullValue>(r#""null""#).expect("valid null value"),
+            NullValue::Null
+        );
+    }
+}
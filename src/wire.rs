@@ -0,0 +1,199 @@
+//! Length-delimited framing of encoded data over byte streams.
+//!
+//! Every frame is written as its length, encoded as a little-endian `u32`,
+//! followed by the frame bytes.
+
+use std::io::{self, Read, Write};
+
+/// The number of bytes used to encode the length of a frame.
+const LENGTH_PREFIX_LEN: usize = 4;
+
+/// The default maximum length of a frame read by a [`FrameReader`].
+pub const DEFAULT_MAX_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+/// Writes length-prefixed frames to a [`Write`] implementation.
+#[derive(Debug)]
+pub struct FrameWriter<W> {
+    /// The underlying writer.
+    inner: W,
+}
+
+impl<W: Write> FrameWriter<W> {
+    /// Returns a new [`FrameWriter`] writing to `inner`.
+    pub const fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Writes `frame` prefixed with its length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `frame` does not fit in a frame, or when writing
+    /// to the underlying writer fails.
+    pub fn write_frame(&mut self, frame: &[u8]) -> io::Result<()> {
+        let len = u32::try_from(frame.len()).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("frame length (is {}) should fit in a u32", frame.len()),
+            )
+        })?;
+        self.inner.write_all(&len.to_le_bytes())?;
+        self.inner.write_all(frame)
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when flushing the underlying writer fails.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads length-prefixed frames from a [`Read`] implementation.
+#[derive(Debug)]
+pub struct FrameReader<R> {
+    /// The underlying reader.
+    inner: R,
+    /// Frames with a length exceeding this value are rejected.
+    max_frame_len: usize,
+}
+
+impl<R: Read> FrameReader<R> {
+    /// Returns a new [`FrameReader`] reading from `inner`, rejecting frames
+    /// longer than [`DEFAULT_MAX_FRAME_LEN`].
+    pub const fn new(inner: R) -> Self {
+        Self::with_max_frame_len(inner, DEFAULT_MAX_FRAME_LEN)
+    }
+
+    /// Returns a new [`FrameReader`] reading from `inner`, rejecting frames
+    /// longer than `max_frame_len`.
+    pub const fn with_max_frame_len(inner: R, max_frame_len: usize) -> Self {
+        Self {
+            inner,
+            max_frame_len,
+        }
+    }
+
+    /// Reads the next frame, replacing the contents of `frame`.
+    ///
+    /// Returns `false` when the underlying reader is at the end of the stream
+    /// before the start of a frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the length of the frame exceeds the maximum frame
+    /// length, when the stream ends in the middle of a frame, or when reading
+    /// from the underlying reader fails.
+    pub fn read_frame(&mut self, frame: &mut Vec<u8>) -> io::Result<bool> {
+        let mut prefix = [0; LENGTH_PREFIX_LEN];
+        let mut filled = 0;
+        while filled < LENGTH_PREFIX_LEN {
+            match self.inner.read(&mut prefix[filled..]) {
+                Ok(0) if filled == 0 => return Ok(false),
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        let len = usize::try_from(u32::from_le_bytes(prefix)).unwrap_or(usize::MAX);
+        if len > self.max_frame_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "frame length (is {len}) should be <= max frame length (is {})",
+                    self.max_frame_len
+                ),
+            ));
+        }
+
+        frame.clear();
+        frame.resize(len, 0);
+        self.inner.read_exact(frame)?;
+        Ok(true)
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Iterator for FrameReader<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut frame = Vec::new();
+        self.read_frame(&mut frame)
+            .map(|more| more.then_some(frame))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn round_trip() {
+        let mut writer = FrameWriter::new(Vec::new());
+        writer.write_frame(b"hello").expect("write");
+        writer.write_frame(&[]).expect("write");
+        writer.write_frame(b"world").expect("write");
+        let bytes = writer.into_inner();
+        assert_eq!(&bytes[..9], b"\x05\x00\x00\x00hello");
+
+        let reader = FrameReader::new(Cursor::new(bytes));
+        let frames = reader.collect::<io::Result<Vec<_>>>().expect("read");
+        assert_eq!(frames, [b"hello".to_vec(), Vec::new(), b"world".to_vec()]);
+    }
+
+    #[test]
+    fn truncated() {
+        let mut reader = FrameReader::new(Cursor::new(b"\x05\x00\x00\x00hel".to_vec()));
+        let mut frame = Vec::new();
+        assert_eq!(
+            reader.read_frame(&mut frame).map_err(|error| error.kind()),
+            Err(io::ErrorKind::UnexpectedEof)
+        );
+
+        let mut reader_prefix = FrameReader::new(Cursor::new(b"\x05\x00".to_vec()));
+        assert_eq!(
+            reader_prefix
+                .read_frame(&mut frame)
+                .map_err(|error| error.kind()),
+            Err(io::ErrorKind::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn max_frame_len() {
+        let mut writer = FrameWriter::new(Vec::new());
+        writer.write_frame(b"hello").expect("write");
+        let mut reader = FrameReader::with_max_frame_len(Cursor::new(writer.into_inner()), 4);
+        let mut frame = Vec::new();
+        assert_eq!(
+            reader.read_frame(&mut frame).map_err(|error| error.kind()),
+            Err(io::ErrorKind::InvalidData)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-#[cfg(feature = "derive")]
+#[cfg(all(feature = "derive", feature = "lists", feature = "strings"))]
 mod tests {
     mod derive {
         mod r#enum {
@@ -121,6 +121,13 @@ mod tests {
                     assert_eq!(array.len(), 2);
                 }
 
+                #[test]
+                fn to_rows() {
+                    let input = [Foo; 3];
+                    let array = input.into_iter().collect::<StructArray<Foo>>();
+                    assert_eq!(array.to_rows().len(), 3);
+                }
+
                 #[test]
                 fn nested() {
                     let input = vec![
@@ -150,7 +157,7 @@ mod tests {
                 use narrow::{
                     array::{StructArray, VariableSizeListArray},
                     bitmap::ValidityBitmap,
-                    ArrayType, Length,
+                    ArrayType, CollectWithCapacity, Length, ShrinkToFit, WithCapacity,
                 };
 
                 #[derive(ArrayType, Default)]
@@ -198,6 +205,31 @@ mod tests {
                     assert_eq!(array.len(), 2);
                 }
 
+                #[test]
+                fn with_capacity() {
+                    let mut array = StructArray::<Foo>::with_capacity(4);
+                    assert!(array.0 .0 .0.capacity() >= 4);
+                    assert!(array.0 .2 .0 .0.offsets.capacity() >= 5);
+                    array.extend([Foo(1, 2, "a")]);
+                    assert_eq!(array.len(), 1);
+
+                    let array: StructArray<Foo, true> = [Some(Foo(1, 2, "n")), None]
+                        .into_iter()
+                        .collect_with_capacity(8);
+                    assert_eq!(array.len(), 2);
+                    assert_eq!(array.null_count(), 1);
+                }
+
+                #[test]
+                fn shrink_to_fit() {
+                    let mut array = StructArray::<Foo>::with_capacity(64);
+                    array.extend([Foo(1, 2, "a"), Foo(3, 4, "")]);
+                    array.shrink_to_fit();
+                    assert_eq!(array.0 .0 .0.capacity(), 2);
+                    assert_eq!(array.0 .2 .0 .0.offsets.capacity(), 3);
+                    assert_eq!(array.0 .2 .0 .0.data.0.capacity(), 1);
+                }
+
                 #[test]
                 fn generic() {
                     let input = [
@@ -223,12 +255,42 @@ mod tests {
                 }
             }
 
+            #[cfg(feature = "json")]
+            mod json {
+                use narrow::{
+                    json::{infer_fields, to_struct_array},
+                    ArrayType, Length,
+                };
+                use serde_json::json;
+
+                #[derive(ArrayType, serde::Deserialize)]
+                struct Point {
+                    x: i64,
+                    y: Option<String>,
+                }
+
+                #[test]
+                fn struct_array() {
+                    let values = [json!({ "x": 1, "y": "a" }), json!({ "x": 2 })];
+                    let fields = infer_fields(&values).expect("compatible values");
+                    assert_eq!(fields.len(), 2);
+                    assert!(!fields[0].nullable && fields[1].nullable);
+                    let array = to_struct_array::<Point>(&values).expect("points");
+                    assert_eq!(array.len(), 2);
+                    assert!(to_struct_array::<Point>(&[json!({ "y": "b" })]).is_err());
+                }
+            }
+
             #[cfg(not(feature = "arrow-rs"))]
             mod named {
                 use narrow::{
-                    array::{StructArray, VariableSizeListArray},
+                    array::{
+                        StructArray, StructArrayChildren, StructArrayError, VariableSizeListArray,
+                    },
                     bitmap::{BitmapRef, ValidityBitmap},
-                    ArrayType, Length,
+                    projection::{ColumnId, FieldNames, ProjectionMask, UnknownField},
+                    table::Table,
+                    ArrayType, ByteLength, Length,
                 };
 
                 #[derive(ArrayType)]
@@ -254,6 +316,7 @@ mod tests {
                 }
 
                 #[derive(ArrayType, Default)]
+                #[narrow(columns)]
                 struct Bar<T> {
                     a: u32,
                     b: Option<bool>,
@@ -370,6 +433,299 @@ mod tests {
                     assert_eq!(array.len(), 2);
                 }
 
+                #[test]
+                fn field_names() {
+                    assert_eq!(<Bar<()> as FieldNames>::FIELD_NAMES, ["a", "b", "c"]);
+                    assert_eq!(
+                        ProjectionMask::from_subset::<Foo<str>, FooBar>(),
+                        Err(UnknownField("foo".to_owned()))
+                    );
+                    assert_eq!(
+                        ProjectionMask::from_subset::<Foo<str>, Bar<()>>()
+                            .map(|mask| mask.selected_indices().collect::<Vec<_>>()),
+                        Ok(vec![0, 1, 2])
+                    );
+                }
+
+                #[test]
+                fn columns() {
+                    assert_eq!(
+                        BarColumns::COLUMNS,
+                        [BarColumns::A, BarColumns::B, BarColumns::C]
+                    );
+                    assert_eq!(BarColumns::B.column_name(), "b");
+                    assert_eq!(BarColumns::C.column_index(), 2);
+                    assert_eq!(
+                        ProjectionMask::from_columns([BarColumns::C, BarColumns::A]),
+                        ProjectionMask::from_indices(3, [0, 2])
+                    );
+                }
+
+                #[test]
+                fn try_new() {
+                    let array = StructArray::<Bar<()>>::try_new(BarArray {
+                        a: [1, 2].into_iter().collect(),
+                        b: [Some(true), None].into_iter().collect(),
+                        c: [(), ()].into_iter().collect(),
+                    })
+                    .expect("equal lengths");
+                    assert_eq!(array.len(), 2);
+
+                    let error = StructArray::<Bar<()>>::try_new(BarArray {
+                        a: [1, 2].into_iter().collect(),
+                        b: [Some(true)].into_iter().collect(),
+                        c: [(), ()].into_iter().collect(),
+                    })
+                    .err();
+                    assert_eq!(
+                        error,
+                        Some(StructArrayError::LengthMismatch {
+                            field: "b",
+                            len: 1,
+                            expected: 2
+                        })
+                    );
+                    assert_eq!(
+                        error.map(|error| error.to_string()).as_deref(),
+                        Some("length of field `b` (is 1) should be equal to 2")
+                    );
+
+                    let nullable = StructArray::<Bar<()>, true>::try_new(
+                        BarArray {
+                            a: [1, 2].into_iter().collect(),
+                            b: [Some(true), None].into_iter().collect(),
+                            c: [(), ()].into_iter().collect(),
+                        },
+                        [true].into_iter().collect(),
+                    )
+                    .err();
+                    assert_eq!(
+                        nullable,
+                        Some(StructArrayError::ValidityLengthMismatch {
+                            len: 1,
+                            expected: 2
+                        })
+                    );
+
+                    #[derive(ArrayType)]
+                    struct Raw {
+                        r#type: u8,
+                    }
+                    let raw = StructArray::<Raw>::try_new(RawArray {
+                        r#type: [1, 2].into_iter().collect(),
+                    })
+                    .expect("single child");
+                    assert_eq!(StructArrayChildren::child_lengths(&raw.0), [("type", 2)]);
+                }
+
+                #[test]
+                fn to_rows() {
+                    let input = [
+                        Bar {
+                            a: 1,
+                            b: Some(true),
+                            c: (),
+                        },
+                        Bar {
+                            a: 2,
+                            b: None,
+                            c: (),
+                        },
+                    ];
+                    let array = input.into_iter().collect::<StructArray<Bar<()>>>();
+                    let rows = array.to_rows();
+                    assert_eq!(
+                        rows.iter().map(|row| (row.a, row.b)).collect::<Vec<_>>(),
+                        [(1, Some(true)), (2, None)]
+                    );
+
+                    let chunks = (1..=3)
+                        .map(|a| Bar { a, b: None, c: () })
+                        .collect::<StructArray<Bar<()>>>()
+                        .to_row_chunks(2)
+                        .map(|chunk| chunk.iter().map(|row| row.a).collect::<Vec<_>>())
+                        .collect::<Vec<_>>();
+                    assert_eq!(chunks, [vec![1, 2], vec![3]]);
+
+                    let input_nullable = [
+                        Some(FooBar {
+                            foo: true,
+                            bar: Bar {
+                                a: 3,
+                                b: Some(false),
+                                c: (),
+                            },
+                        }),
+                        None,
+                    ];
+                    let array_nullable = input_nullable
+                        .into_iter()
+                        .collect::<StructArray<FooBar, true>>();
+                    let rows_nullable = array_nullable
+                        .into_iter()
+                        .map(|row| row.map(|foo_bar| (foo_bar.foo, foo_bar.bar.a)))
+                        .collect::<Vec<_>>();
+                    assert_eq!(rows_nullable, [Some((true, 3)), None]);
+                }
+
+                #[test]
+                fn table() {
+                    let batch = |values: &[u32]| {
+                        values
+                            .iter()
+                            .map(|&a| Bar { a, b: None, c: () })
+                            .collect::<StructArray<Bar<()>>>()
+                    };
+                    let mut table = [batch(&[1, 2]), batch(&[]), batch(&[3])]
+                        .into_iter()
+                        .collect::<Table<Bar<()>>>();
+                    table.append(batch(&[4, 5, 6]));
+                    assert_eq!(table.len(), 6);
+                    assert_eq!(table.batches().len(), 3);
+
+                    let slice = table.slice(1..5);
+                    assert_eq!(slice.batches().len(), 3);
+                    let rows = slice.into_batch().to_rows();
+                    assert_eq!(
+                        rows.iter().map(|row| row.a).collect::<Vec<_>>(),
+                        [2, 3, 4, 5]
+                    );
+
+                    let mut consolidated = Table::from(batch(&[1]));
+                    consolidated.extend([batch(&[2, 3])]);
+                    consolidated.consolidate();
+                    assert_eq!(consolidated.batches().len(), 1);
+                    assert_eq!(consolidated.len(), 3);
+                    assert!(Table::<Bar<()>>::default().into_batch().is_empty());
+                }
+
+                #[test]
+                fn byte_len() {
+                    let batch = [
+                        Bar {
+                            a: 1,
+                            b: Some(true),
+                            c: (),
+                        },
+                        Bar {
+                            a: 2,
+                            b: None,
+                            c: (),
+                        },
+                    ]
+                    .into_iter()
+                    .collect::<StructArray<Bar<()>>>();
+                    // 2 u32 values, 1 byte of bool values and 1 byte of
+                    // validity.
+                    assert_eq!(batch.byte_len(), 10);
+                    let table = Table::from_iter([batch]);
+                    assert_eq!(table.len(), 2);
+                    assert_eq!(table.byte_len(), 10);
+                }
+
+                #[test]
+                #[cfg(feature = "compute")]
+                fn partition_map() {
+                    use narrow::compute::{col, partition_map};
+
+                    let batch = [
+                        (1, Some(true)),
+                        (2, None),
+                        (3, Some(true)),
+                        (4, Some(false)),
+                    ]
+                    .into_iter()
+                    .map(|(a, b)| Bar { a, b, c: () })
+                    .collect::<StructArray<Bar<()>>>();
+                    let partitions = partition_map(&batch, &col::<Bar<()>, _>(|bar| &bar.b));
+                    assert_eq!(partitions.len(), 3);
+                    assert_eq!(
+                        partitions[&Some(true)]
+                            .0
+                            .a
+                            .iter()
+                            .copied()
+                            .collect::<Vec<_>>(),
+                        [1, 3]
+                    );
+                    assert_eq!(partitions[&None].len(), 1);
+                    assert_eq!(partitions[&Some(false)].len(), 1);
+                }
+
+                #[test]
+                #[cfg(feature = "io-text")]
+                fn text() {
+                    use narrow::text::{CsvWriter, NdjsonWriter};
+
+                    #[derive(ArrayType)]
+                    struct Point {
+                        x: i64,
+                        y: Option<f64>,
+                    }
+
+                    let array = [Point { x: 1, y: Some(0.5) }, Point { x: 2, y: None }]
+                        .into_iter()
+                        .collect::<StructArray<Point>>();
+                    let mut csv = CsvWriter::new(Vec::new(), Point::FIELD_NAMES.iter().copied());
+                    csv.write_rows(array).expect("write to vec");
+                    assert_eq!(csv.into_inner(), b"x,y\n1,0.5\n2,\n");
+
+                    let rows = [Point { x: 3, y: None }];
+                    let mut ndjson =
+                        NdjsonWriter::new(Vec::new(), Point::FIELD_NAMES.iter().copied());
+                    ndjson.write_rows(&rows).expect("write to vec");
+                    assert_eq!(ndjson.into_inner(), b"{\"x\":3,\"y\":null}\n");
+                }
+
+                #[test]
+                fn table_index() {
+                    let batch = |values: &[u32]| {
+                        values
+                            .iter()
+                            .map(|&a| Bar { a, b: None, c: () })
+                            .collect::<StructArray<Bar<()>>>()
+                    };
+                    let mut indexed = Table::from(batch(&[1, 2, 1])).build_index(|bar| &bar.a);
+                    indexed.append(batch(&[2, 3]));
+                    assert_eq!(indexed.lookup(&1), [0, 2]);
+                    assert_eq!(indexed.lookup(&2), [1, 3]);
+                    assert!(indexed.lookup(&4).is_empty());
+                    assert_eq!(indexed.table().locate(4), Some((1, 1)));
+                    assert_eq!(indexed.table().locate(5), None);
+                }
+
+                #[test]
+                #[cfg(feature = "compute")]
+                fn selection() {
+                    use narrow::compute::{col, Predicate};
+
+                    let input = [
+                        Bar {
+                            a: 1,
+                            b: Some(true),
+                            c: (),
+                        },
+                        Bar {
+                            a: 2,
+                            b: None,
+                            c: (),
+                        },
+                        Bar {
+                            a: 3,
+                            b: Some(false),
+                            c: (),
+                        },
+                    ];
+                    let array = input.into_iter().collect::<StructArray<Bar<()>>>();
+                    let predicate = col::<Bar<()>, _>(|bar| &bar.a)
+                        .gt(1)
+                        .and(col::<Bar<()>, _>(|bar| &bar.b).eq(true).not());
+                    assert_eq!(
+                        array.selection(&predicate).into_iter().collect::<Vec<_>>(),
+                        [false, false, true]
+                    );
+                }
+
                 #[test]
                 fn generic() {
                     let input = [